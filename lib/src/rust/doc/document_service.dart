@@ -7,14 +7,172 @@ import '../frb_generated.dart';
 import 'document_types.dart';
 import 'package:flutter_rust_bridge/flutter_rust_bridge_for_generated.dart';
 
+// These functions are ignored because they are not marked as `pub`: `document_state`, `encode_output`, `ensure_undo_manager`, `replace_doc`, `resubscribe_changes`, `set_change_listener`, `write_actions`
+
 // Rust type: RustOpaqueMoi<flutter_rust_bridge::for_generated::RustAutoOpaqueInner<DocumentService>>
 abstract class DocumentService implements RustOpaqueInterface {
   Future<Uint8List> applyAction({required List<BlockActionDoc> actions});
 
+  Future<AwarenessChangesDoc> applyAwarenessUpdate({required List<int> update});
+
   Future<void> applyUpdates({required List<Uint8List> updates});
 
+  /// Apply remote updates and return the editor actions that reproduce them, so only the
+  /// affected nodes need to be patched instead of rebuilding from the whole state
+  Future<AppliedUpdateActions> applyUpdatesAsActions({
+    required List<Uint8List> updates,
+  });
+
+  /// Apply only new updates onto the live document instead of replaying the whole history
+  Future<AppliedUpdates> applyUpdatesIncremental({
+    required List<Uint8List> updates,
+  });
+
+  /// Rebuild the document from tagged updates, skipping the corrupt ones and reporting their ids
+  Future<FailedToDecodeUpdates> applyUpdatesTolerant({
+    required List<UpdateWithId> updates,
+  });
+
+  Future<bool> canRedo();
+
+  Future<bool> canUndo();
+
+  /// To be called periodically, y-protocols uses a 30 second timeout checked every 3 seconds
+  Future<AwarenessTimeoutDoc> checkAwarenessTimeouts({
+    required BigInt timeoutMillis,
+  });
+
+  /// Mark the local client as offline, to be broadcast before disconnecting
+  Future<Uint8List> clearLocalAwareness();
+
+  /// The yrs client id of this document, to be persisted and passed back on next launch
+  Future<BigInt> clientId();
+
+  /// Checkpoint update of the current document
+  Future<CompactedUpdate> compactDocument({
+    required bool gc,
+    required UpdateEncodingDoc encoding,
+  });
+
+  /// Squash a stored update log into one checkpoint update, optionally dropping deleted
+  /// content. Does not touch the live document.
+  Future<CompactedUpdate> compactUpdates({
+    required List<Uint8List> updates,
+    required bool gc,
+    required UpdateEncodingDoc encoding,
+  });
+
+  /// Re-encode an update for peers using the other encoding, detecting its current one when `from` is None
+  static Future<Uint8List> convertUpdate({
+    required List<int> update,
+    UpdateEncodingDoc? from,
+    required UpdateEncodingDoc to,
+  }) =>
+      RustLib.instance.api.crateDocDocumentServiceDocumentServiceConvertUpdate(
+        update: update,
+        from: from,
+        to: to,
+      );
+
+  /// Store a named snapshot of the current state. Requires a document created with skip_gc,
+  /// otherwise deleted content needed to render old versions is already gone. The snapshot
+  /// is returned for the host to persist and list, the document itself is left unchanged.
+  Future<CreatedSnapshot> createSnapshot({required String label});
+
+  /// Current UTF-16 offset of an encoded position in the text of the block it was encoded in,
+  /// None once that text was deleted
+  Future<TextPositionDoc?> decodeStickyPosition({
+    required String blockId,
+    required List<int> position,
+  });
+
+  static Future<List<SyncMessageDoc>> decodeSyncMessages({
+    required List<int> data,
+  }) => RustLib.instance.api
+      .crateDocDocumentServiceDocumentServiceDecodeSyncMessages(data: data);
+
+  /// Encoding of an update, None when it decodes as neither v1 nor v2
+  static Future<UpdateEncodingDoc?> detectUpdateEncoding({
+    required List<int> update,
+  }) => RustLib.instance.api
+      .crateDocDocumentServiceDocumentServiceDetectUpdateEncoding(
+        update: update,
+      );
+
+  Future<String> docId();
+
+  /// Switch the document from prevId chains to fractional position keys, keeping the
+  /// current order. Returns the update to broadcast; peers order by the keys once they apply it.
+  Future<Uint8List> enableFractionalOrdering();
+
+  /// Start tracking local `apply_action` edits for undo. Edits closer together than the
+  /// capture timeout are undone as one step; remote updates are never undone.
+  Future<void> enableUndo({required BigInt captureTimeoutMillis});
+
+  /// Awareness update with every known client, for peers that just connected
+  Future<Uint8List> encodeAwarenessUpdate();
+
+  /// Encode only the data a remote peer is missing, given its state vector in the same encoding
+  Future<Uint8List> encodeDiffSince({
+    required List<int> stateVector,
+    required UpdateEncodingDoc encoding,
+  });
+
+  /// Current state vector of the document, the first step of a state vector based sync
+  Future<Uint8List> encodeStateVector({required UpdateEncodingDoc encoding});
+
+  /// Encode a UTF-16 offset in the text of a block as a y.js compatible relative position,
+  /// which keeps pointing at the same character while remote edits are applied
+  Future<Uint8List> encodeStickyPosition({
+    required TextPositionDoc position,
+    required StickyAssocDoc assoc,
+  });
+
+  static Future<Uint8List> encodeSyncMessage({
+    required SyncMessageDoc message,
+  }) => RustLib.instance.api
+      .crateDocDocumentServiceDocumentServiceEncodeSyncMessage(
+        message: message,
+      );
+
+  /// Wrap an update returned by `apply_action` or `undo` into a y-sync Update message
+  static Future<Uint8List> encodeUpdateMessage({
+    required List<int> update,
+    required UpdateEncodingDoc encoding,
+  }) => RustLib.instance.api
+      .crateDocDocumentServiceDocumentServiceEncodeUpdateMessage(
+        update: update,
+        encoding: encoding,
+      );
+
+  /// Render the current document as an HTML fragment, safe to embed in emails and share pages
+  Future<String> exportHtml();
+
+  /// Render the current document as GFM Markdown
+  Future<String> exportMarkdown();
+
+  /// Presence of every connected client, the local one included
+  Future<List<AwarenessPeerDoc>> getAwarenessPeers();
+
   Future<DocumentState> getDocumentState();
 
+  /// Document state as it was when the snapshot was taken
+  Future<DocumentState> getDocumentStateAtSnapshot({
+    required List<int> snapshot,
+  });
+
+  /// Consume an incoming y-sync payload and produce the reply for its sender.
+  /// Sync step 1 is answered with step 2, updates and awareness are applied locally.
+  Future<SyncReplyDoc> handleSyncMessage({required List<int> message});
+
+  /// Initialize the document from AppFlowy editor JSON, either `Document.toJson()` output or
+  /// the flat JSON of `Conversion::document_to_json`, returning the update that creates it
+  Future<Uint8List> initDocFromJson({required String json});
+
+  /// Initialize the document from CommonMark/GFM Markdown instead of an empty page,
+  /// returning the update that creates it
+  Future<Uint8List> initDocFromMarkdown({required String markdown});
+
   Future<Uint8List> initEmptyDoc();
 
   Future<Uint8List> mergeUpdates({required List<Uint8List> updates});
@@ -23,6 +181,48 @@ abstract class DocumentService implements RustOpaqueInterface {
   static Future<DocumentService> newInstance() =>
       RustLib.instance.api.crateDocDocumentServiceDocumentServiceNew();
 
+  /// Create a service for a known document id. Passing a persisted client id keeps the
+  /// device under the same yrs client across restarts; it must never be shared between devices.
+  static Future<DocumentService> newWithOptions({
+    required String docId,
+    required DocumentOptions options,
+  }) =>
+      RustLib.instance.api.crateDocDocumentServiceDocumentServiceNewWithOptions(
+        docId: docId,
+        options: options,
+      );
+
+  Future<UndoRedoResult> redo();
+
+  /// Cut prevId cycles left by concurrent moves and relink blocks whose prev block is gone,
+  /// so the chains describe the order that is shown
+  Future<ChainRepairResult> repairChains();
+
+  /// Restore the document to a snapshot, returning the forward update that does it
+  Future<Uint8List> restoreSnapshot({required List<int> snapshot});
+
+  /// Publish the local user and selection. Returns the awareness update to broadcast to peers.
+  Future<Uint8List> setLocalAwareness({required AwarenessStateDoc state});
+
   /// Setting a root node id in the root map
   Future<Uint8List> setRootNodeId({required String id});
+
+  Future<SiblingOrderingDoc> siblingOrdering();
+
+  /// Messages opening a y-sync connection: sync step 1 with our state vector and our awareness
+  Future<Uint8List> startSync();
+
+  /// End the current undo group so the next edit starts a new step
+  Future<void> stopUndoCapturing();
+
+  /// Push block level change events of every committed transaction to `sink`, replacing
+  /// any previous subscription. The subscription survives documents rebuilt from updates.
+  Stream<DocumentChangeEvent> subscribeChanges();
+
+  Future<UndoRedoResult> undo();
+
+  Future<void> unsubscribeChanges();
+
+  /// Report structural problems of the blocks map, and with `fix` return the update solving them
+  Future<IntegrityReport> validateDocument({required bool fix});
 }
@@ -12,7 +12,125 @@ import '../frb_generated.dart';
 part 'document_types.freezed.dart';
 part 'document_types.g.dart';
 
-// These function are ignored because they are on traits that is not defined in current crate (put an empty `#[frb]` on it to unignore): `fmt`, `fmt`, `fmt`, `fmt`, `fmt`, `fmt`, `fmt`
+// These functions are ignored because they are not marked as `pub`: `at_action`
+// These function are ignored because they are on traits that is not defined in current crate (put an empty `#[frb]` on it to unignore): `assert_fields_are_eq`, `assert_fields_are_eq`, `assert_fields_are_eq`, `assert_fields_are_eq`, `assert_fields_are_eq`, `assert_fields_are_eq`, `assert_fields_are_eq`, `assert_fields_are_eq`, `assert_fields_are_eq`, `clone`, `clone`, `clone`, `clone`, `clone`, `clone`, `clone`, `clone`, `clone`, `clone`, `clone`, `clone`, `clone`, `clone`, `clone`, `clone`, `clone`, `clone`, `clone`, `clone`, `clone`, `clone`, `clone`, `clone`, `clone`, `clone`, `eq`, `eq`, `eq`, `eq`, `eq`, `eq`, `eq`, `eq`, `eq`, `eq`, `eq`, `eq`, `eq`, `eq`, `eq`, `eq`, `eq`, `fmt`, `fmt`, `fmt`, `fmt`, `fmt`, `fmt`, `fmt`, `fmt`, `fmt`, `fmt`, `fmt`, `fmt`, `fmt`, `fmt`, `fmt`, `fmt`, `fmt`, `fmt`, `fmt`, `fmt`, `fmt`, `fmt`, `fmt`, `fmt`, `fmt`, `fmt`, `fmt`, `fmt`, `fmt`, `fmt`, `fmt`, `fmt`, `fmt`, `fmt`, `fmt`, `fmt`
+
+@freezed
+class AppliedUpdateActions with _$AppliedUpdateActions {
+  const factory AppliedUpdateActions({
+    required List<BlockActionDoc> actions,
+    required bool requiresFullReload,
+  }) = _AppliedUpdateActions;
+
+  factory AppliedUpdateActions.fromJson(Map<String, dynamic> json) =>
+      _$AppliedUpdateActionsFromJson(json);
+}
+
+@freezed
+class AppliedUpdates with _$AppliedUpdates {
+  const factory AppliedUpdates({
+    required List<String> changedBlockIds,
+    required List<String> deletedBlockIds,
+    required bool rootIdChanged,
+    required bool hasPendingUpdates,
+  }) = _AppliedUpdates;
+
+  factory AppliedUpdates.fromJson(Map<String, dynamic> json) =>
+      _$AppliedUpdatesFromJson(json);
+}
+
+enum AttributeModeDoc {
+  strings,
+  json;
+
+  static Future<AttributeModeDoc> default_() =>
+      RustLib.instance.api.crateDocDocumentTypesAttributeModeDocDefault();
+}
+
+@freezed
+class AwarenessChangesDoc with _$AwarenessChangesDoc {
+  const AwarenessChangesDoc._();
+  const factory AwarenessChangesDoc({
+    @Uint64ListConverter() required Uint64List added,
+    @Uint64ListConverter() required Uint64List updated,
+    @Uint64ListConverter() required Uint64List removed,
+  }) = _AwarenessChangesDoc;
+  static Future<AwarenessChangesDoc> default_() =>
+      RustLib.instance.api.crateDocDocumentTypesAwarenessChangesDocDefault();
+
+  factory AwarenessChangesDoc.fromJson(Map<String, dynamic> json) =>
+      _$AwarenessChangesDocFromJson(json);
+}
+
+@freezed
+class AwarenessPeerDoc with _$AwarenessPeerDoc {
+  const factory AwarenessPeerDoc({
+    required BigInt clientId,
+    required int clock,
+    required BigInt lastUpdated,
+    required bool isLocal,
+    AwarenessUserDoc? user,
+    AwarenessSelectionDoc? selection,
+  }) = _AwarenessPeerDoc;
+
+  factory AwarenessPeerDoc.fromJson(Map<String, dynamic> json) =>
+      _$AwarenessPeerDocFromJson(json);
+}
+
+@freezed
+class AwarenessSelectionDoc with _$AwarenessSelectionDoc {
+  const factory AwarenessSelectionDoc({
+    required TextPositionDoc anchor,
+    required TextPositionDoc head,
+  }) = _AwarenessSelectionDoc;
+
+  factory AwarenessSelectionDoc.fromJson(Map<String, dynamic> json) =>
+      _$AwarenessSelectionDocFromJson(json);
+}
+
+@freezed
+class AwarenessStateDoc with _$AwarenessStateDoc {
+  const factory AwarenessStateDoc({
+    required AwarenessUserDoc user,
+    AwarenessSelectionDoc? selection,
+  }) = _AwarenessStateDoc;
+
+  factory AwarenessStateDoc.fromJson(Map<String, dynamic> json) =>
+      _$AwarenessStateDocFromJson(json);
+}
+
+class AwarenessTimeoutDoc {
+  final Uint64List removed;
+  final Uint8List? update;
+
+  const AwarenessTimeoutDoc({required this.removed, this.update});
+
+  static Future<AwarenessTimeoutDoc> default_() =>
+      RustLib.instance.api.crateDocDocumentTypesAwarenessTimeoutDocDefault();
+
+  @override
+  int get hashCode => removed.hashCode ^ update.hashCode;
+
+  @override
+  bool operator ==(Object other) =>
+      identical(this, other) ||
+      other is AwarenessTimeoutDoc &&
+          runtimeType == other.runtimeType &&
+          removed == other.removed &&
+          update == other.update;
+}
+
+@freezed
+class AwarenessUserDoc with _$AwarenessUserDoc {
+  const factory AwarenessUserDoc({
+    required String id,
+    required String name,
+    String? color,
+  }) = _AwarenessUserDoc;
+
+  factory AwarenessUserDoc.fromJson(Map<String, dynamic> json) =>
+      _$AwarenessUserDocFromJson(json);
+}
 
 @freezed
 class BlockActionDoc with _$BlockActionDoc {
@@ -46,10 +164,79 @@ class BlockDoc with _$BlockDoc {
       _$BlockDocFromJson(json);
 }
 
+class ChainRepairResult {
+  final List<String> cycleBlockIds;
+  final List<String> danglingBlockIds;
+  final Uint8List update;
+
+  const ChainRepairResult({
+    required this.cycleBlockIds,
+    required this.danglingBlockIds,
+    required this.update,
+  });
+
+  @override
+  int get hashCode =>
+      cycleBlockIds.hashCode ^ danglingBlockIds.hashCode ^ update.hashCode;
+
+  @override
+  bool operator ==(Object other) =>
+      identical(this, other) ||
+      other is ChainRepairResult &&
+          runtimeType == other.runtimeType &&
+          cycleBlockIds == other.cycleBlockIds &&
+          danglingBlockIds == other.danglingBlockIds &&
+          update == other.update;
+}
+
+class CompactedUpdate {
+  final Uint8List update;
+  final Uint8List stateVector;
+  final bool hasPendingUpdates;
+
+  const CompactedUpdate({
+    required this.update,
+    required this.stateVector,
+    required this.hasPendingUpdates,
+  });
+
+  @override
+  int get hashCode =>
+      update.hashCode ^ stateVector.hashCode ^ hasPendingUpdates.hashCode;
+
+  @override
+  bool operator ==(Object other) =>
+      identical(this, other) ||
+      other is CompactedUpdate &&
+          runtimeType == other.runtimeType &&
+          update == other.update &&
+          stateVector == other.stateVector &&
+          hasPendingUpdates == other.hasPendingUpdates;
+}
+
+class CreatedSnapshot {
+  final SnapshotInfo snapshot;
+  final Uint8List data;
+
+  const CreatedSnapshot({required this.snapshot, required this.data});
+
+  @override
+  int get hashCode => snapshot.hashCode ^ data.hashCode;
+
+  @override
+  bool operator ==(Object other) =>
+      identical(this, other) ||
+      other is CreatedSnapshot &&
+          runtimeType == other.runtimeType &&
+          snapshot == other.snapshot &&
+          data == other.data;
+}
+
 class CustomRustError implements FrbException {
   final String message;
+  final int? actionIndex;
 
-  const CustomRustError({required this.message});
+  const CustomRustError({required this.message, this.actionIndex});
 
   // HINT: Make it `#[frb(sync)]` to let it become the default constructor of Dart class.
   static Future<CustomRustError> newInstance({required String message}) =>
@@ -58,14 +245,53 @@ class CustomRustError implements FrbException {
       );
 
   @override
-  int get hashCode => message.hashCode;
+  int get hashCode => message.hashCode ^ actionIndex.hashCode;
 
   @override
   bool operator ==(Object other) =>
       identical(this, other) ||
       other is CustomRustError &&
           runtimeType == other.runtimeType &&
-          message == other.message;
+          message == other.message &&
+          actionIndex == other.actionIndex;
+}
+
+@freezed
+class DocumentChangeEvent with _$DocumentChangeEvent {
+  const factory DocumentChangeEvent({
+    required DocumentChangeKindDoc kind,
+    required String blockId,
+    String? delta,
+    String? origin,
+  }) = _DocumentChangeEvent;
+
+  factory DocumentChangeEvent.fromJson(Map<String, dynamic> json) =>
+      _$DocumentChangeEventFromJson(json);
+}
+
+enum DocumentChangeKindDoc {
+  blockInserted,
+  blockUpdated,
+  blockDeleted,
+  blockMoved,
+  textDeltaChanged,
+}
+
+@freezed
+class DocumentOptions with _$DocumentOptions {
+  const DocumentOptions._();
+  const factory DocumentOptions({
+    BigInt? clientId,
+    required OffsetKindDoc offsetKind,
+    required bool skipGc,
+    required UpdateEncodingDoc updateEncoding,
+    required AttributeModeDoc attributeMode,
+  }) = _DocumentOptions;
+  static Future<DocumentOptions> default_() =>
+      RustLib.instance.api.crateDocDocumentTypesDocumentOptionsDefault();
+
+  factory DocumentOptions.fromJson(Map<String, dynamic> json) =>
+      _$DocumentOptionsFromJson(json);
 }
 
 @freezed
@@ -90,3 +316,194 @@ class FailedToDecodeUpdates with _$FailedToDecodeUpdates {
   factory FailedToDecodeUpdates.fromJson(Map<String, dynamic> json) =>
       _$FailedToDecodeUpdatesFromJson(json);
 }
+
+@freezed
+class IntegrityIssueDoc with _$IntegrityIssueDoc {
+  const factory IntegrityIssueDoc({
+    required IntegrityIssueKindDoc kind,
+    required List<String> blockIds,
+  }) = _IntegrityIssueDoc;
+
+  factory IntegrityIssueDoc.fromJson(Map<String, dynamic> json) =>
+      _$IntegrityIssueDocFromJson(json);
+}
+
+enum IntegrityIssueKindDoc {
+  missingRoot,
+  missingId,
+  missingType,
+  missingParent,
+  orphan,
+  parentCycle,
+  sharedPrevId,
+}
+
+class IntegrityReport {
+  final List<IntegrityIssueDoc> issues;
+  final Uint8List update;
+
+  const IntegrityReport({required this.issues, required this.update});
+
+  @override
+  int get hashCode => issues.hashCode ^ update.hashCode;
+
+  @override
+  bool operator ==(Object other) =>
+      identical(this, other) ||
+      other is IntegrityReport &&
+          runtimeType == other.runtimeType &&
+          issues == other.issues &&
+          update == other.update;
+}
+
+enum OffsetKindDoc {
+  bytes,
+  utf16;
+
+  static Future<OffsetKindDoc> default_() =>
+      RustLib.instance.api.crateDocDocumentTypesOffsetKindDocDefault();
+}
+
+enum SiblingOrderingDoc {
+  prevIdChain,
+  fractionalIndex;
+
+  static Future<SiblingOrderingDoc> default_() =>
+      RustLib.instance.api.crateDocDocumentTypesSiblingOrderingDocDefault();
+}
+
+@freezed
+class SnapshotInfo with _$SnapshotInfo {
+  const factory SnapshotInfo({
+    required String id,
+    required String label,
+    required PlatformInt64 createdAt,
+  }) = _SnapshotInfo;
+
+  factory SnapshotInfo.fromJson(Map<String, dynamic> json) =>
+      _$SnapshotInfoFromJson(json);
+}
+
+enum StickyAssocDoc {
+  after,
+  before;
+
+  static Future<StickyAssocDoc> default_() =>
+      RustLib.instance.api.crateDocDocumentTypesStickyAssocDocDefault();
+}
+
+class SyncMessageDoc {
+  final SyncMessageKindDoc kind;
+  final Uint8List payload;
+
+  const SyncMessageDoc({required this.kind, required this.payload});
+
+  @override
+  int get hashCode => kind.hashCode ^ payload.hashCode;
+
+  @override
+  bool operator ==(Object other) =>
+      identical(this, other) ||
+      other is SyncMessageDoc &&
+          runtimeType == other.runtimeType &&
+          kind == other.kind &&
+          payload == other.payload;
+}
+
+enum SyncMessageKindDoc {
+  syncStep1,
+  syncStep2,
+  update,
+  awareness,
+  awarenessQuery,
+  auth,
+}
+
+class SyncReplyDoc {
+  final Uint8List? reply;
+  final bool documentChanged;
+  final bool awarenessChanged;
+
+  const SyncReplyDoc({
+    this.reply,
+    required this.documentChanged,
+    required this.awarenessChanged,
+  });
+
+  static Future<SyncReplyDoc> default_() =>
+      RustLib.instance.api.crateDocDocumentTypesSyncReplyDocDefault();
+
+  @override
+  int get hashCode =>
+      reply.hashCode ^ documentChanged.hashCode ^ awarenessChanged.hashCode;
+
+  @override
+  bool operator ==(Object other) =>
+      identical(this, other) ||
+      other is SyncReplyDoc &&
+          runtimeType == other.runtimeType &&
+          reply == other.reply &&
+          documentChanged == other.documentChanged &&
+          awarenessChanged == other.awarenessChanged;
+}
+
+@freezed
+class TextPositionDoc with _$TextPositionDoc {
+  const factory TextPositionDoc({
+    required String blockId,
+    required int offset,
+  }) = _TextPositionDoc;
+
+  factory TextPositionDoc.fromJson(Map<String, dynamic> json) =>
+      _$TextPositionDocFromJson(json);
+}
+
+class UndoRedoResult {
+  final bool changed;
+  final Uint8List update;
+  final List<BlockActionDoc> actions;
+
+  const UndoRedoResult({
+    required this.changed,
+    required this.update,
+    required this.actions,
+  });
+
+  @override
+  int get hashCode => changed.hashCode ^ update.hashCode ^ actions.hashCode;
+
+  @override
+  bool operator ==(Object other) =>
+      identical(this, other) ||
+      other is UndoRedoResult &&
+          runtimeType == other.runtimeType &&
+          changed == other.changed &&
+          update == other.update &&
+          actions == other.actions;
+}
+
+enum UpdateEncodingDoc {
+  v1,
+  v2;
+
+  static Future<UpdateEncodingDoc> default_() =>
+      RustLib.instance.api.crateDocDocumentTypesUpdateEncodingDocDefault();
+}
+
+class UpdateWithId {
+  final String id;
+  final Uint8List update;
+
+  const UpdateWithId({required this.id, required this.update});
+
+  @override
+  int get hashCode => id.hashCode ^ update.hashCode;
+
+  @override
+  bool operator ==(Object other) =>
+      identical(this, other) ||
+      other is UpdateWithId &&
+          runtimeType == other.runtimeType &&
+          id == other.id &&
+          update == other.update;
+}
@@ -15,165 +15,109 @@ final _privateConstructorUsedError = UnsupportedError(
   'It seems like you constructed your class using `MyClass._()`. This constructor is only meant to be used by freezed and you are not supposed to need it nor use it.\nPlease check the documentation here for more information: https://github.com/rrousselGit/freezed#adding-getters-and-methods-to-our-models',
 );
 
-BlockActionDoc _$BlockActionDocFromJson(Map<String, dynamic> json) {
-  return _BlockActionDoc.fromJson(json);
+AppliedUpdateActions _$AppliedUpdateActionsFromJson(Map<String, dynamic> json) {
+  return _AppliedUpdateActions.fromJson(json);
 }
 
 /// @nodoc
-mixin _$BlockActionDoc {
-  BlockActionTypeDoc get action => throw _privateConstructorUsedError;
-  BlockDoc get block => throw _privateConstructorUsedError;
-  @Uint32ListConverter()
-  Uint32List get path => throw _privateConstructorUsedError;
-  @Uint32ListConverter()
-  Uint32List? get oldPath => throw _privateConstructorUsedError;
+mixin _$AppliedUpdateActions {
+  List<BlockActionDoc> get actions => throw _privateConstructorUsedError;
+  bool get requiresFullReload => throw _privateConstructorUsedError;
 
-  /// Serializes this BlockActionDoc to a JSON map.
+  /// Serializes this AppliedUpdateActions to a JSON map.
   Map<String, dynamic> toJson() => throw _privateConstructorUsedError;
 
-  /// Create a copy of BlockActionDoc
+  /// Create a copy of AppliedUpdateActions
   /// with the given fields replaced by the non-null parameter values.
   @JsonKey(includeFromJson: false, includeToJson: false)
-  $BlockActionDocCopyWith<BlockActionDoc> get copyWith =>
+  $AppliedUpdateActionsCopyWith<AppliedUpdateActions> get copyWith =>
       throw _privateConstructorUsedError;
 }
 
 /// @nodoc
-abstract class $BlockActionDocCopyWith<$Res> {
-  factory $BlockActionDocCopyWith(
-    BlockActionDoc value,
-    $Res Function(BlockActionDoc) then,
-  ) = _$BlockActionDocCopyWithImpl<$Res, BlockActionDoc>;
+abstract class $AppliedUpdateActionsCopyWith<$Res> {
+  factory $AppliedUpdateActionsCopyWith(
+    AppliedUpdateActions value,
+    $Res Function(AppliedUpdateActions) then,
+  ) = _$AppliedUpdateActionsCopyWithImpl<$Res, AppliedUpdateActions>;
   @useResult
-  $Res call({
-    BlockActionTypeDoc action,
-    BlockDoc block,
-    @Uint32ListConverter() Uint32List path,
-    @Uint32ListConverter() Uint32List? oldPath,
-  });
-
-  $BlockDocCopyWith<$Res> get block;
+  $Res call({List<BlockActionDoc> actions, bool requiresFullReload});
 }
 
 /// @nodoc
-class _$BlockActionDocCopyWithImpl<$Res, $Val extends BlockActionDoc>
-    implements $BlockActionDocCopyWith<$Res> {
-  _$BlockActionDocCopyWithImpl(this._value, this._then);
+class _$AppliedUpdateActionsCopyWithImpl<
+  $Res,
+  $Val extends AppliedUpdateActions
+>
+    implements $AppliedUpdateActionsCopyWith<$Res> {
+  _$AppliedUpdateActionsCopyWithImpl(this._value, this._then);
 
   // ignore: unused_field
   final $Val _value;
   // ignore: unused_field
   final $Res Function($Val) _then;
 
-  /// Create a copy of BlockActionDoc
+  /// Create a copy of AppliedUpdateActions
   /// with the given fields replaced by the non-null parameter values.
   @pragma('vm:prefer-inline')
   @override
-  $Res call({
-    Object? action = null,
-    Object? block = null,
-    Object? path = null,
-    Object? oldPath = freezed,
-  }) {
+  $Res call({Object? actions = null, Object? requiresFullReload = null}) {
     return _then(
       _value.copyWith(
-            action:
-                null == action
-                    ? _value.action
-                    : action // ignore: cast_nullable_to_non_nullable
-                        as BlockActionTypeDoc,
-            block:
-                null == block
-                    ? _value.block
-                    : block // ignore: cast_nullable_to_non_nullable
-                        as BlockDoc,
-            path:
-                null == path
-                    ? _value.path
-                    : path // ignore: cast_nullable_to_non_nullable
-                        as Uint32List,
-            oldPath:
-                freezed == oldPath
-                    ? _value.oldPath
-                    : oldPath // ignore: cast_nullable_to_non_nullable
-                        as Uint32List?,
+            actions:
+                null == actions
+                    ? _value.actions
+                    : actions // ignore: cast_nullable_to_non_nullable
+                        as List<BlockActionDoc>,
+            requiresFullReload:
+                null == requiresFullReload
+                    ? _value.requiresFullReload
+                    : requiresFullReload // ignore: cast_nullable_to_non_nullable
+                        as bool,
           )
           as $Val,
     );
   }
-
-  /// Create a copy of BlockActionDoc
-  /// with the given fields replaced by the non-null parameter values.
-  @override
-  @pragma('vm:prefer-inline')
-  $BlockDocCopyWith<$Res> get block {
-    return $BlockDocCopyWith<$Res>(_value.block, (value) {
-      return _then(_value.copyWith(block: value) as $Val);
-    });
-  }
 }
 
 /// @nodoc
-abstract class _$$BlockActionDocImplCopyWith<$Res>
-    implements $BlockActionDocCopyWith<$Res> {
-  factory _$$BlockActionDocImplCopyWith(
-    _$BlockActionDocImpl value,
-    $Res Function(_$BlockActionDocImpl) then,
-  ) = __$$BlockActionDocImplCopyWithImpl<$Res>;
+abstract class _$$AppliedUpdateActionsImplCopyWith<$Res>
+    implements $AppliedUpdateActionsCopyWith<$Res> {
+  factory _$$AppliedUpdateActionsImplCopyWith(
+    _$AppliedUpdateActionsImpl value,
+    $Res Function(_$AppliedUpdateActionsImpl) then,
+  ) = __$$AppliedUpdateActionsImplCopyWithImpl<$Res>;
   @override
   @useResult
-  $Res call({
-    BlockActionTypeDoc action,
-    BlockDoc block,
-    @Uint32ListConverter() Uint32List path,
-    @Uint32ListConverter() Uint32List? oldPath,
-  });
-
-  @override
-  $BlockDocCopyWith<$Res> get block;
+  $Res call({List<BlockActionDoc> actions, bool requiresFullReload});
 }
 
 /// @nodoc
-class __$$BlockActionDocImplCopyWithImpl<$Res>
-    extends _$BlockActionDocCopyWithImpl<$Res, _$BlockActionDocImpl>
-    implements _$$BlockActionDocImplCopyWith<$Res> {
-  __$$BlockActionDocImplCopyWithImpl(
-    _$BlockActionDocImpl _value,
-    $Res Function(_$BlockActionDocImpl) _then,
+class __$$AppliedUpdateActionsImplCopyWithImpl<$Res>
+    extends _$AppliedUpdateActionsCopyWithImpl<$Res, _$AppliedUpdateActionsImpl>
+    implements _$$AppliedUpdateActionsImplCopyWith<$Res> {
+  __$$AppliedUpdateActionsImplCopyWithImpl(
+    _$AppliedUpdateActionsImpl _value,
+    $Res Function(_$AppliedUpdateActionsImpl) _then,
   ) : super(_value, _then);
 
-  /// Create a copy of BlockActionDoc
+  /// Create a copy of AppliedUpdateActions
   /// with the given fields replaced by the non-null parameter values.
   @pragma('vm:prefer-inline')
   @override
-  $Res call({
-    Object? action = null,
-    Object? block = null,
-    Object? path = null,
-    Object? oldPath = freezed,
-  }) {
+  $Res call({Object? actions = null, Object? requiresFullReload = null}) {
     return _then(
-      _$BlockActionDocImpl(
-        action:
-            null == action
-                ? _value.action
-                : action // ignore: cast_nullable_to_non_nullable
-                    as BlockActionTypeDoc,
-        block:
-            null == block
-                ? _value.block
-                : block // ignore: cast_nullable_to_non_nullable
-                    as BlockDoc,
-        path:
-            null == path
-                ? _value.path
-                : path // ignore: cast_nullable_to_non_nullable
-                    as Uint32List,
-        oldPath:
-            freezed == oldPath
-                ? _value.oldPath
-                : oldPath // ignore: cast_nullable_to_non_nullable
-                    as Uint32List?,
+      _$AppliedUpdateActionsImpl(
+        actions:
+            null == actions
+                ? _value._actions
+                : actions // ignore: cast_nullable_to_non_nullable
+                    as List<BlockActionDoc>,
+        requiresFullReload:
+            null == requiresFullReload
+                ? _value.requiresFullReload
+                : requiresFullReload // ignore: cast_nullable_to_non_nullable
+                    as bool,
       ),
     );
   }
@@ -181,209 +125,167 @@ class __$$BlockActionDocImplCopyWithImpl<$Res>
 
 /// @nodoc
 @JsonSerializable()
-class _$BlockActionDocImpl implements _BlockActionDoc {
-  const _$BlockActionDocImpl({
-    required this.action,
-    required this.block,
-    @Uint32ListConverter() required this.path,
-    @Uint32ListConverter() this.oldPath,
-  });
+class _$AppliedUpdateActionsImpl implements _AppliedUpdateActions {
+  const _$AppliedUpdateActionsImpl({
+    required final List<BlockActionDoc> actions,
+    required this.requiresFullReload,
+  }) : _actions = actions;
 
-  factory _$BlockActionDocImpl.fromJson(Map<String, dynamic> json) =>
-      _$$BlockActionDocImplFromJson(json);
+  factory _$AppliedUpdateActionsImpl.fromJson(Map<String, dynamic> json) =>
+      _$$AppliedUpdateActionsImplFromJson(json);
 
+  final List<BlockActionDoc> _actions;
   @override
-  final BlockActionTypeDoc action;
-  @override
-  final BlockDoc block;
-  @override
-  @Uint32ListConverter()
-  final Uint32List path;
+  List<BlockActionDoc> get actions {
+    if (_actions is EqualUnmodifiableListView) return _actions;
+    // ignore: implicit_dynamic_type
+    return EqualUnmodifiableListView(_actions);
+  }
+
   @override
-  @Uint32ListConverter()
-  final Uint32List? oldPath;
+  final bool requiresFullReload;
 
   @override
   String toString() {
-    return 'BlockActionDoc(action: $action, block: $block, path: $path, oldPath: $oldPath)';
+    return 'AppliedUpdateActions(actions: $actions, requiresFullReload: $requiresFullReload)';
   }
 
   @override
   bool operator ==(Object other) {
     return identical(this, other) ||
         (other.runtimeType == runtimeType &&
-            other is _$BlockActionDocImpl &&
-            (identical(other.action, action) || other.action == action) &&
-            (identical(other.block, block) || other.block == block) &&
-            const DeepCollectionEquality().equals(other.path, path) &&
-            const DeepCollectionEquality().equals(other.oldPath, oldPath));
+            other is _$AppliedUpdateActionsImpl &&
+            const DeepCollectionEquality().equals(other._actions, _actions) &&
+            (identical(other.requiresFullReload, requiresFullReload) ||
+                other.requiresFullReload == requiresFullReload));
   }
 
   @JsonKey(includeFromJson: false, includeToJson: false)
   @override
   int get hashCode => Object.hash(
     runtimeType,
-    action,
-    block,
-    const DeepCollectionEquality().hash(path),
-    const DeepCollectionEquality().hash(oldPath),
+    const DeepCollectionEquality().hash(_actions),
+    requiresFullReload,
   );
 
-  /// Create a copy of BlockActionDoc
+  /// Create a copy of AppliedUpdateActions
   /// with the given fields replaced by the non-null parameter values.
   @JsonKey(includeFromJson: false, includeToJson: false)
   @override
   @pragma('vm:prefer-inline')
-  _$$BlockActionDocImplCopyWith<_$BlockActionDocImpl> get copyWith =>
-      __$$BlockActionDocImplCopyWithImpl<_$BlockActionDocImpl>(
+  _$$AppliedUpdateActionsImplCopyWith<_$AppliedUpdateActionsImpl>
+  get copyWith =>
+      __$$AppliedUpdateActionsImplCopyWithImpl<_$AppliedUpdateActionsImpl>(
         this,
         _$identity,
       );
 
   @override
   Map<String, dynamic> toJson() {
-    return _$$BlockActionDocImplToJson(this);
+    return _$$AppliedUpdateActionsImplToJson(this);
   }
 }
 
-abstract class _BlockActionDoc implements BlockActionDoc {
-  const factory _BlockActionDoc({
-    required final BlockActionTypeDoc action,
-    required final BlockDoc block,
-    @Uint32ListConverter() required final Uint32List path,
-    @Uint32ListConverter() final Uint32List? oldPath,
-  }) = _$BlockActionDocImpl;
+abstract class _AppliedUpdateActions implements AppliedUpdateActions {
+  const factory _AppliedUpdateActions({
+    required final List<BlockActionDoc> actions,
+    required final bool requiresFullReload,
+  }) = _$AppliedUpdateActionsImpl;
 
-  factory _BlockActionDoc.fromJson(Map<String, dynamic> json) =
-      _$BlockActionDocImpl.fromJson;
+  factory _AppliedUpdateActions.fromJson(Map<String, dynamic> json) =
+      _$AppliedUpdateActionsImpl.fromJson;
 
   @override
-  BlockActionTypeDoc get action;
-  @override
-  BlockDoc get block;
-  @override
-  @Uint32ListConverter()
-  Uint32List get path;
+  List<BlockActionDoc> get actions;
   @override
-  @Uint32ListConverter()
-  Uint32List? get oldPath;
+  bool get requiresFullReload;
 
-  /// Create a copy of BlockActionDoc
+  /// Create a copy of AppliedUpdateActions
   /// with the given fields replaced by the non-null parameter values.
   @override
   @JsonKey(includeFromJson: false, includeToJson: false)
-  _$$BlockActionDocImplCopyWith<_$BlockActionDocImpl> get copyWith =>
-      throw _privateConstructorUsedError;
+  _$$AppliedUpdateActionsImplCopyWith<_$AppliedUpdateActionsImpl>
+  get copyWith => throw _privateConstructorUsedError;
 }
 
-BlockDoc _$BlockDocFromJson(Map<String, dynamic> json) {
-  return _BlockDoc.fromJson(json);
+AppliedUpdates _$AppliedUpdatesFromJson(Map<String, dynamic> json) {
+  return _AppliedUpdates.fromJson(json);
 }
 
 /// @nodoc
-mixin _$BlockDoc {
-  String get id => throw _privateConstructorUsedError;
-  String get ty => throw _privateConstructorUsedError;
-  Map<String, String> get attributes => throw _privateConstructorUsedError;
-  String? get delta => throw _privateConstructorUsedError;
-  String? get parentId => throw _privateConstructorUsedError;
-  String? get prevId => throw _privateConstructorUsedError;
-  String? get nextId => throw _privateConstructorUsedError;
-  String? get oldParentId => throw _privateConstructorUsedError;
+mixin _$AppliedUpdates {
+  List<String> get changedBlockIds => throw _privateConstructorUsedError;
+  List<String> get deletedBlockIds => throw _privateConstructorUsedError;
+  bool get rootIdChanged => throw _privateConstructorUsedError;
+  bool get hasPendingUpdates => throw _privateConstructorUsedError;
 
-  /// Serializes this BlockDoc to a JSON map.
+  /// Serializes this AppliedUpdates to a JSON map.
   Map<String, dynamic> toJson() => throw _privateConstructorUsedError;
 
-  /// Create a copy of BlockDoc
+  /// Create a copy of AppliedUpdates
   /// with the given fields replaced by the non-null parameter values.
   @JsonKey(includeFromJson: false, includeToJson: false)
-  $BlockDocCopyWith<BlockDoc> get copyWith =>
+  $AppliedUpdatesCopyWith<AppliedUpdates> get copyWith =>
       throw _privateConstructorUsedError;
 }
 
 /// @nodoc
-abstract class $BlockDocCopyWith<$Res> {
-  factory $BlockDocCopyWith(BlockDoc value, $Res Function(BlockDoc) then) =
-      _$BlockDocCopyWithImpl<$Res, BlockDoc>;
+abstract class $AppliedUpdatesCopyWith<$Res> {
+  factory $AppliedUpdatesCopyWith(
+    AppliedUpdates value,
+    $Res Function(AppliedUpdates) then,
+  ) = _$AppliedUpdatesCopyWithImpl<$Res, AppliedUpdates>;
   @useResult
   $Res call({
-    String id,
-    String ty,
-    Map<String, String> attributes,
-    String? delta,
-    String? parentId,
-    String? prevId,
-    String? nextId,
-    String? oldParentId,
+    List<String> changedBlockIds,
+    List<String> deletedBlockIds,
+    bool rootIdChanged,
+    bool hasPendingUpdates,
   });
 }
 
 /// @nodoc
-class _$BlockDocCopyWithImpl<$Res, $Val extends BlockDoc>
-    implements $BlockDocCopyWith<$Res> {
-  _$BlockDocCopyWithImpl(this._value, this._then);
+class _$AppliedUpdatesCopyWithImpl<$Res, $Val extends AppliedUpdates>
+    implements $AppliedUpdatesCopyWith<$Res> {
+  _$AppliedUpdatesCopyWithImpl(this._value, this._then);
 
   // ignore: unused_field
   final $Val _value;
   // ignore: unused_field
   final $Res Function($Val) _then;
 
-  /// Create a copy of BlockDoc
+  /// Create a copy of AppliedUpdates
   /// with the given fields replaced by the non-null parameter values.
   @pragma('vm:prefer-inline')
   @override
   $Res call({
-    Object? id = null,
-    Object? ty = null,
-    Object? attributes = null,
-    Object? delta = freezed,
-    Object? parentId = freezed,
-    Object? prevId = freezed,
-    Object? nextId = freezed,
-    Object? oldParentId = freezed,
+    Object? changedBlockIds = null,
+    Object? deletedBlockIds = null,
+    Object? rootIdChanged = null,
+    Object? hasPendingUpdates = null,
   }) {
     return _then(
       _value.copyWith(
-            id:
-                null == id
-                    ? _value.id
-                    : id // ignore: cast_nullable_to_non_nullable
-                        as String,
-            ty:
-                null == ty
-                    ? _value.ty
-                    : ty // ignore: cast_nullable_to_non_nullable
-                        as String,
-            attributes:
-                null == attributes
-                    ? _value.attributes
-                    : attributes // ignore: cast_nullable_to_non_nullable
-                        as Map<String, String>,
-            delta:
-                freezed == delta
-                    ? _value.delta
-                    : delta // ignore: cast_nullable_to_non_nullable
-                        as String?,
-            parentId:
-                freezed == parentId
-                    ? _value.parentId
-                    : parentId // ignore: cast_nullable_to_non_nullable
-                        as String?,
-            prevId:
-                freezed == prevId
-                    ? _value.prevId
-                    : prevId // ignore: cast_nullable_to_non_nullable
-                        as String?,
-            nextId:
-                freezed == nextId
-                    ? _value.nextId
-                    : nextId // ignore: cast_nullable_to_non_nullable
-                        as String?,
-            oldParentId:
-                freezed == oldParentId
-                    ? _value.oldParentId
-                    : oldParentId // ignore: cast_nullable_to_non_nullable
-                        as String?,
+            changedBlockIds:
+                null == changedBlockIds
+                    ? _value.changedBlockIds
+                    : changedBlockIds // ignore: cast_nullable_to_non_nullable
+                        as List<String>,
+            deletedBlockIds:
+                null == deletedBlockIds
+                    ? _value.deletedBlockIds
+                    : deletedBlockIds // ignore: cast_nullable_to_non_nullable
+                        as List<String>,
+            rootIdChanged:
+                null == rootIdChanged
+                    ? _value.rootIdChanged
+                    : rootIdChanged // ignore: cast_nullable_to_non_nullable
+                        as bool,
+            hasPendingUpdates:
+                null == hasPendingUpdates
+                    ? _value.hasPendingUpdates
+                    : hasPendingUpdates // ignore: cast_nullable_to_non_nullable
+                        as bool,
           )
           as $Val,
     );
@@ -391,91 +293,63 @@ class _$BlockDocCopyWithImpl<$Res, $Val extends BlockDoc>
 }
 
 /// @nodoc
-abstract class _$$BlockDocImplCopyWith<$Res>
-    implements $BlockDocCopyWith<$Res> {
-  factory _$$BlockDocImplCopyWith(
-    _$BlockDocImpl value,
-    $Res Function(_$BlockDocImpl) then,
-  ) = __$$BlockDocImplCopyWithImpl<$Res>;
+abstract class _$$AppliedUpdatesImplCopyWith<$Res>
+    implements $AppliedUpdatesCopyWith<$Res> {
+  factory _$$AppliedUpdatesImplCopyWith(
+    _$AppliedUpdatesImpl value,
+    $Res Function(_$AppliedUpdatesImpl) then,
+  ) = __$$AppliedUpdatesImplCopyWithImpl<$Res>;
   @override
   @useResult
   $Res call({
-    String id,
-    String ty,
-    Map<String, String> attributes,
-    String? delta,
-    String? parentId,
-    String? prevId,
-    String? nextId,
-    String? oldParentId,
+    List<String> changedBlockIds,
+    List<String> deletedBlockIds,
+    bool rootIdChanged,
+    bool hasPendingUpdates,
   });
 }
 
 /// @nodoc
-class __$$BlockDocImplCopyWithImpl<$Res>
-    extends _$BlockDocCopyWithImpl<$Res, _$BlockDocImpl>
-    implements _$$BlockDocImplCopyWith<$Res> {
-  __$$BlockDocImplCopyWithImpl(
-    _$BlockDocImpl _value,
-    $Res Function(_$BlockDocImpl) _then,
+class __$$AppliedUpdatesImplCopyWithImpl<$Res>
+    extends _$AppliedUpdatesCopyWithImpl<$Res, _$AppliedUpdatesImpl>
+    implements _$$AppliedUpdatesImplCopyWith<$Res> {
+  __$$AppliedUpdatesImplCopyWithImpl(
+    _$AppliedUpdatesImpl _value,
+    $Res Function(_$AppliedUpdatesImpl) _then,
   ) : super(_value, _then);
 
-  /// Create a copy of BlockDoc
+  /// Create a copy of AppliedUpdates
   /// with the given fields replaced by the non-null parameter values.
   @pragma('vm:prefer-inline')
   @override
   $Res call({
-    Object? id = null,
-    Object? ty = null,
-    Object? attributes = null,
-    Object? delta = freezed,
-    Object? parentId = freezed,
-    Object? prevId = freezed,
-    Object? nextId = freezed,
-    Object? oldParentId = freezed,
+    Object? changedBlockIds = null,
+    Object? deletedBlockIds = null,
+    Object? rootIdChanged = null,
+    Object? hasPendingUpdates = null,
   }) {
     return _then(
-      _$BlockDocImpl(
-        id:
-            null == id
-                ? _value.id
-                : id // ignore: cast_nullable_to_non_nullable
-                    as String,
-        ty:
-            null == ty
-                ? _value.ty
-                : ty // ignore: cast_nullable_to_non_nullable
-                    as String,
-        attributes:
-            null == attributes
-                ? _value._attributes
-                : attributes // ignore: cast_nullable_to_non_nullable
-                    as Map<String, String>,
-        delta:
-            freezed == delta
-                ? _value.delta
-                : delta // ignore: cast_nullable_to_non_nullable
-                    as String?,
-        parentId:
-            freezed == parentId
-                ? _value.parentId
-                : parentId // ignore: cast_nullable_to_non_nullable
-                    as String?,
-        prevId:
-            freezed == prevId
-                ? _value.prevId
-                : prevId // ignore: cast_nullable_to_non_nullable
-                    as String?,
-        nextId:
-            freezed == nextId
-                ? _value.nextId
-                : nextId // ignore: cast_nullable_to_non_nullable
-                    as String?,
-        oldParentId:
-            freezed == oldParentId
-                ? _value.oldParentId
-                : oldParentId // ignore: cast_nullable_to_non_nullable
-                    as String?,
+      _$AppliedUpdatesImpl(
+        changedBlockIds:
+            null == changedBlockIds
+                ? _value._changedBlockIds
+                : changedBlockIds // ignore: cast_nullable_to_non_nullable
+                    as List<String>,
+        deletedBlockIds:
+            null == deletedBlockIds
+                ? _value._deletedBlockIds
+                : deletedBlockIds // ignore: cast_nullable_to_non_nullable
+                    as List<String>,
+        rootIdChanged:
+            null == rootIdChanged
+                ? _value.rootIdChanged
+                : rootIdChanged // ignore: cast_nullable_to_non_nullable
+                    as bool,
+        hasPendingUpdates:
+            null == hasPendingUpdates
+                ? _value.hasPendingUpdates
+                : hasPendingUpdates // ignore: cast_nullable_to_non_nullable
+                    as bool,
       ),
     );
   }
@@ -483,139 +357,2387 @@ class __$$BlockDocImplCopyWithImpl<$Res>
 
 /// @nodoc
 @JsonSerializable()
-class _$BlockDocImpl implements _BlockDoc {
-  const _$BlockDocImpl({
-    required this.id,
-    required this.ty,
-    required final Map<String, String> attributes,
-    this.delta,
-    this.parentId,
-    this.prevId,
-    this.nextId,
-    this.oldParentId,
-  }) : _attributes = attributes;
-
-  factory _$BlockDocImpl.fromJson(Map<String, dynamic> json) =>
-      _$$BlockDocImplFromJson(json);
+class _$AppliedUpdatesImpl implements _AppliedUpdates {
+  const _$AppliedUpdatesImpl({
+    required final List<String> changedBlockIds,
+    required final List<String> deletedBlockIds,
+    required this.rootIdChanged,
+    required this.hasPendingUpdates,
+  }) : _changedBlockIds = changedBlockIds,
+       _deletedBlockIds = deletedBlockIds;
+
+  factory _$AppliedUpdatesImpl.fromJson(Map<String, dynamic> json) =>
+      _$$AppliedUpdatesImplFromJson(json);
+
+  final List<String> _changedBlockIds;
+  @override
+  List<String> get changedBlockIds {
+    if (_changedBlockIds is EqualUnmodifiableListView) return _changedBlockIds;
+    // ignore: implicit_dynamic_type
+    return EqualUnmodifiableListView(_changedBlockIds);
+  }
 
+  final List<String> _deletedBlockIds;
   @override
-  final String id;
-  @override
-  final String ty;
-  final Map<String, String> _attributes;
-  @override
-  Map<String, String> get attributes {
-    if (_attributes is EqualUnmodifiableMapView) return _attributes;
+  List<String> get deletedBlockIds {
+    if (_deletedBlockIds is EqualUnmodifiableListView) return _deletedBlockIds;
     // ignore: implicit_dynamic_type
-    return EqualUnmodifiableMapView(_attributes);
+    return EqualUnmodifiableListView(_deletedBlockIds);
   }
 
   @override
-  final String? delta;
-  @override
-  final String? parentId;
-  @override
-  final String? prevId;
-  @override
-  final String? nextId;
+  final bool rootIdChanged;
   @override
-  final String? oldParentId;
+  final bool hasPendingUpdates;
 
   @override
   String toString() {
-    return 'BlockDoc(id: $id, ty: $ty, attributes: $attributes, delta: $delta, parentId: $parentId, prevId: $prevId, nextId: $nextId, oldParentId: $oldParentId)';
+    return 'AppliedUpdates(changedBlockIds: $changedBlockIds, deletedBlockIds: $deletedBlockIds, rootIdChanged: $rootIdChanged, hasPendingUpdates: $hasPendingUpdates)';
   }
 
   @override
   bool operator ==(Object other) {
     return identical(this, other) ||
         (other.runtimeType == runtimeType &&
-            other is _$BlockDocImpl &&
-            (identical(other.id, id) || other.id == id) &&
-            (identical(other.ty, ty) || other.ty == ty) &&
+            other is _$AppliedUpdatesImpl &&
             const DeepCollectionEquality().equals(
-              other._attributes,
-              _attributes,
+              other._changedBlockIds,
+              _changedBlockIds,
             ) &&
-            (identical(other.delta, delta) || other.delta == delta) &&
-            (identical(other.parentId, parentId) ||
-                other.parentId == parentId) &&
-            (identical(other.prevId, prevId) || other.prevId == prevId) &&
-            (identical(other.nextId, nextId) || other.nextId == nextId) &&
-            (identical(other.oldParentId, oldParentId) ||
-                other.oldParentId == oldParentId));
+            const DeepCollectionEquality().equals(
+              other._deletedBlockIds,
+              _deletedBlockIds,
+            ) &&
+            (identical(other.rootIdChanged, rootIdChanged) ||
+                other.rootIdChanged == rootIdChanged) &&
+            (identical(other.hasPendingUpdates, hasPendingUpdates) ||
+                other.hasPendingUpdates == hasPendingUpdates));
   }
 
   @JsonKey(includeFromJson: false, includeToJson: false)
   @override
   int get hashCode => Object.hash(
     runtimeType,
-    id,
-    ty,
-    const DeepCollectionEquality().hash(_attributes),
-    delta,
-    parentId,
-    prevId,
-    nextId,
-    oldParentId,
+    const DeepCollectionEquality().hash(_changedBlockIds),
+    const DeepCollectionEquality().hash(_deletedBlockIds),
+    rootIdChanged,
+    hasPendingUpdates,
   );
 
-  /// Create a copy of BlockDoc
+  /// Create a copy of AppliedUpdates
   /// with the given fields replaced by the non-null parameter values.
   @JsonKey(includeFromJson: false, includeToJson: false)
   @override
   @pragma('vm:prefer-inline')
-  _$$BlockDocImplCopyWith<_$BlockDocImpl> get copyWith =>
-      __$$BlockDocImplCopyWithImpl<_$BlockDocImpl>(this, _$identity);
+  _$$AppliedUpdatesImplCopyWith<_$AppliedUpdatesImpl> get copyWith =>
+      __$$AppliedUpdatesImplCopyWithImpl<_$AppliedUpdatesImpl>(
+        this,
+        _$identity,
+      );
 
   @override
   Map<String, dynamic> toJson() {
-    return _$$BlockDocImplToJson(this);
+    return _$$AppliedUpdatesImplToJson(this);
   }
 }
 
-abstract class _BlockDoc implements BlockDoc {
-  const factory _BlockDoc({
-    required final String id,
-    required final String ty,
-    required final Map<String, String> attributes,
-    final String? delta,
-    final String? parentId,
-    final String? prevId,
-    final String? nextId,
-    final String? oldParentId,
-  }) = _$BlockDocImpl;
+abstract class _AppliedUpdates implements AppliedUpdates {
+  const factory _AppliedUpdates({
+    required final List<String> changedBlockIds,
+    required final List<String> deletedBlockIds,
+    required final bool rootIdChanged,
+    required final bool hasPendingUpdates,
+  }) = _$AppliedUpdatesImpl;
 
-  factory _BlockDoc.fromJson(Map<String, dynamic> json) =
-      _$BlockDocImpl.fromJson;
+  factory _AppliedUpdates.fromJson(Map<String, dynamic> json) =
+      _$AppliedUpdatesImpl.fromJson;
 
   @override
-  String get id;
+  List<String> get changedBlockIds;
   @override
-  String get ty;
+  List<String> get deletedBlockIds;
   @override
-  Map<String, String> get attributes;
+  bool get rootIdChanged;
   @override
-  String? get delta;
+  bool get hasPendingUpdates;
+
+  /// Create a copy of AppliedUpdates
+  /// with the given fields replaced by the non-null parameter values.
   @override
-  String? get parentId;
+  @JsonKey(includeFromJson: false, includeToJson: false)
+  _$$AppliedUpdatesImplCopyWith<_$AppliedUpdatesImpl> get copyWith =>
+      throw _privateConstructorUsedError;
+}
+
+AwarenessChangesDoc _$AwarenessChangesDocFromJson(Map<String, dynamic> json) {
+  return _AwarenessChangesDoc.fromJson(json);
+}
+
+/// @nodoc
+mixin _$AwarenessChangesDoc {
+  @Uint64ListConverter()
+  Uint64List get added => throw _privateConstructorUsedError;
+  @Uint64ListConverter()
+  Uint64List get updated => throw _privateConstructorUsedError;
+  @Uint64ListConverter()
+  Uint64List get removed => throw _privateConstructorUsedError;
+
+  /// Serializes this AwarenessChangesDoc to a JSON map.
+  Map<String, dynamic> toJson() => throw _privateConstructorUsedError;
+
+  /// Create a copy of AwarenessChangesDoc
+  /// with the given fields replaced by the non-null parameter values.
+  @JsonKey(includeFromJson: false, includeToJson: false)
+  $AwarenessChangesDocCopyWith<AwarenessChangesDoc> get copyWith =>
+      throw _privateConstructorUsedError;
+}
+
+/// @nodoc
+abstract class $AwarenessChangesDocCopyWith<$Res> {
+  factory $AwarenessChangesDocCopyWith(
+    AwarenessChangesDoc value,
+    $Res Function(AwarenessChangesDoc) then,
+  ) = _$AwarenessChangesDocCopyWithImpl<$Res, AwarenessChangesDoc>;
+  @useResult
+  $Res call({
+    @Uint64ListConverter() Uint64List added,
+    @Uint64ListConverter() Uint64List updated,
+    @Uint64ListConverter() Uint64List removed,
+  });
+}
+
+/// @nodoc
+class _$AwarenessChangesDocCopyWithImpl<$Res, $Val extends AwarenessChangesDoc>
+    implements $AwarenessChangesDocCopyWith<$Res> {
+  _$AwarenessChangesDocCopyWithImpl(this._value, this._then);
+
+  // ignore: unused_field
+  final $Val _value;
+  // ignore: unused_field
+  final $Res Function($Val) _then;
+
+  /// Create a copy of AwarenessChangesDoc
+  /// with the given fields replaced by the non-null parameter values.
+  @pragma('vm:prefer-inline')
   @override
-  String? get prevId;
+  $Res call({
+    Object? added = null,
+    Object? updated = null,
+    Object? removed = null,
+  }) {
+    return _then(
+      _value.copyWith(
+            added:
+                null == added
+                    ? _value.added
+                    : added // ignore: cast_nullable_to_non_nullable
+                        as Uint64List,
+            updated:
+                null == updated
+                    ? _value.updated
+                    : updated // ignore: cast_nullable_to_non_nullable
+                        as Uint64List,
+            removed:
+                null == removed
+                    ? _value.removed
+                    : removed // ignore: cast_nullable_to_non_nullable
+                        as Uint64List,
+          )
+          as $Val,
+    );
+  }
+}
+
+/// @nodoc
+abstract class _$$AwarenessChangesDocImplCopyWith<$Res>
+    implements $AwarenessChangesDocCopyWith<$Res> {
+  factory _$$AwarenessChangesDocImplCopyWith(
+    _$AwarenessChangesDocImpl value,
+    $Res Function(_$AwarenessChangesDocImpl) then,
+  ) = __$$AwarenessChangesDocImplCopyWithImpl<$Res>;
   @override
-  String? get nextId;
+  @useResult
+  $Res call({
+    @Uint64ListConverter() Uint64List added,
+    @Uint64ListConverter() Uint64List updated,
+    @Uint64ListConverter() Uint64List removed,
+  });
+}
+
+/// @nodoc
+class __$$AwarenessChangesDocImplCopyWithImpl<$Res>
+    extends _$AwarenessChangesDocCopyWithImpl<$Res, _$AwarenessChangesDocImpl>
+    implements _$$AwarenessChangesDocImplCopyWith<$Res> {
+  __$$AwarenessChangesDocImplCopyWithImpl(
+    _$AwarenessChangesDocImpl _value,
+    $Res Function(_$AwarenessChangesDocImpl) _then,
+  ) : super(_value, _then);
+
+  /// Create a copy of AwarenessChangesDoc
+  /// with the given fields replaced by the non-null parameter values.
+  @pragma('vm:prefer-inline')
   @override
-  String? get oldParentId;
+  $Res call({
+    Object? added = null,
+    Object? updated = null,
+    Object? removed = null,
+  }) {
+    return _then(
+      _$AwarenessChangesDocImpl(
+        added:
+            null == added
+                ? _value.added
+                : added // ignore: cast_nullable_to_non_nullable
+                    as Uint64List,
+        updated:
+            null == updated
+                ? _value.updated
+                : updated // ignore: cast_nullable_to_non_nullable
+                    as Uint64List,
+        removed:
+            null == removed
+                ? _value.removed
+                : removed // ignore: cast_nullable_to_non_nullable
+                    as Uint64List,
+      ),
+    );
+  }
+}
 
-  /// Create a copy of BlockDoc
+/// @nodoc
+@JsonSerializable()
+class _$AwarenessChangesDocImpl extends _AwarenessChangesDoc {
+  const _$AwarenessChangesDocImpl({
+    @Uint64ListConverter() required this.added,
+    @Uint64ListConverter() required this.updated,
+    @Uint64ListConverter() required this.removed,
+  }) : super._();
+
+  factory _$AwarenessChangesDocImpl.fromJson(Map<String, dynamic> json) =>
+      _$$AwarenessChangesDocImplFromJson(json);
+
+  @override
+  @Uint64ListConverter()
+  final Uint64List added;
+  @override
+  @Uint64ListConverter()
+  final Uint64List updated;
+  @override
+  @Uint64ListConverter()
+  final Uint64List removed;
+
+  @override
+  String toString() {
+    return 'AwarenessChangesDoc(added: $added, updated: $updated, removed: $removed)';
+  }
+
+  @override
+  bool operator ==(Object other) {
+    return identical(this, other) ||
+        (other.runtimeType == runtimeType &&
+            other is _$AwarenessChangesDocImpl &&
+            const DeepCollectionEquality().equals(other.added, added) &&
+            const DeepCollectionEquality().equals(other.updated, updated) &&
+            const DeepCollectionEquality().equals(other.removed, removed));
+  }
+
+  @JsonKey(includeFromJson: false, includeToJson: false)
+  @override
+  int get hashCode => Object.hash(
+    runtimeType,
+    const DeepCollectionEquality().hash(added),
+    const DeepCollectionEquality().hash(updated),
+    const DeepCollectionEquality().hash(removed),
+  );
+
+  /// Create a copy of AwarenessChangesDoc
+  /// with the given fields replaced by the non-null parameter values.
+  @JsonKey(includeFromJson: false, includeToJson: false)
+  @override
+  @pragma('vm:prefer-inline')
+  _$$AwarenessChangesDocImplCopyWith<_$AwarenessChangesDocImpl> get copyWith =>
+      __$$AwarenessChangesDocImplCopyWithImpl<_$AwarenessChangesDocImpl>(
+        this,
+        _$identity,
+      );
+
+  @override
+  Map<String, dynamic> toJson() {
+    return _$$AwarenessChangesDocImplToJson(this);
+  }
+}
+
+abstract class _AwarenessChangesDoc extends AwarenessChangesDoc {
+  const factory _AwarenessChangesDoc({
+    @Uint64ListConverter() required final Uint64List added,
+    @Uint64ListConverter() required final Uint64List updated,
+    @Uint64ListConverter() required final Uint64List removed,
+  }) = _$AwarenessChangesDocImpl;
+  const _AwarenessChangesDoc._() : super._();
+
+  factory _AwarenessChangesDoc.fromJson(Map<String, dynamic> json) =
+      _$AwarenessChangesDocImpl.fromJson;
+
+  @override
+  @Uint64ListConverter()
+  Uint64List get added;
+  @override
+  @Uint64ListConverter()
+  Uint64List get updated;
+  @override
+  @Uint64ListConverter()
+  Uint64List get removed;
+
+  /// Create a copy of AwarenessChangesDoc
   /// with the given fields replaced by the non-null parameter values.
   @override
   @JsonKey(includeFromJson: false, includeToJson: false)
-  _$$BlockDocImplCopyWith<_$BlockDocImpl> get copyWith =>
+  _$$AwarenessChangesDocImplCopyWith<_$AwarenessChangesDocImpl> get copyWith =>
       throw _privateConstructorUsedError;
 }
 
-DocumentState _$DocumentStateFromJson(Map<String, dynamic> json) {
-  return _DocumentState.fromJson(json);
+AwarenessPeerDoc _$AwarenessPeerDocFromJson(Map<String, dynamic> json) {
+  return _AwarenessPeerDoc.fromJson(json);
+}
+
+/// @nodoc
+mixin _$AwarenessPeerDoc {
+  BigInt get clientId => throw _privateConstructorUsedError;
+  int get clock => throw _privateConstructorUsedError;
+  BigInt get lastUpdated => throw _privateConstructorUsedError;
+  bool get isLocal => throw _privateConstructorUsedError;
+  AwarenessUserDoc? get user => throw _privateConstructorUsedError;
+  AwarenessSelectionDoc? get selection => throw _privateConstructorUsedError;
+
+  /// Serializes this AwarenessPeerDoc to a JSON map.
+  Map<String, dynamic> toJson() => throw _privateConstructorUsedError;
+
+  /// Create a copy of AwarenessPeerDoc
+  /// with the given fields replaced by the non-null parameter values.
+  @JsonKey(includeFromJson: false, includeToJson: false)
+  $AwarenessPeerDocCopyWith<AwarenessPeerDoc> get copyWith =>
+      throw _privateConstructorUsedError;
+}
+
+/// @nodoc
+abstract class $AwarenessPeerDocCopyWith<$Res> {
+  factory $AwarenessPeerDocCopyWith(
+    AwarenessPeerDoc value,
+    $Res Function(AwarenessPeerDoc) then,
+  ) = _$AwarenessPeerDocCopyWithImpl<$Res, AwarenessPeerDoc>;
+  @useResult
+  $Res call({
+    BigInt clientId,
+    int clock,
+    BigInt lastUpdated,
+    bool isLocal,
+    AwarenessUserDoc? user,
+    AwarenessSelectionDoc? selection,
+  });
+
+  $AwarenessUserDocCopyWith<$Res>? get user;
+  $AwarenessSelectionDocCopyWith<$Res>? get selection;
+}
+
+/// @nodoc
+class _$AwarenessPeerDocCopyWithImpl<$Res, $Val extends AwarenessPeerDoc>
+    implements $AwarenessPeerDocCopyWith<$Res> {
+  _$AwarenessPeerDocCopyWithImpl(this._value, this._then);
+
+  // ignore: unused_field
+  final $Val _value;
+  // ignore: unused_field
+  final $Res Function($Val) _then;
+
+  /// Create a copy of AwarenessPeerDoc
+  /// with the given fields replaced by the non-null parameter values.
+  @pragma('vm:prefer-inline')
+  @override
+  $Res call({
+    Object? clientId = null,
+    Object? clock = null,
+    Object? lastUpdated = null,
+    Object? isLocal = null,
+    Object? user = freezed,
+    Object? selection = freezed,
+  }) {
+    return _then(
+      _value.copyWith(
+            clientId:
+                null == clientId
+                    ? _value.clientId
+                    : clientId // ignore: cast_nullable_to_non_nullable
+                        as BigInt,
+            clock:
+                null == clock
+                    ? _value.clock
+                    : clock // ignore: cast_nullable_to_non_nullable
+                        as int,
+            lastUpdated:
+                null == lastUpdated
+                    ? _value.lastUpdated
+                    : lastUpdated // ignore: cast_nullable_to_non_nullable
+                        as BigInt,
+            isLocal:
+                null == isLocal
+                    ? _value.isLocal
+                    : isLocal // ignore: cast_nullable_to_non_nullable
+                        as bool,
+            user:
+                freezed == user
+                    ? _value.user
+                    : user // ignore: cast_nullable_to_non_nullable
+                        as AwarenessUserDoc?,
+            selection:
+                freezed == selection
+                    ? _value.selection
+                    : selection // ignore: cast_nullable_to_non_nullable
+                        as AwarenessSelectionDoc?,
+          )
+          as $Val,
+    );
+  }
+
+  /// Create a copy of AwarenessPeerDoc
+  /// with the given fields replaced by the non-null parameter values.
+  @override
+  @pragma('vm:prefer-inline')
+  $AwarenessUserDocCopyWith<$Res>? get user {
+    if (_value.user == null) {
+      return null;
+    }
+
+    return $AwarenessUserDocCopyWith<$Res>(_value.user!, (value) {
+      return _then(_value.copyWith(user: value) as $Val);
+    });
+  }
+
+  /// Create a copy of AwarenessPeerDoc
+  /// with the given fields replaced by the non-null parameter values.
+  @override
+  @pragma('vm:prefer-inline')
+  $AwarenessSelectionDocCopyWith<$Res>? get selection {
+    if (_value.selection == null) {
+      return null;
+    }
+
+    return $AwarenessSelectionDocCopyWith<$Res>(_value.selection!, (value) {
+      return _then(_value.copyWith(selection: value) as $Val);
+    });
+  }
+}
+
+/// @nodoc
+abstract class _$$AwarenessPeerDocImplCopyWith<$Res>
+    implements $AwarenessPeerDocCopyWith<$Res> {
+  factory _$$AwarenessPeerDocImplCopyWith(
+    _$AwarenessPeerDocImpl value,
+    $Res Function(_$AwarenessPeerDocImpl) then,
+  ) = __$$AwarenessPeerDocImplCopyWithImpl<$Res>;
+  @override
+  @useResult
+  $Res call({
+    BigInt clientId,
+    int clock,
+    BigInt lastUpdated,
+    bool isLocal,
+    AwarenessUserDoc? user,
+    AwarenessSelectionDoc? selection,
+  });
+
+  @override
+  $AwarenessUserDocCopyWith<$Res>? get user;
+  @override
+  $AwarenessSelectionDocCopyWith<$Res>? get selection;
+}
+
+/// @nodoc
+class __$$AwarenessPeerDocImplCopyWithImpl<$Res>
+    extends _$AwarenessPeerDocCopyWithImpl<$Res, _$AwarenessPeerDocImpl>
+    implements _$$AwarenessPeerDocImplCopyWith<$Res> {
+  __$$AwarenessPeerDocImplCopyWithImpl(
+    _$AwarenessPeerDocImpl _value,
+    $Res Function(_$AwarenessPeerDocImpl) _then,
+  ) : super(_value, _then);
+
+  /// Create a copy of AwarenessPeerDoc
+  /// with the given fields replaced by the non-null parameter values.
+  @pragma('vm:prefer-inline')
+  @override
+  $Res call({
+    Object? clientId = null,
+    Object? clock = null,
+    Object? lastUpdated = null,
+    Object? isLocal = null,
+    Object? user = freezed,
+    Object? selection = freezed,
+  }) {
+    return _then(
+      _$AwarenessPeerDocImpl(
+        clientId:
+            null == clientId
+                ? _value.clientId
+                : clientId // ignore: cast_nullable_to_non_nullable
+                    as BigInt,
+        clock:
+            null == clock
+                ? _value.clock
+                : clock // ignore: cast_nullable_to_non_nullable
+                    as int,
+        lastUpdated:
+            null == lastUpdated
+                ? _value.lastUpdated
+                : lastUpdated // ignore: cast_nullable_to_non_nullable
+                    as BigInt,
+        isLocal:
+            null == isLocal
+                ? _value.isLocal
+                : isLocal // ignore: cast_nullable_to_non_nullable
+                    as bool,
+        user:
+            freezed == user
+                ? _value.user
+                : user // ignore: cast_nullable_to_non_nullable
+                    as AwarenessUserDoc?,
+        selection:
+            freezed == selection
+                ? _value.selection
+                : selection // ignore: cast_nullable_to_non_nullable
+                    as AwarenessSelectionDoc?,
+      ),
+    );
+  }
+}
+
+/// @nodoc
+@JsonSerializable()
+class _$AwarenessPeerDocImpl implements _AwarenessPeerDoc {
+  const _$AwarenessPeerDocImpl({
+    required this.clientId,
+    required this.clock,
+    required this.lastUpdated,
+    required this.isLocal,
+    this.user,
+    this.selection,
+  });
+
+  factory _$AwarenessPeerDocImpl.fromJson(Map<String, dynamic> json) =>
+      _$$AwarenessPeerDocImplFromJson(json);
+
+  @override
+  final BigInt clientId;
+  @override
+  final int clock;
+  @override
+  final BigInt lastUpdated;
+  @override
+  final bool isLocal;
+  @override
+  final AwarenessUserDoc? user;
+  @override
+  final AwarenessSelectionDoc? selection;
+
+  @override
+  String toString() {
+    return 'AwarenessPeerDoc(clientId: $clientId, clock: $clock, lastUpdated: $lastUpdated, isLocal: $isLocal, user: $user, selection: $selection)';
+  }
+
+  @override
+  bool operator ==(Object other) {
+    return identical(this, other) ||
+        (other.runtimeType == runtimeType &&
+            other is _$AwarenessPeerDocImpl &&
+            (identical(other.clientId, clientId) ||
+                other.clientId == clientId) &&
+            (identical(other.clock, clock) || other.clock == clock) &&
+            (identical(other.lastUpdated, lastUpdated) ||
+                other.lastUpdated == lastUpdated) &&
+            (identical(other.isLocal, isLocal) || other.isLocal == isLocal) &&
+            (identical(other.user, user) || other.user == user) &&
+            (identical(other.selection, selection) ||
+                other.selection == selection));
+  }
+
+  @JsonKey(includeFromJson: false, includeToJson: false)
+  @override
+  int get hashCode => Object.hash(
+    runtimeType,
+    clientId,
+    clock,
+    lastUpdated,
+    isLocal,
+    user,
+    selection,
+  );
+
+  /// Create a copy of AwarenessPeerDoc
+  /// with the given fields replaced by the non-null parameter values.
+  @JsonKey(includeFromJson: false, includeToJson: false)
+  @override
+  @pragma('vm:prefer-inline')
+  _$$AwarenessPeerDocImplCopyWith<_$AwarenessPeerDocImpl> get copyWith =>
+      __$$AwarenessPeerDocImplCopyWithImpl<_$AwarenessPeerDocImpl>(
+        this,
+        _$identity,
+      );
+
+  @override
+  Map<String, dynamic> toJson() {
+    return _$$AwarenessPeerDocImplToJson(this);
+  }
+}
+
+abstract class _AwarenessPeerDoc implements AwarenessPeerDoc {
+  const factory _AwarenessPeerDoc({
+    required final BigInt clientId,
+    required final int clock,
+    required final BigInt lastUpdated,
+    required final bool isLocal,
+    final AwarenessUserDoc? user,
+    final AwarenessSelectionDoc? selection,
+  }) = _$AwarenessPeerDocImpl;
+
+  factory _AwarenessPeerDoc.fromJson(Map<String, dynamic> json) =
+      _$AwarenessPeerDocImpl.fromJson;
+
+  @override
+  BigInt get clientId;
+  @override
+  int get clock;
+  @override
+  BigInt get lastUpdated;
+  @override
+  bool get isLocal;
+  @override
+  AwarenessUserDoc? get user;
+  @override
+  AwarenessSelectionDoc? get selection;
+
+  /// Create a copy of AwarenessPeerDoc
+  /// with the given fields replaced by the non-null parameter values.
+  @override
+  @JsonKey(includeFromJson: false, includeToJson: false)
+  _$$AwarenessPeerDocImplCopyWith<_$AwarenessPeerDocImpl> get copyWith =>
+      throw _privateConstructorUsedError;
+}
+
+AwarenessSelectionDoc _$AwarenessSelectionDocFromJson(
+  Map<String, dynamic> json,
+) {
+  return _AwarenessSelectionDoc.fromJson(json);
+}
+
+/// @nodoc
+mixin _$AwarenessSelectionDoc {
+  TextPositionDoc get anchor => throw _privateConstructorUsedError;
+  TextPositionDoc get head => throw _privateConstructorUsedError;
+
+  /// Serializes this AwarenessSelectionDoc to a JSON map.
+  Map<String, dynamic> toJson() => throw _privateConstructorUsedError;
+
+  /// Create a copy of AwarenessSelectionDoc
+  /// with the given fields replaced by the non-null parameter values.
+  @JsonKey(includeFromJson: false, includeToJson: false)
+  $AwarenessSelectionDocCopyWith<AwarenessSelectionDoc> get copyWith =>
+      throw _privateConstructorUsedError;
+}
+
+/// @nodoc
+abstract class $AwarenessSelectionDocCopyWith<$Res> {
+  factory $AwarenessSelectionDocCopyWith(
+    AwarenessSelectionDoc value,
+    $Res Function(AwarenessSelectionDoc) then,
+  ) = _$AwarenessSelectionDocCopyWithImpl<$Res, AwarenessSelectionDoc>;
+  @useResult
+  $Res call({TextPositionDoc anchor, TextPositionDoc head});
+
+  $TextPositionDocCopyWith<$Res> get anchor;
+  $TextPositionDocCopyWith<$Res> get head;
+}
+
+/// @nodoc
+class _$AwarenessSelectionDocCopyWithImpl<
+  $Res,
+  $Val extends AwarenessSelectionDoc
+>
+    implements $AwarenessSelectionDocCopyWith<$Res> {
+  _$AwarenessSelectionDocCopyWithImpl(this._value, this._then);
+
+  // ignore: unused_field
+  final $Val _value;
+  // ignore: unused_field
+  final $Res Function($Val) _then;
+
+  /// Create a copy of AwarenessSelectionDoc
+  /// with the given fields replaced by the non-null parameter values.
+  @pragma('vm:prefer-inline')
+  @override
+  $Res call({Object? anchor = null, Object? head = null}) {
+    return _then(
+      _value.copyWith(
+            anchor:
+                null == anchor
+                    ? _value.anchor
+                    : anchor // ignore: cast_nullable_to_non_nullable
+                        as TextPositionDoc,
+            head:
+                null == head
+                    ? _value.head
+                    : head // ignore: cast_nullable_to_non_nullable
+                        as TextPositionDoc,
+          )
+          as $Val,
+    );
+  }
+
+  /// Create a copy of AwarenessSelectionDoc
+  /// with the given fields replaced by the non-null parameter values.
+  @override
+  @pragma('vm:prefer-inline')
+  $TextPositionDocCopyWith<$Res> get anchor {
+    return $TextPositionDocCopyWith<$Res>(_value.anchor, (value) {
+      return _then(_value.copyWith(anchor: value) as $Val);
+    });
+  }
+
+  /// Create a copy of AwarenessSelectionDoc
+  /// with the given fields replaced by the non-null parameter values.
+  @override
+  @pragma('vm:prefer-inline')
+  $TextPositionDocCopyWith<$Res> get head {
+    return $TextPositionDocCopyWith<$Res>(_value.head, (value) {
+      return _then(_value.copyWith(head: value) as $Val);
+    });
+  }
+}
+
+/// @nodoc
+abstract class _$$AwarenessSelectionDocImplCopyWith<$Res>
+    implements $AwarenessSelectionDocCopyWith<$Res> {
+  factory _$$AwarenessSelectionDocImplCopyWith(
+    _$AwarenessSelectionDocImpl value,
+    $Res Function(_$AwarenessSelectionDocImpl) then,
+  ) = __$$AwarenessSelectionDocImplCopyWithImpl<$Res>;
+  @override
+  @useResult
+  $Res call({TextPositionDoc anchor, TextPositionDoc head});
+
+  @override
+  $TextPositionDocCopyWith<$Res> get anchor;
+  @override
+  $TextPositionDocCopyWith<$Res> get head;
+}
+
+/// @nodoc
+class __$$AwarenessSelectionDocImplCopyWithImpl<$Res>
+    extends
+        _$AwarenessSelectionDocCopyWithImpl<$Res, _$AwarenessSelectionDocImpl>
+    implements _$$AwarenessSelectionDocImplCopyWith<$Res> {
+  __$$AwarenessSelectionDocImplCopyWithImpl(
+    _$AwarenessSelectionDocImpl _value,
+    $Res Function(_$AwarenessSelectionDocImpl) _then,
+  ) : super(_value, _then);
+
+  /// Create a copy of AwarenessSelectionDoc
+  /// with the given fields replaced by the non-null parameter values.
+  @pragma('vm:prefer-inline')
+  @override
+  $Res call({Object? anchor = null, Object? head = null}) {
+    return _then(
+      _$AwarenessSelectionDocImpl(
+        anchor:
+            null == anchor
+                ? _value.anchor
+                : anchor // ignore: cast_nullable_to_non_nullable
+                    as TextPositionDoc,
+        head:
+            null == head
+                ? _value.head
+                : head // ignore: cast_nullable_to_non_nullable
+                    as TextPositionDoc,
+      ),
+    );
+  }
+}
+
+/// @nodoc
+@JsonSerializable()
+class _$AwarenessSelectionDocImpl implements _AwarenessSelectionDoc {
+  const _$AwarenessSelectionDocImpl({required this.anchor, required this.head});
+
+  factory _$AwarenessSelectionDocImpl.fromJson(Map<String, dynamic> json) =>
+      _$$AwarenessSelectionDocImplFromJson(json);
+
+  @override
+  final TextPositionDoc anchor;
+  @override
+  final TextPositionDoc head;
+
+  @override
+  String toString() {
+    return 'AwarenessSelectionDoc(anchor: $anchor, head: $head)';
+  }
+
+  @override
+  bool operator ==(Object other) {
+    return identical(this, other) ||
+        (other.runtimeType == runtimeType &&
+            other is _$AwarenessSelectionDocImpl &&
+            (identical(other.anchor, anchor) || other.anchor == anchor) &&
+            (identical(other.head, head) || other.head == head));
+  }
+
+  @JsonKey(includeFromJson: false, includeToJson: false)
+  @override
+  int get hashCode => Object.hash(runtimeType, anchor, head);
+
+  /// Create a copy of AwarenessSelectionDoc
+  /// with the given fields replaced by the non-null parameter values.
+  @JsonKey(includeFromJson: false, includeToJson: false)
+  @override
+  @pragma('vm:prefer-inline')
+  _$$AwarenessSelectionDocImplCopyWith<_$AwarenessSelectionDocImpl>
+  get copyWith =>
+      __$$AwarenessSelectionDocImplCopyWithImpl<_$AwarenessSelectionDocImpl>(
+        this,
+        _$identity,
+      );
+
+  @override
+  Map<String, dynamic> toJson() {
+    return _$$AwarenessSelectionDocImplToJson(this);
+  }
+}
+
+abstract class _AwarenessSelectionDoc implements AwarenessSelectionDoc {
+  const factory _AwarenessSelectionDoc({
+    required final TextPositionDoc anchor,
+    required final TextPositionDoc head,
+  }) = _$AwarenessSelectionDocImpl;
+
+  factory _AwarenessSelectionDoc.fromJson(Map<String, dynamic> json) =
+      _$AwarenessSelectionDocImpl.fromJson;
+
+  @override
+  TextPositionDoc get anchor;
+  @override
+  TextPositionDoc get head;
+
+  /// Create a copy of AwarenessSelectionDoc
+  /// with the given fields replaced by the non-null parameter values.
+  @override
+  @JsonKey(includeFromJson: false, includeToJson: false)
+  _$$AwarenessSelectionDocImplCopyWith<_$AwarenessSelectionDocImpl>
+  get copyWith => throw _privateConstructorUsedError;
+}
+
+AwarenessStateDoc _$AwarenessStateDocFromJson(Map<String, dynamic> json) {
+  return _AwarenessStateDoc.fromJson(json);
+}
+
+/// @nodoc
+mixin _$AwarenessStateDoc {
+  AwarenessUserDoc get user => throw _privateConstructorUsedError;
+  AwarenessSelectionDoc? get selection => throw _privateConstructorUsedError;
+
+  /// Serializes this AwarenessStateDoc to a JSON map.
+  Map<String, dynamic> toJson() => throw _privateConstructorUsedError;
+
+  /// Create a copy of AwarenessStateDoc
+  /// with the given fields replaced by the non-null parameter values.
+  @JsonKey(includeFromJson: false, includeToJson: false)
+  $AwarenessStateDocCopyWith<AwarenessStateDoc> get copyWith =>
+      throw _privateConstructorUsedError;
+}
+
+/// @nodoc
+abstract class $AwarenessStateDocCopyWith<$Res> {
+  factory $AwarenessStateDocCopyWith(
+    AwarenessStateDoc value,
+    $Res Function(AwarenessStateDoc) then,
+  ) = _$AwarenessStateDocCopyWithImpl<$Res, AwarenessStateDoc>;
+  @useResult
+  $Res call({AwarenessUserDoc user, AwarenessSelectionDoc? selection});
+
+  $AwarenessUserDocCopyWith<$Res> get user;
+  $AwarenessSelectionDocCopyWith<$Res>? get selection;
+}
+
+/// @nodoc
+class _$AwarenessStateDocCopyWithImpl<$Res, $Val extends AwarenessStateDoc>
+    implements $AwarenessStateDocCopyWith<$Res> {
+  _$AwarenessStateDocCopyWithImpl(this._value, this._then);
+
+  // ignore: unused_field
+  final $Val _value;
+  // ignore: unused_field
+  final $Res Function($Val) _then;
+
+  /// Create a copy of AwarenessStateDoc
+  /// with the given fields replaced by the non-null parameter values.
+  @pragma('vm:prefer-inline')
+  @override
+  $Res call({Object? user = null, Object? selection = freezed}) {
+    return _then(
+      _value.copyWith(
+            user:
+                null == user
+                    ? _value.user
+                    : user // ignore: cast_nullable_to_non_nullable
+                        as AwarenessUserDoc,
+            selection:
+                freezed == selection
+                    ? _value.selection
+                    : selection // ignore: cast_nullable_to_non_nullable
+                        as AwarenessSelectionDoc?,
+          )
+          as $Val,
+    );
+  }
+
+  /// Create a copy of AwarenessStateDoc
+  /// with the given fields replaced by the non-null parameter values.
+  @override
+  @pragma('vm:prefer-inline')
+  $AwarenessUserDocCopyWith<$Res> get user {
+    return $AwarenessUserDocCopyWith<$Res>(_value.user, (value) {
+      return _then(_value.copyWith(user: value) as $Val);
+    });
+  }
+
+  /// Create a copy of AwarenessStateDoc
+  /// with the given fields replaced by the non-null parameter values.
+  @override
+  @pragma('vm:prefer-inline')
+  $AwarenessSelectionDocCopyWith<$Res>? get selection {
+    if (_value.selection == null) {
+      return null;
+    }
+
+    return $AwarenessSelectionDocCopyWith<$Res>(_value.selection!, (value) {
+      return _then(_value.copyWith(selection: value) as $Val);
+    });
+  }
+}
+
+/// @nodoc
+abstract class _$$AwarenessStateDocImplCopyWith<$Res>
+    implements $AwarenessStateDocCopyWith<$Res> {
+  factory _$$AwarenessStateDocImplCopyWith(
+    _$AwarenessStateDocImpl value,
+    $Res Function(_$AwarenessStateDocImpl) then,
+  ) = __$$AwarenessStateDocImplCopyWithImpl<$Res>;
+  @override
+  @useResult
+  $Res call({AwarenessUserDoc user, AwarenessSelectionDoc? selection});
+
+  @override
+  $AwarenessUserDocCopyWith<$Res> get user;
+  @override
+  $AwarenessSelectionDocCopyWith<$Res>? get selection;
+}
+
+/// @nodoc
+class __$$AwarenessStateDocImplCopyWithImpl<$Res>
+    extends _$AwarenessStateDocCopyWithImpl<$Res, _$AwarenessStateDocImpl>
+    implements _$$AwarenessStateDocImplCopyWith<$Res> {
+  __$$AwarenessStateDocImplCopyWithImpl(
+    _$AwarenessStateDocImpl _value,
+    $Res Function(_$AwarenessStateDocImpl) _then,
+  ) : super(_value, _then);
+
+  /// Create a copy of AwarenessStateDoc
+  /// with the given fields replaced by the non-null parameter values.
+  @pragma('vm:prefer-inline')
+  @override
+  $Res call({Object? user = null, Object? selection = freezed}) {
+    return _then(
+      _$AwarenessStateDocImpl(
+        user:
+            null == user
+                ? _value.user
+                : user // ignore: cast_nullable_to_non_nullable
+                    as AwarenessUserDoc,
+        selection:
+            freezed == selection
+                ? _value.selection
+                : selection // ignore: cast_nullable_to_non_nullable
+                    as AwarenessSelectionDoc?,
+      ),
+    );
+  }
+}
+
+/// @nodoc
+@JsonSerializable()
+class _$AwarenessStateDocImpl implements _AwarenessStateDoc {
+  const _$AwarenessStateDocImpl({required this.user, this.selection});
+
+  factory _$AwarenessStateDocImpl.fromJson(Map<String, dynamic> json) =>
+      _$$AwarenessStateDocImplFromJson(json);
+
+  @override
+  final AwarenessUserDoc user;
+  @override
+  final AwarenessSelectionDoc? selection;
+
+  @override
+  String toString() {
+    return 'AwarenessStateDoc(user: $user, selection: $selection)';
+  }
+
+  @override
+  bool operator ==(Object other) {
+    return identical(this, other) ||
+        (other.runtimeType == runtimeType &&
+            other is _$AwarenessStateDocImpl &&
+            (identical(other.user, user) || other.user == user) &&
+            (identical(other.selection, selection) ||
+                other.selection == selection));
+  }
+
+  @JsonKey(includeFromJson: false, includeToJson: false)
+  @override
+  int get hashCode => Object.hash(runtimeType, user, selection);
+
+  /// Create a copy of AwarenessStateDoc
+  /// with the given fields replaced by the non-null parameter values.
+  @JsonKey(includeFromJson: false, includeToJson: false)
+  @override
+  @pragma('vm:prefer-inline')
+  _$$AwarenessStateDocImplCopyWith<_$AwarenessStateDocImpl> get copyWith =>
+      __$$AwarenessStateDocImplCopyWithImpl<_$AwarenessStateDocImpl>(
+        this,
+        _$identity,
+      );
+
+  @override
+  Map<String, dynamic> toJson() {
+    return _$$AwarenessStateDocImplToJson(this);
+  }
+}
+
+abstract class _AwarenessStateDoc implements AwarenessStateDoc {
+  const factory _AwarenessStateDoc({
+    required final AwarenessUserDoc user,
+    final AwarenessSelectionDoc? selection,
+  }) = _$AwarenessStateDocImpl;
+
+  factory _AwarenessStateDoc.fromJson(Map<String, dynamic> json) =
+      _$AwarenessStateDocImpl.fromJson;
+
+  @override
+  AwarenessUserDoc get user;
+  @override
+  AwarenessSelectionDoc? get selection;
+
+  /// Create a copy of AwarenessStateDoc
+  /// with the given fields replaced by the non-null parameter values.
+  @override
+  @JsonKey(includeFromJson: false, includeToJson: false)
+  _$$AwarenessStateDocImplCopyWith<_$AwarenessStateDocImpl> get copyWith =>
+      throw _privateConstructorUsedError;
+}
+
+AwarenessUserDoc _$AwarenessUserDocFromJson(Map<String, dynamic> json) {
+  return _AwarenessUserDoc.fromJson(json);
+}
+
+/// @nodoc
+mixin _$AwarenessUserDoc {
+  String get id => throw _privateConstructorUsedError;
+  String get name => throw _privateConstructorUsedError;
+  String? get color => throw _privateConstructorUsedError;
+
+  /// Serializes this AwarenessUserDoc to a JSON map.
+  Map<String, dynamic> toJson() => throw _privateConstructorUsedError;
+
+  /// Create a copy of AwarenessUserDoc
+  /// with the given fields replaced by the non-null parameter values.
+  @JsonKey(includeFromJson: false, includeToJson: false)
+  $AwarenessUserDocCopyWith<AwarenessUserDoc> get copyWith =>
+      throw _privateConstructorUsedError;
+}
+
+/// @nodoc
+abstract class $AwarenessUserDocCopyWith<$Res> {
+  factory $AwarenessUserDocCopyWith(
+    AwarenessUserDoc value,
+    $Res Function(AwarenessUserDoc) then,
+  ) = _$AwarenessUserDocCopyWithImpl<$Res, AwarenessUserDoc>;
+  @useResult
+  $Res call({String id, String name, String? color});
+}
+
+/// @nodoc
+class _$AwarenessUserDocCopyWithImpl<$Res, $Val extends AwarenessUserDoc>
+    implements $AwarenessUserDocCopyWith<$Res> {
+  _$AwarenessUserDocCopyWithImpl(this._value, this._then);
+
+  // ignore: unused_field
+  final $Val _value;
+  // ignore: unused_field
+  final $Res Function($Val) _then;
+
+  /// Create a copy of AwarenessUserDoc
+  /// with the given fields replaced by the non-null parameter values.
+  @pragma('vm:prefer-inline')
+  @override
+  $Res call({Object? id = null, Object? name = null, Object? color = freezed}) {
+    return _then(
+      _value.copyWith(
+            id:
+                null == id
+                    ? _value.id
+                    : id // ignore: cast_nullable_to_non_nullable
+                        as String,
+            name:
+                null == name
+                    ? _value.name
+                    : name // ignore: cast_nullable_to_non_nullable
+                        as String,
+            color:
+                freezed == color
+                    ? _value.color
+                    : color // ignore: cast_nullable_to_non_nullable
+                        as String?,
+          )
+          as $Val,
+    );
+  }
+}
+
+/// @nodoc
+abstract class _$$AwarenessUserDocImplCopyWith<$Res>
+    implements $AwarenessUserDocCopyWith<$Res> {
+  factory _$$AwarenessUserDocImplCopyWith(
+    _$AwarenessUserDocImpl value,
+    $Res Function(_$AwarenessUserDocImpl) then,
+  ) = __$$AwarenessUserDocImplCopyWithImpl<$Res>;
+  @override
+  @useResult
+  $Res call({String id, String name, String? color});
+}
+
+/// @nodoc
+class __$$AwarenessUserDocImplCopyWithImpl<$Res>
+    extends _$AwarenessUserDocCopyWithImpl<$Res, _$AwarenessUserDocImpl>
+    implements _$$AwarenessUserDocImplCopyWith<$Res> {
+  __$$AwarenessUserDocImplCopyWithImpl(
+    _$AwarenessUserDocImpl _value,
+    $Res Function(_$AwarenessUserDocImpl) _then,
+  ) : super(_value, _then);
+
+  /// Create a copy of AwarenessUserDoc
+  /// with the given fields replaced by the non-null parameter values.
+  @pragma('vm:prefer-inline')
+  @override
+  $Res call({Object? id = null, Object? name = null, Object? color = freezed}) {
+    return _then(
+      _$AwarenessUserDocImpl(
+        id:
+            null == id
+                ? _value.id
+                : id // ignore: cast_nullable_to_non_nullable
+                    as String,
+        name:
+            null == name
+                ? _value.name
+                : name // ignore: cast_nullable_to_non_nullable
+                    as String,
+        color:
+            freezed == color
+                ? _value.color
+                : color // ignore: cast_nullable_to_non_nullable
+                    as String?,
+      ),
+    );
+  }
+}
+
+/// @nodoc
+@JsonSerializable()
+class _$AwarenessUserDocImpl implements _AwarenessUserDoc {
+  const _$AwarenessUserDocImpl({
+    required this.id,
+    required this.name,
+    this.color,
+  });
+
+  factory _$AwarenessUserDocImpl.fromJson(Map<String, dynamic> json) =>
+      _$$AwarenessUserDocImplFromJson(json);
+
+  @override
+  final String id;
+  @override
+  final String name;
+  @override
+  final String? color;
+
+  @override
+  String toString() {
+    return 'AwarenessUserDoc(id: $id, name: $name, color: $color)';
+  }
+
+  @override
+  bool operator ==(Object other) {
+    return identical(this, other) ||
+        (other.runtimeType == runtimeType &&
+            other is _$AwarenessUserDocImpl &&
+            (identical(other.id, id) || other.id == id) &&
+            (identical(other.name, name) || other.name == name) &&
+            (identical(other.color, color) || other.color == color));
+  }
+
+  @JsonKey(includeFromJson: false, includeToJson: false)
+  @override
+  int get hashCode => Object.hash(runtimeType, id, name, color);
+
+  /// Create a copy of AwarenessUserDoc
+  /// with the given fields replaced by the non-null parameter values.
+  @JsonKey(includeFromJson: false, includeToJson: false)
+  @override
+  @pragma('vm:prefer-inline')
+  _$$AwarenessUserDocImplCopyWith<_$AwarenessUserDocImpl> get copyWith =>
+      __$$AwarenessUserDocImplCopyWithImpl<_$AwarenessUserDocImpl>(
+        this,
+        _$identity,
+      );
+
+  @override
+  Map<String, dynamic> toJson() {
+    return _$$AwarenessUserDocImplToJson(this);
+  }
+}
+
+abstract class _AwarenessUserDoc implements AwarenessUserDoc {
+  const factory _AwarenessUserDoc({
+    required final String id,
+    required final String name,
+    final String? color,
+  }) = _$AwarenessUserDocImpl;
+
+  factory _AwarenessUserDoc.fromJson(Map<String, dynamic> json) =
+      _$AwarenessUserDocImpl.fromJson;
+
+  @override
+  String get id;
+  @override
+  String get name;
+  @override
+  String? get color;
+
+  /// Create a copy of AwarenessUserDoc
+  /// with the given fields replaced by the non-null parameter values.
+  @override
+  @JsonKey(includeFromJson: false, includeToJson: false)
+  _$$AwarenessUserDocImplCopyWith<_$AwarenessUserDocImpl> get copyWith =>
+      throw _privateConstructorUsedError;
+}
+
+BlockActionDoc _$BlockActionDocFromJson(Map<String, dynamic> json) {
+  return _BlockActionDoc.fromJson(json);
+}
+
+/// @nodoc
+mixin _$BlockActionDoc {
+  BlockActionTypeDoc get action => throw _privateConstructorUsedError;
+  BlockDoc get block => throw _privateConstructorUsedError;
+  @Uint32ListConverter()
+  Uint32List get path => throw _privateConstructorUsedError;
+  @Uint32ListConverter()
+  Uint32List? get oldPath => throw _privateConstructorUsedError;
+
+  /// Serializes this BlockActionDoc to a JSON map.
+  Map<String, dynamic> toJson() => throw _privateConstructorUsedError;
+
+  /// Create a copy of BlockActionDoc
+  /// with the given fields replaced by the non-null parameter values.
+  @JsonKey(includeFromJson: false, includeToJson: false)
+  $BlockActionDocCopyWith<BlockActionDoc> get copyWith =>
+      throw _privateConstructorUsedError;
+}
+
+/// @nodoc
+abstract class $BlockActionDocCopyWith<$Res> {
+  factory $BlockActionDocCopyWith(
+    BlockActionDoc value,
+    $Res Function(BlockActionDoc) then,
+  ) = _$BlockActionDocCopyWithImpl<$Res, BlockActionDoc>;
+  @useResult
+  $Res call({
+    BlockActionTypeDoc action,
+    BlockDoc block,
+    @Uint32ListConverter() Uint32List path,
+    @Uint32ListConverter() Uint32List? oldPath,
+  });
+
+  $BlockDocCopyWith<$Res> get block;
+}
+
+/// @nodoc
+class _$BlockActionDocCopyWithImpl<$Res, $Val extends BlockActionDoc>
+    implements $BlockActionDocCopyWith<$Res> {
+  _$BlockActionDocCopyWithImpl(this._value, this._then);
+
+  // ignore: unused_field
+  final $Val _value;
+  // ignore: unused_field
+  final $Res Function($Val) _then;
+
+  /// Create a copy of BlockActionDoc
+  /// with the given fields replaced by the non-null parameter values.
+  @pragma('vm:prefer-inline')
+  @override
+  $Res call({
+    Object? action = null,
+    Object? block = null,
+    Object? path = null,
+    Object? oldPath = freezed,
+  }) {
+    return _then(
+      _value.copyWith(
+            action:
+                null == action
+                    ? _value.action
+                    : action // ignore: cast_nullable_to_non_nullable
+                        as BlockActionTypeDoc,
+            block:
+                null == block
+                    ? _value.block
+                    : block // ignore: cast_nullable_to_non_nullable
+                        as BlockDoc,
+            path:
+                null == path
+                    ? _value.path
+                    : path // ignore: cast_nullable_to_non_nullable
+                        as Uint32List,
+            oldPath:
+                freezed == oldPath
+                    ? _value.oldPath
+                    : oldPath // ignore: cast_nullable_to_non_nullable
+                        as Uint32List?,
+          )
+          as $Val,
+    );
+  }
+
+  /// Create a copy of BlockActionDoc
+  /// with the given fields replaced by the non-null parameter values.
+  @override
+  @pragma('vm:prefer-inline')
+  $BlockDocCopyWith<$Res> get block {
+    return $BlockDocCopyWith<$Res>(_value.block, (value) {
+      return _then(_value.copyWith(block: value) as $Val);
+    });
+  }
+}
+
+/// @nodoc
+abstract class _$$BlockActionDocImplCopyWith<$Res>
+    implements $BlockActionDocCopyWith<$Res> {
+  factory _$$BlockActionDocImplCopyWith(
+    _$BlockActionDocImpl value,
+    $Res Function(_$BlockActionDocImpl) then,
+  ) = __$$BlockActionDocImplCopyWithImpl<$Res>;
+  @override
+  @useResult
+  $Res call({
+    BlockActionTypeDoc action,
+    BlockDoc block,
+    @Uint32ListConverter() Uint32List path,
+    @Uint32ListConverter() Uint32List? oldPath,
+  });
+
+  @override
+  $BlockDocCopyWith<$Res> get block;
+}
+
+/// @nodoc
+class __$$BlockActionDocImplCopyWithImpl<$Res>
+    extends _$BlockActionDocCopyWithImpl<$Res, _$BlockActionDocImpl>
+    implements _$$BlockActionDocImplCopyWith<$Res> {
+  __$$BlockActionDocImplCopyWithImpl(
+    _$BlockActionDocImpl _value,
+    $Res Function(_$BlockActionDocImpl) _then,
+  ) : super(_value, _then);
+
+  /// Create a copy of BlockActionDoc
+  /// with the given fields replaced by the non-null parameter values.
+  @pragma('vm:prefer-inline')
+  @override
+  $Res call({
+    Object? action = null,
+    Object? block = null,
+    Object? path = null,
+    Object? oldPath = freezed,
+  }) {
+    return _then(
+      _$BlockActionDocImpl(
+        action:
+            null == action
+                ? _value.action
+                : action // ignore: cast_nullable_to_non_nullable
+                    as BlockActionTypeDoc,
+        block:
+            null == block
+                ? _value.block
+                : block // ignore: cast_nullable_to_non_nullable
+                    as BlockDoc,
+        path:
+            null == path
+                ? _value.path
+                : path // ignore: cast_nullable_to_non_nullable
+                    as Uint32List,
+        oldPath:
+            freezed == oldPath
+                ? _value.oldPath
+                : oldPath // ignore: cast_nullable_to_non_nullable
+                    as Uint32List?,
+      ),
+    );
+  }
+}
+
+/// @nodoc
+@JsonSerializable()
+class _$BlockActionDocImpl implements _BlockActionDoc {
+  const _$BlockActionDocImpl({
+    required this.action,
+    required this.block,
+    @Uint32ListConverter() required this.path,
+    @Uint32ListConverter() this.oldPath,
+  });
+
+  factory _$BlockActionDocImpl.fromJson(Map<String, dynamic> json) =>
+      _$$BlockActionDocImplFromJson(json);
+
+  @override
+  final BlockActionTypeDoc action;
+  @override
+  final BlockDoc block;
+  @override
+  @Uint32ListConverter()
+  final Uint32List path;
+  @override
+  @Uint32ListConverter()
+  final Uint32List? oldPath;
+
+  @override
+  String toString() {
+    return 'BlockActionDoc(action: $action, block: $block, path: $path, oldPath: $oldPath)';
+  }
+
+  @override
+  bool operator ==(Object other) {
+    return identical(this, other) ||
+        (other.runtimeType == runtimeType &&
+            other is _$BlockActionDocImpl &&
+            (identical(other.action, action) || other.action == action) &&
+            (identical(other.block, block) || other.block == block) &&
+            const DeepCollectionEquality().equals(other.path, path) &&
+            const DeepCollectionEquality().equals(other.oldPath, oldPath));
+  }
+
+  @JsonKey(includeFromJson: false, includeToJson: false)
+  @override
+  int get hashCode => Object.hash(
+    runtimeType,
+    action,
+    block,
+    const DeepCollectionEquality().hash(path),
+    const DeepCollectionEquality().hash(oldPath),
+  );
+
+  /// Create a copy of BlockActionDoc
+  /// with the given fields replaced by the non-null parameter values.
+  @JsonKey(includeFromJson: false, includeToJson: false)
+  @override
+  @pragma('vm:prefer-inline')
+  _$$BlockActionDocImplCopyWith<_$BlockActionDocImpl> get copyWith =>
+      __$$BlockActionDocImplCopyWithImpl<_$BlockActionDocImpl>(
+        this,
+        _$identity,
+      );
+
+  @override
+  Map<String, dynamic> toJson() {
+    return _$$BlockActionDocImplToJson(this);
+  }
+}
+
+abstract class _BlockActionDoc implements BlockActionDoc {
+  const factory _BlockActionDoc({
+    required final BlockActionTypeDoc action,
+    required final BlockDoc block,
+    @Uint32ListConverter() required final Uint32List path,
+    @Uint32ListConverter() final Uint32List? oldPath,
+  }) = _$BlockActionDocImpl;
+
+  factory _BlockActionDoc.fromJson(Map<String, dynamic> json) =
+      _$BlockActionDocImpl.fromJson;
+
+  @override
+  BlockActionTypeDoc get action;
+  @override
+  BlockDoc get block;
+  @override
+  @Uint32ListConverter()
+  Uint32List get path;
+  @override
+  @Uint32ListConverter()
+  Uint32List? get oldPath;
+
+  /// Create a copy of BlockActionDoc
+  /// with the given fields replaced by the non-null parameter values.
+  @override
+  @JsonKey(includeFromJson: false, includeToJson: false)
+  _$$BlockActionDocImplCopyWith<_$BlockActionDocImpl> get copyWith =>
+      throw _privateConstructorUsedError;
+}
+
+BlockDoc _$BlockDocFromJson(Map<String, dynamic> json) {
+  return _BlockDoc.fromJson(json);
+}
+
+/// @nodoc
+mixin _$BlockDoc {
+  String get id => throw _privateConstructorUsedError;
+  String get ty => throw _privateConstructorUsedError;
+  Map<String, String> get attributes => throw _privateConstructorUsedError;
+  String? get delta => throw _privateConstructorUsedError;
+  String? get parentId => throw _privateConstructorUsedError;
+  String? get prevId => throw _privateConstructorUsedError;
+  String? get nextId => throw _privateConstructorUsedError;
+  String? get oldParentId => throw _privateConstructorUsedError;
+
+  /// Serializes this BlockDoc to a JSON map.
+  Map<String, dynamic> toJson() => throw _privateConstructorUsedError;
+
+  /// Create a copy of BlockDoc
+  /// with the given fields replaced by the non-null parameter values.
+  @JsonKey(includeFromJson: false, includeToJson: false)
+  $BlockDocCopyWith<BlockDoc> get copyWith =>
+      throw _privateConstructorUsedError;
+}
+
+/// @nodoc
+abstract class $BlockDocCopyWith<$Res> {
+  factory $BlockDocCopyWith(BlockDoc value, $Res Function(BlockDoc) then) =
+      _$BlockDocCopyWithImpl<$Res, BlockDoc>;
+  @useResult
+  $Res call({
+    String id,
+    String ty,
+    Map<String, String> attributes,
+    String? delta,
+    String? parentId,
+    String? prevId,
+    String? nextId,
+    String? oldParentId,
+  });
+}
+
+/// @nodoc
+class _$BlockDocCopyWithImpl<$Res, $Val extends BlockDoc>
+    implements $BlockDocCopyWith<$Res> {
+  _$BlockDocCopyWithImpl(this._value, this._then);
+
+  // ignore: unused_field
+  final $Val _value;
+  // ignore: unused_field
+  final $Res Function($Val) _then;
+
+  /// Create a copy of BlockDoc
+  /// with the given fields replaced by the non-null parameter values.
+  @pragma('vm:prefer-inline')
+  @override
+  $Res call({
+    Object? id = null,
+    Object? ty = null,
+    Object? attributes = null,
+    Object? delta = freezed,
+    Object? parentId = freezed,
+    Object? prevId = freezed,
+    Object? nextId = freezed,
+    Object? oldParentId = freezed,
+  }) {
+    return _then(
+      _value.copyWith(
+            id:
+                null == id
+                    ? _value.id
+                    : id // ignore: cast_nullable_to_non_nullable
+                        as String,
+            ty:
+                null == ty
+                    ? _value.ty
+                    : ty // ignore: cast_nullable_to_non_nullable
+                        as String,
+            attributes:
+                null == attributes
+                    ? _value.attributes
+                    : attributes // ignore: cast_nullable_to_non_nullable
+                        as Map<String, String>,
+            delta:
+                freezed == delta
+                    ? _value.delta
+                    : delta // ignore: cast_nullable_to_non_nullable
+                        as String?,
+            parentId:
+                freezed == parentId
+                    ? _value.parentId
+                    : parentId // ignore: cast_nullable_to_non_nullable
+                        as String?,
+            prevId:
+                freezed == prevId
+                    ? _value.prevId
+                    : prevId // ignore: cast_nullable_to_non_nullable
+                        as String?,
+            nextId:
+                freezed == nextId
+                    ? _value.nextId
+                    : nextId // ignore: cast_nullable_to_non_nullable
+                        as String?,
+            oldParentId:
+                freezed == oldParentId
+                    ? _value.oldParentId
+                    : oldParentId // ignore: cast_nullable_to_non_nullable
+                        as String?,
+          )
+          as $Val,
+    );
+  }
+}
+
+/// @nodoc
+abstract class _$$BlockDocImplCopyWith<$Res>
+    implements $BlockDocCopyWith<$Res> {
+  factory _$$BlockDocImplCopyWith(
+    _$BlockDocImpl value,
+    $Res Function(_$BlockDocImpl) then,
+  ) = __$$BlockDocImplCopyWithImpl<$Res>;
+  @override
+  @useResult
+  $Res call({
+    String id,
+    String ty,
+    Map<String, String> attributes,
+    String? delta,
+    String? parentId,
+    String? prevId,
+    String? nextId,
+    String? oldParentId,
+  });
+}
+
+/// @nodoc
+class __$$BlockDocImplCopyWithImpl<$Res>
+    extends _$BlockDocCopyWithImpl<$Res, _$BlockDocImpl>
+    implements _$$BlockDocImplCopyWith<$Res> {
+  __$$BlockDocImplCopyWithImpl(
+    _$BlockDocImpl _value,
+    $Res Function(_$BlockDocImpl) _then,
+  ) : super(_value, _then);
+
+  /// Create a copy of BlockDoc
+  /// with the given fields replaced by the non-null parameter values.
+  @pragma('vm:prefer-inline')
+  @override
+  $Res call({
+    Object? id = null,
+    Object? ty = null,
+    Object? attributes = null,
+    Object? delta = freezed,
+    Object? parentId = freezed,
+    Object? prevId = freezed,
+    Object? nextId = freezed,
+    Object? oldParentId = freezed,
+  }) {
+    return _then(
+      _$BlockDocImpl(
+        id:
+            null == id
+                ? _value.id
+                : id // ignore: cast_nullable_to_non_nullable
+                    as String,
+        ty:
+            null == ty
+                ? _value.ty
+                : ty // ignore: cast_nullable_to_non_nullable
+                    as String,
+        attributes:
+            null == attributes
+                ? _value._attributes
+                : attributes // ignore: cast_nullable_to_non_nullable
+                    as Map<String, String>,
+        delta:
+            freezed == delta
+                ? _value.delta
+                : delta // ignore: cast_nullable_to_non_nullable
+                    as String?,
+        parentId:
+            freezed == parentId
+                ? _value.parentId
+                : parentId // ignore: cast_nullable_to_non_nullable
+                    as String?,
+        prevId:
+            freezed == prevId
+                ? _value.prevId
+                : prevId // ignore: cast_nullable_to_non_nullable
+                    as String?,
+        nextId:
+            freezed == nextId
+                ? _value.nextId
+                : nextId // ignore: cast_nullable_to_non_nullable
+                    as String?,
+        oldParentId:
+            freezed == oldParentId
+                ? _value.oldParentId
+                : oldParentId // ignore: cast_nullable_to_non_nullable
+                    as String?,
+      ),
+    );
+  }
+}
+
+/// @nodoc
+@JsonSerializable()
+class _$BlockDocImpl implements _BlockDoc {
+  const _$BlockDocImpl({
+    required this.id,
+    required this.ty,
+    required final Map<String, String> attributes,
+    this.delta,
+    this.parentId,
+    this.prevId,
+    this.nextId,
+    this.oldParentId,
+  }) : _attributes = attributes;
+
+  factory _$BlockDocImpl.fromJson(Map<String, dynamic> json) =>
+      _$$BlockDocImplFromJson(json);
+
+  @override
+  final String id;
+  @override
+  final String ty;
+  final Map<String, String> _attributes;
+  @override
+  Map<String, String> get attributes {
+    if (_attributes is EqualUnmodifiableMapView) return _attributes;
+    // ignore: implicit_dynamic_type
+    return EqualUnmodifiableMapView(_attributes);
+  }
+
+  @override
+  final String? delta;
+  @override
+  final String? parentId;
+  @override
+  final String? prevId;
+  @override
+  final String? nextId;
+  @override
+  final String? oldParentId;
+
+  @override
+  String toString() {
+    return 'BlockDoc(id: $id, ty: $ty, attributes: $attributes, delta: $delta, parentId: $parentId, prevId: $prevId, nextId: $nextId, oldParentId: $oldParentId)';
+  }
+
+  @override
+  bool operator ==(Object other) {
+    return identical(this, other) ||
+        (other.runtimeType == runtimeType &&
+            other is _$BlockDocImpl &&
+            (identical(other.id, id) || other.id == id) &&
+            (identical(other.ty, ty) || other.ty == ty) &&
+            const DeepCollectionEquality().equals(
+              other._attributes,
+              _attributes,
+            ) &&
+            (identical(other.delta, delta) || other.delta == delta) &&
+            (identical(other.parentId, parentId) ||
+                other.parentId == parentId) &&
+            (identical(other.prevId, prevId) || other.prevId == prevId) &&
+            (identical(other.nextId, nextId) || other.nextId == nextId) &&
+            (identical(other.oldParentId, oldParentId) ||
+                other.oldParentId == oldParentId));
+  }
+
+  @JsonKey(includeFromJson: false, includeToJson: false)
+  @override
+  int get hashCode => Object.hash(
+    runtimeType,
+    id,
+    ty,
+    const DeepCollectionEquality().hash(_attributes),
+    delta,
+    parentId,
+    prevId,
+    nextId,
+    oldParentId,
+  );
+
+  /// Create a copy of BlockDoc
+  /// with the given fields replaced by the non-null parameter values.
+  @JsonKey(includeFromJson: false, includeToJson: false)
+  @override
+  @pragma('vm:prefer-inline')
+  _$$BlockDocImplCopyWith<_$BlockDocImpl> get copyWith =>
+      __$$BlockDocImplCopyWithImpl<_$BlockDocImpl>(this, _$identity);
+
+  @override
+  Map<String, dynamic> toJson() {
+    return _$$BlockDocImplToJson(this);
+  }
+}
+
+abstract class _BlockDoc implements BlockDoc {
+  const factory _BlockDoc({
+    required final String id,
+    required final String ty,
+    required final Map<String, String> attributes,
+    final String? delta,
+    final String? parentId,
+    final String? prevId,
+    final String? nextId,
+    final String? oldParentId,
+  }) = _$BlockDocImpl;
+
+  factory _BlockDoc.fromJson(Map<String, dynamic> json) =
+      _$BlockDocImpl.fromJson;
+
+  @override
+  String get id;
+  @override
+  String get ty;
+  @override
+  Map<String, String> get attributes;
+  @override
+  String? get delta;
+  @override
+  String? get parentId;
+  @override
+  String? get prevId;
+  @override
+  String? get nextId;
+  @override
+  String? get oldParentId;
+
+  /// Create a copy of BlockDoc
+  /// with the given fields replaced by the non-null parameter values.
+  @override
+  @JsonKey(includeFromJson: false, includeToJson: false)
+  _$$BlockDocImplCopyWith<_$BlockDocImpl> get copyWith =>
+      throw _privateConstructorUsedError;
+}
+
+DocumentChangeEvent _$DocumentChangeEventFromJson(Map<String, dynamic> json) {
+  return _DocumentChangeEvent.fromJson(json);
+}
+
+/// @nodoc
+mixin _$DocumentChangeEvent {
+  DocumentChangeKindDoc get kind => throw _privateConstructorUsedError;
+  String get blockId => throw _privateConstructorUsedError;
+  String? get delta => throw _privateConstructorUsedError;
+  String? get origin => throw _privateConstructorUsedError;
+
+  /// Serializes this DocumentChangeEvent to a JSON map.
+  Map<String, dynamic> toJson() => throw _privateConstructorUsedError;
+
+  /// Create a copy of DocumentChangeEvent
+  /// with the given fields replaced by the non-null parameter values.
+  @JsonKey(includeFromJson: false, includeToJson: false)
+  $DocumentChangeEventCopyWith<DocumentChangeEvent> get copyWith =>
+      throw _privateConstructorUsedError;
+}
+
+/// @nodoc
+abstract class $DocumentChangeEventCopyWith<$Res> {
+  factory $DocumentChangeEventCopyWith(
+    DocumentChangeEvent value,
+    $Res Function(DocumentChangeEvent) then,
+  ) = _$DocumentChangeEventCopyWithImpl<$Res, DocumentChangeEvent>;
+  @useResult
+  $Res call({
+    DocumentChangeKindDoc kind,
+    String blockId,
+    String? delta,
+    String? origin,
+  });
+}
+
+/// @nodoc
+class _$DocumentChangeEventCopyWithImpl<$Res, $Val extends DocumentChangeEvent>
+    implements $DocumentChangeEventCopyWith<$Res> {
+  _$DocumentChangeEventCopyWithImpl(this._value, this._then);
+
+  // ignore: unused_field
+  final $Val _value;
+  // ignore: unused_field
+  final $Res Function($Val) _then;
+
+  /// Create a copy of DocumentChangeEvent
+  /// with the given fields replaced by the non-null parameter values.
+  @pragma('vm:prefer-inline')
+  @override
+  $Res call({
+    Object? kind = null,
+    Object? blockId = null,
+    Object? delta = freezed,
+    Object? origin = freezed,
+  }) {
+    return _then(
+      _value.copyWith(
+            kind:
+                null == kind
+                    ? _value.kind
+                    : kind // ignore: cast_nullable_to_non_nullable
+                        as DocumentChangeKindDoc,
+            blockId:
+                null == blockId
+                    ? _value.blockId
+                    : blockId // ignore: cast_nullable_to_non_nullable
+                        as String,
+            delta:
+                freezed == delta
+                    ? _value.delta
+                    : delta // ignore: cast_nullable_to_non_nullable
+                        as String?,
+            origin:
+                freezed == origin
+                    ? _value.origin
+                    : origin // ignore: cast_nullable_to_non_nullable
+                        as String?,
+          )
+          as $Val,
+    );
+  }
+}
+
+/// @nodoc
+abstract class _$$DocumentChangeEventImplCopyWith<$Res>
+    implements $DocumentChangeEventCopyWith<$Res> {
+  factory _$$DocumentChangeEventImplCopyWith(
+    _$DocumentChangeEventImpl value,
+    $Res Function(_$DocumentChangeEventImpl) then,
+  ) = __$$DocumentChangeEventImplCopyWithImpl<$Res>;
+  @override
+  @useResult
+  $Res call({
+    DocumentChangeKindDoc kind,
+    String blockId,
+    String? delta,
+    String? origin,
+  });
+}
+
+/// @nodoc
+class __$$DocumentChangeEventImplCopyWithImpl<$Res>
+    extends _$DocumentChangeEventCopyWithImpl<$Res, _$DocumentChangeEventImpl>
+    implements _$$DocumentChangeEventImplCopyWith<$Res> {
+  __$$DocumentChangeEventImplCopyWithImpl(
+    _$DocumentChangeEventImpl _value,
+    $Res Function(_$DocumentChangeEventImpl) _then,
+  ) : super(_value, _then);
+
+  /// Create a copy of DocumentChangeEvent
+  /// with the given fields replaced by the non-null parameter values.
+  @pragma('vm:prefer-inline')
+  @override
+  $Res call({
+    Object? kind = null,
+    Object? blockId = null,
+    Object? delta = freezed,
+    Object? origin = freezed,
+  }) {
+    return _then(
+      _$DocumentChangeEventImpl(
+        kind:
+            null == kind
+                ? _value.kind
+                : kind // ignore: cast_nullable_to_non_nullable
+                    as DocumentChangeKindDoc,
+        blockId:
+            null == blockId
+                ? _value.blockId
+                : blockId // ignore: cast_nullable_to_non_nullable
+                    as String,
+        delta:
+            freezed == delta
+                ? _value.delta
+                : delta // ignore: cast_nullable_to_non_nullable
+                    as String?,
+        origin:
+            freezed == origin
+                ? _value.origin
+                : origin // ignore: cast_nullable_to_non_nullable
+                    as String?,
+      ),
+    );
+  }
+}
+
+/// @nodoc
+@JsonSerializable()
+class _$DocumentChangeEventImpl implements _DocumentChangeEvent {
+  const _$DocumentChangeEventImpl({
+    required this.kind,
+    required this.blockId,
+    this.delta,
+    this.origin,
+  });
+
+  factory _$DocumentChangeEventImpl.fromJson(Map<String, dynamic> json) =>
+      _$$DocumentChangeEventImplFromJson(json);
+
+  @override
+  final DocumentChangeKindDoc kind;
+  @override
+  final String blockId;
+  @override
+  final String? delta;
+  @override
+  final String? origin;
+
+  @override
+  String toString() {
+    return 'DocumentChangeEvent(kind: $kind, blockId: $blockId, delta: $delta, origin: $origin)';
+  }
+
+  @override
+  bool operator ==(Object other) {
+    return identical(this, other) ||
+        (other.runtimeType == runtimeType &&
+            other is _$DocumentChangeEventImpl &&
+            (identical(other.kind, kind) || other.kind == kind) &&
+            (identical(other.blockId, blockId) || other.blockId == blockId) &&
+            (identical(other.delta, delta) || other.delta == delta) &&
+            (identical(other.origin, origin) || other.origin == origin));
+  }
+
+  @JsonKey(includeFromJson: false, includeToJson: false)
+  @override
+  int get hashCode => Object.hash(runtimeType, kind, blockId, delta, origin);
+
+  /// Create a copy of DocumentChangeEvent
+  /// with the given fields replaced by the non-null parameter values.
+  @JsonKey(includeFromJson: false, includeToJson: false)
+  @override
+  @pragma('vm:prefer-inline')
+  _$$DocumentChangeEventImplCopyWith<_$DocumentChangeEventImpl> get copyWith =>
+      __$$DocumentChangeEventImplCopyWithImpl<_$DocumentChangeEventImpl>(
+        this,
+        _$identity,
+      );
+
+  @override
+  Map<String, dynamic> toJson() {
+    return _$$DocumentChangeEventImplToJson(this);
+  }
+}
+
+abstract class _DocumentChangeEvent implements DocumentChangeEvent {
+  const factory _DocumentChangeEvent({
+    required final DocumentChangeKindDoc kind,
+    required final String blockId,
+    final String? delta,
+    final String? origin,
+  }) = _$DocumentChangeEventImpl;
+
+  factory _DocumentChangeEvent.fromJson(Map<String, dynamic> json) =
+      _$DocumentChangeEventImpl.fromJson;
+
+  @override
+  DocumentChangeKindDoc get kind;
+  @override
+  String get blockId;
+  @override
+  String? get delta;
+  @override
+  String? get origin;
+
+  /// Create a copy of DocumentChangeEvent
+  /// with the given fields replaced by the non-null parameter values.
+  @override
+  @JsonKey(includeFromJson: false, includeToJson: false)
+  _$$DocumentChangeEventImplCopyWith<_$DocumentChangeEventImpl> get copyWith =>
+      throw _privateConstructorUsedError;
+}
+
+DocumentOptions _$DocumentOptionsFromJson(Map<String, dynamic> json) {
+  return _DocumentOptions.fromJson(json);
+}
+
+/// @nodoc
+mixin _$DocumentOptions {
+  BigInt? get clientId => throw _privateConstructorUsedError;
+  OffsetKindDoc get offsetKind => throw _privateConstructorUsedError;
+  bool get skipGc => throw _privateConstructorUsedError;
+  UpdateEncodingDoc get updateEncoding => throw _privateConstructorUsedError;
+  AttributeModeDoc get attributeMode => throw _privateConstructorUsedError;
+
+  /// Serializes this DocumentOptions to a JSON map.
+  Map<String, dynamic> toJson() => throw _privateConstructorUsedError;
+
+  /// Create a copy of DocumentOptions
+  /// with the given fields replaced by the non-null parameter values.
+  @JsonKey(includeFromJson: false, includeToJson: false)
+  $DocumentOptionsCopyWith<DocumentOptions> get copyWith =>
+      throw _privateConstructorUsedError;
+}
+
+/// @nodoc
+abstract class $DocumentOptionsCopyWith<$Res> {
+  factory $DocumentOptionsCopyWith(
+    DocumentOptions value,
+    $Res Function(DocumentOptions) then,
+  ) = _$DocumentOptionsCopyWithImpl<$Res, DocumentOptions>;
+  @useResult
+  $Res call({
+    BigInt? clientId,
+    OffsetKindDoc offsetKind,
+    bool skipGc,
+    UpdateEncodingDoc updateEncoding,
+    AttributeModeDoc attributeMode,
+  });
+}
+
+/// @nodoc
+class _$DocumentOptionsCopyWithImpl<$Res, $Val extends DocumentOptions>
+    implements $DocumentOptionsCopyWith<$Res> {
+  _$DocumentOptionsCopyWithImpl(this._value, this._then);
+
+  // ignore: unused_field
+  final $Val _value;
+  // ignore: unused_field
+  final $Res Function($Val) _then;
+
+  /// Create a copy of DocumentOptions
+  /// with the given fields replaced by the non-null parameter values.
+  @pragma('vm:prefer-inline')
+  @override
+  $Res call({
+    Object? clientId = freezed,
+    Object? offsetKind = null,
+    Object? skipGc = null,
+    Object? updateEncoding = null,
+    Object? attributeMode = null,
+  }) {
+    return _then(
+      _value.copyWith(
+            clientId:
+                freezed == clientId
+                    ? _value.clientId
+                    : clientId // ignore: cast_nullable_to_non_nullable
+                        as BigInt?,
+            offsetKind:
+                null == offsetKind
+                    ? _value.offsetKind
+                    : offsetKind // ignore: cast_nullable_to_non_nullable
+                        as OffsetKindDoc,
+            skipGc:
+                null == skipGc
+                    ? _value.skipGc
+                    : skipGc // ignore: cast_nullable_to_non_nullable
+                        as bool,
+            updateEncoding:
+                null == updateEncoding
+                    ? _value.updateEncoding
+                    : updateEncoding // ignore: cast_nullable_to_non_nullable
+                        as UpdateEncodingDoc,
+            attributeMode:
+                null == attributeMode
+                    ? _value.attributeMode
+                    : attributeMode // ignore: cast_nullable_to_non_nullable
+                        as AttributeModeDoc,
+          )
+          as $Val,
+    );
+  }
+}
+
+/// @nodoc
+abstract class _$$DocumentOptionsImplCopyWith<$Res>
+    implements $DocumentOptionsCopyWith<$Res> {
+  factory _$$DocumentOptionsImplCopyWith(
+    _$DocumentOptionsImpl value,
+    $Res Function(_$DocumentOptionsImpl) then,
+  ) = __$$DocumentOptionsImplCopyWithImpl<$Res>;
+  @override
+  @useResult
+  $Res call({
+    BigInt? clientId,
+    OffsetKindDoc offsetKind,
+    bool skipGc,
+    UpdateEncodingDoc updateEncoding,
+    AttributeModeDoc attributeMode,
+  });
+}
+
+/// @nodoc
+class __$$DocumentOptionsImplCopyWithImpl<$Res>
+    extends _$DocumentOptionsCopyWithImpl<$Res, _$DocumentOptionsImpl>
+    implements _$$DocumentOptionsImplCopyWith<$Res> {
+  __$$DocumentOptionsImplCopyWithImpl(
+    _$DocumentOptionsImpl _value,
+    $Res Function(_$DocumentOptionsImpl) _then,
+  ) : super(_value, _then);
+
+  /// Create a copy of DocumentOptions
+  /// with the given fields replaced by the non-null parameter values.
+  @pragma('vm:prefer-inline')
+  @override
+  $Res call({
+    Object? clientId = freezed,
+    Object? offsetKind = null,
+    Object? skipGc = null,
+    Object? updateEncoding = null,
+    Object? attributeMode = null,
+  }) {
+    return _then(
+      _$DocumentOptionsImpl(
+        clientId:
+            freezed == clientId
+                ? _value.clientId
+                : clientId // ignore: cast_nullable_to_non_nullable
+                    as BigInt?,
+        offsetKind:
+            null == offsetKind
+                ? _value.offsetKind
+                : offsetKind // ignore: cast_nullable_to_non_nullable
+                    as OffsetKindDoc,
+        skipGc:
+            null == skipGc
+                ? _value.skipGc
+                : skipGc // ignore: cast_nullable_to_non_nullable
+                    as bool,
+        updateEncoding:
+            null == updateEncoding
+                ? _value.updateEncoding
+                : updateEncoding // ignore: cast_nullable_to_non_nullable
+                    as UpdateEncodingDoc,
+        attributeMode:
+            null == attributeMode
+                ? _value.attributeMode
+                : attributeMode // ignore: cast_nullable_to_non_nullable
+                    as AttributeModeDoc,
+      ),
+    );
+  }
+}
+
+/// @nodoc
+@JsonSerializable()
+class _$DocumentOptionsImpl extends _DocumentOptions {
+  const _$DocumentOptionsImpl({
+    this.clientId,
+    required this.offsetKind,
+    required this.skipGc,
+    required this.updateEncoding,
+    required this.attributeMode,
+  }) : super._();
+
+  factory _$DocumentOptionsImpl.fromJson(Map<String, dynamic> json) =>
+      _$$DocumentOptionsImplFromJson(json);
+
+  @override
+  final BigInt? clientId;
+  @override
+  final OffsetKindDoc offsetKind;
+  @override
+  final bool skipGc;
+  @override
+  final UpdateEncodingDoc updateEncoding;
+  @override
+  final AttributeModeDoc attributeMode;
+
+  @override
+  String toString() {
+    return 'DocumentOptions(clientId: $clientId, offsetKind: $offsetKind, skipGc: $skipGc, updateEncoding: $updateEncoding, attributeMode: $attributeMode)';
+  }
+
+  @override
+  bool operator ==(Object other) {
+    return identical(this, other) ||
+        (other.runtimeType == runtimeType &&
+            other is _$DocumentOptionsImpl &&
+            (identical(other.clientId, clientId) ||
+                other.clientId == clientId) &&
+            (identical(other.offsetKind, offsetKind) ||
+                other.offsetKind == offsetKind) &&
+            (identical(other.skipGc, skipGc) || other.skipGc == skipGc) &&
+            (identical(other.updateEncoding, updateEncoding) ||
+                other.updateEncoding == updateEncoding) &&
+            (identical(other.attributeMode, attributeMode) ||
+                other.attributeMode == attributeMode));
+  }
+
+  @JsonKey(includeFromJson: false, includeToJson: false)
+  @override
+  int get hashCode => Object.hash(
+    runtimeType,
+    clientId,
+    offsetKind,
+    skipGc,
+    updateEncoding,
+    attributeMode,
+  );
+
+  /// Create a copy of DocumentOptions
+  /// with the given fields replaced by the non-null parameter values.
+  @JsonKey(includeFromJson: false, includeToJson: false)
+  @override
+  @pragma('vm:prefer-inline')
+  _$$DocumentOptionsImplCopyWith<_$DocumentOptionsImpl> get copyWith =>
+      __$$DocumentOptionsImplCopyWithImpl<_$DocumentOptionsImpl>(
+        this,
+        _$identity,
+      );
+
+  @override
+  Map<String, dynamic> toJson() {
+    return _$$DocumentOptionsImplToJson(this);
+  }
+}
+
+abstract class _DocumentOptions extends DocumentOptions {
+  const factory _DocumentOptions({
+    final BigInt? clientId,
+    required final OffsetKindDoc offsetKind,
+    required final bool skipGc,
+    required final UpdateEncodingDoc updateEncoding,
+    required final AttributeModeDoc attributeMode,
+  }) = _$DocumentOptionsImpl;
+  const _DocumentOptions._() : super._();
+
+  factory _DocumentOptions.fromJson(Map<String, dynamic> json) =
+      _$DocumentOptionsImpl.fromJson;
+
+  @override
+  BigInt? get clientId;
+  @override
+  OffsetKindDoc get offsetKind;
+  @override
+  bool get skipGc;
+  @override
+  UpdateEncodingDoc get updateEncoding;
+  @override
+  AttributeModeDoc get attributeMode;
+
+  /// Create a copy of DocumentOptions
+  /// with the given fields replaced by the non-null parameter values.
+  @override
+  @JsonKey(includeFromJson: false, includeToJson: false)
+  _$$DocumentOptionsImplCopyWith<_$DocumentOptionsImpl> get copyWith =>
+      throw _privateConstructorUsedError;
+}
+
+DocumentState _$DocumentStateFromJson(Map<String, dynamic> json) {
+  return _DocumentState.fromJson(json);
 }
 
 /// @nodoc
@@ -624,75 +2746,682 @@ mixin _$DocumentState {
   Map<String, BlockDoc> get blocks => throw _privateConstructorUsedError;
   Map<String, List<String>> get childrenMap =>
       throw _privateConstructorUsedError;
-  String get rootId => throw _privateConstructorUsedError;
+  String get rootId => throw _privateConstructorUsedError;
+
+  /// Serializes this DocumentState to a JSON map.
+  Map<String, dynamic> toJson() => throw _privateConstructorUsedError;
+
+  /// Create a copy of DocumentState
+  /// with the given fields replaced by the non-null parameter values.
+  @JsonKey(includeFromJson: false, includeToJson: false)
+  $DocumentStateCopyWith<DocumentState> get copyWith =>
+      throw _privateConstructorUsedError;
+}
+
+/// @nodoc
+abstract class $DocumentStateCopyWith<$Res> {
+  factory $DocumentStateCopyWith(
+    DocumentState value,
+    $Res Function(DocumentState) then,
+  ) = _$DocumentStateCopyWithImpl<$Res, DocumentState>;
+  @useResult
+  $Res call({
+    String docId,
+    Map<String, BlockDoc> blocks,
+    Map<String, List<String>> childrenMap,
+    String rootId,
+  });
+}
+
+/// @nodoc
+class _$DocumentStateCopyWithImpl<$Res, $Val extends DocumentState>
+    implements $DocumentStateCopyWith<$Res> {
+  _$DocumentStateCopyWithImpl(this._value, this._then);
+
+  // ignore: unused_field
+  final $Val _value;
+  // ignore: unused_field
+  final $Res Function($Val) _then;
+
+  /// Create a copy of DocumentState
+  /// with the given fields replaced by the non-null parameter values.
+  @pragma('vm:prefer-inline')
+  @override
+  $Res call({
+    Object? docId = null,
+    Object? blocks = null,
+    Object? childrenMap = null,
+    Object? rootId = null,
+  }) {
+    return _then(
+      _value.copyWith(
+            docId:
+                null == docId
+                    ? _value.docId
+                    : docId // ignore: cast_nullable_to_non_nullable
+                        as String,
+            blocks:
+                null == blocks
+                    ? _value.blocks
+                    : blocks // ignore: cast_nullable_to_non_nullable
+                        as Map<String, BlockDoc>,
+            childrenMap:
+                null == childrenMap
+                    ? _value.childrenMap
+                    : childrenMap // ignore: cast_nullable_to_non_nullable
+                        as Map<String, List<String>>,
+            rootId:
+                null == rootId
+                    ? _value.rootId
+                    : rootId // ignore: cast_nullable_to_non_nullable
+                        as String,
+          )
+          as $Val,
+    );
+  }
+}
+
+/// @nodoc
+abstract class _$$DocumentStateImplCopyWith<$Res>
+    implements $DocumentStateCopyWith<$Res> {
+  factory _$$DocumentStateImplCopyWith(
+    _$DocumentStateImpl value,
+    $Res Function(_$DocumentStateImpl) then,
+  ) = __$$DocumentStateImplCopyWithImpl<$Res>;
+  @override
+  @useResult
+  $Res call({
+    String docId,
+    Map<String, BlockDoc> blocks,
+    Map<String, List<String>> childrenMap,
+    String rootId,
+  });
+}
+
+/// @nodoc
+class __$$DocumentStateImplCopyWithImpl<$Res>
+    extends _$DocumentStateCopyWithImpl<$Res, _$DocumentStateImpl>
+    implements _$$DocumentStateImplCopyWith<$Res> {
+  __$$DocumentStateImplCopyWithImpl(
+    _$DocumentStateImpl _value,
+    $Res Function(_$DocumentStateImpl) _then,
+  ) : super(_value, _then);
+
+  /// Create a copy of DocumentState
+  /// with the given fields replaced by the non-null parameter values.
+  @pragma('vm:prefer-inline')
+  @override
+  $Res call({
+    Object? docId = null,
+    Object? blocks = null,
+    Object? childrenMap = null,
+    Object? rootId = null,
+  }) {
+    return _then(
+      _$DocumentStateImpl(
+        docId:
+            null == docId
+                ? _value.docId
+                : docId // ignore: cast_nullable_to_non_nullable
+                    as String,
+        blocks:
+            null == blocks
+                ? _value._blocks
+                : blocks // ignore: cast_nullable_to_non_nullable
+                    as Map<String, BlockDoc>,
+        childrenMap:
+            null == childrenMap
+                ? _value._childrenMap
+                : childrenMap // ignore: cast_nullable_to_non_nullable
+                    as Map<String, List<String>>,
+        rootId:
+            null == rootId
+                ? _value.rootId
+                : rootId // ignore: cast_nullable_to_non_nullable
+                    as String,
+      ),
+    );
+  }
+}
+
+/// @nodoc
+@JsonSerializable()
+class _$DocumentStateImpl implements _DocumentState {
+  const _$DocumentStateImpl({
+    required this.docId,
+    required final Map<String, BlockDoc> blocks,
+    required final Map<String, List<String>> childrenMap,
+    required this.rootId,
+  }) : _blocks = blocks,
+       _childrenMap = childrenMap;
+
+  factory _$DocumentStateImpl.fromJson(Map<String, dynamic> json) =>
+      _$$DocumentStateImplFromJson(json);
+
+  @override
+  final String docId;
+  final Map<String, BlockDoc> _blocks;
+  @override
+  Map<String, BlockDoc> get blocks {
+    if (_blocks is EqualUnmodifiableMapView) return _blocks;
+    // ignore: implicit_dynamic_type
+    return EqualUnmodifiableMapView(_blocks);
+  }
+
+  final Map<String, List<String>> _childrenMap;
+  @override
+  Map<String, List<String>> get childrenMap {
+    if (_childrenMap is EqualUnmodifiableMapView) return _childrenMap;
+    // ignore: implicit_dynamic_type
+    return EqualUnmodifiableMapView(_childrenMap);
+  }
+
+  @override
+  final String rootId;
+
+  @override
+  String toString() {
+    return 'DocumentState(docId: $docId, blocks: $blocks, childrenMap: $childrenMap, rootId: $rootId)';
+  }
+
+  @override
+  bool operator ==(Object other) {
+    return identical(this, other) ||
+        (other.runtimeType == runtimeType &&
+            other is _$DocumentStateImpl &&
+            (identical(other.docId, docId) || other.docId == docId) &&
+            const DeepCollectionEquality().equals(other._blocks, _blocks) &&
+            const DeepCollectionEquality().equals(
+              other._childrenMap,
+              _childrenMap,
+            ) &&
+            (identical(other.rootId, rootId) || other.rootId == rootId));
+  }
+
+  @JsonKey(includeFromJson: false, includeToJson: false)
+  @override
+  int get hashCode => Object.hash(
+    runtimeType,
+    docId,
+    const DeepCollectionEquality().hash(_blocks),
+    const DeepCollectionEquality().hash(_childrenMap),
+    rootId,
+  );
+
+  /// Create a copy of DocumentState
+  /// with the given fields replaced by the non-null parameter values.
+  @JsonKey(includeFromJson: false, includeToJson: false)
+  @override
+  @pragma('vm:prefer-inline')
+  _$$DocumentStateImplCopyWith<_$DocumentStateImpl> get copyWith =>
+      __$$DocumentStateImplCopyWithImpl<_$DocumentStateImpl>(this, _$identity);
+
+  @override
+  Map<String, dynamic> toJson() {
+    return _$$DocumentStateImplToJson(this);
+  }
+}
+
+abstract class _DocumentState implements DocumentState {
+  const factory _DocumentState({
+    required final String docId,
+    required final Map<String, BlockDoc> blocks,
+    required final Map<String, List<String>> childrenMap,
+    required final String rootId,
+  }) = _$DocumentStateImpl;
+
+  factory _DocumentState.fromJson(Map<String, dynamic> json) =
+      _$DocumentStateImpl.fromJson;
+
+  @override
+  String get docId;
+  @override
+  Map<String, BlockDoc> get blocks;
+  @override
+  Map<String, List<String>> get childrenMap;
+  @override
+  String get rootId;
+
+  /// Create a copy of DocumentState
+  /// with the given fields replaced by the non-null parameter values.
+  @override
+  @JsonKey(includeFromJson: false, includeToJson: false)
+  _$$DocumentStateImplCopyWith<_$DocumentStateImpl> get copyWith =>
+      throw _privateConstructorUsedError;
+}
+
+FailedToDecodeUpdates _$FailedToDecodeUpdatesFromJson(
+  Map<String, dynamic> json,
+) {
+  return _FailedToDecodeUpdates.fromJson(json);
+}
+
+/// @nodoc
+mixin _$FailedToDecodeUpdates {
+  List<String> get failedUpdatesIds => throw _privateConstructorUsedError;
+
+  /// Serializes this FailedToDecodeUpdates to a JSON map.
+  Map<String, dynamic> toJson() => throw _privateConstructorUsedError;
+
+  /// Create a copy of FailedToDecodeUpdates
+  /// with the given fields replaced by the non-null parameter values.
+  @JsonKey(includeFromJson: false, includeToJson: false)
+  $FailedToDecodeUpdatesCopyWith<FailedToDecodeUpdates> get copyWith =>
+      throw _privateConstructorUsedError;
+}
+
+/// @nodoc
+abstract class $FailedToDecodeUpdatesCopyWith<$Res> {
+  factory $FailedToDecodeUpdatesCopyWith(
+    FailedToDecodeUpdates value,
+    $Res Function(FailedToDecodeUpdates) then,
+  ) = _$FailedToDecodeUpdatesCopyWithImpl<$Res, FailedToDecodeUpdates>;
+  @useResult
+  $Res call({List<String> failedUpdatesIds});
+}
+
+/// @nodoc
+class _$FailedToDecodeUpdatesCopyWithImpl<
+  $Res,
+  $Val extends FailedToDecodeUpdates
+>
+    implements $FailedToDecodeUpdatesCopyWith<$Res> {
+  _$FailedToDecodeUpdatesCopyWithImpl(this._value, this._then);
+
+  // ignore: unused_field
+  final $Val _value;
+  // ignore: unused_field
+  final $Res Function($Val) _then;
+
+  /// Create a copy of FailedToDecodeUpdates
+  /// with the given fields replaced by the non-null parameter values.
+  @pragma('vm:prefer-inline')
+  @override
+  $Res call({Object? failedUpdatesIds = null}) {
+    return _then(
+      _value.copyWith(
+            failedUpdatesIds:
+                null == failedUpdatesIds
+                    ? _value.failedUpdatesIds
+                    : failedUpdatesIds // ignore: cast_nullable_to_non_nullable
+                        as List<String>,
+          )
+          as $Val,
+    );
+  }
+}
+
+/// @nodoc
+abstract class _$$FailedToDecodeUpdatesImplCopyWith<$Res>
+    implements $FailedToDecodeUpdatesCopyWith<$Res> {
+  factory _$$FailedToDecodeUpdatesImplCopyWith(
+    _$FailedToDecodeUpdatesImpl value,
+    $Res Function(_$FailedToDecodeUpdatesImpl) then,
+  ) = __$$FailedToDecodeUpdatesImplCopyWithImpl<$Res>;
+  @override
+  @useResult
+  $Res call({List<String> failedUpdatesIds});
+}
+
+/// @nodoc
+class __$$FailedToDecodeUpdatesImplCopyWithImpl<$Res>
+    extends
+        _$FailedToDecodeUpdatesCopyWithImpl<$Res, _$FailedToDecodeUpdatesImpl>
+    implements _$$FailedToDecodeUpdatesImplCopyWith<$Res> {
+  __$$FailedToDecodeUpdatesImplCopyWithImpl(
+    _$FailedToDecodeUpdatesImpl _value,
+    $Res Function(_$FailedToDecodeUpdatesImpl) _then,
+  ) : super(_value, _then);
+
+  /// Create a copy of FailedToDecodeUpdates
+  /// with the given fields replaced by the non-null parameter values.
+  @pragma('vm:prefer-inline')
+  @override
+  $Res call({Object? failedUpdatesIds = null}) {
+    return _then(
+      _$FailedToDecodeUpdatesImpl(
+        failedUpdatesIds:
+            null == failedUpdatesIds
+                ? _value._failedUpdatesIds
+                : failedUpdatesIds // ignore: cast_nullable_to_non_nullable
+                    as List<String>,
+      ),
+    );
+  }
+}
+
+/// @nodoc
+@JsonSerializable()
+class _$FailedToDecodeUpdatesImpl implements _FailedToDecodeUpdates {
+  const _$FailedToDecodeUpdatesImpl({
+    required final List<String> failedUpdatesIds,
+  }) : _failedUpdatesIds = failedUpdatesIds;
+
+  factory _$FailedToDecodeUpdatesImpl.fromJson(Map<String, dynamic> json) =>
+      _$$FailedToDecodeUpdatesImplFromJson(json);
+
+  final List<String> _failedUpdatesIds;
+  @override
+  List<String> get failedUpdatesIds {
+    if (_failedUpdatesIds is EqualUnmodifiableListView)
+      return _failedUpdatesIds;
+    // ignore: implicit_dynamic_type
+    return EqualUnmodifiableListView(_failedUpdatesIds);
+  }
+
+  @override
+  String toString() {
+    return 'FailedToDecodeUpdates(failedUpdatesIds: $failedUpdatesIds)';
+  }
+
+  @override
+  bool operator ==(Object other) {
+    return identical(this, other) ||
+        (other.runtimeType == runtimeType &&
+            other is _$FailedToDecodeUpdatesImpl &&
+            const DeepCollectionEquality().equals(
+              other._failedUpdatesIds,
+              _failedUpdatesIds,
+            ));
+  }
+
+  @JsonKey(includeFromJson: false, includeToJson: false)
+  @override
+  int get hashCode => Object.hash(
+    runtimeType,
+    const DeepCollectionEquality().hash(_failedUpdatesIds),
+  );
+
+  /// Create a copy of FailedToDecodeUpdates
+  /// with the given fields replaced by the non-null parameter values.
+  @JsonKey(includeFromJson: false, includeToJson: false)
+  @override
+  @pragma('vm:prefer-inline')
+  _$$FailedToDecodeUpdatesImplCopyWith<_$FailedToDecodeUpdatesImpl>
+  get copyWith =>
+      __$$FailedToDecodeUpdatesImplCopyWithImpl<_$FailedToDecodeUpdatesImpl>(
+        this,
+        _$identity,
+      );
+
+  @override
+  Map<String, dynamic> toJson() {
+    return _$$FailedToDecodeUpdatesImplToJson(this);
+  }
+}
+
+abstract class _FailedToDecodeUpdates implements FailedToDecodeUpdates {
+  const factory _FailedToDecodeUpdates({
+    required final List<String> failedUpdatesIds,
+  }) = _$FailedToDecodeUpdatesImpl;
+
+  factory _FailedToDecodeUpdates.fromJson(Map<String, dynamic> json) =
+      _$FailedToDecodeUpdatesImpl.fromJson;
+
+  @override
+  List<String> get failedUpdatesIds;
+
+  /// Create a copy of FailedToDecodeUpdates
+  /// with the given fields replaced by the non-null parameter values.
+  @override
+  @JsonKey(includeFromJson: false, includeToJson: false)
+  _$$FailedToDecodeUpdatesImplCopyWith<_$FailedToDecodeUpdatesImpl>
+  get copyWith => throw _privateConstructorUsedError;
+}
+
+IntegrityIssueDoc _$IntegrityIssueDocFromJson(Map<String, dynamic> json) {
+  return _IntegrityIssueDoc.fromJson(json);
+}
+
+/// @nodoc
+mixin _$IntegrityIssueDoc {
+  IntegrityIssueKindDoc get kind => throw _privateConstructorUsedError;
+  List<String> get blockIds => throw _privateConstructorUsedError;
+
+  /// Serializes this IntegrityIssueDoc to a JSON map.
+  Map<String, dynamic> toJson() => throw _privateConstructorUsedError;
+
+  /// Create a copy of IntegrityIssueDoc
+  /// with the given fields replaced by the non-null parameter values.
+  @JsonKey(includeFromJson: false, includeToJson: false)
+  $IntegrityIssueDocCopyWith<IntegrityIssueDoc> get copyWith =>
+      throw _privateConstructorUsedError;
+}
+
+/// @nodoc
+abstract class $IntegrityIssueDocCopyWith<$Res> {
+  factory $IntegrityIssueDocCopyWith(
+    IntegrityIssueDoc value,
+    $Res Function(IntegrityIssueDoc) then,
+  ) = _$IntegrityIssueDocCopyWithImpl<$Res, IntegrityIssueDoc>;
+  @useResult
+  $Res call({IntegrityIssueKindDoc kind, List<String> blockIds});
+}
+
+/// @nodoc
+class _$IntegrityIssueDocCopyWithImpl<$Res, $Val extends IntegrityIssueDoc>
+    implements $IntegrityIssueDocCopyWith<$Res> {
+  _$IntegrityIssueDocCopyWithImpl(this._value, this._then);
+
+  // ignore: unused_field
+  final $Val _value;
+  // ignore: unused_field
+  final $Res Function($Val) _then;
+
+  /// Create a copy of IntegrityIssueDoc
+  /// with the given fields replaced by the non-null parameter values.
+  @pragma('vm:prefer-inline')
+  @override
+  $Res call({Object? kind = null, Object? blockIds = null}) {
+    return _then(
+      _value.copyWith(
+            kind:
+                null == kind
+                    ? _value.kind
+                    : kind // ignore: cast_nullable_to_non_nullable
+                        as IntegrityIssueKindDoc,
+            blockIds:
+                null == blockIds
+                    ? _value.blockIds
+                    : blockIds // ignore: cast_nullable_to_non_nullable
+                        as List<String>,
+          )
+          as $Val,
+    );
+  }
+}
+
+/// @nodoc
+abstract class _$$IntegrityIssueDocImplCopyWith<$Res>
+    implements $IntegrityIssueDocCopyWith<$Res> {
+  factory _$$IntegrityIssueDocImplCopyWith(
+    _$IntegrityIssueDocImpl value,
+    $Res Function(_$IntegrityIssueDocImpl) then,
+  ) = __$$IntegrityIssueDocImplCopyWithImpl<$Res>;
+  @override
+  @useResult
+  $Res call({IntegrityIssueKindDoc kind, List<String> blockIds});
+}
+
+/// @nodoc
+class __$$IntegrityIssueDocImplCopyWithImpl<$Res>
+    extends _$IntegrityIssueDocCopyWithImpl<$Res, _$IntegrityIssueDocImpl>
+    implements _$$IntegrityIssueDocImplCopyWith<$Res> {
+  __$$IntegrityIssueDocImplCopyWithImpl(
+    _$IntegrityIssueDocImpl _value,
+    $Res Function(_$IntegrityIssueDocImpl) _then,
+  ) : super(_value, _then);
+
+  /// Create a copy of IntegrityIssueDoc
+  /// with the given fields replaced by the non-null parameter values.
+  @pragma('vm:prefer-inline')
+  @override
+  $Res call({Object? kind = null, Object? blockIds = null}) {
+    return _then(
+      _$IntegrityIssueDocImpl(
+        kind:
+            null == kind
+                ? _value.kind
+                : kind // ignore: cast_nullable_to_non_nullable
+                    as IntegrityIssueKindDoc,
+        blockIds:
+            null == blockIds
+                ? _value._blockIds
+                : blockIds // ignore: cast_nullable_to_non_nullable
+                    as List<String>,
+      ),
+    );
+  }
+}
+
+/// @nodoc
+@JsonSerializable()
+class _$IntegrityIssueDocImpl implements _IntegrityIssueDoc {
+  const _$IntegrityIssueDocImpl({
+    required this.kind,
+    required final List<String> blockIds,
+  }) : _blockIds = blockIds;
+
+  factory _$IntegrityIssueDocImpl.fromJson(Map<String, dynamic> json) =>
+      _$$IntegrityIssueDocImplFromJson(json);
+
+  @override
+  final IntegrityIssueKindDoc kind;
+  final List<String> _blockIds;
+  @override
+  List<String> get blockIds {
+    if (_blockIds is EqualUnmodifiableListView) return _blockIds;
+    // ignore: implicit_dynamic_type
+    return EqualUnmodifiableListView(_blockIds);
+  }
+
+  @override
+  String toString() {
+    return 'IntegrityIssueDoc(kind: $kind, blockIds: $blockIds)';
+  }
+
+  @override
+  bool operator ==(Object other) {
+    return identical(this, other) ||
+        (other.runtimeType == runtimeType &&
+            other is _$IntegrityIssueDocImpl &&
+            (identical(other.kind, kind) || other.kind == kind) &&
+            const DeepCollectionEquality().equals(other._blockIds, _blockIds));
+  }
+
+  @JsonKey(includeFromJson: false, includeToJson: false)
+  @override
+  int get hashCode => Object.hash(
+    runtimeType,
+    kind,
+    const DeepCollectionEquality().hash(_blockIds),
+  );
+
+  /// Create a copy of IntegrityIssueDoc
+  /// with the given fields replaced by the non-null parameter values.
+  @JsonKey(includeFromJson: false, includeToJson: false)
+  @override
+  @pragma('vm:prefer-inline')
+  _$$IntegrityIssueDocImplCopyWith<_$IntegrityIssueDocImpl> get copyWith =>
+      __$$IntegrityIssueDocImplCopyWithImpl<_$IntegrityIssueDocImpl>(
+        this,
+        _$identity,
+      );
+
+  @override
+  Map<String, dynamic> toJson() {
+    return _$$IntegrityIssueDocImplToJson(this);
+  }
+}
+
+abstract class _IntegrityIssueDoc implements IntegrityIssueDoc {
+  const factory _IntegrityIssueDoc({
+    required final IntegrityIssueKindDoc kind,
+    required final List<String> blockIds,
+  }) = _$IntegrityIssueDocImpl;
+
+  factory _IntegrityIssueDoc.fromJson(Map<String, dynamic> json) =
+      _$IntegrityIssueDocImpl.fromJson;
+
+  @override
+  IntegrityIssueKindDoc get kind;
+  @override
+  List<String> get blockIds;
+
+  /// Create a copy of IntegrityIssueDoc
+  /// with the given fields replaced by the non-null parameter values.
+  @override
+  @JsonKey(includeFromJson: false, includeToJson: false)
+  _$$IntegrityIssueDocImplCopyWith<_$IntegrityIssueDocImpl> get copyWith =>
+      throw _privateConstructorUsedError;
+}
+
+SnapshotInfo _$SnapshotInfoFromJson(Map<String, dynamic> json) {
+  return _SnapshotInfo.fromJson(json);
+}
+
+/// @nodoc
+mixin _$SnapshotInfo {
+  String get id => throw _privateConstructorUsedError;
+  String get label => throw _privateConstructorUsedError;
+  PlatformInt64 get createdAt => throw _privateConstructorUsedError;
 
-  /// Serializes this DocumentState to a JSON map.
+  /// Serializes this SnapshotInfo to a JSON map.
   Map<String, dynamic> toJson() => throw _privateConstructorUsedError;
 
-  /// Create a copy of DocumentState
+  /// Create a copy of SnapshotInfo
   /// with the given fields replaced by the non-null parameter values.
   @JsonKey(includeFromJson: false, includeToJson: false)
-  $DocumentStateCopyWith<DocumentState> get copyWith =>
+  $SnapshotInfoCopyWith<SnapshotInfo> get copyWith =>
       throw _privateConstructorUsedError;
 }
 
 /// @nodoc
-abstract class $DocumentStateCopyWith<$Res> {
-  factory $DocumentStateCopyWith(
-    DocumentState value,
-    $Res Function(DocumentState) then,
-  ) = _$DocumentStateCopyWithImpl<$Res, DocumentState>;
+abstract class $SnapshotInfoCopyWith<$Res> {
+  factory $SnapshotInfoCopyWith(
+    SnapshotInfo value,
+    $Res Function(SnapshotInfo) then,
+  ) = _$SnapshotInfoCopyWithImpl<$Res, SnapshotInfo>;
   @useResult
-  $Res call({
-    String docId,
-    Map<String, BlockDoc> blocks,
-    Map<String, List<String>> childrenMap,
-    String rootId,
-  });
+  $Res call({String id, String label, PlatformInt64 createdAt});
 }
 
 /// @nodoc
-class _$DocumentStateCopyWithImpl<$Res, $Val extends DocumentState>
-    implements $DocumentStateCopyWith<$Res> {
-  _$DocumentStateCopyWithImpl(this._value, this._then);
+class _$SnapshotInfoCopyWithImpl<$Res, $Val extends SnapshotInfo>
+    implements $SnapshotInfoCopyWith<$Res> {
+  _$SnapshotInfoCopyWithImpl(this._value, this._then);
 
   // ignore: unused_field
   final $Val _value;
   // ignore: unused_field
   final $Res Function($Val) _then;
 
-  /// Create a copy of DocumentState
+  /// Create a copy of SnapshotInfo
   /// with the given fields replaced by the non-null parameter values.
   @pragma('vm:prefer-inline')
   @override
   $Res call({
-    Object? docId = null,
-    Object? blocks = null,
-    Object? childrenMap = null,
-    Object? rootId = null,
+    Object? id = null,
+    Object? label = null,
+    Object? createdAt = null,
   }) {
     return _then(
       _value.copyWith(
-            docId:
-                null == docId
-                    ? _value.docId
-                    : docId // ignore: cast_nullable_to_non_nullable
+            id:
+                null == id
+                    ? _value.id
+                    : id // ignore: cast_nullable_to_non_nullable
                         as String,
-            blocks:
-                null == blocks
-                    ? _value.blocks
-                    : blocks // ignore: cast_nullable_to_non_nullable
-                        as Map<String, BlockDoc>,
-            childrenMap:
-                null == childrenMap
-                    ? _value.childrenMap
-                    : childrenMap // ignore: cast_nullable_to_non_nullable
-                        as Map<String, List<String>>,
-            rootId:
-                null == rootId
-                    ? _value.rootId
-                    : rootId // ignore: cast_nullable_to_non_nullable
+            label:
+                null == label
+                    ? _value.label
+                    : label // ignore: cast_nullable_to_non_nullable
                         as String,
+            createdAt:
+                null == createdAt
+                    ? _value.createdAt
+                    : createdAt // ignore: cast_nullable_to_non_nullable
+                        as PlatformInt64,
           )
           as $Val,
     );
@@ -700,63 +3429,52 @@ class _$DocumentStateCopyWithImpl<$Res, $Val extends DocumentState>
 }
 
 /// @nodoc
-abstract class _$$DocumentStateImplCopyWith<$Res>
-    implements $DocumentStateCopyWith<$Res> {
-  factory _$$DocumentStateImplCopyWith(
-    _$DocumentStateImpl value,
-    $Res Function(_$DocumentStateImpl) then,
-  ) = __$$DocumentStateImplCopyWithImpl<$Res>;
+abstract class _$$SnapshotInfoImplCopyWith<$Res>
+    implements $SnapshotInfoCopyWith<$Res> {
+  factory _$$SnapshotInfoImplCopyWith(
+    _$SnapshotInfoImpl value,
+    $Res Function(_$SnapshotInfoImpl) then,
+  ) = __$$SnapshotInfoImplCopyWithImpl<$Res>;
   @override
   @useResult
-  $Res call({
-    String docId,
-    Map<String, BlockDoc> blocks,
-    Map<String, List<String>> childrenMap,
-    String rootId,
-  });
+  $Res call({String id, String label, PlatformInt64 createdAt});
 }
 
 /// @nodoc
-class __$$DocumentStateImplCopyWithImpl<$Res>
-    extends _$DocumentStateCopyWithImpl<$Res, _$DocumentStateImpl>
-    implements _$$DocumentStateImplCopyWith<$Res> {
-  __$$DocumentStateImplCopyWithImpl(
-    _$DocumentStateImpl _value,
-    $Res Function(_$DocumentStateImpl) _then,
+class __$$SnapshotInfoImplCopyWithImpl<$Res>
+    extends _$SnapshotInfoCopyWithImpl<$Res, _$SnapshotInfoImpl>
+    implements _$$SnapshotInfoImplCopyWith<$Res> {
+  __$$SnapshotInfoImplCopyWithImpl(
+    _$SnapshotInfoImpl _value,
+    $Res Function(_$SnapshotInfoImpl) _then,
   ) : super(_value, _then);
 
-  /// Create a copy of DocumentState
+  /// Create a copy of SnapshotInfo
   /// with the given fields replaced by the non-null parameter values.
   @pragma('vm:prefer-inline')
   @override
   $Res call({
-    Object? docId = null,
-    Object? blocks = null,
-    Object? childrenMap = null,
-    Object? rootId = null,
+    Object? id = null,
+    Object? label = null,
+    Object? createdAt = null,
   }) {
     return _then(
-      _$DocumentStateImpl(
-        docId:
-            null == docId
-                ? _value.docId
-                : docId // ignore: cast_nullable_to_non_nullable
+      _$SnapshotInfoImpl(
+        id:
+            null == id
+                ? _value.id
+                : id // ignore: cast_nullable_to_non_nullable
                     as String,
-        blocks:
-            null == blocks
-                ? _value._blocks
-                : blocks // ignore: cast_nullable_to_non_nullable
-                    as Map<String, BlockDoc>,
-        childrenMap:
-            null == childrenMap
-                ? _value._childrenMap
-                : childrenMap // ignore: cast_nullable_to_non_nullable
-                    as Map<String, List<String>>,
-        rootId:
-            null == rootId
-                ? _value.rootId
-                : rootId // ignore: cast_nullable_to_non_nullable
+        label:
+            null == label
+                ? _value.label
+                : label // ignore: cast_nullable_to_non_nullable
                     as String,
+        createdAt:
+            null == createdAt
+                ? _value.createdAt
+                : createdAt // ignore: cast_nullable_to_non_nullable
+                    as PlatformInt64,
       ),
     );
   }
@@ -764,165 +3482,138 @@ class __$$DocumentStateImplCopyWithImpl<$Res>
 
 /// @nodoc
 @JsonSerializable()
-class _$DocumentStateImpl implements _DocumentState {
-  const _$DocumentStateImpl({
-    required this.docId,
-    required final Map<String, BlockDoc> blocks,
-    required final Map<String, List<String>> childrenMap,
-    required this.rootId,
-  }) : _blocks = blocks,
-       _childrenMap = childrenMap;
+class _$SnapshotInfoImpl implements _SnapshotInfo {
+  const _$SnapshotInfoImpl({
+    required this.id,
+    required this.label,
+    required this.createdAt,
+  });
 
-  factory _$DocumentStateImpl.fromJson(Map<String, dynamic> json) =>
-      _$$DocumentStateImplFromJson(json);
+  factory _$SnapshotInfoImpl.fromJson(Map<String, dynamic> json) =>
+      _$$SnapshotInfoImplFromJson(json);
 
   @override
-  final String docId;
-  final Map<String, BlockDoc> _blocks;
-  @override
-  Map<String, BlockDoc> get blocks {
-    if (_blocks is EqualUnmodifiableMapView) return _blocks;
-    // ignore: implicit_dynamic_type
-    return EqualUnmodifiableMapView(_blocks);
-  }
-
-  final Map<String, List<String>> _childrenMap;
+  final String id;
   @override
-  Map<String, List<String>> get childrenMap {
-    if (_childrenMap is EqualUnmodifiableMapView) return _childrenMap;
-    // ignore: implicit_dynamic_type
-    return EqualUnmodifiableMapView(_childrenMap);
-  }
-
+  final String label;
   @override
-  final String rootId;
+  final PlatformInt64 createdAt;
 
   @override
   String toString() {
-    return 'DocumentState(docId: $docId, blocks: $blocks, childrenMap: $childrenMap, rootId: $rootId)';
+    return 'SnapshotInfo(id: $id, label: $label, createdAt: $createdAt)';
   }
 
   @override
   bool operator ==(Object other) {
     return identical(this, other) ||
         (other.runtimeType == runtimeType &&
-            other is _$DocumentStateImpl &&
-            (identical(other.docId, docId) || other.docId == docId) &&
-            const DeepCollectionEquality().equals(other._blocks, _blocks) &&
-            const DeepCollectionEquality().equals(
-              other._childrenMap,
-              _childrenMap,
-            ) &&
-            (identical(other.rootId, rootId) || other.rootId == rootId));
+            other is _$SnapshotInfoImpl &&
+            (identical(other.id, id) || other.id == id) &&
+            (identical(other.label, label) || other.label == label) &&
+            (identical(other.createdAt, createdAt) ||
+                other.createdAt == createdAt));
   }
 
   @JsonKey(includeFromJson: false, includeToJson: false)
   @override
-  int get hashCode => Object.hash(
-    runtimeType,
-    docId,
-    const DeepCollectionEquality().hash(_blocks),
-    const DeepCollectionEquality().hash(_childrenMap),
-    rootId,
-  );
+  int get hashCode => Object.hash(runtimeType, id, label, createdAt);
 
-  /// Create a copy of DocumentState
+  /// Create a copy of SnapshotInfo
   /// with the given fields replaced by the non-null parameter values.
   @JsonKey(includeFromJson: false, includeToJson: false)
   @override
   @pragma('vm:prefer-inline')
-  _$$DocumentStateImplCopyWith<_$DocumentStateImpl> get copyWith =>
-      __$$DocumentStateImplCopyWithImpl<_$DocumentStateImpl>(this, _$identity);
+  _$$SnapshotInfoImplCopyWith<_$SnapshotInfoImpl> get copyWith =>
+      __$$SnapshotInfoImplCopyWithImpl<_$SnapshotInfoImpl>(this, _$identity);
 
   @override
   Map<String, dynamic> toJson() {
-    return _$$DocumentStateImplToJson(this);
+    return _$$SnapshotInfoImplToJson(this);
   }
 }
 
-abstract class _DocumentState implements DocumentState {
-  const factory _DocumentState({
-    required final String docId,
-    required final Map<String, BlockDoc> blocks,
-    required final Map<String, List<String>> childrenMap,
-    required final String rootId,
-  }) = _$DocumentStateImpl;
+abstract class _SnapshotInfo implements SnapshotInfo {
+  const factory _SnapshotInfo({
+    required final String id,
+    required final String label,
+    required final PlatformInt64 createdAt,
+  }) = _$SnapshotInfoImpl;
 
-  factory _DocumentState.fromJson(Map<String, dynamic> json) =
-      _$DocumentStateImpl.fromJson;
+  factory _SnapshotInfo.fromJson(Map<String, dynamic> json) =
+      _$SnapshotInfoImpl.fromJson;
 
   @override
-  String get docId;
-  @override
-  Map<String, BlockDoc> get blocks;
+  String get id;
   @override
-  Map<String, List<String>> get childrenMap;
+  String get label;
   @override
-  String get rootId;
+  PlatformInt64 get createdAt;
 
-  /// Create a copy of DocumentState
+  /// Create a copy of SnapshotInfo
   /// with the given fields replaced by the non-null parameter values.
   @override
   @JsonKey(includeFromJson: false, includeToJson: false)
-  _$$DocumentStateImplCopyWith<_$DocumentStateImpl> get copyWith =>
+  _$$SnapshotInfoImplCopyWith<_$SnapshotInfoImpl> get copyWith =>
       throw _privateConstructorUsedError;
 }
 
-FailedToDecodeUpdates _$FailedToDecodeUpdatesFromJson(
-  Map<String, dynamic> json,
-) {
-  return _FailedToDecodeUpdates.fromJson(json);
+TextPositionDoc _$TextPositionDocFromJson(Map<String, dynamic> json) {
+  return _TextPositionDoc.fromJson(json);
 }
 
 /// @nodoc
-mixin _$FailedToDecodeUpdates {
-  List<String> get failedUpdatesIds => throw _privateConstructorUsedError;
+mixin _$TextPositionDoc {
+  String get blockId => throw _privateConstructorUsedError;
+  int get offset => throw _privateConstructorUsedError;
 
-  /// Serializes this FailedToDecodeUpdates to a JSON map.
+  /// Serializes this TextPositionDoc to a JSON map.
   Map<String, dynamic> toJson() => throw _privateConstructorUsedError;
 
-  /// Create a copy of FailedToDecodeUpdates
+  /// Create a copy of TextPositionDoc
   /// with the given fields replaced by the non-null parameter values.
   @JsonKey(includeFromJson: false, includeToJson: false)
-  $FailedToDecodeUpdatesCopyWith<FailedToDecodeUpdates> get copyWith =>
+  $TextPositionDocCopyWith<TextPositionDoc> get copyWith =>
       throw _privateConstructorUsedError;
 }
 
 /// @nodoc
-abstract class $FailedToDecodeUpdatesCopyWith<$Res> {
-  factory $FailedToDecodeUpdatesCopyWith(
-    FailedToDecodeUpdates value,
-    $Res Function(FailedToDecodeUpdates) then,
-  ) = _$FailedToDecodeUpdatesCopyWithImpl<$Res, FailedToDecodeUpdates>;
+abstract class $TextPositionDocCopyWith<$Res> {
+  factory $TextPositionDocCopyWith(
+    TextPositionDoc value,
+    $Res Function(TextPositionDoc) then,
+  ) = _$TextPositionDocCopyWithImpl<$Res, TextPositionDoc>;
   @useResult
-  $Res call({List<String> failedUpdatesIds});
+  $Res call({String blockId, int offset});
 }
 
 /// @nodoc
-class _$FailedToDecodeUpdatesCopyWithImpl<
-  $Res,
-  $Val extends FailedToDecodeUpdates
->
-    implements $FailedToDecodeUpdatesCopyWith<$Res> {
-  _$FailedToDecodeUpdatesCopyWithImpl(this._value, this._then);
+class _$TextPositionDocCopyWithImpl<$Res, $Val extends TextPositionDoc>
+    implements $TextPositionDocCopyWith<$Res> {
+  _$TextPositionDocCopyWithImpl(this._value, this._then);
 
   // ignore: unused_field
   final $Val _value;
   // ignore: unused_field
   final $Res Function($Val) _then;
 
-  /// Create a copy of FailedToDecodeUpdates
+  /// Create a copy of TextPositionDoc
   /// with the given fields replaced by the non-null parameter values.
   @pragma('vm:prefer-inline')
   @override
-  $Res call({Object? failedUpdatesIds = null}) {
+  $Res call({Object? blockId = null, Object? offset = null}) {
     return _then(
       _value.copyWith(
-            failedUpdatesIds:
-                null == failedUpdatesIds
-                    ? _value.failedUpdatesIds
-                    : failedUpdatesIds // ignore: cast_nullable_to_non_nullable
-                        as List<String>,
+            blockId:
+                null == blockId
+                    ? _value.blockId
+                    : blockId // ignore: cast_nullable_to_non_nullable
+                        as String,
+            offset:
+                null == offset
+                    ? _value.offset
+                    : offset // ignore: cast_nullable_to_non_nullable
+                        as int,
           )
           as $Val,
     );
@@ -930,39 +3621,43 @@ class _$FailedToDecodeUpdatesCopyWithImpl<
 }
 
 /// @nodoc
-abstract class _$$FailedToDecodeUpdatesImplCopyWith<$Res>
-    implements $FailedToDecodeUpdatesCopyWith<$Res> {
-  factory _$$FailedToDecodeUpdatesImplCopyWith(
-    _$FailedToDecodeUpdatesImpl value,
-    $Res Function(_$FailedToDecodeUpdatesImpl) then,
-  ) = __$$FailedToDecodeUpdatesImplCopyWithImpl<$Res>;
+abstract class _$$TextPositionDocImplCopyWith<$Res>
+    implements $TextPositionDocCopyWith<$Res> {
+  factory _$$TextPositionDocImplCopyWith(
+    _$TextPositionDocImpl value,
+    $Res Function(_$TextPositionDocImpl) then,
+  ) = __$$TextPositionDocImplCopyWithImpl<$Res>;
   @override
   @useResult
-  $Res call({List<String> failedUpdatesIds});
+  $Res call({String blockId, int offset});
 }
 
 /// @nodoc
-class __$$FailedToDecodeUpdatesImplCopyWithImpl<$Res>
-    extends
-        _$FailedToDecodeUpdatesCopyWithImpl<$Res, _$FailedToDecodeUpdatesImpl>
-    implements _$$FailedToDecodeUpdatesImplCopyWith<$Res> {
-  __$$FailedToDecodeUpdatesImplCopyWithImpl(
-    _$FailedToDecodeUpdatesImpl _value,
-    $Res Function(_$FailedToDecodeUpdatesImpl) _then,
+class __$$TextPositionDocImplCopyWithImpl<$Res>
+    extends _$TextPositionDocCopyWithImpl<$Res, _$TextPositionDocImpl>
+    implements _$$TextPositionDocImplCopyWith<$Res> {
+  __$$TextPositionDocImplCopyWithImpl(
+    _$TextPositionDocImpl _value,
+    $Res Function(_$TextPositionDocImpl) _then,
   ) : super(_value, _then);
 
-  /// Create a copy of FailedToDecodeUpdates
+  /// Create a copy of TextPositionDoc
   /// with the given fields replaced by the non-null parameter values.
   @pragma('vm:prefer-inline')
   @override
-  $Res call({Object? failedUpdatesIds = null}) {
+  $Res call({Object? blockId = null, Object? offset = null}) {
     return _then(
-      _$FailedToDecodeUpdatesImpl(
-        failedUpdatesIds:
-            null == failedUpdatesIds
-                ? _value._failedUpdatesIds
-                : failedUpdatesIds // ignore: cast_nullable_to_non_nullable
-                    as List<String>,
+      _$TextPositionDocImpl(
+        blockId:
+            null == blockId
+                ? _value.blockId
+                : blockId // ignore: cast_nullable_to_non_nullable
+                    as String,
+        offset:
+            null == offset
+                ? _value.offset
+                : offset // ignore: cast_nullable_to_non_nullable
+                    as int,
       ),
     );
   }
@@ -970,79 +3665,70 @@ class __$$FailedToDecodeUpdatesImplCopyWithImpl<$Res>
 
 /// @nodoc
 @JsonSerializable()
-class _$FailedToDecodeUpdatesImpl implements _FailedToDecodeUpdates {
-  const _$FailedToDecodeUpdatesImpl({
-    required final List<String> failedUpdatesIds,
-  }) : _failedUpdatesIds = failedUpdatesIds;
+class _$TextPositionDocImpl implements _TextPositionDoc {
+  const _$TextPositionDocImpl({required this.blockId, required this.offset});
 
-  factory _$FailedToDecodeUpdatesImpl.fromJson(Map<String, dynamic> json) =>
-      _$$FailedToDecodeUpdatesImplFromJson(json);
+  factory _$TextPositionDocImpl.fromJson(Map<String, dynamic> json) =>
+      _$$TextPositionDocImplFromJson(json);
 
-  final List<String> _failedUpdatesIds;
   @override
-  List<String> get failedUpdatesIds {
-    if (_failedUpdatesIds is EqualUnmodifiableListView)
-      return _failedUpdatesIds;
-    // ignore: implicit_dynamic_type
-    return EqualUnmodifiableListView(_failedUpdatesIds);
-  }
+  final String blockId;
+  @override
+  final int offset;
 
   @override
   String toString() {
-    return 'FailedToDecodeUpdates(failedUpdatesIds: $failedUpdatesIds)';
+    return 'TextPositionDoc(blockId: $blockId, offset: $offset)';
   }
 
   @override
   bool operator ==(Object other) {
     return identical(this, other) ||
         (other.runtimeType == runtimeType &&
-            other is _$FailedToDecodeUpdatesImpl &&
-            const DeepCollectionEquality().equals(
-              other._failedUpdatesIds,
-              _failedUpdatesIds,
-            ));
+            other is _$TextPositionDocImpl &&
+            (identical(other.blockId, blockId) || other.blockId == blockId) &&
+            (identical(other.offset, offset) || other.offset == offset));
   }
 
   @JsonKey(includeFromJson: false, includeToJson: false)
   @override
-  int get hashCode => Object.hash(
-    runtimeType,
-    const DeepCollectionEquality().hash(_failedUpdatesIds),
-  );
+  int get hashCode => Object.hash(runtimeType, blockId, offset);
 
-  /// Create a copy of FailedToDecodeUpdates
+  /// Create a copy of TextPositionDoc
   /// with the given fields replaced by the non-null parameter values.
   @JsonKey(includeFromJson: false, includeToJson: false)
   @override
   @pragma('vm:prefer-inline')
-  _$$FailedToDecodeUpdatesImplCopyWith<_$FailedToDecodeUpdatesImpl>
-  get copyWith =>
-      __$$FailedToDecodeUpdatesImplCopyWithImpl<_$FailedToDecodeUpdatesImpl>(
+  _$$TextPositionDocImplCopyWith<_$TextPositionDocImpl> get copyWith =>
+      __$$TextPositionDocImplCopyWithImpl<_$TextPositionDocImpl>(
         this,
         _$identity,
       );
 
   @override
   Map<String, dynamic> toJson() {
-    return _$$FailedToDecodeUpdatesImplToJson(this);
+    return _$$TextPositionDocImplToJson(this);
   }
 }
 
-abstract class _FailedToDecodeUpdates implements FailedToDecodeUpdates {
-  const factory _FailedToDecodeUpdates({
-    required final List<String> failedUpdatesIds,
-  }) = _$FailedToDecodeUpdatesImpl;
+abstract class _TextPositionDoc implements TextPositionDoc {
+  const factory _TextPositionDoc({
+    required final String blockId,
+    required final int offset,
+  }) = _$TextPositionDocImpl;
 
-  factory _FailedToDecodeUpdates.fromJson(Map<String, dynamic> json) =
-      _$FailedToDecodeUpdatesImpl.fromJson;
+  factory _TextPositionDoc.fromJson(Map<String, dynamic> json) =
+      _$TextPositionDocImpl.fromJson;
 
   @override
-  List<String> get failedUpdatesIds;
+  String get blockId;
+  @override
+  int get offset;
 
-  /// Create a copy of FailedToDecodeUpdates
+  /// Create a copy of TextPositionDoc
   /// with the given fields replaced by the non-null parameter values.
   @override
   @JsonKey(includeFromJson: false, includeToJson: false)
-  _$$FailedToDecodeUpdatesImplCopyWith<_$FailedToDecodeUpdatesImpl>
-  get copyWith => throw _privateConstructorUsedError;
+  _$$TextPositionDocImplCopyWith<_$TextPositionDocImpl> get copyWith =>
+      throw _privateConstructorUsedError;
 }
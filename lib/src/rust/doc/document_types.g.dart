@@ -6,6 +6,141 @@ part of 'document_types.dart';
 // JsonSerializableGenerator
 // **************************************************************************
 
+_$AppliedUpdateActionsImpl _$$AppliedUpdateActionsImplFromJson(
+  Map<String, dynamic> json,
+) => _$AppliedUpdateActionsImpl(
+  actions:
+      (json['actions'] as List<dynamic>)
+          .map((e) => BlockActionDoc.fromJson(e as Map<String, dynamic>))
+          .toList(),
+  requiresFullReload: json['requiresFullReload'] as bool,
+);
+
+Map<String, dynamic> _$$AppliedUpdateActionsImplToJson(
+  _$AppliedUpdateActionsImpl instance,
+) => <String, dynamic>{
+  'actions': instance.actions.map((e) => e.toJson()).toList(),
+  'requiresFullReload': instance.requiresFullReload,
+};
+
+_$AppliedUpdatesImpl _$$AppliedUpdatesImplFromJson(Map<String, dynamic> json) =>
+    _$AppliedUpdatesImpl(
+      changedBlockIds:
+          (json['changedBlockIds'] as List<dynamic>)
+              .map((e) => e as String)
+              .toList(),
+      deletedBlockIds:
+          (json['deletedBlockIds'] as List<dynamic>)
+              .map((e) => e as String)
+              .toList(),
+      rootIdChanged: json['rootIdChanged'] as bool,
+      hasPendingUpdates: json['hasPendingUpdates'] as bool,
+    );
+
+Map<String, dynamic> _$$AppliedUpdatesImplToJson(
+  _$AppliedUpdatesImpl instance,
+) => <String, dynamic>{
+  'changedBlockIds': instance.changedBlockIds,
+  'deletedBlockIds': instance.deletedBlockIds,
+  'rootIdChanged': instance.rootIdChanged,
+  'hasPendingUpdates': instance.hasPendingUpdates,
+};
+
+_$AwarenessChangesDocImpl _$$AwarenessChangesDocImplFromJson(
+  Map<String, dynamic> json,
+) => _$AwarenessChangesDocImpl(
+  added: const Uint64ListConverter().fromJson(json['added'] as List),
+  updated: const Uint64ListConverter().fromJson(json['updated'] as List),
+  removed: const Uint64ListConverter().fromJson(json['removed'] as List),
+);
+
+Map<String, dynamic> _$$AwarenessChangesDocImplToJson(
+  _$AwarenessChangesDocImpl instance,
+) => <String, dynamic>{
+  'added': const Uint64ListConverter().toJson(instance.added),
+  'updated': const Uint64ListConverter().toJson(instance.updated),
+  'removed': const Uint64ListConverter().toJson(instance.removed),
+};
+
+_$AwarenessPeerDocImpl _$$AwarenessPeerDocImplFromJson(
+  Map<String, dynamic> json,
+) => _$AwarenessPeerDocImpl(
+  clientId: BigInt.parse(json['clientId'] as String),
+  clock: (json['clock'] as num).toInt(),
+  lastUpdated: BigInt.parse(json['lastUpdated'] as String),
+  isLocal: json['isLocal'] as bool,
+  user:
+      json['user'] == null
+          ? null
+          : AwarenessUserDoc.fromJson(json['user'] as Map<String, dynamic>),
+  selection:
+      json['selection'] == null
+          ? null
+          : AwarenessSelectionDoc.fromJson(
+            json['selection'] as Map<String, dynamic>,
+          ),
+);
+
+Map<String, dynamic> _$$AwarenessPeerDocImplToJson(
+  _$AwarenessPeerDocImpl instance,
+) => <String, dynamic>{
+  'clientId': instance.clientId.toString(),
+  'clock': instance.clock,
+  'lastUpdated': instance.lastUpdated.toString(),
+  'isLocal': instance.isLocal,
+  'user': instance.user?.toJson(),
+  'selection': instance.selection?.toJson(),
+};
+
+_$AwarenessSelectionDocImpl _$$AwarenessSelectionDocImplFromJson(
+  Map<String, dynamic> json,
+) => _$AwarenessSelectionDocImpl(
+  anchor: TextPositionDoc.fromJson(json['anchor'] as Map<String, dynamic>),
+  head: TextPositionDoc.fromJson(json['head'] as Map<String, dynamic>),
+);
+
+Map<String, dynamic> _$$AwarenessSelectionDocImplToJson(
+  _$AwarenessSelectionDocImpl instance,
+) => <String, dynamic>{
+  'anchor': instance.anchor.toJson(),
+  'head': instance.head.toJson(),
+};
+
+_$AwarenessStateDocImpl _$$AwarenessStateDocImplFromJson(
+  Map<String, dynamic> json,
+) => _$AwarenessStateDocImpl(
+  user: AwarenessUserDoc.fromJson(json['user'] as Map<String, dynamic>),
+  selection:
+      json['selection'] == null
+          ? null
+          : AwarenessSelectionDoc.fromJson(
+            json['selection'] as Map<String, dynamic>,
+          ),
+);
+
+Map<String, dynamic> _$$AwarenessStateDocImplToJson(
+  _$AwarenessStateDocImpl instance,
+) => <String, dynamic>{
+  'user': instance.user.toJson(),
+  'selection': instance.selection?.toJson(),
+};
+
+_$AwarenessUserDocImpl _$$AwarenessUserDocImplFromJson(
+  Map<String, dynamic> json,
+) => _$AwarenessUserDocImpl(
+  id: json['id'] as String,
+  name: json['name'] as String,
+  color: json['color'] as String?,
+);
+
+Map<String, dynamic> _$$AwarenessUserDocImplToJson(
+  _$AwarenessUserDocImpl instance,
+) => <String, dynamic>{
+  'id': instance.id,
+  'name': instance.name,
+  'color': instance.color,
+};
+
 _$BlockActionDocImpl _$$BlockActionDocImplFromJson(Map<String, dynamic> json) =>
     _$BlockActionDocImpl(
       action: $enumDecode(_$BlockActionTypeDocEnumMap, json['action']),
@@ -70,6 +205,71 @@ Map<String, dynamic> _$$BlockDocImplToJson(_$BlockDocImpl instance) =>
       'oldParentId': instance.oldParentId,
     };
 
+_$DocumentChangeEventImpl _$$DocumentChangeEventImplFromJson(
+  Map<String, dynamic> json,
+) => _$DocumentChangeEventImpl(
+  kind: $enumDecode(_$DocumentChangeKindDocEnumMap, json['kind']),
+  blockId: json['blockId'] as String,
+  delta: json['delta'] as String?,
+  origin: json['origin'] as String?,
+);
+
+Map<String, dynamic> _$$DocumentChangeEventImplToJson(
+  _$DocumentChangeEventImpl instance,
+) => <String, dynamic>{
+  'kind': _$DocumentChangeKindDocEnumMap[instance.kind]!,
+  'blockId': instance.blockId,
+  'delta': instance.delta,
+  'origin': instance.origin,
+};
+
+const _$DocumentChangeKindDocEnumMap = {
+  DocumentChangeKindDoc.blockInserted: 'blockInserted',
+  DocumentChangeKindDoc.blockUpdated: 'blockUpdated',
+  DocumentChangeKindDoc.blockDeleted: 'blockDeleted',
+  DocumentChangeKindDoc.blockMoved: 'blockMoved',
+  DocumentChangeKindDoc.textDeltaChanged: 'textDeltaChanged',
+};
+
+_$DocumentOptionsImpl _$$DocumentOptionsImplFromJson(
+  Map<String, dynamic> json,
+) => _$DocumentOptionsImpl(
+  clientId:
+      json['clientId'] == null
+          ? null
+          : BigInt.parse(json['clientId'] as String),
+  offsetKind: $enumDecode(_$OffsetKindDocEnumMap, json['offsetKind']),
+  skipGc: json['skipGc'] as bool,
+  updateEncoding:
+      $enumDecode(_$UpdateEncodingDocEnumMap, json['updateEncoding']),
+  attributeMode: $enumDecode(_$AttributeModeDocEnumMap, json['attributeMode']),
+);
+
+Map<String, dynamic> _$$DocumentOptionsImplToJson(
+  _$DocumentOptionsImpl instance,
+) => <String, dynamic>{
+  'clientId': instance.clientId?.toString(),
+  'offsetKind': _$OffsetKindDocEnumMap[instance.offsetKind]!,
+  'skipGc': instance.skipGc,
+  'updateEncoding': _$UpdateEncodingDocEnumMap[instance.updateEncoding]!,
+  'attributeMode': _$AttributeModeDocEnumMap[instance.attributeMode]!,
+};
+
+const _$OffsetKindDocEnumMap = {
+  OffsetKindDoc.bytes: 'bytes',
+  OffsetKindDoc.utf16: 'utf16',
+};
+
+const _$UpdateEncodingDocEnumMap = {
+  UpdateEncodingDoc.v1: 'v1',
+  UpdateEncodingDoc.v2: 'v2',
+};
+
+const _$AttributeModeDocEnumMap = {
+  AttributeModeDoc.strings: 'strings',
+  AttributeModeDoc.json: 'json',
+};
+
 _$DocumentStateImpl _$$DocumentStateImplFromJson(Map<String, dynamic> json) =>
     _$DocumentStateImpl(
       docId: json['docId'] as String,
@@ -103,3 +303,53 @@ _$FailedToDecodeUpdatesImpl _$$FailedToDecodeUpdatesImplFromJson(
 Map<String, dynamic> _$$FailedToDecodeUpdatesImplToJson(
   _$FailedToDecodeUpdatesImpl instance,
 ) => <String, dynamic>{'failedUpdatesIds': instance.failedUpdatesIds};
+
+_$IntegrityIssueDocImpl _$$IntegrityIssueDocImplFromJson(
+  Map<String, dynamic> json,
+) => _$IntegrityIssueDocImpl(
+  kind: $enumDecode(_$IntegrityIssueKindDocEnumMap, json['kind']),
+  blockIds:
+      (json['blockIds'] as List<dynamic>).map((e) => e as String).toList(),
+);
+
+Map<String, dynamic> _$$IntegrityIssueDocImplToJson(
+  _$IntegrityIssueDocImpl instance,
+) => <String, dynamic>{
+  'kind': _$IntegrityIssueKindDocEnumMap[instance.kind]!,
+  'blockIds': instance.blockIds,
+};
+
+const _$IntegrityIssueKindDocEnumMap = {
+  IntegrityIssueKindDoc.missingRoot: 'missingRoot',
+  IntegrityIssueKindDoc.missingId: 'missingId',
+  IntegrityIssueKindDoc.missingType: 'missingType',
+  IntegrityIssueKindDoc.missingParent: 'missingParent',
+  IntegrityIssueKindDoc.orphan: 'orphan',
+  IntegrityIssueKindDoc.parentCycle: 'parentCycle',
+  IntegrityIssueKindDoc.sharedPrevId: 'sharedPrevId',
+};
+
+_$SnapshotInfoImpl _$$SnapshotInfoImplFromJson(Map<String, dynamic> json) =>
+    _$SnapshotInfoImpl(
+      id: json['id'] as String,
+      label: json['label'] as String,
+      createdAt: (json['createdAt'] as num).toInt(),
+    );
+
+Map<String, dynamic> _$$SnapshotInfoImplToJson(_$SnapshotInfoImpl instance) =>
+    <String, dynamic>{
+      'id': instance.id,
+      'label': instance.label,
+      'createdAt': instance.createdAt,
+    };
+
+_$TextPositionDocImpl _$$TextPositionDocImplFromJson(
+  Map<String, dynamic> json,
+) => _$TextPositionDocImpl(
+  blockId: json['blockId'] as String,
+  offset: (json['offset'] as num).toInt(),
+);
+
+Map<String, dynamic> _$$TextPositionDocImplToJson(
+  _$TextPositionDocImpl instance,
+) => <String, dynamic>{'blockId': instance.blockId, 'offset': instance.offset};
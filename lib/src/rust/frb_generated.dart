@@ -63,7 +63,7 @@ class RustLib extends BaseEntrypoint<RustLibApi, RustLibApiImpl, RustLibWire> {
   String get codegenVersion => '2.9.0';
 
   @override
-  int get rustContentHash => -766104622;
+  int get rustContentHash => -712587541;
 
   static const kDefaultExternalLibraryLoaderConfig =
       ExternalLibraryLoaderConfig(
@@ -79,15 +79,182 @@ abstract class RustLibApi extends BaseApi {
     required List<BlockActionDoc> actions,
   });
 
+  Future<AwarenessChangesDoc>
+  crateDocDocumentServiceDocumentServiceApplyAwarenessUpdate({
+    required DocumentService that,
+    required List<int> update,
+  });
+
   Future<void> crateDocDocumentServiceDocumentServiceApplyUpdates({
     required DocumentService that,
     required List<Uint8List> updates,
   });
 
+  Future<AppliedUpdateActions>
+  crateDocDocumentServiceDocumentServiceApplyUpdatesAsActions({
+    required DocumentService that,
+    required List<Uint8List> updates,
+  });
+
+  Future<AppliedUpdates>
+  crateDocDocumentServiceDocumentServiceApplyUpdatesIncremental({
+    required DocumentService that,
+    required List<Uint8List> updates,
+  });
+
+  Future<FailedToDecodeUpdates>
+  crateDocDocumentServiceDocumentServiceApplyUpdatesTolerant({
+    required DocumentService that,
+    required List<UpdateWithId> updates,
+  });
+
+  Future<bool> crateDocDocumentServiceDocumentServiceCanRedo({
+    required DocumentService that,
+  });
+
+  Future<bool> crateDocDocumentServiceDocumentServiceCanUndo({
+    required DocumentService that,
+  });
+
+  Future<AwarenessTimeoutDoc>
+  crateDocDocumentServiceDocumentServiceCheckAwarenessTimeouts({
+    required DocumentService that,
+    required BigInt timeoutMillis,
+  });
+
+  Future<Uint8List> crateDocDocumentServiceDocumentServiceClearLocalAwareness({
+    required DocumentService that,
+  });
+
+  Future<BigInt> crateDocDocumentServiceDocumentServiceClientId({
+    required DocumentService that,
+  });
+
+  Future<CompactedUpdate>
+  crateDocDocumentServiceDocumentServiceCompactDocument({
+    required DocumentService that,
+    required bool gc,
+    required UpdateEncodingDoc encoding,
+  });
+
+  Future<CompactedUpdate> crateDocDocumentServiceDocumentServiceCompactUpdates({
+    required DocumentService that,
+    required List<Uint8List> updates,
+    required bool gc,
+    required UpdateEncodingDoc encoding,
+  });
+
+  Future<Uint8List> crateDocDocumentServiceDocumentServiceConvertUpdate({
+    required List<int> update,
+    UpdateEncodingDoc? from,
+    required UpdateEncodingDoc to,
+  });
+
+  Future<CreatedSnapshot> crateDocDocumentServiceDocumentServiceCreateSnapshot({
+    required DocumentService that,
+    required String label,
+  });
+
+  Future<TextPositionDoc?>
+  crateDocDocumentServiceDocumentServiceDecodeStickyPosition({
+    required DocumentService that,
+    required String blockId,
+    required List<int> position,
+  });
+
+  Future<List<SyncMessageDoc>>
+  crateDocDocumentServiceDocumentServiceDecodeSyncMessages({
+    required List<int> data,
+  });
+
+  Future<UpdateEncodingDoc?>
+  crateDocDocumentServiceDocumentServiceDetectUpdateEncoding({
+    required List<int> update,
+  });
+
+  Future<String> crateDocDocumentServiceDocumentServiceDocId({
+    required DocumentService that,
+  });
+
+  Future<Uint8List>
+  crateDocDocumentServiceDocumentServiceEnableFractionalOrdering({
+    required DocumentService that,
+  });
+
+  Future<void> crateDocDocumentServiceDocumentServiceEnableUndo({
+    required DocumentService that,
+    required BigInt captureTimeoutMillis,
+  });
+
+  Future<Uint8List>
+  crateDocDocumentServiceDocumentServiceEncodeAwarenessUpdate({
+    required DocumentService that,
+  });
+
+  Future<Uint8List> crateDocDocumentServiceDocumentServiceEncodeDiffSince({
+    required DocumentService that,
+    required List<int> stateVector,
+    required UpdateEncodingDoc encoding,
+  });
+
+  Future<Uint8List> crateDocDocumentServiceDocumentServiceEncodeStateVector({
+    required DocumentService that,
+    required UpdateEncodingDoc encoding,
+  });
+
+  Future<Uint8List> crateDocDocumentServiceDocumentServiceEncodeStickyPosition({
+    required DocumentService that,
+    required TextPositionDoc position,
+    required StickyAssocDoc assoc,
+  });
+
+  Future<Uint8List> crateDocDocumentServiceDocumentServiceEncodeSyncMessage({
+    required SyncMessageDoc message,
+  });
+
+  Future<Uint8List> crateDocDocumentServiceDocumentServiceEncodeUpdateMessage({
+    required List<int> update,
+    required UpdateEncodingDoc encoding,
+  });
+
+  Future<String> crateDocDocumentServiceDocumentServiceExportHtml({
+    required DocumentService that,
+  });
+
+  Future<String> crateDocDocumentServiceDocumentServiceExportMarkdown({
+    required DocumentService that,
+  });
+
+  Future<List<AwarenessPeerDoc>>
+  crateDocDocumentServiceDocumentServiceGetAwarenessPeers({
+    required DocumentService that,
+  });
+
   Future<DocumentState> crateDocDocumentServiceDocumentServiceGetDocumentState({
     required DocumentService that,
   });
 
+  Future<DocumentState>
+  crateDocDocumentServiceDocumentServiceGetDocumentStateAtSnapshot({
+    required DocumentService that,
+    required List<int> snapshot,
+  });
+
+  Future<SyncReplyDoc> crateDocDocumentServiceDocumentServiceHandleSyncMessage({
+    required DocumentService that,
+    required List<int> message,
+  });
+
+  Future<Uint8List> crateDocDocumentServiceDocumentServiceInitDocFromJson({
+    required DocumentService that,
+    required String json,
+  });
+
+  Future<Uint8List> crateDocDocumentServiceDocumentServiceInitDocFromMarkdown({
+    required DocumentService that,
+    required String markdown,
+  });
+
   Future<Uint8List> crateDocDocumentServiceDocumentServiceInitEmptyDoc({
     required DocumentService that,
   });
@@ -99,15 +266,88 @@ abstract class RustLibApi extends BaseApi {
 
   Future<DocumentService> crateDocDocumentServiceDocumentServiceNew();
 
+  Future<DocumentService> crateDocDocumentServiceDocumentServiceNewWithOptions({
+    required String docId,
+    required DocumentOptions options,
+  });
+
+  Future<UndoRedoResult> crateDocDocumentServiceDocumentServiceRedo({
+    required DocumentService that,
+  });
+
+  Future<ChainRepairResult> crateDocDocumentServiceDocumentServiceRepairChains({
+    required DocumentService that,
+  });
+
+  Future<Uint8List> crateDocDocumentServiceDocumentServiceRestoreSnapshot({
+    required DocumentService that,
+    required List<int> snapshot,
+  });
+
+  Future<Uint8List> crateDocDocumentServiceDocumentServiceSetLocalAwareness({
+    required DocumentService that,
+    required AwarenessStateDoc state,
+  });
+
   Future<Uint8List> crateDocDocumentServiceDocumentServiceSetRootNodeId({
     required DocumentService that,
     required String id,
   });
 
+  Future<SiblingOrderingDoc>
+  crateDocDocumentServiceDocumentServiceSiblingOrdering({
+    required DocumentService that,
+  });
+
+  Future<Uint8List> crateDocDocumentServiceDocumentServiceStartSync({
+    required DocumentService that,
+  });
+
+  Future<void> crateDocDocumentServiceDocumentServiceStopUndoCapturing({
+    required DocumentService that,
+  });
+
+  Stream<DocumentChangeEvent>
+  crateDocDocumentServiceDocumentServiceSubscribeChanges({
+    required DocumentService that,
+  });
+
+  Future<UndoRedoResult> crateDocDocumentServiceDocumentServiceUndo({
+    required DocumentService that,
+  });
+
+  Future<void> crateDocDocumentServiceDocumentServiceUnsubscribeChanges({
+    required DocumentService that,
+  });
+
+  Future<IntegrityReport>
+  crateDocDocumentServiceDocumentServiceValidateDocument({
+    required DocumentService that,
+    required bool fix,
+  });
+
+  Future<AttributeModeDoc> crateDocDocumentTypesAttributeModeDocDefault();
+
+  Future<AwarenessChangesDoc> crateDocDocumentTypesAwarenessChangesDocDefault();
+
+  Future<AwarenessTimeoutDoc> crateDocDocumentTypesAwarenessTimeoutDocDefault();
+
   Future<CustomRustError> crateDocDocumentTypesCustomRustErrorNew({
     required String message,
   });
 
+  Future<DocumentOptions> crateDocDocumentTypesDocumentOptionsDefault();
+
+  Future<OffsetKindDoc> crateDocDocumentTypesOffsetKindDocDefault();
+
+  Future<SiblingOrderingDoc> crateDocDocumentTypesSiblingOrderingDocDefault();
+
+  Future<StickyAssocDoc> crateDocDocumentTypesStickyAssocDocDefault();
+
+  Future<SyncReplyDoc> crateDocDocumentTypesSyncReplyDocDefault();
+
+  Future<UpdateEncodingDoc> crateDocDocumentTypesUpdateEncodingDocDefault();
+
   RustArcIncrementStrongCountFnType
   get rust_arc_increment_strong_count_DocumentService;
 
@@ -166,9 +406,10 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
       );
 
   @override
-  Future<void> crateDocDocumentServiceDocumentServiceApplyUpdates({
+  Future<AwarenessChangesDoc>
+  crateDocDocumentServiceDocumentServiceApplyAwarenessUpdate({
     required DocumentService that,
-    required List<Uint8List> updates,
+    required List<int> update,
   }) {
     return handler.executeNormal(
       NormalTask(
@@ -178,7 +419,7 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
             that,
             serializer,
           );
-          sse_encode_list_list_prim_u_8_strict(updates, serializer);
+          sse_encode_list_prim_u_8_loose(update, serializer);
           pdeCallFfi(
             generalizedFrbRustBinding,
             serializer,
@@ -187,35 +428,38 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
           );
         },
         codec: SseCodec(
-          decodeSuccessData: sse_decode_unit,
+          decodeSuccessData: sse_decode_awareness_changes_doc,
           decodeErrorData: sse_decode_custom_rust_error,
         ),
-        constMeta: kCrateDocDocumentServiceDocumentServiceApplyUpdatesConstMeta,
-        argValues: [that, updates],
+        constMeta:
+            kCrateDocDocumentServiceDocumentServiceApplyAwarenessUpdateConstMeta,
+        argValues: [that, update],
         apiImpl: this,
       ),
     );
   }
 
   TaskConstMeta
-  get kCrateDocDocumentServiceDocumentServiceApplyUpdatesConstMeta =>
+  get kCrateDocDocumentServiceDocumentServiceApplyAwarenessUpdateConstMeta =>
       const TaskConstMeta(
-        debugName: "DocumentService_apply_updates",
-        argNames: ["that", "updates"],
+        debugName: "DocumentService_apply_awareness_update",
+        argNames: ["that", "update"],
       );
 
   @override
-  Future<DocumentState> crateDocDocumentServiceDocumentServiceGetDocumentState({
+  Future<void> crateDocDocumentServiceDocumentServiceApplyUpdates({
     required DocumentService that,
+    required List<Uint8List> updates,
   }) {
     return handler.executeNormal(
       NormalTask(
         callFfi: (port_) {
           final serializer = SseSerializer(generalizedFrbRustBinding);
-          sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerDocumentService(
+          sse_encode_Auto_RefMut_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerDocumentService(
             that,
             serializer,
           );
+          sse_encode_list_list_prim_u_8_strict(updates, serializer);
           pdeCallFfi(
             generalizedFrbRustBinding,
             serializer,
@@ -224,27 +468,28 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
           );
         },
         codec: SseCodec(
-          decodeSuccessData: sse_decode_document_state,
+          decodeSuccessData: sse_decode_unit,
           decodeErrorData: sse_decode_custom_rust_error,
         ),
-        constMeta:
-            kCrateDocDocumentServiceDocumentServiceGetDocumentStateConstMeta,
-        argValues: [that],
+        constMeta: kCrateDocDocumentServiceDocumentServiceApplyUpdatesConstMeta,
+        argValues: [that, updates],
         apiImpl: this,
       ),
     );
   }
 
   TaskConstMeta
-  get kCrateDocDocumentServiceDocumentServiceGetDocumentStateConstMeta =>
+  get kCrateDocDocumentServiceDocumentServiceApplyUpdatesConstMeta =>
       const TaskConstMeta(
-        debugName: "DocumentService_get_document_state",
-        argNames: ["that"],
+        debugName: "DocumentService_apply_updates",
+        argNames: ["that", "updates"],
       );
 
   @override
-  Future<Uint8List> crateDocDocumentServiceDocumentServiceInitEmptyDoc({
+  Future<AppliedUpdateActions>
+  crateDocDocumentServiceDocumentServiceApplyUpdatesAsActions({
     required DocumentService that,
+    required List<Uint8List> updates,
   }) {
     return handler.executeNormal(
       NormalTask(
@@ -254,6 +499,7 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
             that,
             serializer,
           );
+          sse_encode_list_list_prim_u_8_strict(updates, serializer);
           pdeCallFfi(
             generalizedFrbRustBinding,
             serializer,
@@ -262,25 +508,27 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
           );
         },
         codec: SseCodec(
-          decodeSuccessData: sse_decode_list_prim_u_8_strict,
+          decodeSuccessData: sse_decode_applied_update_actions,
           decodeErrorData: sse_decode_custom_rust_error,
         ),
-        constMeta: kCrateDocDocumentServiceDocumentServiceInitEmptyDocConstMeta,
-        argValues: [that],
+        constMeta:
+            kCrateDocDocumentServiceDocumentServiceApplyUpdatesAsActionsConstMeta,
+        argValues: [that, updates],
         apiImpl: this,
       ),
     );
   }
 
   TaskConstMeta
-  get kCrateDocDocumentServiceDocumentServiceInitEmptyDocConstMeta =>
+  get kCrateDocDocumentServiceDocumentServiceApplyUpdatesAsActionsConstMeta =>
       const TaskConstMeta(
-        debugName: "DocumentService_init_empty_doc",
-        argNames: ["that"],
+        debugName: "DocumentService_apply_updates_as_actions",
+        argNames: ["that", "updates"],
       );
 
   @override
-  Future<Uint8List> crateDocDocumentServiceDocumentServiceMergeUpdates({
+  Future<AppliedUpdates>
+  crateDocDocumentServiceDocumentServiceApplyUpdatesIncremental({
     required DocumentService that,
     required List<Uint8List> updates,
   }) {
@@ -288,7 +536,7 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
       NormalTask(
         callFfi: (port_) {
           final serializer = SseSerializer(generalizedFrbRustBinding);
-          sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerDocumentService(
+          sse_encode_Auto_RefMut_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerDocumentService(
             that,
             serializer,
           );
@@ -301,10 +549,11 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
           );
         },
         codec: SseCodec(
-          decodeSuccessData: sse_decode_list_prim_u_8_strict,
+          decodeSuccessData: sse_decode_applied_updates,
           decodeErrorData: sse_decode_custom_rust_error,
         ),
-        constMeta: kCrateDocDocumentServiceDocumentServiceMergeUpdatesConstMeta,
+        constMeta:
+            kCrateDocDocumentServiceDocumentServiceApplyUpdatesIncrementalConstMeta,
         argValues: [that, updates],
         apiImpl: this,
       ),
@@ -312,18 +561,27 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
   }
 
   TaskConstMeta
-  get kCrateDocDocumentServiceDocumentServiceMergeUpdatesConstMeta =>
+  get kCrateDocDocumentServiceDocumentServiceApplyUpdatesIncrementalConstMeta =>
       const TaskConstMeta(
-        debugName: "DocumentService_merge_updates",
+        debugName: "DocumentService_apply_updates_incremental",
         argNames: ["that", "updates"],
       );
 
   @override
-  Future<DocumentService> crateDocDocumentServiceDocumentServiceNew() {
+  Future<FailedToDecodeUpdates>
+  crateDocDocumentServiceDocumentServiceApplyUpdatesTolerant({
+    required DocumentService that,
+    required List<UpdateWithId> updates,
+  }) {
     return handler.executeNormal(
       NormalTask(
         callFfi: (port_) {
           final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_Auto_RefMut_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerDocumentService(
+            that,
+            serializer,
+          );
+          sse_encode_list_update_with_id(updates, serializer);
           pdeCallFfi(
             generalizedFrbRustBinding,
             serializer,
@@ -332,34 +590,36 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
           );
         },
         codec: SseCodec(
-          decodeSuccessData:
-              sse_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerDocumentService,
-          decodeErrorData: null,
+          decodeSuccessData: sse_decode_failed_to_decode_updates,
+          decodeErrorData: sse_decode_custom_rust_error,
         ),
-        constMeta: kCrateDocDocumentServiceDocumentServiceNewConstMeta,
-        argValues: [],
+        constMeta:
+            kCrateDocDocumentServiceDocumentServiceApplyUpdatesTolerantConstMeta,
+        argValues: [that, updates],
         apiImpl: this,
       ),
     );
   }
 
-  TaskConstMeta get kCrateDocDocumentServiceDocumentServiceNewConstMeta =>
-      const TaskConstMeta(debugName: "DocumentService_new", argNames: []);
+  TaskConstMeta
+  get kCrateDocDocumentServiceDocumentServiceApplyUpdatesTolerantConstMeta =>
+      const TaskConstMeta(
+        debugName: "DocumentService_apply_updates_tolerant",
+        argNames: ["that", "updates"],
+      );
 
   @override
-  Future<Uint8List> crateDocDocumentServiceDocumentServiceSetRootNodeId({
+  Future<bool> crateDocDocumentServiceDocumentServiceCanRedo({
     required DocumentService that,
-    required String id,
   }) {
     return handler.executeNormal(
       NormalTask(
         callFfi: (port_) {
           final serializer = SseSerializer(generalizedFrbRustBinding);
-          sse_encode_Auto_RefMut_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerDocumentService(
+          sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerDocumentService(
             that,
             serializer,
           );
-          sse_encode_String(id, serializer);
           pdeCallFfi(
             generalizedFrbRustBinding,
             serializer,
@@ -368,33 +628,34 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
           );
         },
         codec: SseCodec(
-          decodeSuccessData: sse_decode_list_prim_u_8_strict,
-          decodeErrorData: sse_decode_custom_rust_error,
+          decodeSuccessData: sse_decode_bool,
+          decodeErrorData: null,
         ),
-        constMeta:
-            kCrateDocDocumentServiceDocumentServiceSetRootNodeIdConstMeta,
-        argValues: [that, id],
+        constMeta: kCrateDocDocumentServiceDocumentServiceCanRedoConstMeta,
+        argValues: [that],
         apiImpl: this,
       ),
     );
   }
 
-  TaskConstMeta
-  get kCrateDocDocumentServiceDocumentServiceSetRootNodeIdConstMeta =>
+  TaskConstMeta get kCrateDocDocumentServiceDocumentServiceCanRedoConstMeta =>
       const TaskConstMeta(
-        debugName: "DocumentService_set_root_node_id",
-        argNames: ["that", "id"],
+        debugName: "DocumentService_can_redo",
+        argNames: ["that"],
       );
 
   @override
-  Future<CustomRustError> crateDocDocumentTypesCustomRustErrorNew({
-    required String message,
+  Future<bool> crateDocDocumentServiceDocumentServiceCanUndo({
+    required DocumentService that,
   }) {
     return handler.executeNormal(
       NormalTask(
         callFfi: (port_) {
           final serializer = SseSerializer(generalizedFrbRustBinding);
-          sse_encode_String(message, serializer);
+          sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerDocumentService(
+            that,
+            serializer,
+          );
           pdeCallFfi(
             generalizedFrbRustBinding,
             serializer,
@@ -403,438 +664,3170 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
           );
         },
         codec: SseCodec(
-          decodeSuccessData: sse_decode_custom_rust_error,
+          decodeSuccessData: sse_decode_bool,
           decodeErrorData: null,
         ),
-        constMeta: kCrateDocDocumentTypesCustomRustErrorNewConstMeta,
-        argValues: [message],
+        constMeta: kCrateDocDocumentServiceDocumentServiceCanUndoConstMeta,
+        argValues: [that],
         apiImpl: this,
       ),
     );
   }
 
-  TaskConstMeta get kCrateDocDocumentTypesCustomRustErrorNewConstMeta =>
+  TaskConstMeta get kCrateDocDocumentServiceDocumentServiceCanUndoConstMeta =>
       const TaskConstMeta(
-        debugName: "custom_rust_error_new",
-        argNames: ["message"],
+        debugName: "DocumentService_can_undo",
+        argNames: ["that"],
       );
 
-  RustArcIncrementStrongCountFnType
-  get rust_arc_increment_strong_count_DocumentService =>
-      wire.rust_arc_increment_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerDocumentService;
-
-  RustArcDecrementStrongCountFnType
-  get rust_arc_decrement_strong_count_DocumentService =>
-      wire.rust_arc_decrement_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerDocumentService;
-
-  @protected
-  DocumentService
-  dco_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerDocumentService(
-    dynamic raw,
-  ) {
-    // Codec=Dco (DartCObject based), see doc to use other codecs
-    return DocumentServiceImpl.frbInternalDcoDecode(raw as List<dynamic>);
+  @override
+  Future<AwarenessTimeoutDoc>
+  crateDocDocumentServiceDocumentServiceCheckAwarenessTimeouts({
+    required DocumentService that,
+    required BigInt timeoutMillis,
+  }) {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_Auto_RefMut_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerDocumentService(
+            that,
+            serializer,
+          );
+          sse_encode_u_64(timeoutMillis, serializer);
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 9,
+            port: port_,
+          );
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_awareness_timeout_doc,
+          decodeErrorData: sse_decode_custom_rust_error,
+        ),
+        constMeta:
+            kCrateDocDocumentServiceDocumentServiceCheckAwarenessTimeoutsConstMeta,
+        argValues: [that, timeoutMillis],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta
+  get kCrateDocDocumentServiceDocumentServiceCheckAwarenessTimeoutsConstMeta =>
+      const TaskConstMeta(
+        debugName: "DocumentService_check_awareness_timeouts",
+        argNames: ["that", "timeoutMillis"],
+      );
+
+  @override
+  Future<Uint8List> crateDocDocumentServiceDocumentServiceClearLocalAwareness({
+    required DocumentService that,
+  }) {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_Auto_RefMut_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerDocumentService(
+            that,
+            serializer,
+          );
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 10,
+            port: port_,
+          );
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_list_prim_u_8_strict,
+          decodeErrorData: sse_decode_custom_rust_error,
+        ),
+        constMeta:
+            kCrateDocDocumentServiceDocumentServiceClearLocalAwarenessConstMeta,
+        argValues: [that],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta
+  get kCrateDocDocumentServiceDocumentServiceClearLocalAwarenessConstMeta =>
+      const TaskConstMeta(
+        debugName: "DocumentService_clear_local_awareness",
+        argNames: ["that"],
+      );
+
+  @override
+  Future<BigInt> crateDocDocumentServiceDocumentServiceClientId({
+    required DocumentService that,
+  }) {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerDocumentService(
+            that,
+            serializer,
+          );
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 11,
+            port: port_,
+          );
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_u_64,
+          decodeErrorData: null,
+        ),
+        constMeta: kCrateDocDocumentServiceDocumentServiceClientIdConstMeta,
+        argValues: [that],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateDocDocumentServiceDocumentServiceClientIdConstMeta =>
+      const TaskConstMeta(
+        debugName: "DocumentService_client_id",
+        argNames: ["that"],
+      );
+
+  @override
+  Future<CompactedUpdate>
+  crateDocDocumentServiceDocumentServiceCompactDocument({
+    required DocumentService that,
+    required bool gc,
+    required UpdateEncodingDoc encoding,
+  }) {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerDocumentService(
+            that,
+            serializer,
+          );
+          sse_encode_bool(gc, serializer);
+          sse_encode_update_encoding_doc(encoding, serializer);
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 12,
+            port: port_,
+          );
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_compacted_update,
+          decodeErrorData: sse_decode_custom_rust_error,
+        ),
+        constMeta:
+            kCrateDocDocumentServiceDocumentServiceCompactDocumentConstMeta,
+        argValues: [that, gc, encoding],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta
+  get kCrateDocDocumentServiceDocumentServiceCompactDocumentConstMeta =>
+      const TaskConstMeta(
+        debugName: "DocumentService_compact_document",
+        argNames: ["that", "gc", "encoding"],
+      );
+
+  @override
+  Future<CompactedUpdate> crateDocDocumentServiceDocumentServiceCompactUpdates({
+    required DocumentService that,
+    required List<Uint8List> updates,
+    required bool gc,
+    required UpdateEncodingDoc encoding,
+  }) {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerDocumentService(
+            that,
+            serializer,
+          );
+          sse_encode_list_list_prim_u_8_strict(updates, serializer);
+          sse_encode_bool(gc, serializer);
+          sse_encode_update_encoding_doc(encoding, serializer);
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 13,
+            port: port_,
+          );
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_compacted_update,
+          decodeErrorData: sse_decode_custom_rust_error,
+        ),
+        constMeta:
+            kCrateDocDocumentServiceDocumentServiceCompactUpdatesConstMeta,
+        argValues: [that, updates, gc, encoding],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta
+  get kCrateDocDocumentServiceDocumentServiceCompactUpdatesConstMeta =>
+      const TaskConstMeta(
+        debugName: "DocumentService_compact_updates",
+        argNames: ["that", "updates", "gc", "encoding"],
+      );
+
+  @override
+  Future<Uint8List> crateDocDocumentServiceDocumentServiceConvertUpdate({
+    required List<int> update,
+    UpdateEncodingDoc? from,
+    required UpdateEncodingDoc to,
+  }) {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_list_prim_u_8_loose(update, serializer);
+          sse_encode_opt_box_autoadd_update_encoding_doc(from, serializer);
+          sse_encode_update_encoding_doc(to, serializer);
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 14,
+            port: port_,
+          );
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_list_prim_u_8_strict,
+          decodeErrorData: sse_decode_custom_rust_error,
+        ),
+        constMeta:
+            kCrateDocDocumentServiceDocumentServiceConvertUpdateConstMeta,
+        argValues: [update, from, to],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta
+  get kCrateDocDocumentServiceDocumentServiceConvertUpdateConstMeta =>
+      const TaskConstMeta(
+        debugName: "DocumentService_convert_update",
+        argNames: ["update", "from", "to"],
+      );
+
+  @override
+  Future<CreatedSnapshot> crateDocDocumentServiceDocumentServiceCreateSnapshot({
+    required DocumentService that,
+    required String label,
+  }) {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerDocumentService(
+            that,
+            serializer,
+          );
+          sse_encode_String(label, serializer);
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 15,
+            port: port_,
+          );
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_created_snapshot,
+          decodeErrorData: sse_decode_custom_rust_error,
+        ),
+        constMeta:
+            kCrateDocDocumentServiceDocumentServiceCreateSnapshotConstMeta,
+        argValues: [that, label],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta
+  get kCrateDocDocumentServiceDocumentServiceCreateSnapshotConstMeta =>
+      const TaskConstMeta(
+        debugName: "DocumentService_create_snapshot",
+        argNames: ["that", "label"],
+      );
+
+  @override
+  Future<TextPositionDoc?>
+  crateDocDocumentServiceDocumentServiceDecodeStickyPosition({
+    required DocumentService that,
+    required String blockId,
+    required List<int> position,
+  }) {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerDocumentService(
+            that,
+            serializer,
+          );
+          sse_encode_String(blockId, serializer);
+          sse_encode_list_prim_u_8_loose(position, serializer);
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 16,
+            port: port_,
+          );
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_opt_box_autoadd_text_position_doc,
+          decodeErrorData: sse_decode_custom_rust_error,
+        ),
+        constMeta:
+            kCrateDocDocumentServiceDocumentServiceDecodeStickyPositionConstMeta,
+        argValues: [that, blockId, position],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta
+  get kCrateDocDocumentServiceDocumentServiceDecodeStickyPositionConstMeta =>
+      const TaskConstMeta(
+        debugName: "DocumentService_decode_sticky_position",
+        argNames: ["that", "blockId", "position"],
+      );
+
+  @override
+  Future<List<SyncMessageDoc>>
+  crateDocDocumentServiceDocumentServiceDecodeSyncMessages({
+    required List<int> data,
+  }) {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_list_prim_u_8_loose(data, serializer);
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 17,
+            port: port_,
+          );
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_list_sync_message_doc,
+          decodeErrorData: sse_decode_custom_rust_error,
+        ),
+        constMeta:
+            kCrateDocDocumentServiceDocumentServiceDecodeSyncMessagesConstMeta,
+        argValues: [data],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta
+  get kCrateDocDocumentServiceDocumentServiceDecodeSyncMessagesConstMeta =>
+      const TaskConstMeta(
+        debugName: "DocumentService_decode_sync_messages",
+        argNames: ["data"],
+      );
+
+  @override
+  Future<UpdateEncodingDoc?>
+  crateDocDocumentServiceDocumentServiceDetectUpdateEncoding({
+    required List<int> update,
+  }) {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_list_prim_u_8_loose(update, serializer);
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 18,
+            port: port_,
+          );
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_opt_box_autoadd_update_encoding_doc,
+          decodeErrorData: null,
+        ),
+        constMeta:
+            kCrateDocDocumentServiceDocumentServiceDetectUpdateEncodingConstMeta,
+        argValues: [update],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta
+  get kCrateDocDocumentServiceDocumentServiceDetectUpdateEncodingConstMeta =>
+      const TaskConstMeta(
+        debugName: "DocumentService_detect_update_encoding",
+        argNames: ["update"],
+      );
+
+  @override
+  Future<String> crateDocDocumentServiceDocumentServiceDocId({
+    required DocumentService that,
+  }) {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerDocumentService(
+            that,
+            serializer,
+          );
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 19,
+            port: port_,
+          );
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_String,
+          decodeErrorData: null,
+        ),
+        constMeta: kCrateDocDocumentServiceDocumentServiceDocIdConstMeta,
+        argValues: [that],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateDocDocumentServiceDocumentServiceDocIdConstMeta =>
+      const TaskConstMeta(
+        debugName: "DocumentService_doc_id",
+        argNames: ["that"],
+      );
+
+  @override
+  Future<Uint8List>
+  crateDocDocumentServiceDocumentServiceEnableFractionalOrdering({
+    required DocumentService that,
+  }) {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_Auto_RefMut_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerDocumentService(
+            that,
+            serializer,
+          );
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 20,
+            port: port_,
+          );
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_list_prim_u_8_strict,
+          decodeErrorData: sse_decode_custom_rust_error,
+        ),
+        constMeta:
+            kCrateDocDocumentServiceDocumentServiceEnableFractionalOrderingConstMeta,
+        argValues: [that],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta
+  get kCrateDocDocumentServiceDocumentServiceEnableFractionalOrderingConstMeta =>
+      const TaskConstMeta(
+        debugName: "DocumentService_enable_fractional_ordering",
+        argNames: ["that"],
+      );
+
+  @override
+  Future<void> crateDocDocumentServiceDocumentServiceEnableUndo({
+    required DocumentService that,
+    required BigInt captureTimeoutMillis,
+  }) {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_Auto_RefMut_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerDocumentService(
+            that,
+            serializer,
+          );
+          sse_encode_u_64(captureTimeoutMillis, serializer);
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 21,
+            port: port_,
+          );
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_unit,
+          decodeErrorData: null,
+        ),
+        constMeta: kCrateDocDocumentServiceDocumentServiceEnableUndoConstMeta,
+        argValues: [that, captureTimeoutMillis],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta
+  get kCrateDocDocumentServiceDocumentServiceEnableUndoConstMeta =>
+      const TaskConstMeta(
+        debugName: "DocumentService_enable_undo",
+        argNames: ["that", "captureTimeoutMillis"],
+      );
+
+  @override
+  Future<Uint8List>
+  crateDocDocumentServiceDocumentServiceEncodeAwarenessUpdate({
+    required DocumentService that,
+  }) {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerDocumentService(
+            that,
+            serializer,
+          );
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 22,
+            port: port_,
+          );
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_list_prim_u_8_strict,
+          decodeErrorData: sse_decode_custom_rust_error,
+        ),
+        constMeta:
+            kCrateDocDocumentServiceDocumentServiceEncodeAwarenessUpdateConstMeta,
+        argValues: [that],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta
+  get kCrateDocDocumentServiceDocumentServiceEncodeAwarenessUpdateConstMeta =>
+      const TaskConstMeta(
+        debugName: "DocumentService_encode_awareness_update",
+        argNames: ["that"],
+      );
+
+  @override
+  Future<Uint8List> crateDocDocumentServiceDocumentServiceEncodeDiffSince({
+    required DocumentService that,
+    required List<int> stateVector,
+    required UpdateEncodingDoc encoding,
+  }) {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerDocumentService(
+            that,
+            serializer,
+          );
+          sse_encode_list_prim_u_8_loose(stateVector, serializer);
+          sse_encode_update_encoding_doc(encoding, serializer);
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 23,
+            port: port_,
+          );
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_list_prim_u_8_strict,
+          decodeErrorData: sse_decode_custom_rust_error,
+        ),
+        constMeta:
+            kCrateDocDocumentServiceDocumentServiceEncodeDiffSinceConstMeta,
+        argValues: [that, stateVector, encoding],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta
+  get kCrateDocDocumentServiceDocumentServiceEncodeDiffSinceConstMeta =>
+      const TaskConstMeta(
+        debugName: "DocumentService_encode_diff_since",
+        argNames: ["that", "stateVector", "encoding"],
+      );
+
+  @override
+  Future<Uint8List> crateDocDocumentServiceDocumentServiceEncodeStateVector({
+    required DocumentService that,
+    required UpdateEncodingDoc encoding,
+  }) {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerDocumentService(
+            that,
+            serializer,
+          );
+          sse_encode_update_encoding_doc(encoding, serializer);
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 24,
+            port: port_,
+          );
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_list_prim_u_8_strict,
+          decodeErrorData: sse_decode_custom_rust_error,
+        ),
+        constMeta:
+            kCrateDocDocumentServiceDocumentServiceEncodeStateVectorConstMeta,
+        argValues: [that, encoding],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta
+  get kCrateDocDocumentServiceDocumentServiceEncodeStateVectorConstMeta =>
+      const TaskConstMeta(
+        debugName: "DocumentService_encode_state_vector",
+        argNames: ["that", "encoding"],
+      );
+
+  @override
+  Future<Uint8List> crateDocDocumentServiceDocumentServiceEncodeStickyPosition({
+    required DocumentService that,
+    required TextPositionDoc position,
+    required StickyAssocDoc assoc,
+  }) {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerDocumentService(
+            that,
+            serializer,
+          );
+          sse_encode_box_autoadd_text_position_doc(position, serializer);
+          sse_encode_sticky_assoc_doc(assoc, serializer);
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 25,
+            port: port_,
+          );
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_list_prim_u_8_strict,
+          decodeErrorData: sse_decode_custom_rust_error,
+        ),
+        constMeta:
+            kCrateDocDocumentServiceDocumentServiceEncodeStickyPositionConstMeta,
+        argValues: [that, position, assoc],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta
+  get kCrateDocDocumentServiceDocumentServiceEncodeStickyPositionConstMeta =>
+      const TaskConstMeta(
+        debugName: "DocumentService_encode_sticky_position",
+        argNames: ["that", "position", "assoc"],
+      );
+
+  @override
+  Future<Uint8List> crateDocDocumentServiceDocumentServiceEncodeSyncMessage({
+    required SyncMessageDoc message,
+  }) {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_box_autoadd_sync_message_doc(message, serializer);
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 26,
+            port: port_,
+          );
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_list_prim_u_8_strict,
+          decodeErrorData: sse_decode_custom_rust_error,
+        ),
+        constMeta:
+            kCrateDocDocumentServiceDocumentServiceEncodeSyncMessageConstMeta,
+        argValues: [message],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta
+  get kCrateDocDocumentServiceDocumentServiceEncodeSyncMessageConstMeta =>
+      const TaskConstMeta(
+        debugName: "DocumentService_encode_sync_message",
+        argNames: ["message"],
+      );
+
+  @override
+  Future<Uint8List> crateDocDocumentServiceDocumentServiceEncodeUpdateMessage({
+    required List<int> update,
+    required UpdateEncodingDoc encoding,
+  }) {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_list_prim_u_8_loose(update, serializer);
+          sse_encode_update_encoding_doc(encoding, serializer);
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 27,
+            port: port_,
+          );
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_list_prim_u_8_strict,
+          decodeErrorData: sse_decode_custom_rust_error,
+        ),
+        constMeta:
+            kCrateDocDocumentServiceDocumentServiceEncodeUpdateMessageConstMeta,
+        argValues: [update, encoding],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta
+  get kCrateDocDocumentServiceDocumentServiceEncodeUpdateMessageConstMeta =>
+      const TaskConstMeta(
+        debugName: "DocumentService_encode_update_message",
+        argNames: ["update", "encoding"],
+      );
+
+  @override
+  Future<String> crateDocDocumentServiceDocumentServiceExportHtml({
+    required DocumentService that,
+  }) {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerDocumentService(
+            that,
+            serializer,
+          );
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 28,
+            port: port_,
+          );
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_String,
+          decodeErrorData: sse_decode_custom_rust_error,
+        ),
+        constMeta: kCrateDocDocumentServiceDocumentServiceExportHtmlConstMeta,
+        argValues: [that],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta
+  get kCrateDocDocumentServiceDocumentServiceExportHtmlConstMeta =>
+      const TaskConstMeta(
+        debugName: "DocumentService_export_html",
+        argNames: ["that"],
+      );
+
+  @override
+  Future<String> crateDocDocumentServiceDocumentServiceExportMarkdown({
+    required DocumentService that,
+  }) {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerDocumentService(
+            that,
+            serializer,
+          );
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 29,
+            port: port_,
+          );
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_String,
+          decodeErrorData: sse_decode_custom_rust_error,
+        ),
+        constMeta:
+            kCrateDocDocumentServiceDocumentServiceExportMarkdownConstMeta,
+        argValues: [that],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta
+  get kCrateDocDocumentServiceDocumentServiceExportMarkdownConstMeta =>
+      const TaskConstMeta(
+        debugName: "DocumentService_export_markdown",
+        argNames: ["that"],
+      );
+
+  @override
+  Future<List<AwarenessPeerDoc>>
+  crateDocDocumentServiceDocumentServiceGetAwarenessPeers({
+    required DocumentService that,
+  }) {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerDocumentService(
+            that,
+            serializer,
+          );
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 30,
+            port: port_,
+          );
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_list_awareness_peer_doc,
+          decodeErrorData: null,
+        ),
+        constMeta:
+            kCrateDocDocumentServiceDocumentServiceGetAwarenessPeersConstMeta,
+        argValues: [that],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta
+  get kCrateDocDocumentServiceDocumentServiceGetAwarenessPeersConstMeta =>
+      const TaskConstMeta(
+        debugName: "DocumentService_get_awareness_peers",
+        argNames: ["that"],
+      );
+
+  @override
+  Future<DocumentState> crateDocDocumentServiceDocumentServiceGetDocumentState({
+    required DocumentService that,
+  }) {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerDocumentService(
+            that,
+            serializer,
+          );
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 31,
+            port: port_,
+          );
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_document_state,
+          decodeErrorData: sse_decode_custom_rust_error,
+        ),
+        constMeta:
+            kCrateDocDocumentServiceDocumentServiceGetDocumentStateConstMeta,
+        argValues: [that],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta
+  get kCrateDocDocumentServiceDocumentServiceGetDocumentStateConstMeta =>
+      const TaskConstMeta(
+        debugName: "DocumentService_get_document_state",
+        argNames: ["that"],
+      );
+
+  @override
+  Future<DocumentState>
+  crateDocDocumentServiceDocumentServiceGetDocumentStateAtSnapshot({
+    required DocumentService that,
+    required List<int> snapshot,
+  }) {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerDocumentService(
+            that,
+            serializer,
+          );
+          sse_encode_list_prim_u_8_loose(snapshot, serializer);
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 32,
+            port: port_,
+          );
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_document_state,
+          decodeErrorData: sse_decode_custom_rust_error,
+        ),
+        constMeta:
+            kCrateDocDocumentServiceDocumentServiceGetDocumentStateAtSnapshotConstMeta,
+        argValues: [that, snapshot],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta
+  get kCrateDocDocumentServiceDocumentServiceGetDocumentStateAtSnapshotConstMeta =>
+      const TaskConstMeta(
+        debugName: "DocumentService_get_document_state_at_snapshot",
+        argNames: ["that", "snapshot"],
+      );
+
+  @override
+  Future<SyncReplyDoc> crateDocDocumentServiceDocumentServiceHandleSyncMessage({
+    required DocumentService that,
+    required List<int> message,
+  }) {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_Auto_RefMut_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerDocumentService(
+            that,
+            serializer,
+          );
+          sse_encode_list_prim_u_8_loose(message, serializer);
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 33,
+            port: port_,
+          );
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_sync_reply_doc,
+          decodeErrorData: sse_decode_custom_rust_error,
+        ),
+        constMeta:
+            kCrateDocDocumentServiceDocumentServiceHandleSyncMessageConstMeta,
+        argValues: [that, message],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta
+  get kCrateDocDocumentServiceDocumentServiceHandleSyncMessageConstMeta =>
+      const TaskConstMeta(
+        debugName: "DocumentService_handle_sync_message",
+        argNames: ["that", "message"],
+      );
+
+  @override
+  Future<Uint8List> crateDocDocumentServiceDocumentServiceInitDocFromJson({
+    required DocumentService that,
+    required String json,
+  }) {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_Auto_RefMut_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerDocumentService(
+            that,
+            serializer,
+          );
+          sse_encode_String(json, serializer);
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 34,
+            port: port_,
+          );
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_list_prim_u_8_strict,
+          decodeErrorData: sse_decode_custom_rust_error,
+        ),
+        constMeta:
+            kCrateDocDocumentServiceDocumentServiceInitDocFromJsonConstMeta,
+        argValues: [that, json],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta
+  get kCrateDocDocumentServiceDocumentServiceInitDocFromJsonConstMeta =>
+      const TaskConstMeta(
+        debugName: "DocumentService_init_doc_from_json",
+        argNames: ["that", "json"],
+      );
+
+  @override
+  Future<Uint8List> crateDocDocumentServiceDocumentServiceInitDocFromMarkdown({
+    required DocumentService that,
+    required String markdown,
+  }) {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_Auto_RefMut_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerDocumentService(
+            that,
+            serializer,
+          );
+          sse_encode_String(markdown, serializer);
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 35,
+            port: port_,
+          );
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_list_prim_u_8_strict,
+          decodeErrorData: sse_decode_custom_rust_error,
+        ),
+        constMeta:
+            kCrateDocDocumentServiceDocumentServiceInitDocFromMarkdownConstMeta,
+        argValues: [that, markdown],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta
+  get kCrateDocDocumentServiceDocumentServiceInitDocFromMarkdownConstMeta =>
+      const TaskConstMeta(
+        debugName: "DocumentService_init_doc_from_markdown",
+        argNames: ["that", "markdown"],
+      );
+
+  @override
+  Future<Uint8List> crateDocDocumentServiceDocumentServiceInitEmptyDoc({
+    required DocumentService that,
+  }) {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_Auto_RefMut_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerDocumentService(
+            that,
+            serializer,
+          );
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 36,
+            port: port_,
+          );
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_list_prim_u_8_strict,
+          decodeErrorData: sse_decode_custom_rust_error,
+        ),
+        constMeta: kCrateDocDocumentServiceDocumentServiceInitEmptyDocConstMeta,
+        argValues: [that],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta
+  get kCrateDocDocumentServiceDocumentServiceInitEmptyDocConstMeta =>
+      const TaskConstMeta(
+        debugName: "DocumentService_init_empty_doc",
+        argNames: ["that"],
+      );
+
+  @override
+  Future<Uint8List> crateDocDocumentServiceDocumentServiceMergeUpdates({
+    required DocumentService that,
+    required List<Uint8List> updates,
+  }) {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerDocumentService(
+            that,
+            serializer,
+          );
+          sse_encode_list_list_prim_u_8_strict(updates, serializer);
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 37,
+            port: port_,
+          );
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_list_prim_u_8_strict,
+          decodeErrorData: sse_decode_custom_rust_error,
+        ),
+        constMeta: kCrateDocDocumentServiceDocumentServiceMergeUpdatesConstMeta,
+        argValues: [that, updates],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta
+  get kCrateDocDocumentServiceDocumentServiceMergeUpdatesConstMeta =>
+      const TaskConstMeta(
+        debugName: "DocumentService_merge_updates",
+        argNames: ["that", "updates"],
+      );
+
+  @override
+  Future<DocumentService> crateDocDocumentServiceDocumentServiceNew() {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 38,
+            port: port_,
+          );
+        },
+        codec: SseCodec(
+          decodeSuccessData:
+              sse_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerDocumentService,
+          decodeErrorData: null,
+        ),
+        constMeta: kCrateDocDocumentServiceDocumentServiceNewConstMeta,
+        argValues: [],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateDocDocumentServiceDocumentServiceNewConstMeta =>
+      const TaskConstMeta(debugName: "DocumentService_new", argNames: []);
+
+  @override
+  Future<DocumentService> crateDocDocumentServiceDocumentServiceNewWithOptions({
+    required String docId,
+    required DocumentOptions options,
+  }) {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_String(docId, serializer);
+          sse_encode_box_autoadd_document_options(options, serializer);
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 39,
+            port: port_,
+          );
+        },
+        codec: SseCodec(
+          decodeSuccessData:
+              sse_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerDocumentService,
+          decodeErrorData: null,
+        ),
+        constMeta:
+            kCrateDocDocumentServiceDocumentServiceNewWithOptionsConstMeta,
+        argValues: [docId, options],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta
+  get kCrateDocDocumentServiceDocumentServiceNewWithOptionsConstMeta =>
+      const TaskConstMeta(
+        debugName: "DocumentService_new_with_options",
+        argNames: ["docId", "options"],
+      );
+
+  @override
+  Future<UndoRedoResult> crateDocDocumentServiceDocumentServiceRedo({
+    required DocumentService that,
+  }) {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_Auto_RefMut_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerDocumentService(
+            that,
+            serializer,
+          );
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 40,
+            port: port_,
+          );
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_undo_redo_result,
+          decodeErrorData: sse_decode_custom_rust_error,
+        ),
+        constMeta: kCrateDocDocumentServiceDocumentServiceRedoConstMeta,
+        argValues: [that],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateDocDocumentServiceDocumentServiceRedoConstMeta =>
+      const TaskConstMeta(
+        debugName: "DocumentService_redo",
+        argNames: ["that"],
+      );
+
+  @override
+  Future<ChainRepairResult> crateDocDocumentServiceDocumentServiceRepairChains({
+    required DocumentService that,
+  }) {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_Auto_RefMut_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerDocumentService(
+            that,
+            serializer,
+          );
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 41,
+            port: port_,
+          );
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_chain_repair_result,
+          decodeErrorData: sse_decode_custom_rust_error,
+        ),
+        constMeta: kCrateDocDocumentServiceDocumentServiceRepairChainsConstMeta,
+        argValues: [that],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta
+  get kCrateDocDocumentServiceDocumentServiceRepairChainsConstMeta =>
+      const TaskConstMeta(
+        debugName: "DocumentService_repair_chains",
+        argNames: ["that"],
+      );
+
+  @override
+  Future<Uint8List> crateDocDocumentServiceDocumentServiceRestoreSnapshot({
+    required DocumentService that,
+    required List<int> snapshot,
+  }) {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_Auto_RefMut_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerDocumentService(
+            that,
+            serializer,
+          );
+          sse_encode_list_prim_u_8_loose(snapshot, serializer);
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 42,
+            port: port_,
+          );
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_list_prim_u_8_strict,
+          decodeErrorData: sse_decode_custom_rust_error,
+        ),
+        constMeta:
+            kCrateDocDocumentServiceDocumentServiceRestoreSnapshotConstMeta,
+        argValues: [that, snapshot],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta
+  get kCrateDocDocumentServiceDocumentServiceRestoreSnapshotConstMeta =>
+      const TaskConstMeta(
+        debugName: "DocumentService_restore_snapshot",
+        argNames: ["that", "snapshot"],
+      );
+
+  @override
+  Future<Uint8List> crateDocDocumentServiceDocumentServiceSetLocalAwareness({
+    required DocumentService that,
+    required AwarenessStateDoc state,
+  }) {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_Auto_RefMut_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerDocumentService(
+            that,
+            serializer,
+          );
+          sse_encode_box_autoadd_awareness_state_doc(state, serializer);
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 43,
+            port: port_,
+          );
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_list_prim_u_8_strict,
+          decodeErrorData: sse_decode_custom_rust_error,
+        ),
+        constMeta:
+            kCrateDocDocumentServiceDocumentServiceSetLocalAwarenessConstMeta,
+        argValues: [that, state],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta
+  get kCrateDocDocumentServiceDocumentServiceSetLocalAwarenessConstMeta =>
+      const TaskConstMeta(
+        debugName: "DocumentService_set_local_awareness",
+        argNames: ["that", "state"],
+      );
+
+  @override
+  Future<Uint8List> crateDocDocumentServiceDocumentServiceSetRootNodeId({
+    required DocumentService that,
+    required String id,
+  }) {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_Auto_RefMut_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerDocumentService(
+            that,
+            serializer,
+          );
+          sse_encode_String(id, serializer);
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 44,
+            port: port_,
+          );
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_list_prim_u_8_strict,
+          decodeErrorData: sse_decode_custom_rust_error,
+        ),
+        constMeta:
+            kCrateDocDocumentServiceDocumentServiceSetRootNodeIdConstMeta,
+        argValues: [that, id],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta
+  get kCrateDocDocumentServiceDocumentServiceSetRootNodeIdConstMeta =>
+      const TaskConstMeta(
+        debugName: "DocumentService_set_root_node_id",
+        argNames: ["that", "id"],
+      );
+
+  @override
+  Future<SiblingOrderingDoc>
+  crateDocDocumentServiceDocumentServiceSiblingOrdering({
+    required DocumentService that,
+  }) {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerDocumentService(
+            that,
+            serializer,
+          );
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 45,
+            port: port_,
+          );
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_sibling_ordering_doc,
+          decodeErrorData: null,
+        ),
+        constMeta:
+            kCrateDocDocumentServiceDocumentServiceSiblingOrderingConstMeta,
+        argValues: [that],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta
+  get kCrateDocDocumentServiceDocumentServiceSiblingOrderingConstMeta =>
+      const TaskConstMeta(
+        debugName: "DocumentService_sibling_ordering",
+        argNames: ["that"],
+      );
+
+  @override
+  Future<Uint8List> crateDocDocumentServiceDocumentServiceStartSync({
+    required DocumentService that,
+  }) {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerDocumentService(
+            that,
+            serializer,
+          );
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 46,
+            port: port_,
+          );
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_list_prim_u_8_strict,
+          decodeErrorData: sse_decode_custom_rust_error,
+        ),
+        constMeta: kCrateDocDocumentServiceDocumentServiceStartSyncConstMeta,
+        argValues: [that],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateDocDocumentServiceDocumentServiceStartSyncConstMeta =>
+      const TaskConstMeta(
+        debugName: "DocumentService_start_sync",
+        argNames: ["that"],
+      );
+
+  @override
+  Future<void> crateDocDocumentServiceDocumentServiceStopUndoCapturing({
+    required DocumentService that,
+  }) {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_Auto_RefMut_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerDocumentService(
+            that,
+            serializer,
+          );
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 47,
+            port: port_,
+          );
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_unit,
+          decodeErrorData: null,
+        ),
+        constMeta:
+            kCrateDocDocumentServiceDocumentServiceStopUndoCapturingConstMeta,
+        argValues: [that],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta
+  get kCrateDocDocumentServiceDocumentServiceStopUndoCapturingConstMeta =>
+      const TaskConstMeta(
+        debugName: "DocumentService_stop_undo_capturing",
+        argNames: ["that"],
+      );
+
+  @override
+  Stream<DocumentChangeEvent>
+  crateDocDocumentServiceDocumentServiceSubscribeChanges({
+    required DocumentService that,
+  }) {
+    final sink = RustStreamSink<DocumentChangeEvent>();
+    unawaited(
+      handler.executeNormal(
+        NormalTask(
+          callFfi: (port_) {
+            final serializer = SseSerializer(generalizedFrbRustBinding);
+            sse_encode_Auto_RefMut_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerDocumentService(
+              that,
+              serializer,
+            );
+            sse_encode_StreamSink_document_change_event_Sse(sink, serializer);
+            pdeCallFfi(
+              generalizedFrbRustBinding,
+              serializer,
+              funcId: 48,
+              port: port_,
+            );
+          },
+          codec: SseCodec(
+            decodeSuccessData: sse_decode_unit,
+            decodeErrorData: null,
+          ),
+          constMeta:
+              kCrateDocDocumentServiceDocumentServiceSubscribeChangesConstMeta,
+          argValues: [that, sink],
+          apiImpl: this,
+        ),
+      ),
+    );
+    return sink.stream;
+  }
+
+  TaskConstMeta
+  get kCrateDocDocumentServiceDocumentServiceSubscribeChangesConstMeta =>
+      const TaskConstMeta(
+        debugName: "DocumentService_subscribe_changes",
+        argNames: ["that", "sink"],
+      );
+
+  @override
+  Future<UndoRedoResult> crateDocDocumentServiceDocumentServiceUndo({
+    required DocumentService that,
+  }) {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_Auto_RefMut_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerDocumentService(
+            that,
+            serializer,
+          );
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 49,
+            port: port_,
+          );
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_undo_redo_result,
+          decodeErrorData: sse_decode_custom_rust_error,
+        ),
+        constMeta: kCrateDocDocumentServiceDocumentServiceUndoConstMeta,
+        argValues: [that],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateDocDocumentServiceDocumentServiceUndoConstMeta =>
+      const TaskConstMeta(
+        debugName: "DocumentService_undo",
+        argNames: ["that"],
+      );
+
+  @override
+  Future<void> crateDocDocumentServiceDocumentServiceUnsubscribeChanges({
+    required DocumentService that,
+  }) {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_Auto_RefMut_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerDocumentService(
+            that,
+            serializer,
+          );
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 50,
+            port: port_,
+          );
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_unit,
+          decodeErrorData: null,
+        ),
+        constMeta:
+            kCrateDocDocumentServiceDocumentServiceUnsubscribeChangesConstMeta,
+        argValues: [that],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta
+  get kCrateDocDocumentServiceDocumentServiceUnsubscribeChangesConstMeta =>
+      const TaskConstMeta(
+        debugName: "DocumentService_unsubscribe_changes",
+        argNames: ["that"],
+      );
+
+  @override
+  Future<IntegrityReport>
+  crateDocDocumentServiceDocumentServiceValidateDocument({
+    required DocumentService that,
+    required bool fix,
+  }) {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_Auto_RefMut_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerDocumentService(
+            that,
+            serializer,
+          );
+          sse_encode_bool(fix, serializer);
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 51,
+            port: port_,
+          );
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_integrity_report,
+          decodeErrorData: sse_decode_custom_rust_error,
+        ),
+        constMeta:
+            kCrateDocDocumentServiceDocumentServiceValidateDocumentConstMeta,
+        argValues: [that, fix],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta
+  get kCrateDocDocumentServiceDocumentServiceValidateDocumentConstMeta =>
+      const TaskConstMeta(
+        debugName: "DocumentService_validate_document",
+        argNames: ["that", "fix"],
+      );
+
+  @override
+  Future<AttributeModeDoc> crateDocDocumentTypesAttributeModeDocDefault() {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 52,
+            port: port_,
+          );
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_attribute_mode_doc,
+          decodeErrorData: null,
+        ),
+        constMeta: kCrateDocDocumentTypesAttributeModeDocDefaultConstMeta,
+        argValues: [],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateDocDocumentTypesAttributeModeDocDefaultConstMeta =>
+      const TaskConstMeta(
+        debugName: "attribute_mode_doc_default",
+        argNames: [],
+      );
+
+  @override
+  Future<AwarenessChangesDoc>
+  crateDocDocumentTypesAwarenessChangesDocDefault() {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 53,
+            port: port_,
+          );
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_awareness_changes_doc,
+          decodeErrorData: null,
+        ),
+        constMeta: kCrateDocDocumentTypesAwarenessChangesDocDefaultConstMeta,
+        argValues: [],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateDocDocumentTypesAwarenessChangesDocDefaultConstMeta =>
+      const TaskConstMeta(
+        debugName: "awareness_changes_doc_default",
+        argNames: [],
+      );
+
+  @override
+  Future<AwarenessTimeoutDoc>
+  crateDocDocumentTypesAwarenessTimeoutDocDefault() {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 54,
+            port: port_,
+          );
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_awareness_timeout_doc,
+          decodeErrorData: null,
+        ),
+        constMeta: kCrateDocDocumentTypesAwarenessTimeoutDocDefaultConstMeta,
+        argValues: [],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateDocDocumentTypesAwarenessTimeoutDocDefaultConstMeta =>
+      const TaskConstMeta(
+        debugName: "awareness_timeout_doc_default",
+        argNames: [],
+      );
+
+  @override
+  Future<CustomRustError> crateDocDocumentTypesCustomRustErrorNew({
+    required String message,
+  }) {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_String(message, serializer);
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 55,
+            port: port_,
+          );
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_custom_rust_error,
+          decodeErrorData: null,
+        ),
+        constMeta: kCrateDocDocumentTypesCustomRustErrorNewConstMeta,
+        argValues: [message],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateDocDocumentTypesCustomRustErrorNewConstMeta =>
+      const TaskConstMeta(
+        debugName: "custom_rust_error_new",
+        argNames: ["message"],
+      );
+
+  @override
+  Future<DocumentOptions> crateDocDocumentTypesDocumentOptionsDefault() {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 56,
+            port: port_,
+          );
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_document_options,
+          decodeErrorData: null,
+        ),
+        constMeta: kCrateDocDocumentTypesDocumentOptionsDefaultConstMeta,
+        argValues: [],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateDocDocumentTypesDocumentOptionsDefaultConstMeta =>
+      const TaskConstMeta(debugName: "document_options_default", argNames: []);
+
+  @override
+  Future<OffsetKindDoc> crateDocDocumentTypesOffsetKindDocDefault() {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 57,
+            port: port_,
+          );
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_offset_kind_doc,
+          decodeErrorData: null,
+        ),
+        constMeta: kCrateDocDocumentTypesOffsetKindDocDefaultConstMeta,
+        argValues: [],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateDocDocumentTypesOffsetKindDocDefaultConstMeta =>
+      const TaskConstMeta(debugName: "offset_kind_doc_default", argNames: []);
+
+  @override
+  Future<SiblingOrderingDoc> crateDocDocumentTypesSiblingOrderingDocDefault() {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 58,
+            port: port_,
+          );
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_sibling_ordering_doc,
+          decodeErrorData: null,
+        ),
+        constMeta: kCrateDocDocumentTypesSiblingOrderingDocDefaultConstMeta,
+        argValues: [],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateDocDocumentTypesSiblingOrderingDocDefaultConstMeta =>
+      const TaskConstMeta(
+        debugName: "sibling_ordering_doc_default",
+        argNames: [],
+      );
+
+  @override
+  Future<StickyAssocDoc> crateDocDocumentTypesStickyAssocDocDefault() {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 59,
+            port: port_,
+          );
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_sticky_assoc_doc,
+          decodeErrorData: null,
+        ),
+        constMeta: kCrateDocDocumentTypesStickyAssocDocDefaultConstMeta,
+        argValues: [],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateDocDocumentTypesStickyAssocDocDefaultConstMeta =>
+      const TaskConstMeta(debugName: "sticky_assoc_doc_default", argNames: []);
+
+  @override
+  Future<SyncReplyDoc> crateDocDocumentTypesSyncReplyDocDefault() {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 60,
+            port: port_,
+          );
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_sync_reply_doc,
+          decodeErrorData: null,
+        ),
+        constMeta: kCrateDocDocumentTypesSyncReplyDocDefaultConstMeta,
+        argValues: [],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateDocDocumentTypesSyncReplyDocDefaultConstMeta =>
+      const TaskConstMeta(debugName: "sync_reply_doc_default", argNames: []);
+
+  @override
+  Future<UpdateEncodingDoc> crateDocDocumentTypesUpdateEncodingDocDefault() {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 61,
+            port: port_,
+          );
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_update_encoding_doc,
+          decodeErrorData: null,
+        ),
+        constMeta: kCrateDocDocumentTypesUpdateEncodingDocDefaultConstMeta,
+        argValues: [],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateDocDocumentTypesUpdateEncodingDocDefaultConstMeta =>
+      const TaskConstMeta(
+        debugName: "update_encoding_doc_default",
+        argNames: [],
+      );
+
+  RustArcIncrementStrongCountFnType
+  get rust_arc_increment_strong_count_DocumentService =>
+      wire.rust_arc_increment_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerDocumentService;
+
+  RustArcDecrementStrongCountFnType
+  get rust_arc_decrement_strong_count_DocumentService =>
+      wire.rust_arc_decrement_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerDocumentService;
+
+  @protected
+  AnyhowException dco_decode_AnyhowException(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return AnyhowException(raw as String);
+  }
+
+  @protected
+  DocumentService
+  dco_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerDocumentService(
+    dynamic raw,
+  ) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return DocumentServiceImpl.frbInternalDcoDecode(raw as List<dynamic>);
+  }
+
+  @protected
+  DocumentService
+  dco_decode_Auto_RefMut_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerDocumentService(
+    dynamic raw,
+  ) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return DocumentServiceImpl.frbInternalDcoDecode(raw as List<dynamic>);
+  }
+
+  @protected
+  DocumentService
+  dco_decode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerDocumentService(
+    dynamic raw,
+  ) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return DocumentServiceImpl.frbInternalDcoDecode(raw as List<dynamic>);
+  }
+
+  @protected
+  Map<String, String> dco_decode_Map_String_String_None(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return Map.fromEntries(
+      dco_decode_list_record_string_string(
+        raw,
+      ).map((e) => MapEntry(e.$1, e.$2)),
+    );
+  }
+
+  @protected
+  Map<String, BlockDoc> dco_decode_Map_String_block_doc_None(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return Map.fromEntries(
+      dco_decode_list_record_string_block_doc(
+        raw,
+      ).map((e) => MapEntry(e.$1, e.$2)),
+    );
+  }
+
+  @protected
+  Map<String, List<String>> dco_decode_Map_String_list_String_None(
+    dynamic raw,
+  ) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return Map.fromEntries(
+      dco_decode_list_record_string_list_string(
+        raw,
+      ).map((e) => MapEntry(e.$1, e.$2)),
+    );
+  }
+
+  @protected
+  DocumentService
+  dco_decode_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerDocumentService(
+    dynamic raw,
+  ) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return DocumentServiceImpl.frbInternalDcoDecode(raw as List<dynamic>);
+  }
+
+  @protected
+  RustStreamSink<DocumentChangeEvent>
+  dco_decode_StreamSink_document_change_event_Sse(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    throw UnimplementedError();
+  }
+
+  @protected
+  String dco_decode_String(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return raw as String;
+  }
+
+  @protected
+  AppliedUpdateActions dco_decode_applied_update_actions(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    final arr = raw as List<dynamic>;
+    if (arr.length != 2)
+      throw Exception('unexpected arr length: expect 2 but see ${arr.length}');
+    return AppliedUpdateActions(
+      actions: dco_decode_list_block_action_doc(arr[0]),
+      requiresFullReload: dco_decode_bool(arr[1]),
+    );
+  }
+
+  @protected
+  AppliedUpdates dco_decode_applied_updates(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    final arr = raw as List<dynamic>;
+    if (arr.length != 4)
+      throw Exception('unexpected arr length: expect 4 but see ${arr.length}');
+    return AppliedUpdates(
+      changedBlockIds: dco_decode_list_String(arr[0]),
+      deletedBlockIds: dco_decode_list_String(arr[1]),
+      rootIdChanged: dco_decode_bool(arr[2]),
+      hasPendingUpdates: dco_decode_bool(arr[3]),
+    );
+  }
+
+  @protected
+  AttributeModeDoc dco_decode_attribute_mode_doc(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return AttributeModeDoc.values[raw as int];
+  }
+
+  @protected
+  AwarenessChangesDoc dco_decode_awareness_changes_doc(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    final arr = raw as List<dynamic>;
+    if (arr.length != 3)
+      throw Exception('unexpected arr length: expect 3 but see ${arr.length}');
+    return AwarenessChangesDoc(
+      added: dco_decode_list_prim_u_64_strict(arr[0]),
+      updated: dco_decode_list_prim_u_64_strict(arr[1]),
+      removed: dco_decode_list_prim_u_64_strict(arr[2]),
+    );
+  }
+
+  @protected
+  AwarenessPeerDoc dco_decode_awareness_peer_doc(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    final arr = raw as List<dynamic>;
+    if (arr.length != 6)
+      throw Exception('unexpected arr length: expect 6 but see ${arr.length}');
+    return AwarenessPeerDoc(
+      clientId: dco_decode_u_64(arr[0]),
+      clock: dco_decode_u_32(arr[1]),
+      lastUpdated: dco_decode_u_64(arr[2]),
+      isLocal: dco_decode_bool(arr[3]),
+      user: dco_decode_opt_box_autoadd_awareness_user_doc(arr[4]),
+      selection: dco_decode_opt_box_autoadd_awareness_selection_doc(arr[5]),
+    );
+  }
+
+  @protected
+  AwarenessSelectionDoc dco_decode_awareness_selection_doc(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    final arr = raw as List<dynamic>;
+    if (arr.length != 2)
+      throw Exception('unexpected arr length: expect 2 but see ${arr.length}');
+    return AwarenessSelectionDoc(
+      anchor: dco_decode_text_position_doc(arr[0]),
+      head: dco_decode_text_position_doc(arr[1]),
+    );
+  }
+
+  @protected
+  AwarenessStateDoc dco_decode_awareness_state_doc(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    final arr = raw as List<dynamic>;
+    if (arr.length != 2)
+      throw Exception('unexpected arr length: expect 2 but see ${arr.length}');
+    return AwarenessStateDoc(
+      user: dco_decode_awareness_user_doc(arr[0]),
+      selection: dco_decode_opt_box_autoadd_awareness_selection_doc(arr[1]),
+    );
+  }
+
+  @protected
+  AwarenessTimeoutDoc dco_decode_awareness_timeout_doc(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    final arr = raw as List<dynamic>;
+    if (arr.length != 2)
+      throw Exception('unexpected arr length: expect 2 but see ${arr.length}');
+    return AwarenessTimeoutDoc(
+      removed: dco_decode_list_prim_u_64_strict(arr[0]),
+      update: dco_decode_opt_list_prim_u_8_strict(arr[1]),
+    );
+  }
+
+  @protected
+  AwarenessUserDoc dco_decode_awareness_user_doc(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    final arr = raw as List<dynamic>;
+    if (arr.length != 3)
+      throw Exception('unexpected arr length: expect 3 but see ${arr.length}');
+    return AwarenessUserDoc(
+      id: dco_decode_String(arr[0]),
+      name: dco_decode_String(arr[1]),
+      color: dco_decode_opt_String(arr[2]),
+    );
+  }
+
+  @protected
+  BlockActionDoc dco_decode_block_action_doc(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    final arr = raw as List<dynamic>;
+    if (arr.length != 4)
+      throw Exception('unexpected arr length: expect 4 but see ${arr.length}');
+    return BlockActionDoc(
+      action: dco_decode_block_action_type_doc(arr[0]),
+      block: dco_decode_block_doc(arr[1]),
+      path: dco_decode_list_prim_u_32_strict(arr[2]),
+      oldPath: dco_decode_opt_list_prim_u_32_strict(arr[3]),
+    );
+  }
+
+  @protected
+  BlockActionTypeDoc dco_decode_block_action_type_doc(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return BlockActionTypeDoc.values[raw as int];
+  }
+
+  @protected
+  BlockDoc dco_decode_block_doc(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    final arr = raw as List<dynamic>;
+    if (arr.length != 8)
+      throw Exception('unexpected arr length: expect 8 but see ${arr.length}');
+    return BlockDoc(
+      id: dco_decode_String(arr[0]),
+      ty: dco_decode_String(arr[1]),
+      attributes: dco_decode_Map_String_String_None(arr[2]),
+      delta: dco_decode_opt_String(arr[3]),
+      parentId: dco_decode_opt_String(arr[4]),
+      prevId: dco_decode_opt_String(arr[5]),
+      nextId: dco_decode_opt_String(arr[6]),
+      oldParentId: dco_decode_opt_String(arr[7]),
+    );
+  }
+
+  @protected
+  bool dco_decode_bool(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return raw as bool;
+  }
+
+  @protected
+  AwarenessSelectionDoc dco_decode_box_autoadd_awareness_selection_doc(
+    dynamic raw,
+  ) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return dco_decode_awareness_selection_doc(raw);
+  }
+
+  @protected
+  AwarenessStateDoc dco_decode_box_autoadd_awareness_state_doc(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return dco_decode_awareness_state_doc(raw);
+  }
+
+  @protected
+  AwarenessUserDoc dco_decode_box_autoadd_awareness_user_doc(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return dco_decode_awareness_user_doc(raw);
+  }
+
+  @protected
+  DocumentOptions dco_decode_box_autoadd_document_options(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return dco_decode_document_options(raw);
+  }
+
+  @protected
+  SyncMessageDoc dco_decode_box_autoadd_sync_message_doc(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return dco_decode_sync_message_doc(raw);
+  }
+
+  @protected
+  TextPositionDoc dco_decode_box_autoadd_text_position_doc(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return dco_decode_text_position_doc(raw);
+  }
+
+  @protected
+  int dco_decode_box_autoadd_u_32(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return raw as int;
+  }
+
+  @protected
+  BigInt dco_decode_box_autoadd_u_64(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return dco_decode_u_64(raw);
+  }
+
+  @protected
+  UpdateEncodingDoc dco_decode_box_autoadd_update_encoding_doc(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return dco_decode_update_encoding_doc(raw);
+  }
+
+  @protected
+  ChainRepairResult dco_decode_chain_repair_result(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    final arr = raw as List<dynamic>;
+    if (arr.length != 3)
+      throw Exception('unexpected arr length: expect 3 but see ${arr.length}');
+    return ChainRepairResult(
+      cycleBlockIds: dco_decode_list_String(arr[0]),
+      danglingBlockIds: dco_decode_list_String(arr[1]),
+      update: dco_decode_list_prim_u_8_strict(arr[2]),
+    );
+  }
+
+  @protected
+  CompactedUpdate dco_decode_compacted_update(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    final arr = raw as List<dynamic>;
+    if (arr.length != 3)
+      throw Exception('unexpected arr length: expect 3 but see ${arr.length}');
+    return CompactedUpdate(
+      update: dco_decode_list_prim_u_8_strict(arr[0]),
+      stateVector: dco_decode_list_prim_u_8_strict(arr[1]),
+      hasPendingUpdates: dco_decode_bool(arr[2]),
+    );
+  }
+
+  @protected
+  CreatedSnapshot dco_decode_created_snapshot(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    final arr = raw as List<dynamic>;
+    if (arr.length != 2)
+      throw Exception('unexpected arr length: expect 2 but see ${arr.length}');
+    return CreatedSnapshot(
+      snapshot: dco_decode_snapshot_info(arr[0]),
+      data: dco_decode_list_prim_u_8_strict(arr[1]),
+    );
+  }
+
+  @protected
+  CustomRustError dco_decode_custom_rust_error(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    final arr = raw as List<dynamic>;
+    if (arr.length != 2)
+      throw Exception('unexpected arr length: expect 2 but see ${arr.length}');
+    return CustomRustError(
+      message: dco_decode_String(arr[0]),
+      actionIndex: dco_decode_opt_box_autoadd_u_32(arr[1]),
+    );
+  }
+
+  @protected
+  DocumentChangeEvent dco_decode_document_change_event(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    final arr = raw as List<dynamic>;
+    if (arr.length != 4)
+      throw Exception('unexpected arr length: expect 4 but see ${arr.length}');
+    return DocumentChangeEvent(
+      kind: dco_decode_document_change_kind_doc(arr[0]),
+      blockId: dco_decode_String(arr[1]),
+      delta: dco_decode_opt_String(arr[2]),
+      origin: dco_decode_opt_String(arr[3]),
+    );
+  }
+
+  @protected
+  DocumentChangeKindDoc dco_decode_document_change_kind_doc(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return DocumentChangeKindDoc.values[raw as int];
+  }
+
+  @protected
+  DocumentOptions dco_decode_document_options(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    final arr = raw as List<dynamic>;
+    if (arr.length != 5)
+      throw Exception('unexpected arr length: expect 5 but see ${arr.length}');
+    return DocumentOptions(
+      clientId: dco_decode_opt_box_autoadd_u_64(arr[0]),
+      offsetKind: dco_decode_offset_kind_doc(arr[1]),
+      skipGc: dco_decode_bool(arr[2]),
+      updateEncoding: dco_decode_update_encoding_doc(arr[3]),
+      attributeMode: dco_decode_attribute_mode_doc(arr[4]),
+    );
+  }
+
+  @protected
+  DocumentState dco_decode_document_state(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    final arr = raw as List<dynamic>;
+    if (arr.length != 4)
+      throw Exception('unexpected arr length: expect 4 but see ${arr.length}');
+    return DocumentState(
+      docId: dco_decode_String(arr[0]),
+      blocks: dco_decode_Map_String_block_doc_None(arr[1]),
+      childrenMap: dco_decode_Map_String_list_String_None(arr[2]),
+      rootId: dco_decode_String(arr[3]),
+    );
+  }
+
+  @protected
+  FailedToDecodeUpdates dco_decode_failed_to_decode_updates(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    final arr = raw as List<dynamic>;
+    if (arr.length != 1)
+      throw Exception('unexpected arr length: expect 1 but see ${arr.length}');
+    return FailedToDecodeUpdates(
+      failedUpdatesIds: dco_decode_list_String(arr[0]),
+    );
+  }
+
+  @protected
+  int dco_decode_i_32(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return raw as int;
+  }
+
+  @protected
+  PlatformInt64 dco_decode_i_64(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return dcoDecodeI64(raw);
+  }
+
+  @protected
+  IntegrityIssueDoc dco_decode_integrity_issue_doc(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    final arr = raw as List<dynamic>;
+    if (arr.length != 2)
+      throw Exception('unexpected arr length: expect 2 but see ${arr.length}');
+    return IntegrityIssueDoc(
+      kind: dco_decode_integrity_issue_kind_doc(arr[0]),
+      blockIds: dco_decode_list_String(arr[1]),
+    );
+  }
+
+  @protected
+  IntegrityIssueKindDoc dco_decode_integrity_issue_kind_doc(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return IntegrityIssueKindDoc.values[raw as int];
+  }
+
+  @protected
+  IntegrityReport dco_decode_integrity_report(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    final arr = raw as List<dynamic>;
+    if (arr.length != 2)
+      throw Exception('unexpected arr length: expect 2 but see ${arr.length}');
+    return IntegrityReport(
+      issues: dco_decode_list_integrity_issue_doc(arr[0]),
+      update: dco_decode_list_prim_u_8_strict(arr[1]),
+    );
+  }
+
+  @protected
+  List<String> dco_decode_list_String(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return (raw as List<dynamic>).map(dco_decode_String).toList();
   }
 
   @protected
-  DocumentService
-  dco_decode_Auto_RefMut_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerDocumentService(
+  List<AwarenessPeerDoc> dco_decode_list_awareness_peer_doc(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return (raw as List<dynamic>).map(dco_decode_awareness_peer_doc).toList();
+  }
+
+  @protected
+  List<BlockActionDoc> dco_decode_list_block_action_doc(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return (raw as List<dynamic>).map(dco_decode_block_action_doc).toList();
+  }
+
+  @protected
+  List<IntegrityIssueDoc> dco_decode_list_integrity_issue_doc(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return (raw as List<dynamic>).map(dco_decode_integrity_issue_doc).toList();
+  }
+
+  @protected
+  List<Uint8List> dco_decode_list_list_prim_u_8_strict(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return (raw as List<dynamic>).map(dco_decode_list_prim_u_8_strict).toList();
+  }
+
+  @protected
+  Uint32List dco_decode_list_prim_u_32_strict(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return raw as Uint32List;
+  }
+
+  @protected
+  Uint64List dco_decode_list_prim_u_64_strict(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return dcoDecodeUint64List(raw);
+  }
+
+  @protected
+  List<int> dco_decode_list_prim_u_8_loose(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return raw as List<int>;
+  }
+
+  @protected
+  Uint8List dco_decode_list_prim_u_8_strict(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return raw as Uint8List;
+  }
+
+  @protected
+  List<(String, BlockDoc)> dco_decode_list_record_string_block_doc(
     dynamic raw,
   ) {
     // Codec=Dco (DartCObject based), see doc to use other codecs
-    return DocumentServiceImpl.frbInternalDcoDecode(raw as List<dynamic>);
+    return (raw as List<dynamic>)
+        .map(dco_decode_record_string_block_doc)
+        .toList();
   }
 
   @protected
-  DocumentService
-  dco_decode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerDocumentService(
+  List<(String, List<String>)> dco_decode_list_record_string_list_string(
     dynamic raw,
   ) {
     // Codec=Dco (DartCObject based), see doc to use other codecs
-    return DocumentServiceImpl.frbInternalDcoDecode(raw as List<dynamic>);
+    return (raw as List<dynamic>)
+        .map(dco_decode_record_string_list_string)
+        .toList();
   }
 
   @protected
-  Map<String, String> dco_decode_Map_String_String_None(dynamic raw) {
+  List<(String, String)> dco_decode_list_record_string_string(dynamic raw) {
     // Codec=Dco (DartCObject based), see doc to use other codecs
-    return Map.fromEntries(
-      dco_decode_list_record_string_string(
-        raw,
-      ).map((e) => MapEntry(e.$1, e.$2)),
-    );
+    return (raw as List<dynamic>).map(dco_decode_record_string_string).toList();
   }
 
   @protected
-  Map<String, BlockDoc> dco_decode_Map_String_block_doc_None(dynamic raw) {
+  List<SyncMessageDoc> dco_decode_list_sync_message_doc(dynamic raw) {
     // Codec=Dco (DartCObject based), see doc to use other codecs
-    return Map.fromEntries(
-      dco_decode_list_record_string_block_doc(
-        raw,
-      ).map((e) => MapEntry(e.$1, e.$2)),
-    );
+    return (raw as List<dynamic>).map(dco_decode_sync_message_doc).toList();
   }
 
   @protected
-  Map<String, List<String>> dco_decode_Map_String_list_String_None(
+  List<UpdateWithId> dco_decode_list_update_with_id(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return (raw as List<dynamic>).map(dco_decode_update_with_id).toList();
+  }
+
+  @protected
+  OffsetKindDoc dco_decode_offset_kind_doc(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return OffsetKindDoc.values[raw as int];
+  }
+
+  @protected
+  String? dco_decode_opt_String(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return raw == null ? null : dco_decode_String(raw);
+  }
+
+  @protected
+  AwarenessSelectionDoc? dco_decode_opt_box_autoadd_awareness_selection_doc(
     dynamic raw,
   ) {
     // Codec=Dco (DartCObject based), see doc to use other codecs
-    return Map.fromEntries(
-      dco_decode_list_record_string_list_string(
-        raw,
-      ).map((e) => MapEntry(e.$1, e.$2)),
-    );
+    return raw == null
+        ? null
+        : dco_decode_box_autoadd_awareness_selection_doc(raw);
   }
 
   @protected
-  DocumentService
-  dco_decode_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerDocumentService(
+  AwarenessUserDoc? dco_decode_opt_box_autoadd_awareness_user_doc(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return raw == null ? null : dco_decode_box_autoadd_awareness_user_doc(raw);
+  }
+
+  @protected
+  TextPositionDoc? dco_decode_opt_box_autoadd_text_position_doc(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return raw == null ? null : dco_decode_box_autoadd_text_position_doc(raw);
+  }
+
+  @protected
+  int? dco_decode_opt_box_autoadd_u_32(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return raw == null ? null : dco_decode_box_autoadd_u_32(raw);
+  }
+
+  @protected
+  BigInt? dco_decode_opt_box_autoadd_u_64(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return raw == null ? null : dco_decode_box_autoadd_u_64(raw);
+  }
+
+  @protected
+  UpdateEncodingDoc? dco_decode_opt_box_autoadd_update_encoding_doc(
     dynamic raw,
   ) {
     // Codec=Dco (DartCObject based), see doc to use other codecs
-    return DocumentServiceImpl.frbInternalDcoDecode(raw as List<dynamic>);
+    return raw == null ? null : dco_decode_box_autoadd_update_encoding_doc(raw);
   }
 
   @protected
-  String dco_decode_String(dynamic raw) {
+  Uint32List? dco_decode_opt_list_prim_u_32_strict(dynamic raw) {
     // Codec=Dco (DartCObject based), see doc to use other codecs
-    return raw as String;
+    return raw == null ? null : dco_decode_list_prim_u_32_strict(raw);
   }
 
   @protected
-  BlockActionDoc dco_decode_block_action_doc(dynamic raw) {
+  Uint8List? dco_decode_opt_list_prim_u_8_strict(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return raw == null ? null : dco_decode_list_prim_u_8_strict(raw);
+  }
+
+  @protected
+  (String, BlockDoc) dco_decode_record_string_block_doc(dynamic raw) {
     // Codec=Dco (DartCObject based), see doc to use other codecs
     final arr = raw as List<dynamic>;
-    if (arr.length != 4)
-      throw Exception('unexpected arr length: expect 4 but see ${arr.length}');
-    return BlockActionDoc(
-      action: dco_decode_block_action_type_doc(arr[0]),
-      block: dco_decode_block_doc(arr[1]),
-      path: dco_decode_list_prim_u_32_strict(arr[2]),
-      oldPath: dco_decode_opt_list_prim_u_32_strict(arr[3]),
+    if (arr.length != 2) {
+      throw Exception('Expected 2 elements, got ${arr.length}');
+    }
+    return (dco_decode_String(arr[0]), dco_decode_block_doc(arr[1]));
+  }
+
+  @protected
+  (String, List<String>) dco_decode_record_string_list_string(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    final arr = raw as List<dynamic>;
+    if (arr.length != 2) {
+      throw Exception('Expected 2 elements, got ${arr.length}');
+    }
+    return (dco_decode_String(arr[0]), dco_decode_list_String(arr[1]));
+  }
+
+  @protected
+  (String, String) dco_decode_record_string_string(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    final arr = raw as List<dynamic>;
+    if (arr.length != 2) {
+      throw Exception('Expected 2 elements, got ${arr.length}');
+    }
+    return (dco_decode_String(arr[0]), dco_decode_String(arr[1]));
+  }
+
+  @protected
+  SiblingOrderingDoc dco_decode_sibling_ordering_doc(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return SiblingOrderingDoc.values[raw as int];
+  }
+
+  @protected
+  SnapshotInfo dco_decode_snapshot_info(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    final arr = raw as List<dynamic>;
+    if (arr.length != 3)
+      throw Exception('unexpected arr length: expect 3 but see ${arr.length}');
+    return SnapshotInfo(
+      id: dco_decode_String(arr[0]),
+      label: dco_decode_String(arr[1]),
+      createdAt: dco_decode_i_64(arr[2]),
+    );
+  }
+
+  @protected
+  StickyAssocDoc dco_decode_sticky_assoc_doc(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return StickyAssocDoc.values[raw as int];
+  }
+
+  @protected
+  SyncMessageDoc dco_decode_sync_message_doc(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    final arr = raw as List<dynamic>;
+    if (arr.length != 2)
+      throw Exception('unexpected arr length: expect 2 but see ${arr.length}');
+    return SyncMessageDoc(
+      kind: dco_decode_sync_message_kind_doc(arr[0]),
+      payload: dco_decode_list_prim_u_8_strict(arr[1]),
+    );
+  }
+
+  @protected
+  SyncMessageKindDoc dco_decode_sync_message_kind_doc(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return SyncMessageKindDoc.values[raw as int];
+  }
+
+  @protected
+  SyncReplyDoc dco_decode_sync_reply_doc(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    final arr = raw as List<dynamic>;
+    if (arr.length != 3)
+      throw Exception('unexpected arr length: expect 3 but see ${arr.length}');
+    return SyncReplyDoc(
+      reply: dco_decode_opt_list_prim_u_8_strict(arr[0]),
+      documentChanged: dco_decode_bool(arr[1]),
+      awarenessChanged: dco_decode_bool(arr[2]),
+    );
+  }
+
+  @protected
+  TextPositionDoc dco_decode_text_position_doc(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    final arr = raw as List<dynamic>;
+    if (arr.length != 2)
+      throw Exception('unexpected arr length: expect 2 but see ${arr.length}');
+    return TextPositionDoc(
+      blockId: dco_decode_String(arr[0]),
+      offset: dco_decode_u_32(arr[1]),
+    );
+  }
+
+  @protected
+  int dco_decode_u_32(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return raw as int;
+  }
+
+  @protected
+  BigInt dco_decode_u_64(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return dcoDecodeU64(raw);
+  }
+
+  @protected
+  int dco_decode_u_8(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return raw as int;
+  }
+
+  @protected
+  UndoRedoResult dco_decode_undo_redo_result(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    final arr = raw as List<dynamic>;
+    if (arr.length != 3)
+      throw Exception('unexpected arr length: expect 3 but see ${arr.length}');
+    return UndoRedoResult(
+      changed: dco_decode_bool(arr[0]),
+      update: dco_decode_list_prim_u_8_strict(arr[1]),
+      actions: dco_decode_list_block_action_doc(arr[2]),
+    );
+  }
+
+  @protected
+  void dco_decode_unit(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return;
+  }
+
+  @protected
+  UpdateEncodingDoc dco_decode_update_encoding_doc(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return UpdateEncodingDoc.values[raw as int];
+  }
+
+  @protected
+  UpdateWithId dco_decode_update_with_id(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    final arr = raw as List<dynamic>;
+    if (arr.length != 2)
+      throw Exception('unexpected arr length: expect 2 but see ${arr.length}');
+    return UpdateWithId(
+      id: dco_decode_String(arr[0]),
+      update: dco_decode_list_prim_u_8_strict(arr[1]),
+    );
+  }
+
+  @protected
+  BigInt dco_decode_usize(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return dcoDecodeU64(raw);
+  }
+
+  @protected
+  AnyhowException sse_decode_AnyhowException(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var inner = sse_decode_String(deserializer);
+    return AnyhowException(inner);
+  }
+
+  @protected
+  DocumentService
+  sse_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerDocumentService(
+    SseDeserializer deserializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    return DocumentServiceImpl.frbInternalSseDecode(
+      sse_decode_usize(deserializer),
+      sse_decode_i_32(deserializer),
+    );
+  }
+
+  @protected
+  DocumentService
+  sse_decode_Auto_RefMut_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerDocumentService(
+    SseDeserializer deserializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    return DocumentServiceImpl.frbInternalSseDecode(
+      sse_decode_usize(deserializer),
+      sse_decode_i_32(deserializer),
     );
   }
 
   @protected
-  BlockActionTypeDoc dco_decode_block_action_type_doc(dynamic raw) {
-    // Codec=Dco (DartCObject based), see doc to use other codecs
-    return BlockActionTypeDoc.values[raw as int];
+  DocumentService
+  sse_decode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerDocumentService(
+    SseDeserializer deserializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    return DocumentServiceImpl.frbInternalSseDecode(
+      sse_decode_usize(deserializer),
+      sse_decode_i_32(deserializer),
+    );
   }
 
   @protected
-  BlockDoc dco_decode_block_doc(dynamic raw) {
-    // Codec=Dco (DartCObject based), see doc to use other codecs
-    final arr = raw as List<dynamic>;
-    if (arr.length != 8)
-      throw Exception('unexpected arr length: expect 8 but see ${arr.length}');
-    return BlockDoc(
-      id: dco_decode_String(arr[0]),
-      ty: dco_decode_String(arr[1]),
-      attributes: dco_decode_Map_String_String_None(arr[2]),
-      delta: dco_decode_opt_String(arr[3]),
-      parentId: dco_decode_opt_String(arr[4]),
-      prevId: dco_decode_opt_String(arr[5]),
-      nextId: dco_decode_opt_String(arr[6]),
-      oldParentId: dco_decode_opt_String(arr[7]),
-    );
+  Map<String, String> sse_decode_Map_String_String_None(
+    SseDeserializer deserializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var inner = sse_decode_list_record_string_string(deserializer);
+    return Map.fromEntries(inner.map((e) => MapEntry(e.$1, e.$2)));
   }
 
   @protected
-  CustomRustError dco_decode_custom_rust_error(dynamic raw) {
-    // Codec=Dco (DartCObject based), see doc to use other codecs
-    final arr = raw as List<dynamic>;
-    if (arr.length != 1)
-      throw Exception('unexpected arr length: expect 1 but see ${arr.length}');
-    return CustomRustError(message: dco_decode_String(arr[0]));
+  Map<String, BlockDoc> sse_decode_Map_String_block_doc_None(
+    SseDeserializer deserializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var inner = sse_decode_list_record_string_block_doc(deserializer);
+    return Map.fromEntries(inner.map((e) => MapEntry(e.$1, e.$2)));
   }
 
   @protected
-  DocumentState dco_decode_document_state(dynamic raw) {
-    // Codec=Dco (DartCObject based), see doc to use other codecs
-    final arr = raw as List<dynamic>;
-    if (arr.length != 4)
-      throw Exception('unexpected arr length: expect 4 but see ${arr.length}');
-    return DocumentState(
-      docId: dco_decode_String(arr[0]),
-      blocks: dco_decode_Map_String_block_doc_None(arr[1]),
-      childrenMap: dco_decode_Map_String_list_String_None(arr[2]),
-      rootId: dco_decode_String(arr[3]),
-    );
+  Map<String, List<String>> sse_decode_Map_String_list_String_None(
+    SseDeserializer deserializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var inner = sse_decode_list_record_string_list_string(deserializer);
+    return Map.fromEntries(inner.map((e) => MapEntry(e.$1, e.$2)));
   }
 
   @protected
-  FailedToDecodeUpdates dco_decode_failed_to_decode_updates(dynamic raw) {
-    // Codec=Dco (DartCObject based), see doc to use other codecs
-    final arr = raw as List<dynamic>;
-    if (arr.length != 1)
-      throw Exception('unexpected arr length: expect 1 but see ${arr.length}');
-    return FailedToDecodeUpdates(
-      failedUpdatesIds: dco_decode_list_String(arr[0]),
+  DocumentService
+  sse_decode_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerDocumentService(
+    SseDeserializer deserializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    return DocumentServiceImpl.frbInternalSseDecode(
+      sse_decode_usize(deserializer),
+      sse_decode_i_32(deserializer),
     );
   }
 
   @protected
-  int dco_decode_i_32(dynamic raw) {
-    // Codec=Dco (DartCObject based), see doc to use other codecs
-    return raw as int;
+  RustStreamSink<DocumentChangeEvent>
+  sse_decode_StreamSink_document_change_event_Sse(
+    SseDeserializer deserializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    throw UnimplementedError('Unreachable ()');
   }
 
   @protected
-  List<String> dco_decode_list_String(dynamic raw) {
-    // Codec=Dco (DartCObject based), see doc to use other codecs
-    return (raw as List<dynamic>).map(dco_decode_String).toList();
+  String sse_decode_String(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var inner = sse_decode_list_prim_u_8_strict(deserializer);
+    return utf8.decoder.convert(inner);
   }
 
   @protected
-  List<BlockActionDoc> dco_decode_list_block_action_doc(dynamic raw) {
-    // Codec=Dco (DartCObject based), see doc to use other codecs
-    return (raw as List<dynamic>).map(dco_decode_block_action_doc).toList();
+  AppliedUpdateActions sse_decode_applied_update_actions(
+    SseDeserializer deserializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var var_actions = sse_decode_list_block_action_doc(deserializer);
+    var var_requiresFullReload = sse_decode_bool(deserializer);
+    return AppliedUpdateActions(
+      actions: var_actions,
+      requiresFullReload: var_requiresFullReload,
+    );
   }
 
   @protected
-  List<Uint8List> dco_decode_list_list_prim_u_8_strict(dynamic raw) {
-    // Codec=Dco (DartCObject based), see doc to use other codecs
-    return (raw as List<dynamic>).map(dco_decode_list_prim_u_8_strict).toList();
+  AppliedUpdates sse_decode_applied_updates(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var var_changedBlockIds = sse_decode_list_String(deserializer);
+    var var_deletedBlockIds = sse_decode_list_String(deserializer);
+    var var_rootIdChanged = sse_decode_bool(deserializer);
+    var var_hasPendingUpdates = sse_decode_bool(deserializer);
+    return AppliedUpdates(
+      changedBlockIds: var_changedBlockIds,
+      deletedBlockIds: var_deletedBlockIds,
+      rootIdChanged: var_rootIdChanged,
+      hasPendingUpdates: var_hasPendingUpdates,
+    );
   }
 
   @protected
-  Uint32List dco_decode_list_prim_u_32_strict(dynamic raw) {
-    // Codec=Dco (DartCObject based), see doc to use other codecs
-    return raw as Uint32List;
+  AttributeModeDoc sse_decode_attribute_mode_doc(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var inner = sse_decode_i_32(deserializer);
+    return AttributeModeDoc.values[inner];
   }
 
   @protected
-  Uint8List dco_decode_list_prim_u_8_strict(dynamic raw) {
-    // Codec=Dco (DartCObject based), see doc to use other codecs
-    return raw as Uint8List;
+  AwarenessChangesDoc sse_decode_awareness_changes_doc(
+    SseDeserializer deserializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var var_added = sse_decode_list_prim_u_64_strict(deserializer);
+    var var_updated = sse_decode_list_prim_u_64_strict(deserializer);
+    var var_removed = sse_decode_list_prim_u_64_strict(deserializer);
+    return AwarenessChangesDoc(
+      added: var_added,
+      updated: var_updated,
+      removed: var_removed,
+    );
   }
 
   @protected
-  List<(String, BlockDoc)> dco_decode_list_record_string_block_doc(
-    dynamic raw,
-  ) {
-    // Codec=Dco (DartCObject based), see doc to use other codecs
-    return (raw as List<dynamic>)
-        .map(dco_decode_record_string_block_doc)
-        .toList();
+  AwarenessPeerDoc sse_decode_awareness_peer_doc(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var var_clientId = sse_decode_u_64(deserializer);
+    var var_clock = sse_decode_u_32(deserializer);
+    var var_lastUpdated = sse_decode_u_64(deserializer);
+    var var_isLocal = sse_decode_bool(deserializer);
+    var var_user = sse_decode_opt_box_autoadd_awareness_user_doc(deserializer);
+    var var_selection = sse_decode_opt_box_autoadd_awareness_selection_doc(
+      deserializer,
+    );
+    return AwarenessPeerDoc(
+      clientId: var_clientId,
+      clock: var_clock,
+      lastUpdated: var_lastUpdated,
+      isLocal: var_isLocal,
+      user: var_user,
+      selection: var_selection,
+    );
   }
 
   @protected
-  List<(String, List<String>)> dco_decode_list_record_string_list_string(
-    dynamic raw,
+  AwarenessSelectionDoc sse_decode_awareness_selection_doc(
+    SseDeserializer deserializer,
   ) {
-    // Codec=Dco (DartCObject based), see doc to use other codecs
-    return (raw as List<dynamic>)
-        .map(dco_decode_record_string_list_string)
-        .toList();
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var var_anchor = sse_decode_text_position_doc(deserializer);
+    var var_head = sse_decode_text_position_doc(deserializer);
+    return AwarenessSelectionDoc(anchor: var_anchor, head: var_head);
   }
 
   @protected
-  List<(String, String)> dco_decode_list_record_string_string(dynamic raw) {
-    // Codec=Dco (DartCObject based), see doc to use other codecs
-    return (raw as List<dynamic>).map(dco_decode_record_string_string).toList();
+  AwarenessStateDoc sse_decode_awareness_state_doc(
+    SseDeserializer deserializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var var_user = sse_decode_awareness_user_doc(deserializer);
+    var var_selection = sse_decode_opt_box_autoadd_awareness_selection_doc(
+      deserializer,
+    );
+    return AwarenessStateDoc(user: var_user, selection: var_selection);
   }
 
   @protected
-  String? dco_decode_opt_String(dynamic raw) {
-    // Codec=Dco (DartCObject based), see doc to use other codecs
-    return raw == null ? null : dco_decode_String(raw);
+  AwarenessTimeoutDoc sse_decode_awareness_timeout_doc(
+    SseDeserializer deserializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var var_removed = sse_decode_list_prim_u_64_strict(deserializer);
+    var var_update = sse_decode_opt_list_prim_u_8_strict(deserializer);
+    return AwarenessTimeoutDoc(removed: var_removed, update: var_update);
   }
 
   @protected
-  Uint32List? dco_decode_opt_list_prim_u_32_strict(dynamic raw) {
-    // Codec=Dco (DartCObject based), see doc to use other codecs
-    return raw == null ? null : dco_decode_list_prim_u_32_strict(raw);
+  AwarenessUserDoc sse_decode_awareness_user_doc(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var var_id = sse_decode_String(deserializer);
+    var var_name = sse_decode_String(deserializer);
+    var var_color = sse_decode_opt_String(deserializer);
+    return AwarenessUserDoc(id: var_id, name: var_name, color: var_color);
   }
 
   @protected
-  (String, BlockDoc) dco_decode_record_string_block_doc(dynamic raw) {
-    // Codec=Dco (DartCObject based), see doc to use other codecs
-    final arr = raw as List<dynamic>;
-    if (arr.length != 2) {
-      throw Exception('Expected 2 elements, got ${arr.length}');
-    }
-    return (dco_decode_String(arr[0]), dco_decode_block_doc(arr[1]));
+  BlockActionDoc sse_decode_block_action_doc(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var var_action = sse_decode_block_action_type_doc(deserializer);
+    var var_block = sse_decode_block_doc(deserializer);
+    var var_path = sse_decode_list_prim_u_32_strict(deserializer);
+    var var_oldPath = sse_decode_opt_list_prim_u_32_strict(deserializer);
+    return BlockActionDoc(
+      action: var_action,
+      block: var_block,
+      path: var_path,
+      oldPath: var_oldPath,
+    );
   }
 
   @protected
-  (String, List<String>) dco_decode_record_string_list_string(dynamic raw) {
-    // Codec=Dco (DartCObject based), see doc to use other codecs
-    final arr = raw as List<dynamic>;
-    if (arr.length != 2) {
-      throw Exception('Expected 2 elements, got ${arr.length}');
-    }
-    return (dco_decode_String(arr[0]), dco_decode_list_String(arr[1]));
+  BlockActionTypeDoc sse_decode_block_action_type_doc(
+    SseDeserializer deserializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var inner = sse_decode_i_32(deserializer);
+    return BlockActionTypeDoc.values[inner];
   }
 
   @protected
-  (String, String) dco_decode_record_string_string(dynamic raw) {
-    // Codec=Dco (DartCObject based), see doc to use other codecs
-    final arr = raw as List<dynamic>;
-    if (arr.length != 2) {
-      throw Exception('Expected 2 elements, got ${arr.length}');
-    }
-    return (dco_decode_String(arr[0]), dco_decode_String(arr[1]));
+  BlockDoc sse_decode_block_doc(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var var_id = sse_decode_String(deserializer);
+    var var_ty = sse_decode_String(deserializer);
+    var var_attributes = sse_decode_Map_String_String_None(deserializer);
+    var var_delta = sse_decode_opt_String(deserializer);
+    var var_parentId = sse_decode_opt_String(deserializer);
+    var var_prevId = sse_decode_opt_String(deserializer);
+    var var_nextId = sse_decode_opt_String(deserializer);
+    var var_oldParentId = sse_decode_opt_String(deserializer);
+    return BlockDoc(
+      id: var_id,
+      ty: var_ty,
+      attributes: var_attributes,
+      delta: var_delta,
+      parentId: var_parentId,
+      prevId: var_prevId,
+      nextId: var_nextId,
+      oldParentId: var_oldParentId,
+    );
   }
 
   @protected
-  int dco_decode_u_32(dynamic raw) {
-    // Codec=Dco (DartCObject based), see doc to use other codecs
-    return raw as int;
+  bool sse_decode_bool(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    return deserializer.buffer.getUint8() != 0;
   }
 
   @protected
-  int dco_decode_u_8(dynamic raw) {
-    // Codec=Dco (DartCObject based), see doc to use other codecs
-    return raw as int;
+  AwarenessSelectionDoc sse_decode_box_autoadd_awareness_selection_doc(
+    SseDeserializer deserializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    return (sse_decode_awareness_selection_doc(deserializer));
   }
 
   @protected
-  void dco_decode_unit(dynamic raw) {
-    // Codec=Dco (DartCObject based), see doc to use other codecs
-    return;
+  AwarenessStateDoc sse_decode_box_autoadd_awareness_state_doc(
+    SseDeserializer deserializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    return (sse_decode_awareness_state_doc(deserializer));
   }
 
   @protected
-  BigInt dco_decode_usize(dynamic raw) {
-    // Codec=Dco (DartCObject based), see doc to use other codecs
-    return dcoDecodeU64(raw);
+  AwarenessUserDoc sse_decode_box_autoadd_awareness_user_doc(
+    SseDeserializer deserializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    return (sse_decode_awareness_user_doc(deserializer));
   }
 
   @protected
-  DocumentService
-  sse_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerDocumentService(
+  DocumentOptions sse_decode_box_autoadd_document_options(
     SseDeserializer deserializer,
   ) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    return DocumentServiceImpl.frbInternalSseDecode(
-      sse_decode_usize(deserializer),
-      sse_decode_i_32(deserializer),
-    );
+    return (sse_decode_document_options(deserializer));
   }
 
   @protected
-  DocumentService
-  sse_decode_Auto_RefMut_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerDocumentService(
+  SyncMessageDoc sse_decode_box_autoadd_sync_message_doc(
     SseDeserializer deserializer,
   ) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    return DocumentServiceImpl.frbInternalSseDecode(
-      sse_decode_usize(deserializer),
-      sse_decode_i_32(deserializer),
-    );
+    return (sse_decode_sync_message_doc(deserializer));
   }
 
   @protected
-  DocumentService
-  sse_decode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerDocumentService(
+  TextPositionDoc sse_decode_box_autoadd_text_position_doc(
     SseDeserializer deserializer,
   ) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    return DocumentServiceImpl.frbInternalSseDecode(
-      sse_decode_usize(deserializer),
-      sse_decode_i_32(deserializer),
-    );
+    return (sse_decode_text_position_doc(deserializer));
+  }
+
+  @protected
+  int sse_decode_box_autoadd_u_32(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    return (sse_decode_u_32(deserializer));
   }
 
   @protected
-  Map<String, String> sse_decode_Map_String_String_None(
-    SseDeserializer deserializer,
-  ) {
+  BigInt sse_decode_box_autoadd_u_64(SseDeserializer deserializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    var inner = sse_decode_list_record_string_string(deserializer);
-    return Map.fromEntries(inner.map((e) => MapEntry(e.$1, e.$2)));
+    return (sse_decode_u_64(deserializer));
   }
 
   @protected
-  Map<String, BlockDoc> sse_decode_Map_String_block_doc_None(
+  UpdateEncodingDoc sse_decode_box_autoadd_update_encoding_doc(
     SseDeserializer deserializer,
   ) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    var inner = sse_decode_list_record_string_block_doc(deserializer);
-    return Map.fromEntries(inner.map((e) => MapEntry(e.$1, e.$2)));
+    return (sse_decode_update_encoding_doc(deserializer));
   }
 
   @protected
-  Map<String, List<String>> sse_decode_Map_String_list_String_None(
+  ChainRepairResult sse_decode_chain_repair_result(
     SseDeserializer deserializer,
   ) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    var inner = sse_decode_list_record_string_list_string(deserializer);
-    return Map.fromEntries(inner.map((e) => MapEntry(e.$1, e.$2)));
+    var var_cycleBlockIds = sse_decode_list_String(deserializer);
+    var var_danglingBlockIds = sse_decode_list_String(deserializer);
+    var var_update = sse_decode_list_prim_u_8_strict(deserializer);
+    return ChainRepairResult(
+      cycleBlockIds: var_cycleBlockIds,
+      danglingBlockIds: var_danglingBlockIds,
+      update: var_update,
+    );
   }
 
   @protected
-  DocumentService
-  sse_decode_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerDocumentService(
-    SseDeserializer deserializer,
-  ) {
+  CompactedUpdate sse_decode_compacted_update(SseDeserializer deserializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    return DocumentServiceImpl.frbInternalSseDecode(
-      sse_decode_usize(deserializer),
-      sse_decode_i_32(deserializer),
+    var var_update = sse_decode_list_prim_u_8_strict(deserializer);
+    var var_stateVector = sse_decode_list_prim_u_8_strict(deserializer);
+    var var_hasPendingUpdates = sse_decode_bool(deserializer);
+    return CompactedUpdate(
+      update: var_update,
+      stateVector: var_stateVector,
+      hasPendingUpdates: var_hasPendingUpdates,
     );
   }
 
   @protected
-  String sse_decode_String(SseDeserializer deserializer) {
+  CreatedSnapshot sse_decode_created_snapshot(SseDeserializer deserializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    var inner = sse_decode_list_prim_u_8_strict(deserializer);
-    return utf8.decoder.convert(inner);
+    var var_snapshot = sse_decode_snapshot_info(deserializer);
+    var var_data = sse_decode_list_prim_u_8_strict(deserializer);
+    return CreatedSnapshot(snapshot: var_snapshot, data: var_data);
   }
 
   @protected
-  BlockActionDoc sse_decode_block_action_doc(SseDeserializer deserializer) {
+  CustomRustError sse_decode_custom_rust_error(SseDeserializer deserializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    var var_action = sse_decode_block_action_type_doc(deserializer);
-    var var_block = sse_decode_block_doc(deserializer);
-    var var_path = sse_decode_list_prim_u_32_strict(deserializer);
-    var var_oldPath = sse_decode_opt_list_prim_u_32_strict(deserializer);
-    return BlockActionDoc(
-      action: var_action,
-      block: var_block,
-      path: var_path,
-      oldPath: var_oldPath,
-    );
+    var var_message = sse_decode_String(deserializer);
+    var var_actionIndex = sse_decode_opt_box_autoadd_u_32(deserializer);
+    return CustomRustError(message: var_message, actionIndex: var_actionIndex);
   }
 
   @protected
-  BlockActionTypeDoc sse_decode_block_action_type_doc(
+  DocumentChangeEvent sse_decode_document_change_event(
     SseDeserializer deserializer,
   ) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    var inner = sse_decode_i_32(deserializer);
-    return BlockActionTypeDoc.values[inner];
+    var var_kind = sse_decode_document_change_kind_doc(deserializer);
+    var var_blockId = sse_decode_String(deserializer);
+    var var_delta = sse_decode_opt_String(deserializer);
+    var var_origin = sse_decode_opt_String(deserializer);
+    return DocumentChangeEvent(
+      kind: var_kind,
+      blockId: var_blockId,
+      delta: var_delta,
+      origin: var_origin,
+    );
   }
 
   @protected
-  BlockDoc sse_decode_block_doc(SseDeserializer deserializer) {
+  DocumentChangeKindDoc sse_decode_document_change_kind_doc(
+    SseDeserializer deserializer,
+  ) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    var var_id = sse_decode_String(deserializer);
-    var var_ty = sse_decode_String(deserializer);
-    var var_attributes = sse_decode_Map_String_String_None(deserializer);
-    var var_delta = sse_decode_opt_String(deserializer);
-    var var_parentId = sse_decode_opt_String(deserializer);
-    var var_prevId = sse_decode_opt_String(deserializer);
-    var var_nextId = sse_decode_opt_String(deserializer);
-    var var_oldParentId = sse_decode_opt_String(deserializer);
-    return BlockDoc(
-      id: var_id,
-      ty: var_ty,
-      attributes: var_attributes,
-      delta: var_delta,
-      parentId: var_parentId,
-      prevId: var_prevId,
-      nextId: var_nextId,
-      oldParentId: var_oldParentId,
-    );
+    var inner = sse_decode_i_32(deserializer);
+    return DocumentChangeKindDoc.values[inner];
   }
 
   @protected
-  CustomRustError sse_decode_custom_rust_error(SseDeserializer deserializer) {
+  DocumentOptions sse_decode_document_options(SseDeserializer deserializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    var var_message = sse_decode_String(deserializer);
-    return CustomRustError(message: var_message);
+    var var_clientId = sse_decode_opt_box_autoadd_u_64(deserializer);
+    var var_offsetKind = sse_decode_offset_kind_doc(deserializer);
+    var var_skipGc = sse_decode_bool(deserializer);
+    var var_updateEncoding = sse_decode_update_encoding_doc(deserializer);
+    var var_attributeMode = sse_decode_attribute_mode_doc(deserializer);
+    return DocumentOptions(
+      clientId: var_clientId,
+      offsetKind: var_offsetKind,
+      skipGc: var_skipGc,
+      updateEncoding: var_updateEncoding,
+      attributeMode: var_attributeMode,
+    );
   }
 
   @protected
@@ -867,6 +3860,39 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
     return deserializer.buffer.getInt32();
   }
 
+  @protected
+  PlatformInt64 sse_decode_i_64(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    return deserializer.buffer.getPlatformInt64();
+  }
+
+  @protected
+  IntegrityIssueDoc sse_decode_integrity_issue_doc(
+    SseDeserializer deserializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var var_kind = sse_decode_integrity_issue_kind_doc(deserializer);
+    var var_blockIds = sse_decode_list_String(deserializer);
+    return IntegrityIssueDoc(kind: var_kind, blockIds: var_blockIds);
+  }
+
+  @protected
+  IntegrityIssueKindDoc sse_decode_integrity_issue_kind_doc(
+    SseDeserializer deserializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var inner = sse_decode_i_32(deserializer);
+    return IntegrityIssueKindDoc.values[inner];
+  }
+
+  @protected
+  IntegrityReport sse_decode_integrity_report(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var var_issues = sse_decode_list_integrity_issue_doc(deserializer);
+    var var_update = sse_decode_list_prim_u_8_strict(deserializer);
+    return IntegrityReport(issues: var_issues, update: var_update);
+  }
+
   @protected
   List<String> sse_decode_list_String(SseDeserializer deserializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
@@ -879,6 +3905,20 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
     return ans_;
   }
 
+  @protected
+  List<AwarenessPeerDoc> sse_decode_list_awareness_peer_doc(
+    SseDeserializer deserializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+
+    var len_ = sse_decode_i_32(deserializer);
+    var ans_ = <AwarenessPeerDoc>[];
+    for (var idx_ = 0; idx_ < len_; ++idx_) {
+      ans_.add(sse_decode_awareness_peer_doc(deserializer));
+    }
+    return ans_;
+  }
+
   @protected
   List<BlockActionDoc> sse_decode_list_block_action_doc(
     SseDeserializer deserializer,
@@ -893,6 +3933,20 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
     return ans_;
   }
 
+  @protected
+  List<IntegrityIssueDoc> sse_decode_list_integrity_issue_doc(
+    SseDeserializer deserializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+
+    var len_ = sse_decode_i_32(deserializer);
+    var ans_ = <IntegrityIssueDoc>[];
+    for (var idx_ = 0; idx_ < len_; ++idx_) {
+      ans_.add(sse_decode_integrity_issue_doc(deserializer));
+    }
+    return ans_;
+  }
+
   @protected
   List<Uint8List> sse_decode_list_list_prim_u_8_strict(
     SseDeserializer deserializer,
@@ -914,6 +3968,20 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
     return deserializer.buffer.getUint32List(len_);
   }
 
+  @protected
+  Uint64List sse_decode_list_prim_u_64_strict(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var len_ = sse_decode_i_32(deserializer);
+    return deserializer.buffer.getUint64List(len_);
+  }
+
+  @protected
+  List<int> sse_decode_list_prim_u_8_loose(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var len_ = sse_decode_i_32(deserializer);
+    return deserializer.buffer.getUint8List(len_);
+  }
+
   @protected
   Uint8List sse_decode_list_prim_u_8_strict(SseDeserializer deserializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
@@ -963,6 +4031,41 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
     return ans_;
   }
 
+  @protected
+  List<SyncMessageDoc> sse_decode_list_sync_message_doc(
+    SseDeserializer deserializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+
+    var len_ = sse_decode_i_32(deserializer);
+    var ans_ = <SyncMessageDoc>[];
+    for (var idx_ = 0; idx_ < len_; ++idx_) {
+      ans_.add(sse_decode_sync_message_doc(deserializer));
+    }
+    return ans_;
+  }
+
+  @protected
+  List<UpdateWithId> sse_decode_list_update_with_id(
+    SseDeserializer deserializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+
+    var len_ = sse_decode_i_32(deserializer);
+    var ans_ = <UpdateWithId>[];
+    for (var idx_ = 0; idx_ < len_; ++idx_) {
+      ans_.add(sse_decode_update_with_id(deserializer));
+    }
+    return ans_;
+  }
+
+  @protected
+  OffsetKindDoc sse_decode_offset_kind_doc(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var inner = sse_decode_i_32(deserializer);
+    return OffsetKindDoc.values[inner];
+  }
+
   @protected
   String? sse_decode_opt_String(SseDeserializer deserializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
@@ -974,6 +4077,80 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
     }
   }
 
+  @protected
+  AwarenessSelectionDoc? sse_decode_opt_box_autoadd_awareness_selection_doc(
+    SseDeserializer deserializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+
+    if (sse_decode_bool(deserializer)) {
+      return (sse_decode_box_autoadd_awareness_selection_doc(deserializer));
+    } else {
+      return null;
+    }
+  }
+
+  @protected
+  AwarenessUserDoc? sse_decode_opt_box_autoadd_awareness_user_doc(
+    SseDeserializer deserializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+
+    if (sse_decode_bool(deserializer)) {
+      return (sse_decode_box_autoadd_awareness_user_doc(deserializer));
+    } else {
+      return null;
+    }
+  }
+
+  @protected
+  TextPositionDoc? sse_decode_opt_box_autoadd_text_position_doc(
+    SseDeserializer deserializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+
+    if (sse_decode_bool(deserializer)) {
+      return (sse_decode_box_autoadd_text_position_doc(deserializer));
+    } else {
+      return null;
+    }
+  }
+
+  @protected
+  int? sse_decode_opt_box_autoadd_u_32(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+
+    if (sse_decode_bool(deserializer)) {
+      return (sse_decode_box_autoadd_u_32(deserializer));
+    } else {
+      return null;
+    }
+  }
+
+  @protected
+  BigInt? sse_decode_opt_box_autoadd_u_64(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+
+    if (sse_decode_bool(deserializer)) {
+      return (sse_decode_box_autoadd_u_64(deserializer));
+    } else {
+      return null;
+    }
+  }
+
+  @protected
+  UpdateEncodingDoc? sse_decode_opt_box_autoadd_update_encoding_doc(
+    SseDeserializer deserializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+
+    if (sse_decode_bool(deserializer)) {
+      return (sse_decode_box_autoadd_update_encoding_doc(deserializer));
+    } else {
+      return null;
+    }
+  }
+
   @protected
   Uint32List? sse_decode_opt_list_prim_u_32_strict(
     SseDeserializer deserializer,
@@ -987,6 +4164,17 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
     }
   }
 
+  @protected
+  Uint8List? sse_decode_opt_list_prim_u_8_strict(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+
+    if (sse_decode_bool(deserializer)) {
+      return (sse_decode_list_prim_u_8_strict(deserializer));
+    } else {
+      return null;
+    }
+  }
+
   @protected
   (String, BlockDoc) sse_decode_record_string_block_doc(
     SseDeserializer deserializer,
@@ -1017,23 +4205,122 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
     return (var_field0, var_field1);
   }
 
+  @protected
+  SiblingOrderingDoc sse_decode_sibling_ordering_doc(
+    SseDeserializer deserializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var inner = sse_decode_i_32(deserializer);
+    return SiblingOrderingDoc.values[inner];
+  }
+
+  @protected
+  SnapshotInfo sse_decode_snapshot_info(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var var_id = sse_decode_String(deserializer);
+    var var_label = sse_decode_String(deserializer);
+    var var_createdAt = sse_decode_i_64(deserializer);
+    return SnapshotInfo(id: var_id, label: var_label, createdAt: var_createdAt);
+  }
+
+  @protected
+  StickyAssocDoc sse_decode_sticky_assoc_doc(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var inner = sse_decode_i_32(deserializer);
+    return StickyAssocDoc.values[inner];
+  }
+
+  @protected
+  SyncMessageDoc sse_decode_sync_message_doc(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var var_kind = sse_decode_sync_message_kind_doc(deserializer);
+    var var_payload = sse_decode_list_prim_u_8_strict(deserializer);
+    return SyncMessageDoc(kind: var_kind, payload: var_payload);
+  }
+
+  @protected
+  SyncMessageKindDoc sse_decode_sync_message_kind_doc(
+    SseDeserializer deserializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var inner = sse_decode_i_32(deserializer);
+    return SyncMessageKindDoc.values[inner];
+  }
+
+  @protected
+  SyncReplyDoc sse_decode_sync_reply_doc(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var var_reply = sse_decode_opt_list_prim_u_8_strict(deserializer);
+    var var_documentChanged = sse_decode_bool(deserializer);
+    var var_awarenessChanged = sse_decode_bool(deserializer);
+    return SyncReplyDoc(
+      reply: var_reply,
+      documentChanged: var_documentChanged,
+      awarenessChanged: var_awarenessChanged,
+    );
+  }
+
+  @protected
+  TextPositionDoc sse_decode_text_position_doc(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var var_blockId = sse_decode_String(deserializer);
+    var var_offset = sse_decode_u_32(deserializer);
+    return TextPositionDoc(blockId: var_blockId, offset: var_offset);
+  }
+
   @protected
   int sse_decode_u_32(SseDeserializer deserializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
     return deserializer.buffer.getUint32();
   }
 
+  @protected
+  BigInt sse_decode_u_64(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    return deserializer.buffer.getBigUint64();
+  }
+
   @protected
   int sse_decode_u_8(SseDeserializer deserializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
     return deserializer.buffer.getUint8();
   }
 
+  @protected
+  UndoRedoResult sse_decode_undo_redo_result(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var var_changed = sse_decode_bool(deserializer);
+    var var_update = sse_decode_list_prim_u_8_strict(deserializer);
+    var var_actions = sse_decode_list_block_action_doc(deserializer);
+    return UndoRedoResult(
+      changed: var_changed,
+      update: var_update,
+      actions: var_actions,
+    );
+  }
+
   @protected
   void sse_decode_unit(SseDeserializer deserializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
   }
 
+  @protected
+  UpdateEncodingDoc sse_decode_update_encoding_doc(
+    SseDeserializer deserializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var inner = sse_decode_i_32(deserializer);
+    return UpdateEncodingDoc.values[inner];
+  }
+
+  @protected
+  UpdateWithId sse_decode_update_with_id(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var var_id = sse_decode_String(deserializer);
+    var var_update = sse_decode_list_prim_u_8_strict(deserializer);
+    return UpdateWithId(id: var_id, update: var_update);
+  }
+
   @protected
   BigInt sse_decode_usize(SseDeserializer deserializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
@@ -1041,9 +4328,12 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
   }
 
   @protected
-  bool sse_decode_bool(SseDeserializer deserializer) {
+  void sse_encode_AnyhowException(
+    AnyhowException self,
+    SseSerializer serializer,
+  ) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    return deserializer.buffer.getUint8() != 0;
+    sse_encode_String(self.message, serializer);
   }
 
   @protected
@@ -1134,12 +4424,132 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
     );
   }
 
+  @protected
+  void sse_encode_StreamSink_document_change_event_Sse(
+    RustStreamSink<DocumentChangeEvent> self,
+    SseSerializer serializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_String(
+      self.setupAndSerialize(
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_document_change_event,
+          decodeErrorData: sse_decode_AnyhowException,
+        ),
+      ),
+      serializer,
+    );
+  }
+
   @protected
   void sse_encode_String(String self, SseSerializer serializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
     sse_encode_list_prim_u_8_strict(utf8.encoder.convert(self), serializer);
   }
 
+  @protected
+  void sse_encode_applied_update_actions(
+    AppliedUpdateActions self,
+    SseSerializer serializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_list_block_action_doc(self.actions, serializer);
+    sse_encode_bool(self.requiresFullReload, serializer);
+  }
+
+  @protected
+  void sse_encode_applied_updates(
+    AppliedUpdates self,
+    SseSerializer serializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_list_String(self.changedBlockIds, serializer);
+    sse_encode_list_String(self.deletedBlockIds, serializer);
+    sse_encode_bool(self.rootIdChanged, serializer);
+    sse_encode_bool(self.hasPendingUpdates, serializer);
+  }
+
+  @protected
+  void sse_encode_attribute_mode_doc(
+    AttributeModeDoc self,
+    SseSerializer serializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_i_32(self.index, serializer);
+  }
+
+  @protected
+  void sse_encode_awareness_changes_doc(
+    AwarenessChangesDoc self,
+    SseSerializer serializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_list_prim_u_64_strict(self.added, serializer);
+    sse_encode_list_prim_u_64_strict(self.updated, serializer);
+    sse_encode_list_prim_u_64_strict(self.removed, serializer);
+  }
+
+  @protected
+  void sse_encode_awareness_peer_doc(
+    AwarenessPeerDoc self,
+    SseSerializer serializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_u_64(self.clientId, serializer);
+    sse_encode_u_32(self.clock, serializer);
+    sse_encode_u_64(self.lastUpdated, serializer);
+    sse_encode_bool(self.isLocal, serializer);
+    sse_encode_opt_box_autoadd_awareness_user_doc(self.user, serializer);
+    sse_encode_opt_box_autoadd_awareness_selection_doc(
+      self.selection,
+      serializer,
+    );
+  }
+
+  @protected
+  void sse_encode_awareness_selection_doc(
+    AwarenessSelectionDoc self,
+    SseSerializer serializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_text_position_doc(self.anchor, serializer);
+    sse_encode_text_position_doc(self.head, serializer);
+  }
+
+  @protected
+  void sse_encode_awareness_state_doc(
+    AwarenessStateDoc self,
+    SseSerializer serializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_awareness_user_doc(self.user, serializer);
+    sse_encode_opt_box_autoadd_awareness_selection_doc(
+      self.selection,
+      serializer,
+    );
+  }
+
+  @protected
+  void sse_encode_awareness_timeout_doc(
+    AwarenessTimeoutDoc self,
+    SseSerializer serializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_list_prim_u_64_strict(self.removed, serializer);
+    sse_encode_opt_list_prim_u_8_strict(self.update, serializer);
+  }
+
+  @protected
+  void sse_encode_awareness_user_doc(
+    AwarenessUserDoc self,
+    SseSerializer serializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_String(self.id, serializer);
+    sse_encode_String(self.name, serializer);
+    sse_encode_opt_String(self.color, serializer);
+  }
+
   @protected
   void sse_encode_block_action_doc(
     BlockActionDoc self,
@@ -1174,6 +4584,119 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
     sse_encode_opt_String(self.oldParentId, serializer);
   }
 
+  @protected
+  void sse_encode_bool(bool self, SseSerializer serializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    serializer.buffer.putUint8(self ? 1 : 0);
+  }
+
+  @protected
+  void sse_encode_box_autoadd_awareness_selection_doc(
+    AwarenessSelectionDoc self,
+    SseSerializer serializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_awareness_selection_doc(self, serializer);
+  }
+
+  @protected
+  void sse_encode_box_autoadd_awareness_state_doc(
+    AwarenessStateDoc self,
+    SseSerializer serializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_awareness_state_doc(self, serializer);
+  }
+
+  @protected
+  void sse_encode_box_autoadd_awareness_user_doc(
+    AwarenessUserDoc self,
+    SseSerializer serializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_awareness_user_doc(self, serializer);
+  }
+
+  @protected
+  void sse_encode_box_autoadd_document_options(
+    DocumentOptions self,
+    SseSerializer serializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_document_options(self, serializer);
+  }
+
+  @protected
+  void sse_encode_box_autoadd_sync_message_doc(
+    SyncMessageDoc self,
+    SseSerializer serializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_sync_message_doc(self, serializer);
+  }
+
+  @protected
+  void sse_encode_box_autoadd_text_position_doc(
+    TextPositionDoc self,
+    SseSerializer serializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_text_position_doc(self, serializer);
+  }
+
+  @protected
+  void sse_encode_box_autoadd_u_32(int self, SseSerializer serializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_u_32(self, serializer);
+  }
+
+  @protected
+  void sse_encode_box_autoadd_u_64(BigInt self, SseSerializer serializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_u_64(self, serializer);
+  }
+
+  @protected
+  void sse_encode_box_autoadd_update_encoding_doc(
+    UpdateEncodingDoc self,
+    SseSerializer serializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_update_encoding_doc(self, serializer);
+  }
+
+  @protected
+  void sse_encode_chain_repair_result(
+    ChainRepairResult self,
+    SseSerializer serializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_list_String(self.cycleBlockIds, serializer);
+    sse_encode_list_String(self.danglingBlockIds, serializer);
+    sse_encode_list_prim_u_8_strict(self.update, serializer);
+  }
+
+  @protected
+  void sse_encode_compacted_update(
+    CompactedUpdate self,
+    SseSerializer serializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_list_prim_u_8_strict(self.update, serializer);
+    sse_encode_list_prim_u_8_strict(self.stateVector, serializer);
+    sse_encode_bool(self.hasPendingUpdates, serializer);
+  }
+
+  @protected
+  void sse_encode_created_snapshot(
+    CreatedSnapshot self,
+    SseSerializer serializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_snapshot_info(self.snapshot, serializer);
+    sse_encode_list_prim_u_8_strict(self.data, serializer);
+  }
+
   @protected
   void sse_encode_custom_rust_error(
     CustomRustError self,
@@ -1181,6 +4704,41 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
   ) {
     // Codec=Sse (Serialization based), see doc to use other codecs
     sse_encode_String(self.message, serializer);
+    sse_encode_opt_box_autoadd_u_32(self.actionIndex, serializer);
+  }
+
+  @protected
+  void sse_encode_document_change_event(
+    DocumentChangeEvent self,
+    SseSerializer serializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_document_change_kind_doc(self.kind, serializer);
+    sse_encode_String(self.blockId, serializer);
+    sse_encode_opt_String(self.delta, serializer);
+    sse_encode_opt_String(self.origin, serializer);
+  }
+
+  @protected
+  void sse_encode_document_change_kind_doc(
+    DocumentChangeKindDoc self,
+    SseSerializer serializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_i_32(self.index, serializer);
+  }
+
+  @protected
+  void sse_encode_document_options(
+    DocumentOptions self,
+    SseSerializer serializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_opt_box_autoadd_u_64(self.clientId, serializer);
+    sse_encode_offset_kind_doc(self.offsetKind, serializer);
+    sse_encode_bool(self.skipGc, serializer);
+    sse_encode_update_encoding_doc(self.updateEncoding, serializer);
+    sse_encode_attribute_mode_doc(self.attributeMode, serializer);
   }
 
   @protected
@@ -1207,6 +4765,41 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
     serializer.buffer.putInt32(self);
   }
 
+  @protected
+  void sse_encode_i_64(PlatformInt64 self, SseSerializer serializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    serializer.buffer.putPlatformInt64(self);
+  }
+
+  @protected
+  void sse_encode_integrity_issue_doc(
+    IntegrityIssueDoc self,
+    SseSerializer serializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_integrity_issue_kind_doc(self.kind, serializer);
+    sse_encode_list_String(self.blockIds, serializer);
+  }
+
+  @protected
+  void sse_encode_integrity_issue_kind_doc(
+    IntegrityIssueKindDoc self,
+    SseSerializer serializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_i_32(self.index, serializer);
+  }
+
+  @protected
+  void sse_encode_integrity_report(
+    IntegrityReport self,
+    SseSerializer serializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_list_integrity_issue_doc(self.issues, serializer);
+    sse_encode_list_prim_u_8_strict(self.update, serializer);
+  }
+
   @protected
   void sse_encode_list_String(List<String> self, SseSerializer serializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
@@ -1216,6 +4809,18 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
     }
   }
 
+  @protected
+  void sse_encode_list_awareness_peer_doc(
+    List<AwarenessPeerDoc> self,
+    SseSerializer serializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_i_32(self.length, serializer);
+    for (final item in self) {
+      sse_encode_awareness_peer_doc(item, serializer);
+    }
+  }
+
   @protected
   void sse_encode_list_block_action_doc(
     List<BlockActionDoc> self,
@@ -1223,31 +4828,65 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
   ) {
     // Codec=Sse (Serialization based), see doc to use other codecs
     sse_encode_i_32(self.length, serializer);
-    for (final item in self) {
-      sse_encode_block_action_doc(item, serializer);
-    }
+    for (final item in self) {
+      sse_encode_block_action_doc(item, serializer);
+    }
+  }
+
+  @protected
+  void sse_encode_list_integrity_issue_doc(
+    List<IntegrityIssueDoc> self,
+    SseSerializer serializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_i_32(self.length, serializer);
+    for (final item in self) {
+      sse_encode_integrity_issue_doc(item, serializer);
+    }
+  }
+
+  @protected
+  void sse_encode_list_list_prim_u_8_strict(
+    List<Uint8List> self,
+    SseSerializer serializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_i_32(self.length, serializer);
+    for (final item in self) {
+      sse_encode_list_prim_u_8_strict(item, serializer);
+    }
+  }
+
+  @protected
+  void sse_encode_list_prim_u_32_strict(
+    Uint32List self,
+    SseSerializer serializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_i_32(self.length, serializer);
+    serializer.buffer.putUint32List(self);
   }
 
   @protected
-  void sse_encode_list_list_prim_u_8_strict(
-    List<Uint8List> self,
+  void sse_encode_list_prim_u_64_strict(
+    Uint64List self,
     SseSerializer serializer,
   ) {
     // Codec=Sse (Serialization based), see doc to use other codecs
     sse_encode_i_32(self.length, serializer);
-    for (final item in self) {
-      sse_encode_list_prim_u_8_strict(item, serializer);
-    }
+    serializer.buffer.putUint64List(self);
   }
 
   @protected
-  void sse_encode_list_prim_u_32_strict(
-    Uint32List self,
+  void sse_encode_list_prim_u_8_loose(
+    List<int> self,
     SseSerializer serializer,
   ) {
     // Codec=Sse (Serialization based), see doc to use other codecs
     sse_encode_i_32(self.length, serializer);
-    serializer.buffer.putUint32List(self);
+    serializer.buffer.putUint8List(
+      self is Uint8List ? self : Uint8List.fromList(self),
+    );
   }
 
   @protected
@@ -1296,6 +4935,39 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
     }
   }
 
+  @protected
+  void sse_encode_list_sync_message_doc(
+    List<SyncMessageDoc> self,
+    SseSerializer serializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_i_32(self.length, serializer);
+    for (final item in self) {
+      sse_encode_sync_message_doc(item, serializer);
+    }
+  }
+
+  @protected
+  void sse_encode_list_update_with_id(
+    List<UpdateWithId> self,
+    SseSerializer serializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_i_32(self.length, serializer);
+    for (final item in self) {
+      sse_encode_update_with_id(item, serializer);
+    }
+  }
+
+  @protected
+  void sse_encode_offset_kind_doc(
+    OffsetKindDoc self,
+    SseSerializer serializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_i_32(self.index, serializer);
+  }
+
   @protected
   void sse_encode_opt_String(String? self, SseSerializer serializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
@@ -1306,6 +4978,78 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
     }
   }
 
+  @protected
+  void sse_encode_opt_box_autoadd_awareness_selection_doc(
+    AwarenessSelectionDoc? self,
+    SseSerializer serializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+
+    sse_encode_bool(self != null, serializer);
+    if (self != null) {
+      sse_encode_box_autoadd_awareness_selection_doc(self, serializer);
+    }
+  }
+
+  @protected
+  void sse_encode_opt_box_autoadd_awareness_user_doc(
+    AwarenessUserDoc? self,
+    SseSerializer serializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+
+    sse_encode_bool(self != null, serializer);
+    if (self != null) {
+      sse_encode_box_autoadd_awareness_user_doc(self, serializer);
+    }
+  }
+
+  @protected
+  void sse_encode_opt_box_autoadd_text_position_doc(
+    TextPositionDoc? self,
+    SseSerializer serializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+
+    sse_encode_bool(self != null, serializer);
+    if (self != null) {
+      sse_encode_box_autoadd_text_position_doc(self, serializer);
+    }
+  }
+
+  @protected
+  void sse_encode_opt_box_autoadd_u_32(int? self, SseSerializer serializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+
+    sse_encode_bool(self != null, serializer);
+    if (self != null) {
+      sse_encode_box_autoadd_u_32(self, serializer);
+    }
+  }
+
+  @protected
+  void sse_encode_opt_box_autoadd_u_64(BigInt? self, SseSerializer serializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+
+    sse_encode_bool(self != null, serializer);
+    if (self != null) {
+      sse_encode_box_autoadd_u_64(self, serializer);
+    }
+  }
+
+  @protected
+  void sse_encode_opt_box_autoadd_update_encoding_doc(
+    UpdateEncodingDoc? self,
+    SseSerializer serializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+
+    sse_encode_bool(self != null, serializer);
+    if (self != null) {
+      sse_encode_box_autoadd_update_encoding_doc(self, serializer);
+    }
+  }
+
   @protected
   void sse_encode_opt_list_prim_u_32_strict(
     Uint32List? self,
@@ -1319,6 +5063,19 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
     }
   }
 
+  @protected
+  void sse_encode_opt_list_prim_u_8_strict(
+    Uint8List? self,
+    SseSerializer serializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+
+    sse_encode_bool(self != null, serializer);
+    if (self != null) {
+      sse_encode_list_prim_u_8_strict(self, serializer);
+    }
+  }
+
   @protected
   void sse_encode_record_string_block_doc(
     (String, BlockDoc) self,
@@ -1349,33 +5106,123 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
     sse_encode_String(self.$2, serializer);
   }
 
+  @protected
+  void sse_encode_sibling_ordering_doc(
+    SiblingOrderingDoc self,
+    SseSerializer serializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_i_32(self.index, serializer);
+  }
+
+  @protected
+  void sse_encode_snapshot_info(SnapshotInfo self, SseSerializer serializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_String(self.id, serializer);
+    sse_encode_String(self.label, serializer);
+    sse_encode_i_64(self.createdAt, serializer);
+  }
+
+  @protected
+  void sse_encode_sticky_assoc_doc(
+    StickyAssocDoc self,
+    SseSerializer serializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_i_32(self.index, serializer);
+  }
+
+  @protected
+  void sse_encode_sync_message_doc(
+    SyncMessageDoc self,
+    SseSerializer serializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_sync_message_kind_doc(self.kind, serializer);
+    sse_encode_list_prim_u_8_strict(self.payload, serializer);
+  }
+
+  @protected
+  void sse_encode_sync_message_kind_doc(
+    SyncMessageKindDoc self,
+    SseSerializer serializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_i_32(self.index, serializer);
+  }
+
+  @protected
+  void sse_encode_sync_reply_doc(SyncReplyDoc self, SseSerializer serializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_opt_list_prim_u_8_strict(self.reply, serializer);
+    sse_encode_bool(self.documentChanged, serializer);
+    sse_encode_bool(self.awarenessChanged, serializer);
+  }
+
+  @protected
+  void sse_encode_text_position_doc(
+    TextPositionDoc self,
+    SseSerializer serializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_String(self.blockId, serializer);
+    sse_encode_u_32(self.offset, serializer);
+  }
+
   @protected
   void sse_encode_u_32(int self, SseSerializer serializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
     serializer.buffer.putUint32(self);
   }
 
+  @protected
+  void sse_encode_u_64(BigInt self, SseSerializer serializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    serializer.buffer.putBigUint64(self);
+  }
+
   @protected
   void sse_encode_u_8(int self, SseSerializer serializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
     serializer.buffer.putUint8(self);
   }
 
+  @protected
+  void sse_encode_undo_redo_result(
+    UndoRedoResult self,
+    SseSerializer serializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_bool(self.changed, serializer);
+    sse_encode_list_prim_u_8_strict(self.update, serializer);
+    sse_encode_list_block_action_doc(self.actions, serializer);
+  }
+
   @protected
   void sse_encode_unit(void self, SseSerializer serializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
   }
 
   @protected
-  void sse_encode_usize(BigInt self, SseSerializer serializer) {
+  void sse_encode_update_encoding_doc(
+    UpdateEncodingDoc self,
+    SseSerializer serializer,
+  ) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    serializer.buffer.putBigUint64(self);
+    sse_encode_i_32(self.index, serializer);
   }
 
   @protected
-  void sse_encode_bool(bool self, SseSerializer serializer) {
+  void sse_encode_update_with_id(UpdateWithId self, SseSerializer serializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    serializer.buffer.putUint8(self ? 1 : 0);
+    sse_encode_String(self.id, serializer);
+    sse_encode_list_prim_u_8_strict(self.update, serializer);
+  }
+
+  @protected
+  void sse_encode_usize(BigInt self, SseSerializer serializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    serializer.buffer.putBigUint64(self);
   }
 }
 
@@ -1404,15 +5251,223 @@ class DocumentServiceImpl extends RustOpaque implements DocumentService {
         actions: actions,
       );
 
+  Future<AwarenessChangesDoc> applyAwarenessUpdate({
+    required List<int> update,
+  }) => RustLib.instance.api
+      .crateDocDocumentServiceDocumentServiceApplyAwarenessUpdate(
+        that: this,
+        update: update,
+      );
+
   Future<void> applyUpdates({required List<Uint8List> updates}) =>
       RustLib.instance.api.crateDocDocumentServiceDocumentServiceApplyUpdates(
         that: this,
         updates: updates,
       );
 
+  /// Apply remote updates and return the editor actions that reproduce them, so only the
+  /// affected nodes need to be patched instead of rebuilding from the whole state
+  Future<AppliedUpdateActions> applyUpdatesAsActions({
+    required List<Uint8List> updates,
+  }) => RustLib.instance.api
+      .crateDocDocumentServiceDocumentServiceApplyUpdatesAsActions(
+        that: this,
+        updates: updates,
+      );
+
+  /// Apply only new updates onto the live document instead of replaying the whole history
+  Future<AppliedUpdates> applyUpdatesIncremental({
+    required List<Uint8List> updates,
+  }) => RustLib.instance.api
+      .crateDocDocumentServiceDocumentServiceApplyUpdatesIncremental(
+        that: this,
+        updates: updates,
+      );
+
+  /// Rebuild the document from tagged updates, skipping the corrupt ones and reporting their ids
+  Future<FailedToDecodeUpdates> applyUpdatesTolerant({
+    required List<UpdateWithId> updates,
+  }) => RustLib.instance.api
+      .crateDocDocumentServiceDocumentServiceApplyUpdatesTolerant(
+        that: this,
+        updates: updates,
+      );
+
+  Future<bool> canRedo() => RustLib.instance.api
+      .crateDocDocumentServiceDocumentServiceCanRedo(that: this);
+
+  Future<bool> canUndo() => RustLib.instance.api
+      .crateDocDocumentServiceDocumentServiceCanUndo(that: this);
+
+  /// To be called periodically, y-protocols uses a 30 second timeout checked every 3 seconds
+  Future<AwarenessTimeoutDoc> checkAwarenessTimeouts({
+    required BigInt timeoutMillis,
+  }) => RustLib.instance.api
+      .crateDocDocumentServiceDocumentServiceCheckAwarenessTimeouts(
+        that: this,
+        timeoutMillis: timeoutMillis,
+      );
+
+  /// Mark the local client as offline, to be broadcast before disconnecting
+  Future<Uint8List> clearLocalAwareness() => RustLib.instance.api
+      .crateDocDocumentServiceDocumentServiceClearLocalAwareness(that: this);
+
+  /// The yrs client id of this document, to be persisted and passed back on next launch
+  Future<BigInt> clientId() => RustLib.instance.api
+      .crateDocDocumentServiceDocumentServiceClientId(that: this);
+
+  /// Checkpoint update of the current document
+  Future<CompactedUpdate> compactDocument({
+    required bool gc,
+    required UpdateEncodingDoc encoding,
+  }) => RustLib.instance.api
+      .crateDocDocumentServiceDocumentServiceCompactDocument(
+        that: this,
+        gc: gc,
+        encoding: encoding,
+      );
+
+  /// Squash a stored update log into one checkpoint update, optionally dropping deleted
+  /// content. Does not touch the live document.
+  Future<CompactedUpdate> compactUpdates({
+    required List<Uint8List> updates,
+    required bool gc,
+    required UpdateEncodingDoc encoding,
+  }) =>
+      RustLib.instance.api.crateDocDocumentServiceDocumentServiceCompactUpdates(
+        that: this,
+        updates: updates,
+        gc: gc,
+        encoding: encoding,
+      );
+
+  /// Store a named snapshot of the current state. Requires a document created with skip_gc,
+  /// otherwise deleted content needed to render old versions is already gone. The snapshot
+  /// is returned for the host to persist and list, the document itself is left unchanged.
+  Future<CreatedSnapshot> createSnapshot({required String label}) =>
+      RustLib.instance.api.crateDocDocumentServiceDocumentServiceCreateSnapshot(
+        that: this,
+        label: label,
+      );
+
+  /// Current UTF-16 offset of an encoded position in the text of the block it was encoded in,
+  /// None once that text was deleted
+  Future<TextPositionDoc?> decodeStickyPosition({
+    required String blockId,
+    required List<int> position,
+  }) => RustLib.instance.api
+      .crateDocDocumentServiceDocumentServiceDecodeStickyPosition(
+        that: this,
+        blockId: blockId,
+        position: position,
+      );
+
+  Future<String> docId() => RustLib.instance.api
+      .crateDocDocumentServiceDocumentServiceDocId(that: this);
+
+  /// Switch the document from prevId chains to fractional position keys, keeping the
+  /// current order. Returns the update to broadcast; peers order by the keys once they apply it.
+  Future<Uint8List> enableFractionalOrdering() => RustLib.instance.api
+      .crateDocDocumentServiceDocumentServiceEnableFractionalOrdering(
+        that: this,
+      );
+
+  /// Start tracking local `apply_action` edits for undo. Edits closer together than the
+  /// capture timeout are undone as one step; remote updates are never undone.
+  Future<void> enableUndo({required BigInt captureTimeoutMillis}) =>
+      RustLib.instance.api.crateDocDocumentServiceDocumentServiceEnableUndo(
+        that: this,
+        captureTimeoutMillis: captureTimeoutMillis,
+      );
+
+  /// Awareness update with every known client, for peers that just connected
+  Future<Uint8List> encodeAwarenessUpdate() => RustLib.instance.api
+      .crateDocDocumentServiceDocumentServiceEncodeAwarenessUpdate(that: this);
+
+  /// Encode only the data a remote peer is missing, given its state vector in the same encoding
+  Future<Uint8List> encodeDiffSince({
+    required List<int> stateVector,
+    required UpdateEncodingDoc encoding,
+  }) => RustLib.instance.api
+      .crateDocDocumentServiceDocumentServiceEncodeDiffSince(
+        that: this,
+        stateVector: stateVector,
+        encoding: encoding,
+      );
+
+  /// Current state vector of the document, the first step of a state vector based sync
+  Future<Uint8List> encodeStateVector({required UpdateEncodingDoc encoding}) =>
+      RustLib.instance.api
+          .crateDocDocumentServiceDocumentServiceEncodeStateVector(
+            that: this,
+            encoding: encoding,
+          );
+
+  /// Encode a UTF-16 offset in the text of a block as a y.js compatible relative position,
+  /// which keeps pointing at the same character while remote edits are applied
+  Future<Uint8List> encodeStickyPosition({
+    required TextPositionDoc position,
+    required StickyAssocDoc assoc,
+  }) => RustLib.instance.api
+      .crateDocDocumentServiceDocumentServiceEncodeStickyPosition(
+        that: this,
+        position: position,
+        assoc: assoc,
+      );
+
+  /// Render the current document as an HTML fragment, safe to embed in emails and share pages
+  Future<String> exportHtml() => RustLib.instance.api
+      .crateDocDocumentServiceDocumentServiceExportHtml(that: this);
+
+  /// Render the current document as GFM Markdown
+  Future<String> exportMarkdown() => RustLib.instance.api
+      .crateDocDocumentServiceDocumentServiceExportMarkdown(that: this);
+
+  /// Presence of every connected client, the local one included
+  Future<List<AwarenessPeerDoc>> getAwarenessPeers() => RustLib.instance.api
+      .crateDocDocumentServiceDocumentServiceGetAwarenessPeers(that: this);
+
   Future<DocumentState> getDocumentState() => RustLib.instance.api
       .crateDocDocumentServiceDocumentServiceGetDocumentState(that: this);
 
+  /// Document state as it was when the snapshot was taken
+  Future<DocumentState> getDocumentStateAtSnapshot({
+    required List<int> snapshot,
+  }) => RustLib.instance.api
+      .crateDocDocumentServiceDocumentServiceGetDocumentStateAtSnapshot(
+        that: this,
+        snapshot: snapshot,
+      );
+
+  /// Consume an incoming y-sync payload and produce the reply for its sender.
+  /// Sync step 1 is answered with step 2, updates and awareness are applied locally.
+  Future<SyncReplyDoc> handleSyncMessage({required List<int> message}) =>
+      RustLib.instance.api
+          .crateDocDocumentServiceDocumentServiceHandleSyncMessage(
+            that: this,
+            message: message,
+          );
+
+  /// Initialize the document from AppFlowy editor JSON, either `Document.toJson()` output or
+  /// the flat JSON of `Conversion::document_to_json`, returning the update that creates it
+  Future<Uint8List> initDocFromJson({required String json}) => RustLib
+      .instance
+      .api
+      .crateDocDocumentServiceDocumentServiceInitDocFromJson(
+        that: this,
+        json: json,
+      );
+
+  /// Initialize the document from CommonMark/GFM Markdown instead of an empty page,
+  /// returning the update that creates it
+  Future<Uint8List> initDocFromMarkdown({required String markdown}) => RustLib
+      .instance
+      .api
+      .crateDocDocumentServiceDocumentServiceInitDocFromMarkdown(
+        that: this,
+        markdown: markdown,
+      );
+
   Future<Uint8List> initEmptyDoc() => RustLib.instance.api
       .crateDocDocumentServiceDocumentServiceInitEmptyDoc(that: this);
 
@@ -1422,7 +5477,63 @@ class DocumentServiceImpl extends RustOpaque implements DocumentService {
         updates: updates,
       );
 
+  Future<UndoRedoResult> redo() => RustLib.instance.api
+      .crateDocDocumentServiceDocumentServiceRedo(that: this);
+
+  /// Cut prevId cycles left by concurrent moves and relink blocks whose prev block is gone,
+  /// so the chains describe the order that is shown
+  Future<ChainRepairResult> repairChains() => RustLib.instance.api
+      .crateDocDocumentServiceDocumentServiceRepairChains(that: this);
+
+  /// Restore the document to a snapshot, returning the forward update that does it
+  Future<Uint8List> restoreSnapshot({required List<int> snapshot}) => RustLib
+      .instance
+      .api
+      .crateDocDocumentServiceDocumentServiceRestoreSnapshot(
+        that: this,
+        snapshot: snapshot,
+      );
+
+  /// Publish the local user and selection. Returns the awareness update to broadcast to peers.
+  Future<Uint8List> setLocalAwareness({required AwarenessStateDoc state}) =>
+      RustLib.instance.api
+          .crateDocDocumentServiceDocumentServiceSetLocalAwareness(
+            that: this,
+            state: state,
+          );
+
   /// Setting a root node id in the root map
   Future<Uint8List> setRootNodeId({required String id}) => RustLib.instance.api
       .crateDocDocumentServiceDocumentServiceSetRootNodeId(that: this, id: id);
+
+  Future<SiblingOrderingDoc> siblingOrdering() => RustLib.instance.api
+      .crateDocDocumentServiceDocumentServiceSiblingOrdering(that: this);
+
+  /// Messages opening a y-sync connection: sync step 1 with our state vector and our awareness
+  Future<Uint8List> startSync() => RustLib.instance.api
+      .crateDocDocumentServiceDocumentServiceStartSync(that: this);
+
+  /// End the current undo group so the next edit starts a new step
+  Future<void> stopUndoCapturing() => RustLib.instance.api
+      .crateDocDocumentServiceDocumentServiceStopUndoCapturing(that: this);
+
+  /// Push block level change events of every committed transaction to `sink`, replacing
+  /// any previous subscription. The subscription survives documents rebuilt from updates.
+  Stream<DocumentChangeEvent> subscribeChanges() => RustLib.instance.api
+      .crateDocDocumentServiceDocumentServiceSubscribeChanges(that: this);
+
+  Future<UndoRedoResult> undo() => RustLib.instance.api
+      .crateDocDocumentServiceDocumentServiceUndo(that: this);
+
+  Future<void> unsubscribeChanges() => RustLib.instance.api
+      .crateDocDocumentServiceDocumentServiceUnsubscribeChanges(that: this);
+
+  /// Report structural problems of the blocks map, and with `fix` return the update solving them
+  Future<IntegrityReport> validateDocument({required bool fix}) => RustLib
+      .instance
+      .api
+      .crateDocDocumentServiceDocumentServiceValidateDocument(
+        that: this,
+        fix: fix,
+      );
 }
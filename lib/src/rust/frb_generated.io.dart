@@ -23,6 +23,9 @@ abstract class RustLibApiImplPlatform extends BaseApiImpl<RustLibWire> {
   get rust_arc_decrement_strong_count_DocumentServicePtr =>
       wire._rust_arc_decrement_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerDocumentServicePtr;
 
+  @protected
+  AnyhowException dco_decode_AnyhowException(dynamic raw);
+
   @protected
   DocumentService
   dco_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerDocumentService(
@@ -56,9 +59,40 @@ abstract class RustLibApiImplPlatform extends BaseApiImpl<RustLibWire> {
     dynamic raw,
   );
 
+  @protected
+  RustStreamSink<DocumentChangeEvent>
+  dco_decode_StreamSink_document_change_event_Sse(dynamic raw);
+
   @protected
   String dco_decode_String(dynamic raw);
 
+  @protected
+  AppliedUpdateActions dco_decode_applied_update_actions(dynamic raw);
+
+  @protected
+  AppliedUpdates dco_decode_applied_updates(dynamic raw);
+
+  @protected
+  AttributeModeDoc dco_decode_attribute_mode_doc(dynamic raw);
+
+  @protected
+  AwarenessChangesDoc dco_decode_awareness_changes_doc(dynamic raw);
+
+  @protected
+  AwarenessPeerDoc dco_decode_awareness_peer_doc(dynamic raw);
+
+  @protected
+  AwarenessSelectionDoc dco_decode_awareness_selection_doc(dynamic raw);
+
+  @protected
+  AwarenessStateDoc dco_decode_awareness_state_doc(dynamic raw);
+
+  @protected
+  AwarenessTimeoutDoc dco_decode_awareness_timeout_doc(dynamic raw);
+
+  @protected
+  AwarenessUserDoc dco_decode_awareness_user_doc(dynamic raw);
+
   @protected
   BlockActionDoc dco_decode_block_action_doc(dynamic raw);
 
@@ -68,9 +102,59 @@ abstract class RustLibApiImplPlatform extends BaseApiImpl<RustLibWire> {
   @protected
   BlockDoc dco_decode_block_doc(dynamic raw);
 
+  @protected
+  bool dco_decode_bool(dynamic raw);
+
+  @protected
+  AwarenessSelectionDoc dco_decode_box_autoadd_awareness_selection_doc(
+    dynamic raw,
+  );
+
+  @protected
+  AwarenessStateDoc dco_decode_box_autoadd_awareness_state_doc(dynamic raw);
+
+  @protected
+  AwarenessUserDoc dco_decode_box_autoadd_awareness_user_doc(dynamic raw);
+
+  @protected
+  DocumentOptions dco_decode_box_autoadd_document_options(dynamic raw);
+
+  @protected
+  SyncMessageDoc dco_decode_box_autoadd_sync_message_doc(dynamic raw);
+
+  @protected
+  TextPositionDoc dco_decode_box_autoadd_text_position_doc(dynamic raw);
+
+  @protected
+  int dco_decode_box_autoadd_u_32(dynamic raw);
+
+  @protected
+  BigInt dco_decode_box_autoadd_u_64(dynamic raw);
+
+  @protected
+  UpdateEncodingDoc dco_decode_box_autoadd_update_encoding_doc(dynamic raw);
+
+  @protected
+  ChainRepairResult dco_decode_chain_repair_result(dynamic raw);
+
+  @protected
+  CompactedUpdate dco_decode_compacted_update(dynamic raw);
+
+  @protected
+  CreatedSnapshot dco_decode_created_snapshot(dynamic raw);
+
   @protected
   CustomRustError dco_decode_custom_rust_error(dynamic raw);
 
+  @protected
+  DocumentChangeEvent dco_decode_document_change_event(dynamic raw);
+
+  @protected
+  DocumentChangeKindDoc dco_decode_document_change_kind_doc(dynamic raw);
+
+  @protected
+  DocumentOptions dco_decode_document_options(dynamic raw);
+
   @protected
   DocumentState dco_decode_document_state(dynamic raw);
 
@@ -80,18 +164,42 @@ abstract class RustLibApiImplPlatform extends BaseApiImpl<RustLibWire> {
   @protected
   int dco_decode_i_32(dynamic raw);
 
+  @protected
+  PlatformInt64 dco_decode_i_64(dynamic raw);
+
+  @protected
+  IntegrityIssueDoc dco_decode_integrity_issue_doc(dynamic raw);
+
+  @protected
+  IntegrityIssueKindDoc dco_decode_integrity_issue_kind_doc(dynamic raw);
+
+  @protected
+  IntegrityReport dco_decode_integrity_report(dynamic raw);
+
   @protected
   List<String> dco_decode_list_String(dynamic raw);
 
+  @protected
+  List<AwarenessPeerDoc> dco_decode_list_awareness_peer_doc(dynamic raw);
+
   @protected
   List<BlockActionDoc> dco_decode_list_block_action_doc(dynamic raw);
 
+  @protected
+  List<IntegrityIssueDoc> dco_decode_list_integrity_issue_doc(dynamic raw);
+
   @protected
   List<Uint8List> dco_decode_list_list_prim_u_8_strict(dynamic raw);
 
   @protected
   Uint32List dco_decode_list_prim_u_32_strict(dynamic raw);
 
+  @protected
+  Uint64List dco_decode_list_prim_u_64_strict(dynamic raw);
+
+  @protected
+  List<int> dco_decode_list_prim_u_8_loose(dynamic raw);
+
   @protected
   Uint8List dco_decode_list_prim_u_8_strict(dynamic raw);
 
@@ -106,12 +214,46 @@ abstract class RustLibApiImplPlatform extends BaseApiImpl<RustLibWire> {
   @protected
   List<(String, String)> dco_decode_list_record_string_string(dynamic raw);
 
+  @protected
+  List<SyncMessageDoc> dco_decode_list_sync_message_doc(dynamic raw);
+
+  @protected
+  List<UpdateWithId> dco_decode_list_update_with_id(dynamic raw);
+
+  @protected
+  OffsetKindDoc dco_decode_offset_kind_doc(dynamic raw);
+
   @protected
   String? dco_decode_opt_String(dynamic raw);
 
+  @protected
+  AwarenessSelectionDoc? dco_decode_opt_box_autoadd_awareness_selection_doc(
+    dynamic raw,
+  );
+
+  @protected
+  AwarenessUserDoc? dco_decode_opt_box_autoadd_awareness_user_doc(dynamic raw);
+
+  @protected
+  TextPositionDoc? dco_decode_opt_box_autoadd_text_position_doc(dynamic raw);
+
+  @protected
+  int? dco_decode_opt_box_autoadd_u_32(dynamic raw);
+
+  @protected
+  BigInt? dco_decode_opt_box_autoadd_u_64(dynamic raw);
+
+  @protected
+  UpdateEncodingDoc? dco_decode_opt_box_autoadd_update_encoding_doc(
+    dynamic raw,
+  );
+
   @protected
   Uint32List? dco_decode_opt_list_prim_u_32_strict(dynamic raw);
 
+  @protected
+  Uint8List? dco_decode_opt_list_prim_u_8_strict(dynamic raw);
+
   @protected
   (String, BlockDoc) dco_decode_record_string_block_doc(dynamic raw);
 
@@ -121,18 +263,54 @@ abstract class RustLibApiImplPlatform extends BaseApiImpl<RustLibWire> {
   @protected
   (String, String) dco_decode_record_string_string(dynamic raw);
 
+  @protected
+  SiblingOrderingDoc dco_decode_sibling_ordering_doc(dynamic raw);
+
+  @protected
+  SnapshotInfo dco_decode_snapshot_info(dynamic raw);
+
+  @protected
+  StickyAssocDoc dco_decode_sticky_assoc_doc(dynamic raw);
+
+  @protected
+  SyncMessageDoc dco_decode_sync_message_doc(dynamic raw);
+
+  @protected
+  SyncMessageKindDoc dco_decode_sync_message_kind_doc(dynamic raw);
+
+  @protected
+  SyncReplyDoc dco_decode_sync_reply_doc(dynamic raw);
+
+  @protected
+  TextPositionDoc dco_decode_text_position_doc(dynamic raw);
+
   @protected
   int dco_decode_u_32(dynamic raw);
 
+  @protected
+  BigInt dco_decode_u_64(dynamic raw);
+
   @protected
   int dco_decode_u_8(dynamic raw);
 
+  @protected
+  UndoRedoResult dco_decode_undo_redo_result(dynamic raw);
+
   @protected
   void dco_decode_unit(dynamic raw);
 
+  @protected
+  UpdateEncodingDoc dco_decode_update_encoding_doc(dynamic raw);
+
+  @protected
+  UpdateWithId dco_decode_update_with_id(dynamic raw);
+
   @protected
   BigInt dco_decode_usize(dynamic raw);
 
+  @protected
+  AnyhowException sse_decode_AnyhowException(SseDeserializer deserializer);
+
   @protected
   DocumentService
   sse_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerDocumentService(
@@ -172,9 +350,50 @@ abstract class RustLibApiImplPlatform extends BaseApiImpl<RustLibWire> {
     SseDeserializer deserializer,
   );
 
+  @protected
+  RustStreamSink<DocumentChangeEvent>
+  sse_decode_StreamSink_document_change_event_Sse(SseDeserializer deserializer);
+
   @protected
   String sse_decode_String(SseDeserializer deserializer);
 
+  @protected
+  AppliedUpdateActions sse_decode_applied_update_actions(
+    SseDeserializer deserializer,
+  );
+
+  @protected
+  AppliedUpdates sse_decode_applied_updates(SseDeserializer deserializer);
+
+  @protected
+  AttributeModeDoc sse_decode_attribute_mode_doc(SseDeserializer deserializer);
+
+  @protected
+  AwarenessChangesDoc sse_decode_awareness_changes_doc(
+    SseDeserializer deserializer,
+  );
+
+  @protected
+  AwarenessPeerDoc sse_decode_awareness_peer_doc(SseDeserializer deserializer);
+
+  @protected
+  AwarenessSelectionDoc sse_decode_awareness_selection_doc(
+    SseDeserializer deserializer,
+  );
+
+  @protected
+  AwarenessStateDoc sse_decode_awareness_state_doc(
+    SseDeserializer deserializer,
+  );
+
+  @protected
+  AwarenessTimeoutDoc sse_decode_awareness_timeout_doc(
+    SseDeserializer deserializer,
+  );
+
+  @protected
+  AwarenessUserDoc sse_decode_awareness_user_doc(SseDeserializer deserializer);
+
   @protected
   BlockActionDoc sse_decode_block_action_doc(SseDeserializer deserializer);
 
@@ -186,9 +405,77 @@ abstract class RustLibApiImplPlatform extends BaseApiImpl<RustLibWire> {
   @protected
   BlockDoc sse_decode_block_doc(SseDeserializer deserializer);
 
+  @protected
+  bool sse_decode_bool(SseDeserializer deserializer);
+
+  @protected
+  AwarenessSelectionDoc sse_decode_box_autoadd_awareness_selection_doc(
+    SseDeserializer deserializer,
+  );
+
+  @protected
+  AwarenessStateDoc sse_decode_box_autoadd_awareness_state_doc(
+    SseDeserializer deserializer,
+  );
+
+  @protected
+  AwarenessUserDoc sse_decode_box_autoadd_awareness_user_doc(
+    SseDeserializer deserializer,
+  );
+
+  @protected
+  DocumentOptions sse_decode_box_autoadd_document_options(
+    SseDeserializer deserializer,
+  );
+
+  @protected
+  SyncMessageDoc sse_decode_box_autoadd_sync_message_doc(
+    SseDeserializer deserializer,
+  );
+
+  @protected
+  TextPositionDoc sse_decode_box_autoadd_text_position_doc(
+    SseDeserializer deserializer,
+  );
+
+  @protected
+  int sse_decode_box_autoadd_u_32(SseDeserializer deserializer);
+
+  @protected
+  BigInt sse_decode_box_autoadd_u_64(SseDeserializer deserializer);
+
+  @protected
+  UpdateEncodingDoc sse_decode_box_autoadd_update_encoding_doc(
+    SseDeserializer deserializer,
+  );
+
+  @protected
+  ChainRepairResult sse_decode_chain_repair_result(
+    SseDeserializer deserializer,
+  );
+
+  @protected
+  CompactedUpdate sse_decode_compacted_update(SseDeserializer deserializer);
+
+  @protected
+  CreatedSnapshot sse_decode_created_snapshot(SseDeserializer deserializer);
+
   @protected
   CustomRustError sse_decode_custom_rust_error(SseDeserializer deserializer);
 
+  @protected
+  DocumentChangeEvent sse_decode_document_change_event(
+    SseDeserializer deserializer,
+  );
+
+  @protected
+  DocumentChangeKindDoc sse_decode_document_change_kind_doc(
+    SseDeserializer deserializer,
+  );
+
+  @protected
+  DocumentOptions sse_decode_document_options(SseDeserializer deserializer);
+
   @protected
   DocumentState sse_decode_document_state(SseDeserializer deserializer);
 
@@ -200,14 +487,40 @@ abstract class RustLibApiImplPlatform extends BaseApiImpl<RustLibWire> {
   @protected
   int sse_decode_i_32(SseDeserializer deserializer);
 
+  @protected
+  PlatformInt64 sse_decode_i_64(SseDeserializer deserializer);
+
+  @protected
+  IntegrityIssueDoc sse_decode_integrity_issue_doc(
+    SseDeserializer deserializer,
+  );
+
+  @protected
+  IntegrityIssueKindDoc sse_decode_integrity_issue_kind_doc(
+    SseDeserializer deserializer,
+  );
+
+  @protected
+  IntegrityReport sse_decode_integrity_report(SseDeserializer deserializer);
+
   @protected
   List<String> sse_decode_list_String(SseDeserializer deserializer);
 
+  @protected
+  List<AwarenessPeerDoc> sse_decode_list_awareness_peer_doc(
+    SseDeserializer deserializer,
+  );
+
   @protected
   List<BlockActionDoc> sse_decode_list_block_action_doc(
     SseDeserializer deserializer,
   );
 
+  @protected
+  List<IntegrityIssueDoc> sse_decode_list_integrity_issue_doc(
+    SseDeserializer deserializer,
+  );
+
   @protected
   List<Uint8List> sse_decode_list_list_prim_u_8_strict(
     SseDeserializer deserializer,
@@ -216,6 +529,12 @@ abstract class RustLibApiImplPlatform extends BaseApiImpl<RustLibWire> {
   @protected
   Uint32List sse_decode_list_prim_u_32_strict(SseDeserializer deserializer);
 
+  @protected
+  Uint64List sse_decode_list_prim_u_64_strict(SseDeserializer deserializer);
+
+  @protected
+  List<int> sse_decode_list_prim_u_8_loose(SseDeserializer deserializer);
+
   @protected
   Uint8List sse_decode_list_prim_u_8_strict(SseDeserializer deserializer);
 
@@ -234,14 +553,56 @@ abstract class RustLibApiImplPlatform extends BaseApiImpl<RustLibWire> {
     SseDeserializer deserializer,
   );
 
+  @protected
+  List<SyncMessageDoc> sse_decode_list_sync_message_doc(
+    SseDeserializer deserializer,
+  );
+
+  @protected
+  List<UpdateWithId> sse_decode_list_update_with_id(
+    SseDeserializer deserializer,
+  );
+
+  @protected
+  OffsetKindDoc sse_decode_offset_kind_doc(SseDeserializer deserializer);
+
   @protected
   String? sse_decode_opt_String(SseDeserializer deserializer);
 
+  @protected
+  AwarenessSelectionDoc? sse_decode_opt_box_autoadd_awareness_selection_doc(
+    SseDeserializer deserializer,
+  );
+
+  @protected
+  AwarenessUserDoc? sse_decode_opt_box_autoadd_awareness_user_doc(
+    SseDeserializer deserializer,
+  );
+
+  @protected
+  TextPositionDoc? sse_decode_opt_box_autoadd_text_position_doc(
+    SseDeserializer deserializer,
+  );
+
+  @protected
+  int? sse_decode_opt_box_autoadd_u_32(SseDeserializer deserializer);
+
+  @protected
+  BigInt? sse_decode_opt_box_autoadd_u_64(SseDeserializer deserializer);
+
+  @protected
+  UpdateEncodingDoc? sse_decode_opt_box_autoadd_update_encoding_doc(
+    SseDeserializer deserializer,
+  );
+
   @protected
   Uint32List? sse_decode_opt_list_prim_u_32_strict(
     SseDeserializer deserializer,
   );
 
+  @protected
+  Uint8List? sse_decode_opt_list_prim_u_8_strict(SseDeserializer deserializer);
+
   @protected
   (String, BlockDoc) sse_decode_record_string_block_doc(
     SseDeserializer deserializer,
@@ -257,20 +618,62 @@ abstract class RustLibApiImplPlatform extends BaseApiImpl<RustLibWire> {
     SseDeserializer deserializer,
   );
 
+  @protected
+  SiblingOrderingDoc sse_decode_sibling_ordering_doc(
+    SseDeserializer deserializer,
+  );
+
+  @protected
+  SnapshotInfo sse_decode_snapshot_info(SseDeserializer deserializer);
+
+  @protected
+  StickyAssocDoc sse_decode_sticky_assoc_doc(SseDeserializer deserializer);
+
+  @protected
+  SyncMessageDoc sse_decode_sync_message_doc(SseDeserializer deserializer);
+
+  @protected
+  SyncMessageKindDoc sse_decode_sync_message_kind_doc(
+    SseDeserializer deserializer,
+  );
+
+  @protected
+  SyncReplyDoc sse_decode_sync_reply_doc(SseDeserializer deserializer);
+
+  @protected
+  TextPositionDoc sse_decode_text_position_doc(SseDeserializer deserializer);
+
   @protected
   int sse_decode_u_32(SseDeserializer deserializer);
 
+  @protected
+  BigInt sse_decode_u_64(SseDeserializer deserializer);
+
   @protected
   int sse_decode_u_8(SseDeserializer deserializer);
 
+  @protected
+  UndoRedoResult sse_decode_undo_redo_result(SseDeserializer deserializer);
+
   @protected
   void sse_decode_unit(SseDeserializer deserializer);
 
+  @protected
+  UpdateEncodingDoc sse_decode_update_encoding_doc(
+    SseDeserializer deserializer,
+  );
+
+  @protected
+  UpdateWithId sse_decode_update_with_id(SseDeserializer deserializer);
+
   @protected
   BigInt sse_decode_usize(SseDeserializer deserializer);
 
   @protected
-  bool sse_decode_bool(SseDeserializer deserializer);
+  void sse_encode_AnyhowException(
+    AnyhowException self,
+    SseSerializer serializer,
+  );
 
   @protected
   void
@@ -318,9 +721,69 @@ abstract class RustLibApiImplPlatform extends BaseApiImpl<RustLibWire> {
     SseSerializer serializer,
   );
 
+  @protected
+  void sse_encode_StreamSink_document_change_event_Sse(
+    RustStreamSink<DocumentChangeEvent> self,
+    SseSerializer serializer,
+  );
+
   @protected
   void sse_encode_String(String self, SseSerializer serializer);
 
+  @protected
+  void sse_encode_applied_update_actions(
+    AppliedUpdateActions self,
+    SseSerializer serializer,
+  );
+
+  @protected
+  void sse_encode_applied_updates(
+    AppliedUpdates self,
+    SseSerializer serializer,
+  );
+
+  @protected
+  void sse_encode_attribute_mode_doc(
+    AttributeModeDoc self,
+    SseSerializer serializer,
+  );
+
+  @protected
+  void sse_encode_awareness_changes_doc(
+    AwarenessChangesDoc self,
+    SseSerializer serializer,
+  );
+
+  @protected
+  void sse_encode_awareness_peer_doc(
+    AwarenessPeerDoc self,
+    SseSerializer serializer,
+  );
+
+  @protected
+  void sse_encode_awareness_selection_doc(
+    AwarenessSelectionDoc self,
+    SseSerializer serializer,
+  );
+
+  @protected
+  void sse_encode_awareness_state_doc(
+    AwarenessStateDoc self,
+    SseSerializer serializer,
+  );
+
+  @protected
+  void sse_encode_awareness_timeout_doc(
+    AwarenessTimeoutDoc self,
+    SseSerializer serializer,
+  );
+
+  @protected
+  void sse_encode_awareness_user_doc(
+    AwarenessUserDoc self,
+    SseSerializer serializer,
+  );
+
   @protected
   void sse_encode_block_action_doc(
     BlockActionDoc self,
@@ -336,12 +799,99 @@ abstract class RustLibApiImplPlatform extends BaseApiImpl<RustLibWire> {
   @protected
   void sse_encode_block_doc(BlockDoc self, SseSerializer serializer);
 
+  @protected
+  void sse_encode_bool(bool self, SseSerializer serializer);
+
+  @protected
+  void sse_encode_box_autoadd_awareness_selection_doc(
+    AwarenessSelectionDoc self,
+    SseSerializer serializer,
+  );
+
+  @protected
+  void sse_encode_box_autoadd_awareness_state_doc(
+    AwarenessStateDoc self,
+    SseSerializer serializer,
+  );
+
+  @protected
+  void sse_encode_box_autoadd_awareness_user_doc(
+    AwarenessUserDoc self,
+    SseSerializer serializer,
+  );
+
+  @protected
+  void sse_encode_box_autoadd_document_options(
+    DocumentOptions self,
+    SseSerializer serializer,
+  );
+
+  @protected
+  void sse_encode_box_autoadd_sync_message_doc(
+    SyncMessageDoc self,
+    SseSerializer serializer,
+  );
+
+  @protected
+  void sse_encode_box_autoadd_text_position_doc(
+    TextPositionDoc self,
+    SseSerializer serializer,
+  );
+
+  @protected
+  void sse_encode_box_autoadd_u_32(int self, SseSerializer serializer);
+
+  @protected
+  void sse_encode_box_autoadd_u_64(BigInt self, SseSerializer serializer);
+
+  @protected
+  void sse_encode_box_autoadd_update_encoding_doc(
+    UpdateEncodingDoc self,
+    SseSerializer serializer,
+  );
+
+  @protected
+  void sse_encode_chain_repair_result(
+    ChainRepairResult self,
+    SseSerializer serializer,
+  );
+
+  @protected
+  void sse_encode_compacted_update(
+    CompactedUpdate self,
+    SseSerializer serializer,
+  );
+
+  @protected
+  void sse_encode_created_snapshot(
+    CreatedSnapshot self,
+    SseSerializer serializer,
+  );
+
   @protected
   void sse_encode_custom_rust_error(
     CustomRustError self,
     SseSerializer serializer,
   );
 
+  @protected
+  void sse_encode_document_change_event(
+    DocumentChangeEvent self,
+    SseSerializer serializer,
+  );
+
+  @protected
+  void sse_encode_document_change_kind_doc(
+    DocumentChangeKindDoc self,
+    SseSerializer serializer,
+  );
+
+  @protected
+  void sse_encode_document_options(
+    DocumentOptions self,
+    SseSerializer serializer,
+  );
+
   @protected
   void sse_encode_document_state(DocumentState self, SseSerializer serializer);
 
@@ -354,15 +904,48 @@ abstract class RustLibApiImplPlatform extends BaseApiImpl<RustLibWire> {
   @protected
   void sse_encode_i_32(int self, SseSerializer serializer);
 
+  @protected
+  void sse_encode_i_64(PlatformInt64 self, SseSerializer serializer);
+
+  @protected
+  void sse_encode_integrity_issue_doc(
+    IntegrityIssueDoc self,
+    SseSerializer serializer,
+  );
+
+  @protected
+  void sse_encode_integrity_issue_kind_doc(
+    IntegrityIssueKindDoc self,
+    SseSerializer serializer,
+  );
+
+  @protected
+  void sse_encode_integrity_report(
+    IntegrityReport self,
+    SseSerializer serializer,
+  );
+
   @protected
   void sse_encode_list_String(List<String> self, SseSerializer serializer);
 
+  @protected
+  void sse_encode_list_awareness_peer_doc(
+    List<AwarenessPeerDoc> self,
+    SseSerializer serializer,
+  );
+
   @protected
   void sse_encode_list_block_action_doc(
     List<BlockActionDoc> self,
     SseSerializer serializer,
   );
 
+  @protected
+  void sse_encode_list_integrity_issue_doc(
+    List<IntegrityIssueDoc> self,
+    SseSerializer serializer,
+  );
+
   @protected
   void sse_encode_list_list_prim_u_8_strict(
     List<Uint8List> self,
@@ -375,6 +958,15 @@ abstract class RustLibApiImplPlatform extends BaseApiImpl<RustLibWire> {
     SseSerializer serializer,
   );
 
+  @protected
+  void sse_encode_list_prim_u_64_strict(
+    Uint64List self,
+    SseSerializer serializer,
+  );
+
+  @protected
+  void sse_encode_list_prim_u_8_loose(List<int> self, SseSerializer serializer);
+
   @protected
   void sse_encode_list_prim_u_8_strict(
     Uint8List self,
@@ -399,15 +991,66 @@ abstract class RustLibApiImplPlatform extends BaseApiImpl<RustLibWire> {
     SseSerializer serializer,
   );
 
+  @protected
+  void sse_encode_list_sync_message_doc(
+    List<SyncMessageDoc> self,
+    SseSerializer serializer,
+  );
+
+  @protected
+  void sse_encode_list_update_with_id(
+    List<UpdateWithId> self,
+    SseSerializer serializer,
+  );
+
+  @protected
+  void sse_encode_offset_kind_doc(OffsetKindDoc self, SseSerializer serializer);
+
   @protected
   void sse_encode_opt_String(String? self, SseSerializer serializer);
 
+  @protected
+  void sse_encode_opt_box_autoadd_awareness_selection_doc(
+    AwarenessSelectionDoc? self,
+    SseSerializer serializer,
+  );
+
+  @protected
+  void sse_encode_opt_box_autoadd_awareness_user_doc(
+    AwarenessUserDoc? self,
+    SseSerializer serializer,
+  );
+
+  @protected
+  void sse_encode_opt_box_autoadd_text_position_doc(
+    TextPositionDoc? self,
+    SseSerializer serializer,
+  );
+
+  @protected
+  void sse_encode_opt_box_autoadd_u_32(int? self, SseSerializer serializer);
+
+  @protected
+  void sse_encode_opt_box_autoadd_u_64(BigInt? self, SseSerializer serializer);
+
+  @protected
+  void sse_encode_opt_box_autoadd_update_encoding_doc(
+    UpdateEncodingDoc? self,
+    SseSerializer serializer,
+  );
+
   @protected
   void sse_encode_opt_list_prim_u_32_strict(
     Uint32List? self,
     SseSerializer serializer,
   );
 
+  @protected
+  void sse_encode_opt_list_prim_u_8_strict(
+    Uint8List? self,
+    SseSerializer serializer,
+  );
+
   @protected
   void sse_encode_record_string_block_doc(
     (String, BlockDoc) self,
@@ -426,20 +1069,71 @@ abstract class RustLibApiImplPlatform extends BaseApiImpl<RustLibWire> {
     SseSerializer serializer,
   );
 
+  @protected
+  void sse_encode_sibling_ordering_doc(
+    SiblingOrderingDoc self,
+    SseSerializer serializer,
+  );
+
+  @protected
+  void sse_encode_snapshot_info(SnapshotInfo self, SseSerializer serializer);
+
+  @protected
+  void sse_encode_sticky_assoc_doc(
+    StickyAssocDoc self,
+    SseSerializer serializer,
+  );
+
+  @protected
+  void sse_encode_sync_message_doc(
+    SyncMessageDoc self,
+    SseSerializer serializer,
+  );
+
+  @protected
+  void sse_encode_sync_message_kind_doc(
+    SyncMessageKindDoc self,
+    SseSerializer serializer,
+  );
+
+  @protected
+  void sse_encode_sync_reply_doc(SyncReplyDoc self, SseSerializer serializer);
+
+  @protected
+  void sse_encode_text_position_doc(
+    TextPositionDoc self,
+    SseSerializer serializer,
+  );
+
   @protected
   void sse_encode_u_32(int self, SseSerializer serializer);
 
+  @protected
+  void sse_encode_u_64(BigInt self, SseSerializer serializer);
+
   @protected
   void sse_encode_u_8(int self, SseSerializer serializer);
 
+  @protected
+  void sse_encode_undo_redo_result(
+    UndoRedoResult self,
+    SseSerializer serializer,
+  );
+
   @protected
   void sse_encode_unit(void self, SseSerializer serializer);
 
   @protected
-  void sse_encode_usize(BigInt self, SseSerializer serializer);
+  void sse_encode_update_encoding_doc(
+    UpdateEncodingDoc self,
+    SseSerializer serializer,
+  );
 
   @protected
-  void sse_encode_bool(bool self, SseSerializer serializer);
+  void sse_encode_update_with_id(UpdateWithId self, SseSerializer serializer);
+
+  @protected
+  void sse_encode_usize(BigInt self, SseSerializer serializer);
 }
 
 // Section: wire_class
@@ -16,3 +16,18 @@ class Uint32ListConverter implements JsonConverter<Uint32List, List<dynamic>> {
     return object.toList();
   }
 }
+
+// Converter for Uint64List
+class Uint64ListConverter implements JsonConverter<Uint64List, List<dynamic>> {
+  const Uint64ListConverter();
+
+  @override
+  Uint64List fromJson(List<dynamic> json) {
+    return Uint64List.fromList(json.cast<int>());
+  }
+
+  @override
+  List<int> toJson(Uint64List object) {
+    return object.toList();
+  }
+}
@@ -11,4 +11,54 @@ pub const TYPE: &str = "type";
 pub const PARENT_ID: &str = "parentId";
 pub const PREV_ID: &str = "prevId";
 pub const NEXT_ID: &str = "nextId";
-pub const DEFAULT_PARENT: &str = "default_parent";
\ No newline at end of file
+pub const DEFAULT_PARENT: &str = "default_parent";
+
+/// Constants for fractional sibling ordering
+pub const ORDERING: &str = "ordering";
+pub const FRACTIONAL_ORDERING: &str = "fractional";
+pub const POSITION: &str = "position";
+pub const POSITION_CLIENT: &str = "positionClient";
+
+/// Transaction origins
+pub const LOCAL_ACTION_ORIGIN: &str = "local_action";
+/// Reported to change subscribers for undo and redo steps, whose origin is not text
+pub const UNDO_MANAGER_ORIGIN: &str = "undo_manager";
+/// Chain repairs are not local edits, so the undo manager does not track them
+pub const CHAIN_REPAIR_ORIGIN: &str = "chain_repair";
+pub const INTEGRITY_REPAIR_ORIGIN: &str = "integrity_repair";
+/// Not tracked by the undo manager either, an undo would drop the keys but keep the ordering
+pub const ORDERING_MIGRATION_ORIGIN: &str = "ordering_migration";
+/// Restores are tracked by the undo manager like local edits, but reported apart from them
+pub const SNAPSHOT_RESTORE_ORIGIN: &str = "snapshot_restore";
+
+/// Block types and attributes of the AppFlowy editor
+pub const PARAGRAPH_TYPE: &str = "paragraph";
+pub const HEADING_TYPE: &str = "heading";
+pub const BULLETED_LIST_TYPE: &str = "bulleted_list";
+pub const NUMBERED_LIST_TYPE: &str = "numbered_list";
+pub const TODO_LIST_TYPE: &str = "todo_list";
+pub const QUOTE_TYPE: &str = "quote";
+pub const CODE_TYPE: &str = "code";
+pub const DIVIDER_TYPE: &str = "divider";
+pub const IMAGE_TYPE: &str = "image";
+pub const TABLE_TYPE: &str = "table";
+pub const TABLE_CELL_TYPE: &str = "table/cell";
+pub const LEVEL_ATTR: &str = "level";
+pub const NUMBER_ATTR: &str = "number";
+pub const CHECKED_ATTR: &str = "checked";
+pub const LANGUAGE_ATTR: &str = "language";
+pub const URL_ATTR: &str = "url";
+pub const ROWS_LEN_ATTR: &str = "rowsLen";
+pub const COLS_LEN_ATTR: &str = "colsLen";
+pub const ROW_POSITION_ATTR: &str = "rowPosition";
+pub const COL_POSITION_ATTR: &str = "colPosition";
+
+/// Inline attributes of text deltas
+pub const BOLD: &str = "bold";
+pub const ITALIC: &str = "italic";
+pub const UNDERLINE: &str = "underline";
+pub const STRIKETHROUGH: &str = "strikethrough";
+pub const CODE: &str = "code";
+pub const HREF: &str = "href";
+pub const FONT_COLOR: &str = "font_color";
+pub const BG_COLOR: &str = "bg_color";
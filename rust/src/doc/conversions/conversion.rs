@@ -1,9 +1,9 @@
 use std::collections::HashMap;
 use std::sync::Arc;
 use serde_json::{Value, Map as JsonMap, json};
-use yrs::{Any as YrsAny, types::Delta, ReadTxn, Map, Array};
+use yrs::{Any as YrsAny, types::{Delta, ToJson}, ReadTxn, Map, Array, OffsetKind, Options, Out};
 
-use crate::doc::document_types::{BlockDoc, DocumentState};
+use crate::doc::document_types::{AttributeModeDoc, BlockDoc, DocumentOptions, DocumentState, OffsetKindDoc};
 use crate::doc::constants::{ID, TYPE, PARENT_ID, PREV_ID, TEXT, ATTRIBUTES};
 use crate::doc::error::DocError;
 use crate::doc::document_types::CustomRustError;
@@ -63,6 +63,53 @@ impl Conversion {
         }
     }
 
+    /// Value stored for a block attribute. In Json mode every value has to be a JSON literal,
+    /// text included, so a string such as "123" is never taken for a number.
+    pub fn attribute_to_yrs(value: &str, mode: AttributeModeDoc) -> Result<YrsAny, CustomRustError> {
+        match mode {
+            AttributeModeDoc::Strings => Ok(YrsAny::String(Arc::from(value))),
+            AttributeModeDoc::Json => serde_json::from_str::<Value>(value)
+                .map(|json| Self::json_to_yrs_any(&json))
+                .map_err(|e| DocError::InvalidOperation(format!("Attribute value {} is not a JSON literal: {}", value, e)).into()),
+        }
+    }
+
+    /// String form of a stored block attribute
+    pub fn attribute_from_yrs<T: ReadTxn>(txn: &T, value: &Out, mode: AttributeModeDoc) -> String {
+        match mode {
+            AttributeModeDoc::Strings => value.clone().to_string(txn),
+            AttributeModeDoc::Json => Self::attribute_any_to_json(&value.to_json(txn)).to_string(),
+        }
+    }
+
+    /// Like `yrs_any_to_json`, but whole numbers are written back as integers, as they were sent
+    fn attribute_any_to_json(any: &YrsAny) -> Value {
+        match any {
+            YrsAny::Number(n) if n.fract() == 0.0 && n.abs() < i64::MAX as f64 => Value::Number((*n as i64).into()),
+            YrsAny::Array(arr) => Value::Array(arr.iter().map(Self::attribute_any_to_json).collect()),
+            YrsAny::Map(map) => Value::Object(
+                map.iter()
+                   .map(|(k, v)| (k.to_string(), Self::attribute_any_to_json(v)))
+                   .collect()
+            ),
+            other => Self::yrs_any_to_json(other),
+        }
+    }
+
+    /// Convert document options coming from Dart to yrs options
+    pub fn document_options_to_yrs(options: &DocumentOptions) -> Options {
+        let mut yrs_options = match options.client_id {
+            Some(client_id) => Options::with_client_id(client_id),
+            None => Options::default(),
+        };
+        yrs_options.offset_kind = match options.offset_kind {
+            OffsetKindDoc::Bytes => OffsetKind::Bytes,
+            OffsetKindDoc::Utf16 => OffsetKind::Utf16,
+        };
+        yrs_options.skip_gc = options.skip_gc;
+        yrs_options
+    }
+
     /// Convert a Yrs delta to JSON
     pub fn delta_to_json<T: ReadTxn>(
         txn: &T,
@@ -1,13 +1,24 @@
+use std::sync::Arc;
+
 use flutter_rust_bridge::{frb, DartFnFuture};
 use log::{error, info};
-use yrs::{merge_updates_v2, Doc, Map, ReadTxn, Transact};
+use yrs::sync::Awareness;
+use yrs::undo::UndoManager;
+use yrs::{Doc, Map, Options, Out, ReadTxn, Subscription, Transact};
 
+use super::conversions::conversion::Conversion;
 use super::error::DocError;
-use super::operations::{block_ops::BlockOperations, delta_ops::DeltaOperations, update_ops::UpdateOperations};
+use super::operations::{awareness_ops::AwarenessOperations, block_ops::BlockOperations, change_ops::{ChangeListener, ChangeOperations, UndoOrigin}, delta_ops::DeltaOperations, html_ops::HtmlOperations, import_ops::ImportOperations, integrity_ops::IntegrityOperations, markdown_ops::MarkdownOperations, position_ops::PositionOperations, protocol_ops::ProtocolOperations, repair_ops::RepairOperations, snapshot_ops::SnapshotOperations, sticky_ops::StickyOperations, undo_ops::UndoOperations, update_ops::UpdateOperations, validation_ops::ValidationOperations};
 
-use crate::doc::constants::{BLOCKS, DEFAULT_PARENT, ROOT_ID};
-use crate::doc::document_types::{BlockActionDoc, BlockActionTypeDoc, CustomRustError, DocumentState, FailedToDecodeUpdates};
+use crate::doc::constants::{BLOCKS, DEFAULT_PARENT, LOCAL_ACTION_ORIGIN, ROOT_ID};
+use crate::doc::document_types::{
+    AppliedUpdateActions, AppliedUpdates, AttributeModeDoc, AwarenessChangesDoc, CompactedUpdate, AwarenessPeerDoc, AwarenessStateDoc, AwarenessTimeoutDoc, BlockActionDoc, BlockActionTypeDoc, ChainRepairResult, IntegrityReport, CreatedSnapshot, CustomRustError, DocumentChangeEvent,
+    DocumentOptions, DocumentState,
+    FailedToDecodeUpdates, SiblingOrderingDoc, StickyAssocDoc, SyncMessageDoc, SyncReplyDoc, TextPositionDoc, UndoRedoResult, UpdateEncodingDoc, UpdateWithId,
+};
+use crate::doc::utils::block_index::BlockIndex;
 use crate::doc::utils::util::MapExt;
+use crate::frb_generated::StreamSink;
 use crate::{log_info, log_error};
 
 
@@ -15,15 +26,75 @@ use crate::{log_info, log_error};
 pub struct DocumentService {
     doc: Doc,
     doc_id: String,
+    // Options every rebuilt document is created with, client id included
+    options: Options,
+    // Encoding of the updates handed out for local edits, incoming updates may use either
+    update_encoding: UpdateEncodingDoc,
+    // String form of block attribute values exchanged with the editor
+    attribute_mode: AttributeModeDoc,
+    // Capture timeout of the undo history, None while undo is disabled
+    undo_capture_timeout_millis: Option<u64>,
+    // Created lazily once the blocks map exists, dropped whenever the document is rebuilt
+    undo_manager: Option<UndoManager<()>>,
+    // Shared with the change subscription, which tells undo steps apart by it
+    undo_origin: UndoOrigin,
+    // Kept across rebuilds so the subscription can be moved to the new document
+    change_listener: Option<ChangeListener>,
+    change_subscription: Option<Subscription>,
+    // Sibling and parent links used by local edits, follows whichever document is current
+    block_index: BlockIndex,
+    // Presence of connected clients, kept across rebuilds since it is not part of the document.
+    // Shares the current document, which the sync protocol handler reads from and writes to.
+    awareness: Awareness,
 }
 
 impl DocumentService {
 
     #[frb]
     pub fn new() -> Self {
-        log_info!("Creating new document service");
-        let doc_id = "xxxx".to_string();
-        Self { doc_id, doc: Doc::new() }
+        Self::new_with_options("xxxx".to_string(), DocumentOptions::default())
+    }
+
+    #[frb]
+    /// Create a service for a known document id. Passing a persisted client id keeps the
+    /// device under the same yrs client across restarts; it must never be shared between devices.
+    pub fn new_with_options(doc_id: String, options: DocumentOptions) -> Self {
+        log_info!("Creating new document service for doc_id: {}", doc_id);
+        // The converted options already carry a concrete client id, so documents
+        // rebuilt from updates keep the same one
+        let update_encoding = options.update_encoding;
+        let attribute_mode = options.attribute_mode;
+        let options = Conversion::document_options_to_yrs(&options);
+        let doc = Doc::with_options(options.clone());
+        log_info!("Document service for doc_id: {} uses client id {}", doc_id, options.client_id);
+        let awareness = AwarenessOperations::create(&doc);
+        let mut block_index = BlockIndex::default();
+        block_index.attach(&doc);
+        Self {
+            doc_id,
+            doc,
+            options,
+            update_encoding,
+            attribute_mode,
+            undo_capture_timeout_millis: None,
+            undo_manager: None,
+            undo_origin: UndoOrigin::default(),
+            change_listener: None,
+            change_subscription: None,
+            block_index,
+            awareness,
+        }
+    }
+
+    #[frb]
+    /// The yrs client id of this document, to be persisted and passed back on next launch
+    pub fn client_id(&self) -> u64 {
+        self.doc.client_id()
+    }
+
+    #[frb]
+    pub fn doc_id(&self) -> String {
+        self.doc_id.clone()
     }
 
     #[no_mangle]
@@ -45,12 +116,47 @@ impl DocumentService {
         // Create the empty state update
         log_info!("init_empty_doc: Encoding state for doc_id: {}", self.doc_id);
         let empty_state = yrs::StateVector::default();
-        let update = txn.encode_state_as_update_v2(&empty_state);
+        let update = match self.update_encoding {
+            UpdateEncodingDoc::V1 => txn.encode_state_as_update_v1(&empty_state),
+            UpdateEncodingDoc::V2 => txn.encode_state_as_update_v2(&empty_state),
+        };
         
         log_info!("init_empty_doc: Finished for doc_id: {}", self.doc_id);
         Ok(update)
     }
 
+    #[no_mangle]
+    #[inline(never)]
+    #[frb]
+    /// Initialize the document from CommonMark/GFM Markdown instead of an empty page,
+    /// returning the update that creates it
+    pub fn init_doc_from_markdown(&mut self, markdown: String) -> Result<Vec<u8>, CustomRustError> {
+        MarkdownOperations::import_markdown(&self.doc, &self.doc_id, &markdown, self.update_encoding, self.attribute_mode)
+    }
+
+    #[no_mangle]
+    #[inline(never)]
+    #[frb]
+    /// Initialize the document from AppFlowy editor JSON, either `Document.toJson()` output or
+    /// the flat JSON of `Conversion::document_to_json`, returning the update that creates it
+    pub fn init_doc_from_json(&mut self, json: String) -> Result<Vec<u8>, CustomRustError> {
+        ImportOperations::import_json(&self.doc, &self.doc_id, &json, self.update_encoding, self.attribute_mode)
+    }
+
+    #[frb]
+    /// Render the current document as GFM Markdown
+    pub fn export_markdown(&self) -> Result<String, CustomRustError> {
+        let state = self.document_state(AttributeModeDoc::Strings)?;
+        Ok(MarkdownOperations::export_markdown(&state))
+    }
+
+    #[frb]
+    /// Render the current document as an HTML fragment, safe to embed in emails and share pages
+    pub fn export_html(&self) -> Result<String, CustomRustError> {
+        let state = self.document_state(AttributeModeDoc::Strings)?;
+        Ok(HtmlOperations::export_html(&state))
+    }
+
     #[no_mangle]
 #[inline(never)]
 #[frb]
@@ -61,52 +167,106 @@ pub fn apply_action(
     log_info!("apply_action: Starting with {} actions for doc_id: {}", 
              actions.len(), self.doc_id);
     
-    // Get document handle and start transaction
+    self.ensure_undo_manager();
+
+    // Get document handle
     let doc = &self.doc;
     let root = doc.get_or_insert_map(ROOT_ID);
-    let mut txn = doc.transact_mut();
-    
-    // Process each action
-    for action in actions {
-        let blocks_map = root.get_or_init_map(&mut txn, BLOCKS);
-        
-        // Delegate to specialized operation handlers
-        match action.action {
-            BlockActionTypeDoc::Insert => {
-                BlockOperations::insert_node(&mut txn, blocks_map, action)?;
-            },
-            BlockActionTypeDoc::Update => {
-                BlockOperations::update_node(&mut txn, blocks_map, action)?;
-            },
-            BlockActionTypeDoc::Delete => {
-                let parent_id = action.block.parent_id
-                    .unwrap_or_else(|| DEFAULT_PARENT.to_owned());
-                
-                BlockOperations::delete_node(&mut txn, blocks_map, &action.block.id, &parent_id)?;
-            },
-            BlockActionTypeDoc::Move => {
-                if let (Some(old_path), Some(parent_id), Some(old_parent_id)) = 
-                    (action.old_path.as_ref(), action.block.parent_id.as_ref(), action.block.old_parent_id.as_ref()) {
-                    BlockOperations::move_block(
-                        &mut txn, blocks_map,
-                        old_path, &action.path, parent_id, old_parent_id,
-                        &action.block.id, action.block.prev_id, action.block.next_id
-                    )?;
-                } else {
-                    return Err(DocError::InvalidOperation("Missing required fields for move operation".into()).into());
-                }
-            }
+
+    // Batches the document cannot take are turned down before anything is copied
+    {
+        let txn = doc.transact();
+        let blocks_map = match root.get(&txn, BLOCKS) {
+            Some(Out::YMap(map)) => Some(map),
+            _ => None,
+        };
+        if let Some(blocks_map) = &blocks_map {
+            self.block_index.refresh(&txn, blocks_map);
         }
+        ValidationOperations::validate_actions(&txn, blocks_map.as_ref(), &self.block_index, &actions)?;
     }
-    
-    // Generate update from the transaction
-    log_info!("apply_action: Encoding state for doc_id: {}", self.doc_id);
-    let before_state = txn.before_state();
-    let update = txn.encode_diff_v2(before_state);
+
+    // A transaction cannot be rolled back, so the batch is written to a copy of the document
+    // and only the update of a batch written without errors is applied here
+    let scratch = UpdateOperations::copy_doc(doc, &self.options)?;
+    let mut index = self.block_index.detached();
+    let update = Self::write_actions(&scratch, &mut index, actions, self.attribute_mode, self.update_encoding)?;
+
+    log_info!("apply_action: Applying written actions to doc_id: {}", self.doc_id);
+    {
+        let mut txn = doc.transact_mut_with(LOCAL_ACTION_ORIGIN);
+        txn.apply_update(UpdateOperations::decode_update(&update)?)
+            .map_err(|e| DocError::MergeError(format!("Failed to apply written actions: {}", e)))?;
+    }
+
+    // The copied index already holds what the batch wrote
+    self.block_index.adopt(index);
     
     Ok(update)
 }
 
+    /// Write a batch of actions to `doc`, returning the update they produced. The error
+    /// names the index of the action that failed.
+    fn write_actions(
+        doc: &Doc,
+        index: &mut BlockIndex,
+        actions: Vec<BlockActionDoc>,
+        attribute_mode: AttributeModeDoc,
+        update_encoding: UpdateEncodingDoc
+    ) -> Result<Vec<u8>, CustomRustError> {
+        let root = doc.get_or_insert_map(ROOT_ID);
+        let mut txn = doc.transact_mut_with(LOCAL_ACTION_ORIGIN);
+        let blocks_map = root.get_or_init_map(&mut txn, BLOCKS);
+        index.refresh(&txn, &blocks_map);
+        let ordering = PositionOperations::ordering(&txn, &root);
+
+        // Process each action
+        for (i, action) in actions.into_iter().enumerate() {
+            // Inserted and moved blocks of fractionally ordered documents get a key between their new neighbours
+            let placement = match action.action {
+                BlockActionTypeDoc::Insert | BlockActionTypeDoc::Move if ordering == SiblingOrderingDoc::FractionalIndex => {
+                    Some((action.block.id.clone(), action.block.parent_id.clone(), action.block.prev_id.clone()))
+                }
+                _ => None,
+            };
+
+            // Delegate to specialized operation handlers
+            let result = match action.action {
+                BlockActionTypeDoc::Insert => {
+                    BlockOperations::insert_node(&mut txn, blocks_map.clone(), index, action, attribute_mode).map(|_| ())
+                },
+                BlockActionTypeDoc::Update => {
+                    BlockOperations::update_node(&mut txn, blocks_map.clone(), action, attribute_mode)
+                },
+                BlockActionTypeDoc::Delete => {
+                    let parent_id = action.block.parent_id
+                        .unwrap_or_else(|| DEFAULT_PARENT.to_owned());
+
+                    BlockOperations::delete_node(&mut txn, blocks_map.clone(), index, &action.block.id, &parent_id)
+                },
+                BlockActionTypeDoc::Move => {
+                    BlockOperations::move_block(&mut txn, blocks_map.clone(), index, action)
+                }
+            };
+
+            result
+                .and_then(|()| match placement {
+                    Some((block_id, parent_id, prev_id)) => PositionOperations::place_block(
+                        &mut txn, &blocks_map, index, &block_id, parent_id.as_deref(), prev_id.as_deref()
+                    ),
+                    None => Ok(()),
+                })
+                .map_err(|e| {
+                    log_error!("apply_action: Action {} failed: {}", i, e.message);
+                    e.at_action(i)
+                })?;
+        }
+
+        // Generate update from the transaction
+        let before_state = txn.before_state();
+        Ok(UpdateOperations::encode_diff(&txn, before_state, update_encoding))
+    }
+
     #[no_mangle]
     #[inline(never)]
     #[frb]
@@ -114,22 +274,74 @@ pub fn apply_action(
         log_info!("apply_updates: Starting with {} updates for doc_id: {}", updates.len(), self.doc_id);
         
         // Create a new document to apply updates to
-        let new_doc = Doc::new();
+        let new_doc = Doc::with_options(self.options.clone());
         
         // Apply updates to the new document
         let result = UpdateOperations::apply_updates_inner(new_doc.clone(), &self.doc_id, updates)?;
         
         // Replace the current document with the new one
-        self.doc = new_doc;
+        self.replace_doc(new_doc);
         
         log_info!("apply_updates: Successfully applied updates for doc_id: {}", self.doc_id);
         Ok(result)
     }
 
+    #[no_mangle]
+    #[inline(never)]
+    #[frb]
+    /// Rebuild the document from tagged updates, skipping the corrupt ones and reporting their ids
+    pub fn apply_updates_tolerant(&mut self, updates: Vec<UpdateWithId>) -> Result<FailedToDecodeUpdates, CustomRustError> {
+        log_info!("apply_updates_tolerant: Starting with {} updates for doc_id: {}", updates.len(), self.doc_id);
+
+        let new_doc = Doc::with_options(self.options.clone());
+        let failed = UpdateOperations::apply_updates_tolerant(new_doc.clone(), &self.doc_id, updates)?;
+        self.replace_doc(new_doc);
+
+        log_info!("apply_updates_tolerant: Finished for doc_id: {}", self.doc_id);
+        Ok(failed)
+    }
+
+    /// Swap in a document rebuilt from updates, moving everything bound to the old one
+    fn replace_doc(&mut self, new_doc: Doc) {
+        *self.awareness.doc_mut() = new_doc.clone();
+        self.doc = new_doc;
+        self.undo_manager = None;
+        self.block_index.attach(&self.doc);
+        self.resubscribe_changes();
+    }
+
+    #[no_mangle]
+    #[inline(never)]
+    #[frb]
+    /// Apply only new updates onto the live document instead of replaying the whole history
+    pub fn apply_updates_incremental(&mut self, updates: Vec<Vec<u8>>) -> Result<AppliedUpdates, CustomRustError> {
+        log_info!("apply_updates_incremental: Starting with {} updates for doc_id: {}", updates.len(), self.doc_id);
+
+        let result = UpdateOperations::apply_updates_incremental(&self.doc, &self.doc_id, updates)?;
+
+        log_info!("apply_updates_incremental: Successfully applied updates for doc_id: {}", self.doc_id);
+        Ok(result)
+    }
+
+    #[no_mangle]
+    #[inline(never)]
+    #[frb]
+    /// Apply remote updates and return the editor actions that reproduce them, so only the
+    /// affected nodes need to be patched instead of rebuilding from the whole state
+    pub fn apply_updates_as_actions(&mut self, updates: Vec<Vec<u8>>) -> Result<AppliedUpdateActions, CustomRustError> {
+        log_info!("apply_updates_as_actions: Starting with {} updates for doc_id: {}", updates.len(), self.doc_id);
+        UpdateOperations::apply_updates_as_actions(&self.doc, &self.doc_id, &mut self.block_index, updates, self.attribute_mode)
+    }
+
     #[no_mangle]
     #[inline(never)]
     #[frb]
     pub fn get_document_state(&self) -> Result<DocumentState, CustomRustError> {
+        self.document_state(self.attribute_mode)
+    }
+
+    /// Exporters read attributes in the string form whatever the configured mode is
+    fn document_state(&self, attribute_mode: AttributeModeDoc) -> Result<DocumentState, CustomRustError> {
         log_info!("get_document_state: Starting for doc_id: {}", self.doc_id);
         
         let doc = &self.doc;
@@ -137,28 +349,306 @@ pub fn apply_action(
         let txn = doc.transact();
         
         // Extract document state through specialized function
-        let state = UpdateOperations::extract_document_state(&txn, root, &self.doc_id)?;
+        let state = UpdateOperations::extract_document_state(&txn, root, &self.doc_id, attribute_mode)?;
         
         log_info!("get_document_state: Finished for doc_id: {}", self.doc_id);
         Ok(state)
     }
 
+    #[frb]
+    /// Squash a stored update log into one checkpoint update, optionally dropping deleted
+    /// content. Does not touch the live document.
+    pub fn compact_updates(&self, updates: Vec<Vec<u8>>, gc: bool, encoding: UpdateEncodingDoc) -> Result<CompactedUpdate, CustomRustError> {
+        UpdateOperations::compact_updates(&self.doc_id, updates, gc, encoding)
+    }
+
+    #[frb]
+    /// Checkpoint update of the current document
+    pub fn compact_document(&self, gc: bool, encoding: UpdateEncodingDoc) -> Result<CompactedUpdate, CustomRustError> {
+        UpdateOperations::compact_doc(&self.doc, &self.doc_id, gc, encoding)
+    }
+
     #[frb]
     pub fn merge_updates(&self, updates: Vec<Vec<u8>>) -> Result<Vec<u8>, CustomRustError> {
         log_info!("merge_updates: Merging {} updates", updates.len());
         
-        match merge_updates_v2(updates) {
+        match UpdateOperations::merge_updates(updates) {
             Ok(update) => {
                 log_info!("merge_updates: Successfully merged updates");
-                Ok(update)
+                self.encode_output(update)
             },
             Err(e) => {
                 log_error!("merge_updates: Failed to merge updates: {}", e);
-                Err(DocError::EncodingError(format!("Failed to merge updates: {}", e)).into())
+                Err(e)
             }
         }
     }
 
+    #[frb]
+    /// Start tracking local `apply_action` edits for undo. Edits closer together than the
+    /// capture timeout are undone as one step; remote updates are never undone.
+    pub fn enable_undo(&mut self, capture_timeout_millis: u64) {
+        log_info!("enable_undo: Enabling undo for doc_id: {}", self.doc_id);
+        self.undo_capture_timeout_millis = Some(capture_timeout_millis);
+        self.undo_manager = None;
+        self.ensure_undo_manager();
+    }
+
+    #[frb]
+    /// End the current undo group so the next edit starts a new step
+    pub fn stop_undo_capturing(&mut self) {
+        if let Some(manager) = self.undo_manager.as_mut() {
+            manager.reset();
+        }
+    }
+
+    #[frb]
+    pub fn can_undo(&self) -> bool {
+        self.undo_manager.as_ref().map(|manager| manager.can_undo()).unwrap_or(false)
+    }
+
+    #[frb]
+    pub fn can_redo(&self) -> bool {
+        self.undo_manager.as_ref().map(|manager| manager.can_redo()).unwrap_or(false)
+    }
+
+    #[no_mangle]
+    #[inline(never)]
+    #[frb]
+    pub fn undo(&mut self) -> Result<UndoRedoResult, CustomRustError> {
+        self.ensure_undo_manager();
+        let manager = self.undo_manager.as_mut()
+            .ok_or_else(|| DocError::StateError("Undo is not enabled for this document".into()))?;
+        let result = UndoOperations::undo(manager, &self.doc_id, self.attribute_mode)?;
+        Ok(UndoRedoResult { update: self.encode_output(result.update)?, ..result })
+    }
+
+    #[no_mangle]
+    #[inline(never)]
+    #[frb]
+    pub fn redo(&mut self) -> Result<UndoRedoResult, CustomRustError> {
+        self.ensure_undo_manager();
+        let manager = self.undo_manager.as_mut()
+            .ok_or_else(|| DocError::StateError("Undo is not enabled for this document".into()))?;
+        let result = UndoOperations::redo(manager, &self.doc_id, self.attribute_mode)?;
+        Ok(UndoRedoResult { update: self.encode_output(result.update)?, ..result })
+    }
+
+    /// Operations produce v2 updates, re-encode them in the configured output encoding
+    fn encode_output(&self, update: Vec<u8>) -> Result<Vec<u8>, CustomRustError> {
+        UpdateOperations::convert_update(update, Some(UpdateEncodingDoc::V2), self.update_encoding)
+    }
+
+    fn ensure_undo_manager(&mut self) {
+        if let (Some(capture_timeout_millis), None) = (self.undo_capture_timeout_millis, &self.undo_manager) {
+            self.undo_manager = UndoOperations::create_manager(&self.doc, capture_timeout_millis);
+            if let Ok(mut undo_origin) = self.undo_origin.lock() {
+                *undo_origin = self.undo_manager.as_ref().map(UndoManager::as_origin);
+            }
+        }
+    }
+
+    #[frb]
+    /// Push block level change events of every committed transaction to `sink`, replacing
+    /// any previous subscription. The subscription survives documents rebuilt from updates.
+    pub fn subscribe_changes(&mut self, sink: StreamSink<DocumentChangeEvent>) {
+        log_info!("subscribe_changes: Subscribing to changes of doc_id: {}", self.doc_id);
+        let doc_id = self.doc_id.clone();
+        self.set_change_listener(Arc::new(move |event| {
+            if let Err(e) = sink.add(event) {
+                log_error!("subscribe_changes: Failed to push change event for doc_id: {}: {:?}", doc_id, e);
+            }
+        }));
+    }
+
+    #[frb]
+    pub fn unsubscribe_changes(&mut self) {
+        log_info!("unsubscribe_changes: Unsubscribing from changes of doc_id: {}", self.doc_id);
+        self.change_listener = None;
+        self.change_subscription = None;
+    }
+
+    fn set_change_listener(&mut self, listener: ChangeListener) {
+        self.change_listener = Some(listener);
+        self.resubscribe_changes();
+    }
+
+    fn resubscribe_changes(&mut self) {
+        self.change_subscription = self.change_listener
+            .clone()
+            .map(|listener| ChangeOperations::subscribe(&self.doc, listener, self.undo_origin.clone()));
+    }
+
+    #[frb]
+    /// Encode a UTF-16 offset in the text of a block as a y.js compatible relative position,
+    /// which keeps pointing at the same character while remote edits are applied
+    pub fn encode_sticky_position(&self, position: TextPositionDoc, assoc: StickyAssocDoc) -> Result<Vec<u8>, CustomRustError> {
+        StickyOperations::encode_position(&self.doc, &position, assoc)
+    }
+
+    #[frb]
+    /// Current UTF-16 offset of an encoded position in the text of the block it was encoded in,
+    /// None once that text was deleted
+    pub fn decode_sticky_position(&self, block_id: String, position: Vec<u8>) -> Result<Option<TextPositionDoc>, CustomRustError> {
+        StickyOperations::decode_position(&self.doc, &block_id, &position)
+    }
+
+    #[frb]
+    /// Publish the local user and selection. Returns the awareness update to broadcast to peers.
+    pub fn set_local_awareness(&mut self, state: AwarenessStateDoc) -> Result<Vec<u8>, CustomRustError> {
+        AwarenessOperations::set_local_state(&self.awareness, &self.doc, state)
+    }
+
+    #[frb]
+    /// Mark the local client as offline, to be broadcast before disconnecting
+    pub fn clear_local_awareness(&mut self) -> Result<Vec<u8>, CustomRustError> {
+        log_info!("clear_local_awareness: Clearing local presence for doc_id: {}", self.doc_id);
+        AwarenessOperations::clear_local_state(&self.awareness)
+    }
+
+    #[frb]
+    /// Awareness update with every known client, for peers that just connected
+    pub fn encode_awareness_update(&self) -> Result<Vec<u8>, CustomRustError> {
+        AwarenessOperations::encode_update(&self.awareness)
+    }
+
+    #[frb]
+    pub fn apply_awareness_update(&mut self, update: Vec<u8>) -> Result<AwarenessChangesDoc, CustomRustError> {
+        AwarenessOperations::apply_update(&self.awareness, &update)
+    }
+
+    #[frb]
+    /// Presence of every connected client, the local one included
+    pub fn get_awareness_peers(&self) -> Vec<AwarenessPeerDoc> {
+        AwarenessOperations::peers(&self.awareness, &self.doc)
+    }
+
+    #[frb]
+    /// To be called periodically, y-protocols uses a 30 second timeout checked every 3 seconds
+    pub fn check_awareness_timeouts(&mut self, timeout_millis: u64) -> Result<AwarenessTimeoutDoc, CustomRustError> {
+        AwarenessOperations::check_timeouts(&self.awareness, timeout_millis)
+    }
+
+    #[frb]
+    /// Messages opening a y-sync connection: sync step 1 with our state vector and our awareness
+    pub fn start_sync(&self) -> Result<Vec<u8>, CustomRustError> {
+        log_info!("start_sync: Starting sync for doc_id: {}", self.doc_id);
+        ProtocolOperations::start(&self.awareness)
+    }
+
+    #[frb]
+    /// Consume an incoming y-sync payload and produce the reply for its sender.
+    /// Sync step 1 is answered with step 2, updates and awareness are applied locally.
+    pub fn handle_sync_message(&mut self, message: Vec<u8>) -> Result<SyncReplyDoc, CustomRustError> {
+        log_info!("handle_sync_message: Handling {} bytes for doc_id: {}", message.len(), self.doc_id);
+        ProtocolOperations::handle(&self.awareness, &message)
+    }
+
+    #[frb]
+    pub fn encode_sync_message(message: SyncMessageDoc) -> Result<Vec<u8>, CustomRustError> {
+        ProtocolOperations::encode_message(message)
+    }
+
+    #[frb]
+    pub fn decode_sync_messages(data: Vec<u8>) -> Result<Vec<SyncMessageDoc>, CustomRustError> {
+        ProtocolOperations::decode_messages(&data)
+    }
+
+    #[frb]
+    /// Wrap an update returned by `apply_action` or `undo` into a y-sync Update message
+    pub fn encode_update_message(update: Vec<u8>, encoding: UpdateEncodingDoc) -> Result<Vec<u8>, CustomRustError> {
+        ProtocolOperations::encode_update_message(&update, encoding)
+    }
+
+    #[frb]
+    /// Store a named snapshot of the current state. Requires a document created with skip_gc,
+    /// otherwise deleted content needed to render old versions is already gone. The snapshot
+    /// is returned for the host to persist and list, the document itself is left unchanged.
+    pub fn create_snapshot(&self, label: String) -> Result<CreatedSnapshot, CustomRustError> {
+        SnapshotOperations::create_snapshot(&self.doc, &self.doc_id, label)
+    }
+
+    #[frb]
+    /// Document state as it was when the snapshot was taken
+    pub fn get_document_state_at_snapshot(&self, snapshot: Vec<u8>) -> Result<DocumentState, CustomRustError> {
+        SnapshotOperations::document_state_at(&self.doc, &self.doc_id, &snapshot, self.attribute_mode)
+    }
+
+    #[no_mangle]
+    #[inline(never)]
+    #[frb]
+    /// Restore the document to a snapshot, returning the forward update that does it
+    pub fn restore_snapshot(&mut self, snapshot: Vec<u8>) -> Result<Vec<u8>, CustomRustError> {
+        self.ensure_undo_manager();
+        let update = SnapshotOperations::restore_snapshot(&self.doc, &self.doc_id, &snapshot)?;
+        self.encode_output(update)
+    }
+
+    #[frb]
+    /// Encoding of an update, None when it decodes as neither v1 nor v2
+    pub fn detect_update_encoding(update: Vec<u8>) -> Option<UpdateEncodingDoc> {
+        UpdateOperations::detect_encoding(&update)
+    }
+
+    #[frb]
+    /// Re-encode an update for peers using the other encoding, detecting its current one when `from` is None
+    pub fn convert_update(update: Vec<u8>, from: Option<UpdateEncodingDoc>, to: UpdateEncodingDoc) -> Result<Vec<u8>, CustomRustError> {
+        UpdateOperations::convert_update(update, from, to)
+    }
+
+    #[frb]
+    /// Current state vector of the document, the first step of a state vector based sync
+    pub fn encode_state_vector(&self, encoding: UpdateEncodingDoc) -> Result<Vec<u8>, CustomRustError> {
+        log_info!("encode_state_vector: Encoding {:?} state vector for doc_id: {}", encoding, self.doc_id);
+
+        let txn = self.doc.transact();
+        Ok(UpdateOperations::encode_state_vector(&txn, encoding))
+    }
+
+    #[frb]
+    /// Encode only the data a remote peer is missing, given its state vector in the same encoding
+    pub fn encode_diff_since(&self, state_vector: Vec<u8>, encoding: UpdateEncodingDoc) -> Result<Vec<u8>, CustomRustError> {
+        log_info!("encode_diff_since: Encoding {:?} diff for doc_id: {}", encoding, self.doc_id);
+
+        let txn = self.doc.transact();
+        let diff = UpdateOperations::encode_diff_since(&txn, &state_vector, encoding)?;
+
+        log_info!("encode_diff_since: Encoded {} bytes for doc_id: {}", diff.len(), self.doc_id);
+        Ok(diff)
+    }
+
+    #[frb]
+    pub fn sibling_ordering(&self) -> SiblingOrderingDoc {
+        let root = self.doc.get_or_insert_map(ROOT_ID);
+        PositionOperations::ordering(&self.doc.transact(), &root)
+    }
+
+    #[no_mangle]
+    #[inline(never)]
+    #[frb]
+    /// Switch the document from prevId chains to fractional position keys, keeping the
+    /// current order. Returns the update to broadcast; peers order by the keys once they apply it.
+    pub fn enable_fractional_ordering(&mut self) -> Result<Vec<u8>, CustomRustError> {
+        PositionOperations::enable_fractional_ordering(&self.doc, &self.doc_id, self.update_encoding)
+    }
+
+    #[no_mangle]
+    #[inline(never)]
+    #[frb]
+    /// Cut prevId cycles left by concurrent moves and relink blocks whose prev block is gone,
+    /// so the chains describe the order that is shown
+    pub fn repair_chains(&mut self) -> Result<ChainRepairResult, CustomRustError> {
+        RepairOperations::repair_chains(&self.doc, &self.doc_id, self.update_encoding)
+    }
+
+    #[no_mangle]
+    #[inline(never)]
+    #[frb]
+    /// Report structural problems of the blocks map, and with `fix` return the update solving them
+    pub fn validate_document(&mut self, fix: bool) -> Result<IntegrityReport, CustomRustError> {
+        IntegrityOperations::validate_document(&self.doc, &self.doc_id, fix, self.update_encoding)
+    }
+
     #[no_mangle]
     #[inline(never)]
     #[frb]
@@ -174,10 +664,1247 @@ pub fn apply_action(
 
         // Encode the state as an update
         let before_state = txn.before_state();
-        let update = txn.encode_diff_v2(before_state);
+        let update = UpdateOperations::encode_diff(&txn, before_state, self.update_encoding);
         log_info!("set_root_node_id: Finished for doc_id: {}", self.doc_id);
         Ok(update)
     }
 
 
-}
\ No newline at end of file
+}
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::collections::HashMap;
+    use crate::doc::document_types::{AwarenessSelectionDoc, AwarenessUserDoc, BlockDoc, DocumentChangeKindDoc, OffsetKindDoc, SyncMessageKindDoc};
+    use crate::doc::constants::{ID, PARENT_ID, PREV_ID, SNAPSHOT_RESTORE_ORIGIN, TYPE, UNDO_MANAGER_ORIGIN};
+    use crate::doc::document_types::{IntegrityIssueDoc, IntegrityIssueKindDoc};
+    use yrs::updates::decoder::Decode;
+    use yrs::Update;
+
+    fn insert_action(id: &str, parent_id: Option<&str>, prev_id: Option<&str>, text: &str) -> BlockActionDoc {
+        BlockActionDoc {
+            action: BlockActionTypeDoc::Insert,
+            block: BlockDoc {
+                id: id.to_string(),
+                ty: "paragraph".to_string(),
+                attributes: HashMap::new(),
+                delta: Some(format!("[{{\"insert\":\"{}\"}}]", text)),
+                parent_id: parent_id.map(|s| s.to_string()),
+                prev_id: prev_id.map(|s| s.to_string()),
+                next_id: None,
+                old_parent_id: None,
+            },
+            path: vec![0],
+            old_path: None,
+        }
+    }
+
+    #[test]
+    fn test_apply_updates_incremental_keeps_doc_and_reports_changes() {
+        let mut local = DocumentService::new();
+        let mut updates = vec![local.init_empty_doc().unwrap()];
+        updates.push(local.set_root_node_id("page".to_string()).unwrap());
+        updates.push(local.apply_action(vec![insert_action("page", None, None, "")]).unwrap());
+        updates.push(local.apply_action(vec![insert_action("a", Some("page"), None, "first")]).unwrap());
+
+        let mut remote = DocumentService::new();
+        let client_id = remote.doc.client_id();
+
+        let applied = remote.apply_updates_incremental(updates).unwrap();
+        assert_eq!(applied.changed_block_ids, vec!["a".to_string(), "page".to_string()]);
+        assert!(applied.deleted_block_ids.is_empty());
+        assert!(applied.root_id_changed);
+        assert!(!applied.has_pending_updates);
+
+        // Only the new update is applied and only the new block is reported
+        let update = local.apply_action(vec![insert_action("b", Some("page"), Some("a"), "second")]).unwrap();
+        let applied = remote.apply_updates_incremental(vec![update]).unwrap();
+        assert_eq!(applied.changed_block_ids, vec!["b".to_string()]);
+        assert!(!applied.root_id_changed);
+        assert_eq!(remote.doc.client_id(), client_id);
+
+        let state = remote.get_document_state().unwrap();
+        assert_eq!(state.children_map["page"], vec!["a".to_string(), "b".to_string()]);
+    }
+
+    #[test]
+    fn test_apply_updates_incremental_rejects_corrupt_batch() {
+        let mut local = DocumentService::new();
+        let update = local.init_empty_doc().unwrap();
+
+        let mut remote = DocumentService::new();
+        let result = remote.apply_updates_incremental(vec![update, vec![0xFF, 0x01]]);
+        assert!(result.is_err());
+
+        // Nothing from the batch was applied
+        let txn = remote.doc.transact();
+        assert!(txn.state_vector().is_empty());
+    }
+
+    #[test]
+    fn test_state_vector_sync_exchanges_only_missing_data() {
+        let mut local = DocumentService::new();
+        let init = local.init_empty_doc().unwrap();
+        let root = local.set_root_node_id("page".to_string()).unwrap();
+
+        let mut remote = DocumentService::new();
+        remote.apply_updates_incremental(vec![init, root]).unwrap();
+        local.apply_action(vec![insert_action("page", None, None, "")]).unwrap();
+
+        for encoding in [UpdateEncodingDoc::V1, UpdateEncodingDoc::V2] {
+            let remote_state_vector = remote.encode_state_vector(encoding).unwrap();
+            let diff = local.encode_diff_since(remote_state_vector, encoding).unwrap();
+            let full = local.encode_diff_since(empty_state_vector(encoding), encoding).unwrap();
+            assert!(diff.len() < full.len());
+        }
+
+        let diff = local.encode_diff_since(remote.encode_state_vector(UpdateEncodingDoc::V2).unwrap(), UpdateEncodingDoc::V2).unwrap();
+        remote.apply_updates_incremental(vec![diff]).unwrap();
+        assert_eq!(
+            remote.encode_state_vector(UpdateEncodingDoc::V1).unwrap(),
+            local.encode_state_vector(UpdateEncodingDoc::V1).unwrap()
+        );
+    }
+
+    fn empty_state_vector(encoding: UpdateEncodingDoc) -> Vec<u8> {
+        DocumentService::new().encode_state_vector(encoding).unwrap()
+    }
+
+    #[test]
+    fn test_undo_redo_tracks_only_local_actions() {
+        let mut local = DocumentService::new();
+        let mut updates = vec![local.init_empty_doc().unwrap()];
+        updates.push(local.set_root_node_id("page".to_string()).unwrap());
+        updates.push(local.apply_action(vec![insert_action("page", None, None, "")]).unwrap());
+
+        let mut service = DocumentService::new();
+        service.apply_updates_incremental(updates).unwrap();
+        service.enable_undo(0);
+        assert!(!service.can_undo());
+
+        service.apply_action(vec![insert_action("a", Some("page"), None, "mine")]).unwrap();
+        let remote = local.apply_action(vec![insert_action("b", Some("page"), None, "theirs")]).unwrap();
+        service.apply_updates_incremental(vec![remote]).unwrap();
+        assert!(service.can_undo());
+
+        let undone = service.undo().unwrap();
+        assert!(undone.changed);
+        assert!(!undone.update.is_empty());
+        assert_eq!(undone.actions.len(), 1);
+        assert!(matches!(undone.actions[0].action, BlockActionTypeDoc::Delete));
+        assert_eq!(undone.actions[0].block.id, "a");
+
+        // The remote block survives the undo
+        let state = service.get_document_state().unwrap();
+        assert_eq!(state.children_map["page"], vec!["b".to_string()]);
+        assert!(!service.undo().unwrap().changed);
+
+        let redone = service.redo().unwrap();
+        assert!(redone.changed);
+        assert_eq!(redone.actions.len(), 1);
+        assert!(matches!(redone.actions[0].action, BlockActionTypeDoc::Insert));
+        assert_eq!(redone.actions[0].block.id, "a");
+        assert_eq!(redone.actions[0].block.delta.as_deref(), Some("[{\"insert\":\"mine\"}]"));
+    }
+
+    #[test]
+    fn test_undo_text_edit_returns_text_diff() {
+        let mut service = DocumentService::new();
+        service.init_empty_doc().unwrap();
+        service.set_root_node_id("page".to_string()).unwrap();
+        service.apply_action(vec![insert_action("page", None, None, "")]).unwrap();
+        service.apply_action(vec![insert_action("a", Some("page"), None, "text")]).unwrap();
+        service.enable_undo(0);
+
+        let mut edit = insert_action("a", Some("page"), None, "");
+        edit.action = BlockActionTypeDoc::Update;
+        edit.block.delta = Some("[{\"retain\":4},{\"insert\":\"!\"}]".to_string());
+        service.apply_action(vec![edit]).unwrap();
+
+        let undone = service.undo().unwrap();
+        assert_eq!(undone.actions.len(), 1);
+        assert!(matches!(undone.actions[0].action, BlockActionTypeDoc::Update));
+        assert_eq!(undone.actions[0].path, vec![0]);
+        assert_eq!(undone.actions[0].block.delta.as_deref(), Some("[{\"retain\":4},{\"delete\":1}]"));
+    }
+
+    #[test]
+    fn test_snapshot_restore_generates_forward_update() {
+        let options = DocumentOptions { skip_gc: true, ..Default::default() };
+        let mut service = DocumentService::new_with_options("doc".to_string(), options);
+        let mut updates = vec![service.init_empty_doc().unwrap()];
+        updates.push(service.set_root_node_id("page".to_string()).unwrap());
+        updates.push(service.apply_action(vec![insert_action("page", None, None, "")]).unwrap());
+        updates.push(service.apply_action(vec![insert_action("a", Some("page"), None, "v1")]).unwrap());
+
+        // Taking a snapshot leaves the synced document alone
+        let state_vector = service.doc.transact().state_vector();
+        let created = service.create_snapshot("first draft".to_string()).unwrap();
+        assert_eq!(created.snapshot.label, "first draft");
+        assert_eq!(service.doc.transact().state_vector(), state_vector);
+
+        let mut edit = insert_action("a", Some("page"), None, "");
+        edit.action = BlockActionTypeDoc::Update;
+        edit.block.delta = Some("[{\"retain\":2},{\"insert\":\" edited\"}]".to_string());
+        updates.push(service.apply_action(vec![edit]).unwrap());
+        updates.push(service.apply_action(vec![insert_action("b", Some("page"), Some("a"), "later")]).unwrap());
+
+        let past = service.get_document_state_at_snapshot(created.data.clone()).unwrap();
+        assert_eq!(past.children_map["page"], vec!["a".to_string()]);
+        assert_eq!(past.blocks["a"].delta.as_deref(), Some("[{\"insert\":\"v1\"}]"));
+
+        // A peer receiving only the forward update ends up at the snapshot as well
+        let mut peer = DocumentService::new_with_options("doc".to_string(), DocumentOptions { skip_gc: true, ..Default::default() });
+        peer.apply_updates_incremental(updates).unwrap();
+        service.enable_undo(0);
+        let events = Arc::new(std::sync::Mutex::new(Vec::new()));
+        let sink = events.clone();
+        service.set_change_listener(Arc::new(move |event| sink.lock().unwrap().push(event)));
+        let restore = service.restore_snapshot(created.data).unwrap();
+        peer.apply_updates_incremental(vec![restore]).unwrap();
+        let received = std::mem::take(&mut *events.lock().unwrap());
+        assert!(!received.is_empty());
+        assert!(received.iter().all(|event| event.origin.as_deref() == Some(SNAPSHOT_RESTORE_ORIGIN)));
+
+        for state in [service.get_document_state().unwrap(), peer.get_document_state().unwrap()] {
+            assert_eq!(state.children_map["page"], vec!["a".to_string()]);
+            assert_eq!(state.blocks["a"].delta.as_deref(), Some("[{\"insert\":\"v1\"}]"));
+            assert!(!state.blocks.contains_key("b"));
+        }
+
+        // A restore is undone like a local edit
+        service.undo().unwrap();
+        let state = service.get_document_state().unwrap();
+        assert_eq!(state.blocks["a"].delta.as_deref(), Some("[{\"insert\":\"v1 edited\"}]"));
+        assert!(state.blocks.contains_key("b"));
+    }
+
+    #[test]
+    fn test_snapshots_require_gc_disabled() {
+        let mut service = DocumentService::new();
+        service.init_empty_doc().unwrap();
+        assert!(service.create_snapshot("label".to_string()).is_err());
+    }
+
+    #[test]
+    fn test_apply_updates_as_actions_patches_only_affected_blocks() {
+        let mut local = DocumentService::new();
+        let mut updates = vec![local.init_empty_doc().unwrap()];
+        updates.push(local.set_root_node_id("page".to_string()).unwrap());
+        updates.push(local.apply_action(vec![insert_action("page", None, None, "")]).unwrap());
+        updates.push(local.apply_action(vec![insert_action("a", Some("page"), None, "a")]).unwrap());
+        updates.push(local.apply_action(vec![insert_action("b", Some("page"), Some("a"), "b")]).unwrap());
+        updates.push(local.apply_action(vec![insert_action("c", Some("page"), Some("b"), "c")]).unwrap());
+
+        let mut remote = DocumentService::new();
+        let initial = remote.apply_updates_as_actions(updates).unwrap();
+        assert!(initial.requires_full_reload);
+
+        let mut edit = insert_action("a", Some("page"), None, "");
+        edit.action = BlockActionTypeDoc::Update;
+        edit.block.delta = Some("[{\"retain\":1},{\"insert\":\"!\"}]".to_string());
+        let mut delete = insert_action("b", Some("page"), None, "");
+        delete.action = BlockActionTypeDoc::Delete;
+        let batch = vec![
+            local.apply_action(vec![edit]).unwrap(),
+            local.apply_action(vec![delete]).unwrap(),
+            local.apply_action(vec![insert_action("d", Some("page"), Some("c"), "d")]).unwrap(),
+        ];
+
+        let result = remote.apply_updates_as_actions(batch).unwrap();
+        assert!(!result.requires_full_reload);
+        let summary: Vec<(BlockActionTypeDoc, String, Vec<u32>)> = result.actions
+            .iter()
+            .map(|action| (action.action, action.block.id.clone(), action.path.clone()))
+            .collect();
+        assert_eq!(summary, vec![
+            (BlockActionTypeDoc::Delete, "b".to_string(), vec![1]),
+            (BlockActionTypeDoc::Insert, "d".to_string(), vec![2]),
+            (BlockActionTypeDoc::Update, "a".to_string(), vec![0]),
+        ]);
+        assert_eq!(result.actions[2].block.delta.as_deref(), Some("[{\"retain\":1},{\"insert\":\"!\"}]"));
+
+        // Moving `a` after `c` reports a single move even though prevIds of several blocks changed
+        let mut move_action = insert_action("a", Some("page"), Some("c"), "");
+        move_action.action = BlockActionTypeDoc::Move;
+        move_action.block.old_parent_id = Some("page".to_string());
+        move_action.old_path = Some(vec![0]);
+        move_action.path = vec![1];
+        let result = remote.apply_updates_as_actions(vec![local.apply_action(vec![move_action]).unwrap()]).unwrap();
+        assert_eq!(result.actions.len(), 1);
+        assert!(matches!(result.actions[0].action, BlockActionTypeDoc::Move));
+        assert_eq!(result.actions[0].block.id, "a");
+        assert_eq!(result.actions[0].old_path, Some(vec![0]));
+        assert_eq!(result.actions[0].path, vec![1]);
+    }
+
+    #[test]
+    fn test_new_with_options_keeps_client_id_across_rebuilds() {
+        let options = DocumentOptions { client_id: Some(42), skip_gc: true, ..Default::default() };
+        let mut service = DocumentService::new_with_options("doc-1".to_string(), options);
+        assert_eq!(service.doc_id(), "doc-1");
+        assert_eq!(service.client_id(), 42);
+
+        let update = service.init_empty_doc().unwrap();
+        service.apply_updates(vec![update]).unwrap();
+        assert_eq!(service.client_id(), 42);
+        assert!(service.doc.skip_gc());
+    }
+
+    #[test]
+    fn test_apply_updates_tolerant_skips_corrupt_updates() {
+        let mut local = DocumentService::new();
+        let init = local.init_empty_doc().unwrap();
+        let root = local.set_root_node_id("page".to_string()).unwrap();
+        let mut truncated = local.apply_action(vec![insert_action("page", None, None, "")]).unwrap();
+        truncated.truncate(truncated.len() / 2);
+
+        let mut remote = DocumentService::new();
+        let failed = remote.apply_updates_tolerant(vec![
+            UpdateWithId { id: "1".to_string(), update: init },
+            UpdateWithId { id: "2".to_string(), update: truncated },
+            UpdateWithId { id: "3".to_string(), update: root },
+        ]).unwrap();
+
+        assert_eq!(failed.failed_updates_ids, vec!["2".to_string()]);
+        let state = remote.get_document_state().unwrap();
+        assert_eq!(state.root_id, "page");
+    }
+
+    #[test]
+    fn test_change_subscription_reports_block_events_with_origin() {
+        let mut local = DocumentService::new();
+        let mut updates = vec![local.init_empty_doc().unwrap()];
+        updates.push(local.set_root_node_id("page".to_string()).unwrap());
+        updates.push(local.apply_action(vec![insert_action("page", None, None, "")]).unwrap());
+
+        let mut service = DocumentService::new();
+        let events = Arc::new(std::sync::Mutex::new(Vec::new()));
+        let sink = events.clone();
+        service.set_change_listener(Arc::new(move |event| sink.lock().unwrap().push(event)));
+
+        // The subscription moves to the rebuilt document
+        service.apply_updates(updates).unwrap();
+        events.lock().unwrap().clear();
+
+        service.apply_action(vec![insert_action("a", Some("page"), None, "text")]).unwrap();
+        let mut edit = insert_action("a", Some("page"), None, "");
+        edit.action = BlockActionTypeDoc::Update;
+        edit.block.delta = Some("[{\"retain\":4},{\"insert\":\"!\"}]".to_string());
+        service.apply_action(vec![edit]).unwrap();
+
+        let received = std::mem::take(&mut *events.lock().unwrap());
+        let kinds: Vec<_> = received.iter().map(|event| (event.kind, event.block_id.as_str())).collect();
+        assert!(kinds.contains(&(DocumentChangeKindDoc::BlockInserted, "a")));
+        assert!(kinds.contains(&(DocumentChangeKindDoc::TextDeltaChanged, "a")));
+        assert!(received.iter().all(|event| event.origin.as_deref() == Some(LOCAL_ACTION_ORIGIN)));
+
+        let text_event = received.iter().find(|event| event.kind == DocumentChangeKindDoc::TextDeltaChanged).unwrap();
+        assert_eq!(text_event.delta.as_deref(), Some("[{\"retain\":4},{\"insert\":\"!\"}]"));
+
+        // Remote updates carry no origin
+        let update = local.apply_action(vec![insert_action("b", Some("page"), None, "remote")]).unwrap();
+        service.apply_updates_incremental(vec![update]).unwrap();
+        let received = std::mem::take(&mut *events.lock().unwrap());
+        assert!(received.iter().any(|event| event.kind == DocumentChangeKindDoc::BlockInserted && event.block_id == "b"));
+        assert!(received.iter().all(|event| event.origin.is_none()));
+
+        service.unsubscribe_changes();
+        service.apply_action(vec![insert_action("c", Some("page"), Some("a"), "")]).unwrap();
+        assert!(events.lock().unwrap().is_empty());
+    }
+
+    #[test]
+    fn test_awareness_selection_follows_remote_edits() {
+        let mut local = DocumentService::new();
+        let mut updates = vec![local.init_empty_doc().unwrap()];
+        updates.push(local.set_root_node_id("page".to_string()).unwrap());
+        updates.push(local.apply_action(vec![insert_action("page", None, None, "")]).unwrap());
+        updates.push(local.apply_action(vec![insert_action("a", Some("page"), None, "hello")]).unwrap());
+
+        let mut remote = DocumentService::new();
+        remote.apply_updates(updates).unwrap();
+
+        let position = |offset| TextPositionDoc { block_id: "a".to_string(), offset };
+        let user = AwarenessUserDoc { id: "user-1".to_string(), name: "Ada".to_string(), color: None };
+        let update = local.set_local_awareness(AwarenessStateDoc {
+            user: user.clone(),
+            selection: Some(AwarenessSelectionDoc { anchor: position(1), head: position(3) }),
+        }).unwrap();
+
+        let changes = remote.apply_awareness_update(update).unwrap();
+        assert_eq!(changes.added, vec![local.client_id()]);
+
+        // Text inserted in front of the selection shifts it
+        let mut edit = insert_action("a", Some("page"), None, "");
+        edit.action = BlockActionTypeDoc::Update;
+        edit.block.delta = Some("[{\"insert\":\">> \"}]".to_string());
+        remote.apply_updates_incremental(vec![local.apply_action(vec![edit]).unwrap()]).unwrap();
+
+        let peers = remote.get_awareness_peers();
+        assert_eq!(peers.len(), 1);
+        assert!(!peers[0].is_local);
+        assert_eq!(peers[0].user, Some(user));
+        assert_eq!(peers[0].selection, Some(AwarenessSelectionDoc { anchor: position(4), head: position(6) }));
+
+        // The selection is resolved in the block it was made in and goes away with it
+        let mut delete = insert_action("a", Some("page"), None, "");
+        delete.action = BlockActionTypeDoc::Delete;
+        remote.apply_updates_incremental(vec![local.apply_action(vec![delete]).unwrap()]).unwrap();
+        assert_eq!(remote.get_awareness_peers()[0].selection, None);
+
+        let changes = remote.apply_awareness_update(local.clear_local_awareness().unwrap()).unwrap();
+        assert_eq!(changes.removed, vec![local.client_id()]);
+        assert!(remote.get_awareness_peers().is_empty());
+    }
+
+    #[test]
+    fn test_awareness_timeouts_drop_silent_peers() {
+        let mut local = DocumentService::new();
+        let mut remote = DocumentService::new();
+        let user = AwarenessUserDoc { id: "user-1".to_string(), name: "Ada".to_string(), color: Some("#ff0000".to_string()) };
+        let update = local.set_local_awareness(AwarenessStateDoc { user, selection: None }).unwrap();
+        remote.apply_awareness_update(update).unwrap();
+
+        assert!(remote.check_awareness_timeouts(30_000).unwrap().removed.is_empty());
+        let timeouts = remote.check_awareness_timeouts(0).unwrap();
+        assert_eq!(timeouts.removed, vec![local.client_id()]);
+        assert!(timeouts.update.is_none());
+
+        // The local state is renewed instead of dropped
+        let timeouts = local.check_awareness_timeouts(0).unwrap();
+        assert!(timeouts.removed.is_empty());
+        assert!(timeouts.update.is_some());
+        assert_eq!(local.get_awareness_peers().len(), 1);
+    }
+
+    #[test]
+    fn test_sticky_positions_survive_remote_edits() {
+        let mut local = DocumentService::new();
+        let mut updates = vec![local.init_empty_doc().unwrap()];
+        updates.push(local.set_root_node_id("page".to_string()).unwrap());
+        updates.push(local.apply_action(vec![insert_action("page", None, None, "")]).unwrap());
+        updates.push(local.apply_action(vec![insert_action("a", Some("page"), None, "h\u{e9} \u{1f44b} you")]).unwrap());
+
+        let mut remote = DocumentService::new();
+        remote.apply_updates(updates).unwrap();
+
+        let position = |offset| TextPositionDoc { block_id: "a".to_string(), offset };
+        // Right after the emoji, which takes two UTF-16 code units
+        let after_emoji = remote.encode_sticky_position(position(5), StickyAssocDoc::After).unwrap();
+        let end = remote.encode_sticky_position(position(9), StickyAssocDoc::After).unwrap();
+        assert!(remote.encode_sticky_position(position(4), StickyAssocDoc::After).is_err());
+        assert!(remote.encode_sticky_position(position(10), StickyAssocDoc::After).is_err());
+
+        let mut edit = insert_action("a", Some("page"), None, "");
+        edit.action = BlockActionTypeDoc::Update;
+        edit.block.delta = Some("[{\"insert\":\">> \"}]".to_string());
+        remote.apply_updates_incremental(vec![local.apply_action(vec![edit]).unwrap()]).unwrap();
+
+        assert_eq!(remote.decode_sticky_position("a".to_string(), after_emoji).unwrap(), Some(position(8)));
+        assert_eq!(remote.decode_sticky_position("a".to_string(), end.clone()).unwrap(), Some(position(12)));
+        // An index only resolves within the text of the block it was encoded in
+        assert_eq!(remote.decode_sticky_position("page".to_string(), end.clone()).unwrap(), None);
+
+        let mut delete = insert_action("a", Some("page"), None, "");
+        delete.action = BlockActionTypeDoc::Delete;
+        remote.apply_updates_incremental(vec![local.apply_action(vec![delete]).unwrap()]).unwrap();
+        assert_eq!(remote.decode_sticky_position("a".to_string(), end).unwrap(), None);
+    }
+
+    #[test]
+    fn test_sync_protocol_handshake_and_updates() {
+        let mut local = DocumentService::new();
+        let mut updates = vec![local.init_empty_doc().unwrap()];
+        updates.push(local.set_root_node_id("page".to_string()).unwrap());
+        updates.push(local.apply_action(vec![insert_action("page", None, None, "")]).unwrap());
+
+        // The server is rebuilt from stored updates, the client starts empty
+        let mut server = DocumentService::new();
+        server.apply_updates(updates).unwrap();
+        let mut client = DocumentService::new();
+
+        let hello = client.start_sync().unwrap();
+        let messages = DocumentService::decode_sync_messages(hello.clone()).unwrap();
+        let kinds: Vec<_> = messages.iter().map(|message| message.kind).collect();
+        assert_eq!(kinds, vec![SyncMessageKindDoc::SyncStep1, SyncMessageKindDoc::Awareness]);
+        let reencoded: Vec<u8> = messages
+            .into_iter()
+            .flat_map(|message| DocumentService::encode_sync_message(message).unwrap())
+            .collect();
+        assert_eq!(reencoded, hello);
+
+        let answer = server.handle_sync_message(hello).unwrap();
+        assert!(!answer.document_changed);
+        let reply = answer.reply.expect("sync step 1 is answered with step 2");
+        let kinds: Vec<_> = DocumentService::decode_sync_messages(reply.clone()).unwrap()
+            .into_iter()
+            .map(|message| message.kind)
+            .collect();
+        assert_eq!(kinds, vec![SyncMessageKindDoc::SyncStep2]);
+
+        let applied = client.handle_sync_message(reply).unwrap();
+        assert!(applied.document_changed);
+        assert!(applied.reply.is_none());
+        assert_eq!(client.get_document_state().unwrap().root_id, "page");
+
+        // Incremental local edits travel as Update messages
+        let update = client.apply_action(vec![insert_action("a", Some("page"), None, "hi")]).unwrap();
+        let message = DocumentService::encode_update_message(update, UpdateEncodingDoc::V2).unwrap();
+        assert!(server.handle_sync_message(message).unwrap().document_changed);
+        assert_eq!(server.get_document_state().unwrap().children_map["page"], vec!["a".to_string()]);
+    }
+
+    #[test]
+    fn test_compact_updates_replaces_log_with_checkpoint() {
+        let mut local = DocumentService::new();
+        let mut updates = vec![local.init_empty_doc().unwrap()];
+        updates.push(local.set_root_node_id("page".to_string()).unwrap());
+        updates.push(local.apply_action(vec![insert_action("page", None, None, "")]).unwrap());
+        updates.push(local.apply_action(vec![insert_action("a", Some("page"), None, "kept")]).unwrap());
+        updates.push(local.apply_action(vec![insert_action("b", Some("page"), Some("a"), &"removed ".repeat(50))]).unwrap());
+        let mut delete = insert_action("b", Some("page"), Some("a"), "");
+        delete.action = BlockActionTypeDoc::Delete;
+        updates.push(local.apply_action(vec![delete]).unwrap());
+
+        let kept = local.compact_updates(updates.clone(), false, UpdateEncodingDoc::V2).unwrap();
+        let collected = local.compact_updates(updates, true, UpdateEncodingDoc::V2).unwrap();
+        assert!(collected.update.len() < kept.update.len());
+        assert!(!collected.has_pending_updates);
+        assert_eq!(collected.state_vector, local.encode_state_vector(UpdateEncodingDoc::V2).unwrap());
+        assert_eq!(local.compact_document(true, UpdateEncodingDoc::V2).unwrap().state_vector, collected.state_vector);
+
+        // Rows written after the checkpoint still apply on top of it
+        let later = local.apply_action(vec![insert_action("c", Some("page"), Some("a"), "later")]).unwrap();
+        for checkpoint in [kept, collected] {
+            let mut remote = DocumentService::new();
+            remote.apply_updates(vec![checkpoint.update, later.clone()]).unwrap();
+            let state = remote.get_document_state().unwrap();
+            assert_eq!(state.children_map["page"], vec!["a".to_string(), "c".to_string()]);
+            assert!(!state.blocks.contains_key("b"));
+        }
+    }
+
+    #[test]
+    fn test_v1_output_encoding_is_detected_and_converted() {
+        let options = DocumentOptions { update_encoding: UpdateEncodingDoc::V1, ..Default::default() };
+        let mut local = DocumentService::new_with_options("doc".to_string(), options);
+        let mut updates = vec![local.init_empty_doc().unwrap()];
+        updates.push(local.set_root_node_id("page".to_string()).unwrap());
+        updates.push(local.apply_action(vec![insert_action("page", None, None, "")]).unwrap());
+        updates.push(local.apply_action(vec![insert_action("a", Some("page"), None, "v1 text")]).unwrap());
+        for update in &updates {
+            assert_eq!(DocumentService::detect_update_encoding(update.clone()), Some(UpdateEncodingDoc::V1));
+        }
+
+        // A default service accepts v1 and v2 updates mixed in one batch
+        let v2 = DocumentService::convert_update(updates[3].clone(), None, UpdateEncodingDoc::V2).unwrap();
+        assert_eq!(DocumentService::detect_update_encoding(v2.clone()), Some(UpdateEncodingDoc::V2));
+        let mut remote = DocumentService::new();
+        remote.apply_updates(vec![updates[0].clone(), updates[1].clone(), updates[2].clone(), v2.clone()]).unwrap();
+        assert_eq!(remote.get_document_state().unwrap().children_map["page"], vec!["a".to_string()]);
+
+        let back = DocumentService::convert_update(v2, Some(UpdateEncodingDoc::V2), UpdateEncodingDoc::V1).unwrap();
+        assert_eq!(Update::decode_v1(&back).unwrap().state_vector(), Update::decode_v1(&updates[3]).unwrap().state_vector());
+        assert_eq!(DocumentService::detect_update_encoding(vec![0xff, 0xff, 0xff]), None);
+    }
+
+    #[test]
+    fn test_init_doc_from_markdown() {
+        let markdown = "# Title\n\nSome **bold** and *italic* with `code` and [a link](https://appflowy.io).\n\n\
+            - first\n  - nested\n- [x] done\n\n3. three\n4. four\n\n> quoted\n\n```rust\nfn main() {}\n```\n\n---\n\n\
+            | a | b |\n|---|---|\n| 1 | ~~2~~ |\n";
+        let mut local = DocumentService::new();
+        let update = local.init_doc_from_markdown(markdown.to_string()).unwrap();
+        assert!(local.init_doc_from_markdown("again".to_string()).is_err());
+
+        let mut remote = DocumentService::new();
+        remote.apply_updates(vec![update]).unwrap();
+        let state = remote.get_document_state().unwrap();
+        let block = |id: &String| &state.blocks[id];
+        let children = |id: &String| state.children_map.get(id).cloned().unwrap_or_default();
+
+        let top = children(&state.root_id);
+        let types: Vec<&str> = top.iter().map(|id| block(id).ty.as_str()).collect();
+        assert_eq!(types, vec![
+            "heading", "paragraph", "bulleted_list", "todo_list", "numbered_list", "numbered_list",
+            "quote", "code", "divider", "table",
+        ]);
+        assert_eq!(block(&top[0]).attributes["level"], "1");
+        assert_eq!(
+            block(&top[1]).delta.as_deref().unwrap(),
+            r#"[{"insert":"Some "},{"attributes":{"bold":true},"insert":"bold"},{"insert":" and "},{"attributes":{"italic":true},"insert":"italic"},{"insert":" with "},{"attributes":{"code":true},"insert":"code"},{"insert":" and "},{"attributes":{"href":"https://appflowy.io"},"insert":"a link"},{"insert":"."}]"#
+        );
+        let nested = children(&top[2]);
+        assert_eq!(block(&nested[0]).delta.as_deref().unwrap(), r#"[{"insert":"nested"}]"#);
+        assert_eq!(block(&top[3]).attributes["checked"], "true");
+        assert_eq!(block(&top[4]).attributes["number"], "3");
+        assert!(!block(&top[5]).attributes.contains_key("number"));
+        assert_eq!(block(&top[7]).attributes["language"], "rust");
+        assert_eq!(block(&top[7]).delta.as_deref().unwrap(), r#"[{"insert":"fn main() {}"}]"#);
+
+        let table = block(&top[9]);
+        assert_eq!((table.attributes["rowsLen"].as_str(), table.attributes["colsLen"].as_str()), ("2", "2"));
+        let cells = children(&top[9]);
+        assert_eq!(cells.len(), 4);
+        let last = block(&cells[3]);
+        assert_eq!((last.attributes["rowPosition"].as_str(), last.attributes["colPosition"].as_str()), ("1", "1"));
+        let cell_text = children(&cells[3]);
+        assert_eq!(block(&cell_text[0]).delta.as_deref().unwrap(), r#"[{"attributes":{"strikethrough":true},"insert":"2"}]"#);
+    }
+
+    #[test]
+    fn test_export_markdown() {
+        let markdown = "# Title\n\nSome **bold** and *italic* with `code`, [a link](https://appflowy.io) and 2 * 3.\n\n\
+            - first\n  - nested\n- [x] done\n\n3. three\n4. four\n\n> quoted\n\n```rust\nfn main() {}\n```\n\n---\n\n\
+            ![](https://appflowy.io/logo.png)\n\n| a | b |\n|---|---|\n| 1 | ~~2~~ |\n";
+        let mut local = DocumentService::new();
+        local.init_doc_from_markdown(markdown.to_string()).unwrap();
+        let exported = local.export_markdown().unwrap();
+        assert_eq!(exported, "# Title\n\nSome **bold** and *italic* with `code`, [a link](https://appflowy.io) and 2 \\* 3.\n\n\
+            - first\n  - nested\n- [x] done\n3. three\n4. four\n\n> quoted\n\n```rust\nfn main() {}\n```\n\n---\n\n\
+            ![](https://appflowy.io/logo.png)\n\n| a | b |\n|---|---|\n| 1 | ~~2~~ |\n");
+
+        // Exported Markdown imports back into the same document
+        let mut reimported = DocumentService::new();
+        reimported.init_doc_from_markdown(exported.clone()).unwrap();
+        assert_eq!(reimported.export_markdown().unwrap(), exported);
+    }
+
+    #[test]
+    fn test_export_html_escapes_and_sanitizes() {
+        let mut local = DocumentService::new();
+        local.init_doc_from_markdown("# A < b\n\n- one\n- two\n\n3. three\n\n```js\nif (a < b) {}\n```\n\n![](javascript:alert(1))".to_string()).unwrap();
+        let state = local.get_document_state().unwrap();
+        let last_id = state.children_map[&state.root_id].last().unwrap();
+        let mut styled = insert_action("styled", Some(&state.root_id), Some(last_id), "");
+        styled.block.delta = Some(serde_json::json!([
+            {"insert": "safe", "attributes": {"bold": true, "underline": true, "href": "https://appflowy.io?a=1&b=2"}},
+            {"insert": " evil", "attributes": {"href": " JaVa\tScript:alert(1)", "font_color": "0xFFFF0000", "bg_color": "red;background:url(x)"}},
+        ]).to_string());
+        local.apply_action(vec![styled]).unwrap();
+
+        assert_eq!(local.export_html().unwrap(), concat!(
+            "<h1>A &lt; b</h1><ul><li>one</li><li>two</li></ul><ol start=\"3\"><li>three</li></ol>",
+            "<pre><code class=\"language-js\">if (a &lt; b) {}</code></pre>",
+            "<p><a href=\"https://appflowy.io?a=1&amp;b=2\" rel=\"noopener noreferrer\"><strong><u>safe</u></strong></a>",
+            "<span style=\"color: rgba(255, 0, 0, 1.000)\"> evil</span></p>",
+        ));
+    }
+
+    #[test]
+    fn test_init_doc_from_json() {
+        let nested = serde_json::json!({"document": {"type": "page", "children": [
+            {"type": "heading", "data": {"level": 2, "delta": [{"insert": "Title"}]}},
+            {"id": "list", "type": "todo_list", "data": {"checked": false, "delta": [{"insert": "task"}]}, "children": [
+                {"type": "paragraph", "delta": [{"insert": "old style", "attributes": {"bold": true}}]},
+            ]},
+            {"type": "divider", "data": {}},
+        ]}});
+        let mut imported = DocumentService::new();
+        let update = imported.init_doc_from_json(nested.to_string()).unwrap();
+
+        let mut remote = DocumentService::new();
+        remote.apply_updates(vec![update]).unwrap();
+        let state = remote.get_document_state().unwrap();
+        let top = &state.children_map[&state.root_id];
+        assert_eq!(top.len(), 3);
+        assert_eq!(state.blocks[&top[0]].attributes["level"], "2");
+        assert_eq!(top[1], "list");
+        assert_eq!(state.blocks["list"].attributes["checked"], "false");
+        let nested_child = &state.blocks[&state.children_map["list"][0]];
+        assert_eq!(nested_child.delta.as_deref().unwrap(), r#"[{"attributes":{"bold":true},"insert":"old style"}]"#);
+        assert_eq!(state.blocks[&top[2]].ty, "divider");
+
+        // The flat JSON of an existing document imports back into the same tree
+        let flat = Conversion::document_to_json(&state).unwrap();
+        let mut copy = DocumentService::new();
+        copy.init_doc_from_json(flat.to_string()).unwrap();
+        let copied = copy.get_document_state().unwrap();
+        assert_eq!(copied.root_id, state.root_id);
+        assert_eq!(copied.children_map, state.children_map);
+        assert_eq!(copy.export_markdown().unwrap(), remote.export_markdown().unwrap());
+
+        let duplicate = serde_json::json!({"document": {"id": "a", "type": "page", "children": [{"id": "a", "type": "paragraph"}]}});
+        assert!(DocumentService::new().init_doc_from_json(duplicate.to_string()).is_err());
+    }
+
+    #[test]
+    fn test_json_attribute_mode_keeps_types() {
+        let options = DocumentOptions { attribute_mode: AttributeModeDoc::Json, ..Default::default() };
+        let mut local = DocumentService::new_with_options("doc".to_string(), options.clone());
+        let mut updates = vec![local.init_empty_doc().unwrap()];
+        updates.push(local.set_root_node_id("page".to_string()).unwrap());
+        updates.push(local.apply_action(vec![insert_action("page", None, None, "")]).unwrap());
+        let mut todo = insert_action("a", Some("page"), None, "task");
+        todo.block.attributes = HashMap::from([
+            ("checked".to_string(), "true".to_string()),
+            ("level".to_string(), "2".to_string()),
+            ("widths".to_string(), "[1,2]".to_string()),
+            ("label".to_string(), "\"plain text\"".to_string()),
+        ]);
+        updates.push(local.apply_action(vec![todo]).unwrap());
+
+        // Text that is not quoted is rejected instead of guessed at
+        let mut unquoted = insert_action("b", Some("page"), Some("a"), "");
+        unquoted.block.attributes = HashMap::from([("label".to_string(), "plain text".to_string())]);
+        assert!(local.apply_action(vec![unquoted]).is_err());
+
+        let mut typed = DocumentService::new_with_options("doc".to_string(), options);
+        typed.apply_updates(updates.clone()).unwrap();
+        let attributes = &typed.get_document_state().unwrap().blocks["a"].attributes;
+        assert_eq!(attributes["checked"], "true");
+        assert_eq!(attributes["level"], "2");
+        assert_eq!(attributes["widths"], "[1,2]");
+        assert_eq!(attributes["label"], "\"plain text\"");
+
+        // Peers in the string mode still read the values they used to
+        let mut strings = DocumentService::new();
+        strings.apply_updates(updates).unwrap();
+        let attributes = &strings.get_document_state().unwrap().blocks["a"].attributes;
+        assert_eq!(attributes["checked"], "true");
+        assert_eq!(attributes["level"], "2");
+        assert_eq!(attributes["label"], "plain text");
+    }
+
+    #[test]
+    fn test_block_index_follows_remote_updates() {
+        let mut local = DocumentService::new();
+        let mut updates = vec![local.init_empty_doc().unwrap()];
+        updates.push(local.set_root_node_id("page".to_string()).unwrap());
+        updates.push(local.apply_action(vec![insert_action("page", None, None, "")]).unwrap());
+        updates.push(local.apply_action(vec![insert_action("a", Some("page"), None, "a")]).unwrap());
+        updates.push(local.apply_action(vec![insert_action("b", Some("page"), Some("a"), "b")]).unwrap());
+
+        let mut remote = DocumentService::new();
+        remote.apply_updates_incremental(updates).unwrap();
+        let remote_updates = vec![
+            remote.apply_action(vec![insert_action("c", Some("page"), Some("a"), "c")]).unwrap(),
+            remote.apply_action(vec![insert_action("x", Some("b"), None, "nested")]).unwrap(),
+        ];
+        local.apply_updates_incremental(remote_updates).unwrap();
+
+        // Both edits only find the remote blocks if the index was rebuilt
+        local.apply_action(vec![insert_action("d", Some("page"), Some("a"), "d")]).unwrap();
+        let mut delete = insert_action("b", Some("page"), Some("c"), "");
+        delete.action = BlockActionTypeDoc::Delete;
+        local.apply_action(vec![delete]).unwrap();
+
+        let state = local.get_document_state().unwrap();
+        assert_eq!(state.children_map["page"], vec!["a".to_string(), "d".to_string(), "c".to_string()]);
+        assert!(!state.blocks.contains_key("x"));
+    }
+
+    #[test]
+    fn test_fractional_ordering_converges_on_concurrent_inserts() {
+        let mut local = DocumentService::new();
+        let mut updates = vec![local.init_empty_doc().unwrap()];
+        updates.push(local.set_root_node_id("page".to_string()).unwrap());
+        updates.push(local.apply_action(vec![insert_action("page", None, None, "")]).unwrap());
+        updates.push(local.apply_action(vec![
+            insert_action("a", Some("page"), None, "a"),
+            insert_action("b", Some("page"), Some("a"), "b"),
+            insert_action("c", Some("page"), Some("b"), "c"),
+        ]).unwrap());
+        let order = |service: &DocumentService| service.get_document_state().unwrap().children_map["page"].clone();
+
+        // The migration keeps the chain order
+        assert_eq!(local.sibling_ordering(), SiblingOrderingDoc::PrevIdChain);
+        updates.push(local.enable_fractional_ordering().unwrap());
+        assert_eq!(local.sibling_ordering(), SiblingOrderingDoc::FractionalIndex);
+        assert_eq!(order(&local), vec!["a", "b", "c"]);
+
+        let mut remote = DocumentService::new();
+        remote.apply_updates(updates).unwrap();
+        assert_eq!(order(&remote), vec!["a", "b", "c"]);
+
+        // Both peers insert after `a` at the same time and end up with the same order
+        let local_update = local.apply_action(vec![insert_action("x", Some("page"), Some("a"), "x")]).unwrap();
+        let remote_update = remote.apply_action(vec![insert_action("y", Some("page"), Some("a"), "y")]).unwrap();
+        local.apply_updates_incremental(vec![remote_update]).unwrap();
+        remote.apply_updates_incremental(vec![local_update]).unwrap();
+        let converged = order(&local);
+        assert_eq!(converged, order(&remote));
+        assert_eq!(converged[0], "a");
+        assert!(converged[1..3].contains(&"x".to_string()) && converged[1..3].contains(&"y".to_string()));
+        assert_eq!(converged[3..], ["b", "c"]);
+
+        // Moving `c` to the front only rewrites its own key
+        let mut move_action = insert_action("c", Some("page"), None, "");
+        move_action.action = BlockActionTypeDoc::Move;
+        move_action.block.old_parent_id = Some("page".to_string());
+        move_action.old_path = Some(vec![4]);
+        move_action.path = vec![0];
+        remote.apply_updates_incremental(vec![local.apply_action(vec![move_action]).unwrap()]).unwrap();
+        assert_eq!(order(&remote)[0], "c");
+        assert_eq!(order(&remote), order(&local));
+    }
+
+    #[test]
+    fn test_repair_chains_heals_cycles_and_dangling_links() {
+        let mut local = DocumentService::new();
+        let mut updates = vec![local.init_empty_doc().unwrap()];
+        updates.push(local.set_root_node_id("page".to_string()).unwrap());
+        updates.push(local.apply_action(vec![insert_action("page", None, None, "")]).unwrap());
+        updates.push(local.apply_action(vec![
+            insert_action("a", Some("page"), None, "a"),
+            insert_action("b", Some("page"), Some("a"), "b"),
+            insert_action("c", Some("page"), Some("b"), "c"),
+            insert_action("d", Some("page"), Some("c"), "d"),
+        ]).unwrap());
+        let mut remote = DocumentService::new();
+        remote.apply_updates(updates).unwrap();
+
+        // Links no single edit would write, as left behind by concurrent moves and deletes
+        {
+            let root = local.doc.get_or_insert_map(ROOT_ID);
+            let mut txn = local.doc.transact_mut();
+            let Some(Out::YMap(blocks_map)) = root.get(&txn, BLOCKS) else { panic!("no blocks map") };
+            for (id, prev_id) in [("a", "c"), ("d", "gone")] {
+                let Some(Out::YMap(block)) = blocks_map.get(&txn, id) else { panic!("no block {}", id) };
+                block.insert(&mut txn, PREV_ID, prev_id);
+            }
+        }
+        let order = |service: &DocumentService| service.get_document_state().unwrap().children_map["page"].clone();
+        let sync = |from: &DocumentService, to: &mut DocumentService| {
+            let state_vector = to.encode_state_vector(UpdateEncodingDoc::V2).unwrap();
+            to.apply_updates_incremental(vec![from.encode_diff_since(state_vector, UpdateEncodingDoc::V2).unwrap()]).unwrap();
+        };
+        sync(&local, &mut remote);
+        let broken_order = order(&local);
+        assert_eq!(broken_order.len(), 4);
+        assert_eq!(order(&remote), broken_order);
+
+        // Both peers repair at the same time and write the same links
+        let repair = local.repair_chains().unwrap();
+        assert_eq!(repair.cycle_block_ids.len(), 1);
+        assert!(["a", "b", "c"].contains(&repair.cycle_block_ids[0].as_str()));
+        assert_eq!(repair.dangling_block_ids, vec!["d"]);
+        assert!(!repair.update.is_empty());
+        let remote_repair = remote.repair_chains().unwrap();
+        assert_eq!(remote_repair.cycle_block_ids, repair.cycle_block_ids);
+        sync(&local, &mut remote);
+        sync(&remote, &mut local);
+
+        // The shown order is kept and nothing is left to repair
+        assert_eq!(order(&local), broken_order);
+        assert_eq!(order(&remote), broken_order);
+        let again = local.repair_chains().unwrap();
+        assert!(again.cycle_block_ids.is_empty() && again.dangling_block_ids.is_empty() && again.update.is_empty());
+    }
+
+    #[test]
+    fn test_validate_document_reports_and_fixes_structure() {
+        let mut local = DocumentService::new();
+        let mut updates = vec![local.init_empty_doc().unwrap()];
+        updates.push(local.set_root_node_id("page".to_string()).unwrap());
+        updates.push(local.apply_action(vec![insert_action("page", None, None, "")]).unwrap());
+        updates.push(local.apply_action(vec![
+            insert_action("a", Some("page"), None, "a"),
+            insert_action("b", Some("page"), Some("a"), "b"),
+            insert_action("c", Some("page"), Some("b"), "c"),
+        ]).unwrap());
+        let mut remote = DocumentService::new();
+        remote.apply_updates(updates).unwrap();
+        assert!(local.validate_document(false).unwrap().issues.is_empty());
+
+        // Blocks written by buggy or concurrent clients
+        {
+            let root = local.doc.get_or_insert_map(ROOT_ID);
+            let mut txn = local.doc.transact_mut();
+            let blocks_map = root.get_or_init_map(&mut txn, BLOCKS);
+            let block = |txn: &mut yrs::TransactionMut, id: &str| blocks_map.get_or_init_map(txn, id);
+            block(&mut txn, "a").remove(&mut txn, TYPE);
+            block(&mut txn, "b").remove(&mut txn, ID);
+            block(&mut txn, "c").insert(&mut txn, PARENT_ID, "gone");
+            for (id, parent_id, prev_id) in [("o", None, None), ("p1", Some("p2"), None), ("p2", Some("p1"), None), ("d", Some("page"), Some("a"))] {
+                let new_block = block(&mut txn, id);
+                new_block.insert(&mut txn, ID, id);
+                new_block.insert(&mut txn, TYPE, "paragraph");
+                if let Some(parent_id) = parent_id {
+                    new_block.insert(&mut txn, PARENT_ID, parent_id);
+                }
+                if let Some(prev_id) = prev_id {
+                    new_block.insert(&mut txn, PREV_ID, prev_id);
+                }
+            }
+        }
+        let shown_order = local.get_document_state().unwrap().children_map["page"].clone();
+
+        let ids = |ids: &[&str]| ids.iter().map(|id| id.to_string()).collect::<Vec<_>>();
+        let report = local.validate_document(true).unwrap();
+        assert_eq!(report.issues, vec![
+            IntegrityIssueDoc { kind: IntegrityIssueKindDoc::MissingId, block_ids: ids(&["b"]) },
+            IntegrityIssueDoc { kind: IntegrityIssueKindDoc::MissingType, block_ids: ids(&["a"]) },
+            IntegrityIssueDoc { kind: IntegrityIssueKindDoc::MissingParent, block_ids: ids(&["c"]) },
+            IntegrityIssueDoc { kind: IntegrityIssueKindDoc::Orphan, block_ids: ids(&["o"]) },
+            IntegrityIssueDoc { kind: IntegrityIssueKindDoc::ParentCycle, block_ids: ids(&["p1", "p2"]) },
+            IntegrityIssueDoc { kind: IntegrityIssueKindDoc::SharedPrevId, block_ids: ids(&["b", "d"]) },
+        ]);
+
+        // Cut off blocks are appended to the root, siblings keep their order
+        assert!(local.validate_document(false).unwrap().issues.is_empty());
+        let state = local.get_document_state().unwrap();
+        // The cut parent cycle was already shown last under the root
+        let mut expected: Vec<String> = shown_order.iter().filter(|id| *id != "p1").cloned().collect();
+        assert_eq!(shown_order.last().map(String::as_str), Some("p1"));
+        expected.extend(ids(&["c", "o", "p1"]));
+        assert_eq!(state.children_map["page"], expected);
+        assert_eq!(state.children_map["p1"], vec!["p2"]);
+        assert_eq!(state.blocks["a"].ty, "paragraph");
+
+        remote.apply_updates_incremental(vec![local.encode_diff_since(
+            remote.encode_state_vector(UpdateEncodingDoc::V2).unwrap(),
+            UpdateEncodingDoc::V2
+        ).unwrap()]).unwrap();
+        assert_eq!(remote.get_document_state().unwrap().children_map["page"], expected);
+
+        // A root id without its block gets the block back
+        let mut rootless = DocumentService::new();
+        rootless.init_empty_doc().unwrap();
+        rootless.set_root_node_id("page".to_string()).unwrap();
+        rootless.apply_action(vec![insert_action("a", Some("page"), None, "a")]).unwrap();
+        let report = rootless.validate_document(true).unwrap();
+        assert_eq!(report.issues, vec![IntegrityIssueDoc { kind: IntegrityIssueKindDoc::MissingRoot, block_ids: ids(&["page"]) }]);
+        let state = rootless.get_document_state().unwrap();
+        assert_eq!(state.blocks["page"].ty, "page");
+        assert_eq!(state.children_map["page"], vec!["a"]);
+    }
+
+    #[test]
+    fn test_apply_action_rejects_whole_batch_with_action_index() {
+        let mut service = DocumentService::new();
+        service.init_empty_doc().unwrap();
+        service.set_root_node_id("page".to_string()).unwrap();
+        service.apply_action(vec![
+            insert_action("page", None, None, ""),
+            insert_action("a", Some("page"), None, "a"),
+            insert_action("b", Some("page"), Some("a"), "b"),
+        ]).unwrap();
+        let state_vector = service.encode_state_vector(UpdateEncodingDoc::V1).unwrap();
+
+        let mut update_a = insert_action("a", Some("page"), None, "");
+        update_a.action = BlockActionTypeDoc::Update;
+        update_a.block.delta = Some(r#"[{"retain": 1}, {"insert": "!"}]"#.to_string());
+        let mut delete_missing = insert_action("missing", Some("page"), None, "");
+        delete_missing.action = BlockActionTypeDoc::Delete;
+        let mut delete_b = insert_action("b", Some("page"), Some("a"), "");
+        delete_b.action = BlockActionTypeDoc::Delete;
+        let mut update_b = insert_action("b", Some("page"), Some("a"), "");
+        update_b.action = BlockActionTypeDoc::Update;
+        let mut overlong_retain = insert_action("c", Some("page"), Some("b"), "");
+        overlong_retain.action = BlockActionTypeDoc::Update;
+        overlong_retain.block.delta = Some(r#"[{"retain": 2}]"#.to_string());
+
+        for (batch, rejected) in [
+            (vec![insert_action("c", Some("page"), Some("b"), "c"), update_a.clone(), delete_missing], 2),
+            (vec![update_a.clone(), delete_b.clone(), update_b], 2),
+            (vec![insert_action("c", Some("page"), Some("b"), "c"), overlong_retain], 1),
+        ] {
+            let error = service.apply_action(batch).unwrap_err();
+            assert_eq!(error.action_index, Some(rejected), "{}", error.message);
+            // Nothing of the rejected batch reached the document
+            assert_eq!(service.encode_state_vector(UpdateEncodingDoc::V1).unwrap(), state_vector);
+        }
+
+        // Later actions see what earlier ones did, including blocks deleted with their parent
+        let mut delete_x = insert_action("x", Some("page"), Some("b"), "");
+        delete_x.action = BlockActionTypeDoc::Delete;
+        let mut update_y = insert_action("y", Some("x"), None, "");
+        update_y.action = BlockActionTypeDoc::Update;
+        let batch = vec![
+            insert_action("x", Some("page"), Some("b"), "x"),
+            insert_action("y", Some("x"), None, "y"),
+            update_a,
+            delete_x,
+        ];
+        service.apply_action(batch).unwrap();
+        let state = service.get_document_state().unwrap();
+        assert_eq!(state.children_map["page"], vec!["a", "b"]);
+        assert!(!state.blocks.contains_key("y"));
+        assert_eq!(service.apply_action(vec![update_y]).unwrap_err().action_index, Some(0));
+    }
+
+    #[test]
+    fn test_moves_into_own_subtree_are_rejected_and_merged_cycles_resolved() {
+        let mut local = DocumentService::new();
+        let mut updates = vec![local.init_empty_doc().unwrap()];
+        updates.push(local.set_root_node_id("page".to_string()).unwrap());
+        updates.push(local.apply_action(vec![
+            insert_action("page", None, None, ""),
+            insert_action("a", Some("page"), None, "a"),
+            insert_action("b", Some("page"), Some("a"), "b"),
+            insert_action("a1", Some("a"), None, "a1"),
+        ]).unwrap());
+        let mut remote = DocumentService::new();
+        remote.apply_updates(updates).unwrap();
+
+        let move_action = |id: &str, parent_id: &str, prev_id: Option<&str>| {
+            let mut action = insert_action(id, Some(parent_id), prev_id, "");
+            action.action = BlockActionTypeDoc::Move;
+            action.block.old_parent_id = Some("page".to_string());
+            action.old_path = Some(vec![0]);
+            action.path = vec![0, 0];
+            action
+        };
+
+        // Neither the block itself nor anything nested under it can become its parent
+        let state_vector = local.encode_state_vector(UpdateEncodingDoc::V1).unwrap();
+        for batch in [
+            vec![move_action("a", "a", None)],
+            vec![move_action("a", "a1", None)],
+            vec![move_action("b", "a1", None), move_action("a", "b", None)],
+        ] {
+            let rejected = batch.len() - 1;
+            let error = local.apply_action(batch).unwrap_err();
+            assert_eq!(error.action_index, Some(rejected as u32), "{}", error.message);
+        }
+        assert_eq!(local.encode_state_vector(UpdateEncodingDoc::V1).unwrap(), state_vector);
+
+        // Each move is fine on its own, together they make `a` and `b` each other's parent
+        let local_update = local.apply_action(vec![move_action("a", "b", None)]).unwrap();
+        let remote_update = remote.apply_action(vec![move_action("b", "a", Some("a1"))]).unwrap();
+        local.apply_updates_incremental(vec![remote_update]).unwrap();
+        remote.apply_updates_incremental(vec![local_update]).unwrap();
+
+        // Both replicas cut the cycle at `a`, keeping the subtree in the document
+        for service in [&local, &remote] {
+            let state = service.get_document_state().unwrap();
+            assert_eq!(state.children_map["page"], vec!["a"]);
+            assert_eq!(state.children_map["a"], vec!["a1", "b"]);
+        }
+
+        // The integrity fix writes the same resolution down
+        let report = local.validate_document(true).unwrap();
+        assert_eq!(report.issues[0], IntegrityIssueDoc {
+            kind: IntegrityIssueKindDoc::ParentCycle,
+            block_ids: vec!["a".to_string(), "b".to_string()],
+        });
+        remote.apply_updates_incremental(vec![report.update]).unwrap();
+        for service in [&mut local, &mut remote] {
+            assert!(service.validate_document(false).unwrap().issues.is_empty());
+            let state = service.get_document_state().unwrap();
+            assert_eq!(state.children_map["page"], vec!["a"]);
+            assert_eq!(state.children_map["a"], vec!["a1", "b"]);
+        }
+    }
+
+    #[test]
+    fn test_change_events_only_label_known_origins() {
+        let mut service = DocumentService::new();
+        service.init_empty_doc().unwrap();
+        service.set_root_node_id("page".to_string()).unwrap();
+        service.apply_action(vec![insert_action("page", None, None, "")]).unwrap();
+        service.enable_undo(0);
+
+        let events = Arc::new(std::sync::Mutex::new(Vec::new()));
+        let sink = events.clone();
+        service.set_change_listener(Arc::new(move |event| sink.lock().unwrap().push(event)));
+        let origins = || -> Vec<Option<String>> {
+            std::mem::take(&mut *events.lock().unwrap()).into_iter().map(|event| event.origin).collect()
+        };
+
+        // Origins of sync providers are neither reported nor mistaken for undo steps
+        let root = service.doc.get_or_insert_map(ROOT_ID);
+        for (i, origin) in [yrs::Origin::from("sync_provider"), yrs::Origin::from(7u64)].into_iter().enumerate() {
+            let mut txn = service.doc.transact_mut_with(origin);
+            let blocks_map = root.get_or_init_map(&mut txn, BLOCKS);
+            blocks_map.get_or_init_map(&mut txn, format!("remote{}", i)).insert(&mut txn, ID, "remote");
+        }
+        let received = origins();
+        assert!(!received.is_empty());
+        assert!(received.iter().all(Option::is_none));
+
+        service.apply_action(vec![insert_action("a", Some("page"), None, "a")]).unwrap();
+        assert!(origins().iter().all(|origin| origin.as_deref() == Some(LOCAL_ACTION_ORIGIN)));
+        service.undo().unwrap();
+        let received = origins();
+        assert!(!received.is_empty());
+        assert!(received.iter().all(|origin| origin.as_deref() == Some(UNDO_MANAGER_ORIGIN)));
+    }
+
+    #[test]
+    fn test_import_json_generated_ids_skip_explicit_ones() {
+        let options = DocumentOptions { client_id: Some(1), ..Default::default() };
+        let mut service = DocumentService::new_with_options("doc".to_string(), options);
+        // The page would get the generated id 1_1 before the child bringing it along is reached
+        let json = r#"{"document": {"type": "page", "children": [
+            {"type": "paragraph"},
+            {"id": "1_1", "type": "paragraph"}
+        ]}}"#;
+        service.init_doc_from_json(json.to_string()).unwrap();
+
+        let state = service.get_document_state().unwrap();
+        assert_eq!(state.blocks.len(), 3);
+        assert_ne!(state.root_id, "1_1");
+        let children = &state.children_map[&state.root_id];
+        assert_eq!(children.len(), 2);
+        assert_eq!(children[1], "1_1");
+        assert_eq!(state.blocks["1_1"].parent_id.as_deref(), Some(state.root_id.as_str()));
+    }
+
+    #[test]
+    fn test_apply_updates_as_actions_reads_paths_of_nested_blocks() {
+        let mut local = DocumentService::new();
+        let mut updates = vec![local.init_empty_doc().unwrap()];
+        updates.push(local.set_root_node_id("page".to_string()).unwrap());
+        updates.push(local.apply_action(vec![insert_action("page", None, None, "")]).unwrap());
+        updates.push(local.apply_action(vec![insert_action("a", Some("page"), None, "a")]).unwrap());
+        updates.push(local.apply_action(vec![insert_action("b", Some("page"), Some("a"), "b")]).unwrap());
+        updates.push(local.apply_action(vec![insert_action("b1", Some("b"), None, "b1")]).unwrap());
+
+        let mut remote = DocumentService::new();
+        remote.apply_updates_as_actions(updates).unwrap();
+
+        // Only `b2` changes, yet its path goes through `b`, which sits after `a`
+        let result = remote.apply_updates_as_actions(vec![
+            local.apply_action(vec![insert_action("b2", Some("b"), Some("b1"), "b2")]).unwrap()
+        ]).unwrap();
+        assert!(!result.requires_full_reload);
+        assert_eq!(result.actions.len(), 1);
+        assert_eq!(result.actions[0].block.id, "b2");
+        assert_eq!(result.actions[0].path, vec![1, 1]);
+
+        // Deleting `b` reports one delete for the subtree, read before the update removed it
+        let mut delete = insert_action("b", Some("page"), None, "");
+        delete.action = BlockActionTypeDoc::Delete;
+        let result = remote.apply_updates_as_actions(vec![local.apply_action(vec![delete]).unwrap()]).unwrap();
+        assert!(!result.requires_full_reload);
+        let summary: Vec<(BlockActionTypeDoc, String, Vec<u32>)> = result.actions
+            .iter()
+            .map(|action| (action.action, action.block.id.clone(), action.path.clone()))
+            .collect();
+        assert_eq!(summary, vec![(BlockActionTypeDoc::Delete, "b".to_string(), vec![1])]);
+    }
+
+    #[test]
+    fn test_apply_action_accepts_dangling_prev_and_checks_utf16_lengths() {
+        for offset_kind in [OffsetKindDoc::Bytes, OffsetKindDoc::Utf16] {
+            let options = DocumentOptions { offset_kind, ..Default::default() };
+            let mut service = DocumentService::new_with_options("doc".to_string(), options);
+            service.init_empty_doc().unwrap();
+            service.set_root_node_id("page".to_string()).unwrap();
+            service.apply_action(vec![
+                insert_action("page", None, None, ""),
+                insert_action("a", Some("page"), None, "é😀"),
+            ]).unwrap();
+
+            // A previous block that is not there leaves the block dangling instead of rejecting it
+            service.apply_action(vec![insert_action("b", Some("page"), Some("missing"), "b")]).unwrap();
+            let state = service.get_document_state().unwrap();
+            assert_eq!(state.children_map["page"].len(), 2);
+            assert!(state.children_map["page"].contains(&"b".to_string()));
+
+            // "é😀" is 3 UTF-16 units long whatever the document counts, though 6 bytes
+            let mut overlong_retain = insert_action("a", Some("page"), None, "");
+            overlong_retain.action = BlockActionTypeDoc::Update;
+            overlong_retain.block.delta = Some(r#"[{"retain": 4}, {"insert": "!"}]"#.to_string());
+            assert_eq!(service.apply_action(vec![overlong_retain]).unwrap_err().action_index, Some(0));
+        }
+
+        let options = DocumentOptions { offset_kind: OffsetKindDoc::Utf16, ..Default::default() };
+        let mut service = DocumentService::new_with_options("doc".to_string(), options);
+        service.init_empty_doc().unwrap();
+        service.set_root_node_id("page".to_string()).unwrap();
+        service.apply_action(vec![
+            insert_action("page", None, None, ""),
+            insert_action("a", Some("page"), None, "é😀"),
+        ]).unwrap();
+        let mut append = insert_action("a", Some("page"), None, "");
+        append.action = BlockActionTypeDoc::Update;
+        append.block.delta = Some(r#"[{"retain": 3}, {"insert": "!"}]"#.to_string());
+        service.apply_action(vec![append]).unwrap();
+        let state = service.get_document_state().unwrap();
+        assert_eq!(state.blocks["a"].delta.as_deref(), Some(r#"[{"insert":"é😀!"}]"#));
+    }
+
+    #[test]
+    fn test_apply_action_failing_while_writing_leaves_document_untouched() {
+        let options = DocumentOptions { attribute_mode: AttributeModeDoc::Json, ..Default::default() };
+        let mut service = DocumentService::new_with_options("doc".to_string(), options);
+        let mut updates = vec![service.init_empty_doc().unwrap()];
+        updates.push(service.set_root_node_id("page".to_string()).unwrap());
+        updates.push(service.apply_action(vec![insert_action("page", None, None, "")]).unwrap());
+        let state_vector = service.encode_state_vector(UpdateEncodingDoc::V1).unwrap();
+
+        // Attribute values are only converted while writing, after `a` was already inserted
+        let mut not_json = insert_action("b", Some("page"), Some("a"), "b");
+        not_json.block.attributes = HashMap::from([("label".to_string(), "plain text".to_string())]);
+        let error = service.apply_action(vec![insert_action("a", Some("page"), None, "a"), not_json]).unwrap_err();
+        assert_eq!(error.action_index, Some(1));
+        assert_eq!(service.encode_state_vector(UpdateEncodingDoc::V1).unwrap(), state_vector);
+        assert!(!service.get_document_state().unwrap().blocks.contains_key("a"));
+
+        // The next batch is written on top of the untouched document and indexed as usual
+        updates.push(service.apply_action(vec![
+            insert_action("a", Some("page"), None, "a"),
+            insert_action("b", Some("page"), Some("a"), "b"),
+        ]).unwrap());
+        let mut remote = DocumentService::new();
+        remote.apply_updates(updates).unwrap();
+        assert_eq!(remote.get_document_state().unwrap().children_map["page"], vec!["a", "b"]);
+        let mut delete_a = insert_action("a", Some("page"), None, "");
+        delete_a.action = BlockActionTypeDoc::Delete;
+        service.apply_action(vec![delete_a]).unwrap();
+        let state = service.get_document_state().unwrap();
+        assert_eq!(state.children_map["page"], vec!["b"]);
+        assert_eq!(state.blocks["b"].prev_id, None);
+    }
+
+    #[test]
+    fn test_fractional_ordering_survives_undo_and_unkeyed_blocks() {
+        let mut service = DocumentService::new();
+        service.enable_undo(0);
+        service.init_empty_doc().unwrap();
+        service.set_root_node_id("page".to_string()).unwrap();
+        service.apply_action(vec![insert_action("page", None, None, "")]).unwrap();
+        service.apply_action(vec![insert_action("a", Some("page"), None, "a")]).unwrap();
+        service.apply_action(vec![insert_action("b", Some("page"), Some("a"), "b")]).unwrap();
+        service.enable_fractional_ordering().unwrap();
+        let order = |service: &DocumentService| service.get_document_state().unwrap().children_map["page"].clone();
+
+        // Undo reverts the last edit, not the migration
+        service.undo().unwrap();
+        assert_eq!(service.sibling_ordering(), SiblingOrderingDoc::FractionalIndex);
+        assert_eq!(order(&service), vec!["a"]);
+
+        // A block written by a client that does not know about positions
+        {
+            let root = service.doc.get_or_insert_map(ROOT_ID);
+            let mut txn = service.doc.transact_mut();
+            let old = root.get_or_init_map(&mut txn, BLOCKS).get_or_init_map(&mut txn, "old");
+            for (key, value) in [(ID, "old"), (TYPE, "paragraph"), (PARENT_ID, "page"), (PREV_ID, "a")] {
+                old.insert(&mut txn, key, value);
+            }
+        }
+        assert_eq!(order(&service), vec!["a", "old"]);
+
+        // Inserted after it, a block is keyed after the nearest keyed block instead of first
+        service.apply_action(vec![insert_action("c", Some("page"), Some("old"), "c")]).unwrap();
+        assert_eq!(order(&service), vec!["a", "c", "old"]);
+    }
+
+    #[test]
+    fn test_markdown_drops_html_and_fences_code_spans() {
+        let mut local = DocumentService::new();
+        local.init_doc_from_markdown("<div>\nhidden\n</div>\n\nkeep <b>this</b> text".to_string()).unwrap();
+        let state = local.get_document_state().unwrap();
+        let top = &state.children_map[&state.root_id];
+        assert_eq!(top.len(), 1);
+        assert_eq!(state.blocks[&top[0]].delta.as_deref(), Some(r#"[{"insert":"keep this text"}]"#));
+
+        let mut code = insert_action("code", Some(&state.root_id), Some(&top[0]), "");
+        code.block.delta = Some(serde_json::json!([
+            {"insert": "a ``b`` c", "attributes": {"code": true}},
+            {"insert": " and "},
+            {"insert": "`tick", "attributes": {"code": true}},
+        ]).to_string());
+        local.apply_action(vec![code]).unwrap();
+
+        let exported = local.export_markdown().unwrap();
+        assert!(exported.ends_with("```a ``b`` c``` and `` `tick ``\n"));
+        let mut reimported = DocumentService::new();
+        reimported.init_doc_from_markdown(exported.clone()).unwrap();
+        assert_eq!(reimported.export_markdown().unwrap(), exported);
+    }
+}
@@ -4,7 +4,7 @@ use serde::{Deserialize, Serialize};
 use std::collections::HashMap;
 
 #[frb]
-#[derive(Serialize, Deserialize, Debug)]
+#[derive(Serialize, Deserialize, Debug, Clone, Copy, PartialEq, Eq)]
 pub enum BlockActionTypeDoc {
     Insert,
     Update,
@@ -12,8 +12,59 @@ pub enum BlockActionTypeDoc {
     Move,
 }
 
+// How text offsets and lengths are counted inside the document
+#[frb]
+#[derive(Serialize, Deserialize, Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum OffsetKindDoc {
+    #[default]
+    Bytes,
+    Utf16,
+}
+
+// How block attribute values are exchanged as strings. Strings keeps the historical form where
+// every stored value is stringified; Json carries each value as a JSON literal, so booleans,
+// numbers and nested maps keep their type. Text has to be quoted too, values that are not
+// JSON literals are rejected
+#[frb]
+#[derive(Serialize, Deserialize, Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum AttributeModeDoc {
+    #[default]
+    Strings,
+    Json,
+}
+
+// How siblings are ordered in a document. PrevIdChain follows the prevId pointers; FractionalIndex
+// orders by the position key of each block, and is switched on per document by a migration
+#[frb]
+#[derive(Serialize, Deserialize, Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum SiblingOrderingDoc {
+    #[default]
+    PrevIdChain,
+    FractionalIndex,
+}
+
+// Options used to construct the underlying yrs document
 #[frb(unignore, dart_metadata=("freezed"), json_serializable)]
-#[derive(Serialize, Deserialize, Debug)]
+#[derive(Serialize, Deserialize, Debug, Clone, Default)]
+pub struct DocumentOptions {
+    // Stable yrs client id of this device, a random one is generated when missing
+    #[serde(rename = "clientId")]
+    pub client_id: Option<u64>,
+    #[serde(rename = "offsetKind")]
+    pub offset_kind: OffsetKindDoc,
+    // Keep deleted content around instead of garbage collecting it
+    #[serde(rename = "skipGc")]
+    pub skip_gc: bool,
+    // Encoding of the updates produced by local edits, v1 for peers running Yjs defaults
+    #[serde(rename = "updateEncoding", default)]
+    pub update_encoding: UpdateEncodingDoc,
+    // How block attribute values are stored, typed JSON or the strings older clients expect
+    #[serde(rename = "attributeMode", default)]
+    pub attribute_mode: AttributeModeDoc,
+}
+
+#[frb(unignore, dart_metadata=("freezed"), json_serializable)]
+#[derive(Serialize, Deserialize, Debug, Clone)]
 pub struct BlockDoc {
     pub id: String,
     pub ty: String,
@@ -30,7 +81,7 @@ pub struct BlockDoc {
 }
 
 #[frb(unignore, dart_metadata=("freezed"), json_serializable)]
-#[derive(Serialize, Deserialize, Debug)]
+#[derive(Serialize, Deserialize, Debug, Clone)]
 pub struct BlockActionDoc {
     pub action: BlockActionTypeDoc,
     pub block: BlockDoc,
@@ -39,13 +90,275 @@ pub struct BlockActionDoc {
     pub old_path: Option<Vec<u32>>, //For Move action
 }
 
+// Binary encoding of updates and state vectors exchanged with peers
+#[frb]
+#[derive(Serialize, Deserialize, Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum UpdateEncodingDoc {
+    V1,
+    #[default]
+    V2,
+}
+
+// Editor actions equivalent to a batch of remote updates
+#[frb(unignore, dart_metadata=("freezed"), json_serializable)]
+#[derive(Serialize, Deserialize, Debug)]
+pub struct AppliedUpdateActions {
+    pub actions: Vec<BlockActionDoc>,
+    // Set when the root changed or the document was empty, so patching is not possible
+    #[serde(rename = "requiresFullReload")]
+    pub requires_full_reload: bool,
+}
+
+// An encoded update tagged with the id of the row it was loaded from
+#[frb]
+#[derive(Serialize, Deserialize, Debug, Clone)]
+pub struct UpdateWithId {
+    pub id: String,
+    pub update: Vec<u8>,
+}
+
 #[frb(unignore, dart_metadata=("freezed"), json_serializable)]
 #[derive(Serialize, Deserialize, Debug)]
 pub struct FailedToDecodeUpdates {
     pub failed_updates_ids: Vec<String>,
 }
 
+// Summary of what an incremental update application touched
+#[frb(unignore, dart_metadata=("freezed"), json_serializable)]
+#[derive(Serialize, Deserialize, Debug)]
+pub struct AppliedUpdates {
+    pub changed_block_ids: Vec<String>,
+    pub deleted_block_ids: Vec<String>,
+    pub root_id_changed: bool,
+    // True when some updates are waiting for missing predecessors
+    pub has_pending_updates: bool,
+}
+
+
+
+#[frb]
+#[derive(Serialize, Deserialize, Debug, Clone, Copy, PartialEq, Eq)]
+pub enum DocumentChangeKindDoc {
+    BlockInserted,
+    BlockUpdated,
+    BlockDeleted,
+    BlockMoved,
+    TextDeltaChanged,
+}
+
+// A single change pushed to subscribers after a transaction is committed
+#[frb(unignore, dart_metadata=("freezed"), json_serializable)]
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq)]
+pub struct DocumentChangeEvent {
+    pub kind: DocumentChangeKindDoc,
+    #[serde(rename = "blockId")]
+    pub block_id: String,
+    // JSON text delta, only set for TextDeltaChanged
+    pub delta: Option<String>,
+    // Origin of the transaction, e.g. "local_action", None for remote updates
+    pub origin: Option<String>,
+}
+
+// Side a sticky position keeps to when text is inserted exactly at it
+#[frb]
+#[derive(Serialize, Deserialize, Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum StickyAssocDoc {
+    #[default]
+    After,
+    Before,
+}
 
+// User shown next to a collaborator's cursor
+#[frb(unignore, dart_metadata=("freezed"), json_serializable)]
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq)]
+pub struct AwarenessUserDoc {
+    pub id: String,
+    pub name: String,
+    pub color: Option<String>,
+}
+
+// A text position inside a block, offset counted in UTF-16 code units like Dart strings
+#[frb(unignore, dart_metadata=("freezed"), json_serializable)]
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq)]
+pub struct TextPositionDoc {
+    #[serde(rename = "blockId")]
+    pub block_id: String,
+    pub offset: u32,
+}
+
+#[frb(unignore, dart_metadata=("freezed"), json_serializable)]
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq)]
+pub struct AwarenessSelectionDoc {
+    pub anchor: TextPositionDoc,
+    pub head: TextPositionDoc,
+}
+
+// Presence of the local client, published to peers
+#[frb(unignore, dart_metadata=("freezed"), json_serializable)]
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq)]
+pub struct AwarenessStateDoc {
+    pub user: AwarenessUserDoc,
+    pub selection: Option<AwarenessSelectionDoc>,
+}
+
+// Presence of a known client, with the selection resolved against the current document
+#[frb(unignore, dart_metadata=("freezed"), json_serializable)]
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq)]
+pub struct AwarenessPeerDoc {
+    #[serde(rename = "clientId")]
+    pub client_id: u64,
+    pub clock: u32,
+    // Milliseconds since the Unix epoch of the last state received from this client
+    #[serde(rename = "lastUpdated")]
+    pub last_updated: u64,
+    #[serde(rename = "isLocal")]
+    pub is_local: bool,
+    // None when the peer publishes a state in a shape we do not understand
+    pub user: Option<AwarenessUserDoc>,
+    // None when nothing is selected or the selected text no longer exists
+    pub selection: Option<AwarenessSelectionDoc>,
+}
+
+// Clients whose presence changed after applying an awareness update
+#[frb(unignore, dart_metadata=("freezed"), json_serializable)]
+#[derive(Serialize, Deserialize, Debug, Clone, Default)]
+pub struct AwarenessChangesDoc {
+    pub added: Vec<u64>,
+    pub updated: Vec<u64>,
+    pub removed: Vec<u64>,
+}
+
+#[frb]
+#[derive(Serialize, Deserialize, Debug, Clone, Default)]
+pub struct AwarenessTimeoutDoc {
+    // Remote clients dropped because they were silent for longer than the timeout
+    pub removed: Vec<u64>,
+    // Renewed local state to broadcast, set when the local state was about to time out for peers
+    pub update: Option<Vec<u8>>,
+}
+
+// Message types of the y-sync protocol
+#[frb]
+#[derive(Serialize, Deserialize, Debug, Clone, Copy, PartialEq, Eq)]
+pub enum SyncMessageKindDoc {
+    SyncStep1,
+    SyncStep2,
+    Update,
+    Awareness,
+    AwarenessQuery,
+    Auth,
+}
+
+// A single y-sync message. The payload is the state vector for SyncStep1, a v1 update for
+// SyncStep2 and Update, an awareness update for Awareness, empty for AwarenessQuery and the
+// UTF-8 reason of a denied permission for Auth, empty when permission was granted.
+#[frb]
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq)]
+pub struct SyncMessageDoc {
+    pub kind: SyncMessageKindDoc,
+    pub payload: Vec<u8>,
+}
+
+// Outcome of handling an incoming y-sync message
+#[frb]
+#[derive(Serialize, Deserialize, Debug, Clone, Default)]
+pub struct SyncReplyDoc {
+    // Messages to send back to the sender, None when nothing needs to be answered
+    pub reply: Option<Vec<u8>>,
+    // Set when a sync step 2 or update was applied to the document
+    pub document_changed: bool,
+    pub awareness_changed: bool,
+}
+
+// A single update replacing a stored update log
+#[frb]
+#[derive(Serialize, Deserialize, Debug, Clone)]
+pub struct CompactedUpdate {
+    pub update: Vec<u8>,
+    // State vector covered by the update, to compare with rows stored after compaction
+    pub state_vector: Vec<u8>,
+    // True when some updates wait for missing predecessors, they are still part of the update
+    pub has_pending_updates: bool,
+}
+
+// Result of an undo or redo step
+#[frb]
+#[derive(Serialize, Deserialize, Debug)]
+pub struct UndoRedoResult {
+    // False when there was nothing to undo or redo
+    pub changed: bool,
+    // Update to persist and send to peers
+    pub update: Vec<u8>,
+    // Actions needed to bring the editor in line with the document
+    pub actions: Vec<BlockActionDoc>,
+}
+
+// Outcome of repairing the prevId chains. Relinked blocks now point at the block shown before them.
+#[frb]
+#[derive(Serialize, Deserialize, Debug)]
+pub struct ChainRepairResult {
+    // Blocks where a prevId cycle was cut open
+    pub cycle_block_ids: Vec<String>,
+    // Blocks whose prevId named a missing block or one under another parent
+    pub dangling_block_ids: Vec<String>,
+    // Update to persist and send to peers, empty when nothing needed repairing
+    pub update: Vec<u8>,
+}
+
+// Structural problems of the blocks map that make the editor lose or duplicate blocks
+#[frb]
+#[derive(Serialize, Deserialize, Debug, Clone, Copy, PartialEq, Eq)]
+pub enum IntegrityIssueKindDoc {
+    // The root id is not set or names no block
+    MissingRoot,
+    MissingId,
+    MissingType,
+    // The parentId names a block that does not exist
+    MissingParent,
+    // A block without parent other than the root, with everything nested under it
+    Orphan,
+    // Blocks whose parentIds lead back to themselves
+    ParentCycle,
+    // Blocks with the same prevId
+    SharedPrevId,
+}
+
+// A problem found by the integrity check and the blocks it concerns, sorted by id
+#[frb(unignore, dart_metadata=("freezed"), json_serializable)]
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq)]
+pub struct IntegrityIssueDoc {
+    pub kind: IntegrityIssueKindDoc,
+    #[serde(rename = "blockIds")]
+    pub block_ids: Vec<String>,
+}
+
+// Result of an integrity check
+#[frb]
+#[derive(Serialize, Deserialize, Debug)]
+pub struct IntegrityReport {
+    pub issues: Vec<IntegrityIssueDoc>,
+    // Update fixing the issues, empty when fixing was not asked for or not needed
+    pub update: Vec<u8>,
+}
+
+// Metadata of a named snapshot, persisted by the host next to the snapshot data
+#[frb(unignore, dart_metadata=("freezed"), json_serializable)]
+#[derive(Serialize, Deserialize, Debug, Clone)]
+pub struct SnapshotInfo {
+    pub id: String,
+    pub label: String,
+    // Milliseconds since the Unix epoch
+    #[serde(rename = "createdAt")]
+    pub created_at: i64,
+}
+
+#[frb]
+#[derive(Serialize, Deserialize, Debug)]
+pub struct CreatedSnapshot {
+    pub snapshot: SnapshotInfo,
+    // Encoded yrs snapshot for the host to persist. It is not part of the synced document.
+    pub data: Vec<u8>,
+}
 
 // Define the document structure for Flutter
 #[frb(unignore, dart_metadata=("freezed"), json_serializable)]
@@ -63,12 +376,23 @@ pub struct DocumentState {
 #[derive(Debug)]
 pub struct CustomRustError {
     pub message: String,
+    // Position of the rejected action when a batch of actions failed
+    pub action_index: Option<u32>,
 }
 
 impl CustomRustError {
     pub fn new(message: &str) -> Self {
         CustomRustError {
             message: message.to_string(),
+            action_index: None,
+        }
+    }
+
+    /// The same error, blamed on the action at `index` of a batch
+    pub(crate) fn at_action(self, index: usize) -> Self {
+        CustomRustError {
+            message: format!("Action {}: {}", index, self.message),
+            action_index: Some(index as u32),
         }
     }
 }
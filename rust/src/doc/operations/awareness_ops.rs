@@ -0,0 +1,177 @@
+use std::time::{SystemTime, UNIX_EPOCH};
+
+use serde::{Deserialize, Serialize};
+use yrs::sync::awareness::AwarenessUpdate;
+use yrs::sync::Awareness;
+use yrs::updates::decoder::Decode;
+use yrs::updates::encoder::Encode;
+use yrs::{Doc, StickyIndex};
+
+use crate::doc::document_types::{
+    AwarenessChangesDoc, AwarenessPeerDoc, AwarenessSelectionDoc, AwarenessStateDoc, AwarenessTimeoutDoc,
+    AwarenessUserDoc, CustomRustError, StickyAssocDoc, TextPositionDoc,
+};
+use crate::doc::error::DocError;
+use crate::doc::operations::sticky_ops::StickyOperations;
+use crate::{log_info, log_error};
+
+/// Awareness state as replicated to peers. Selections are stored as relative positions
+/// (y.js RelativePosition JSON) so they keep pointing at the same characters while the
+/// text is edited concurrently.
+#[derive(Serialize, Deserialize)]
+struct StoredState {
+    user: AwarenessUserDoc,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    selection: Option<StoredSelection>,
+}
+
+#[derive(Serialize, Deserialize)]
+struct StoredSelection {
+    anchor: StoredPosition,
+    head: StoredPosition,
+}
+
+#[derive(Serialize, Deserialize)]
+struct StoredPosition {
+    #[serde(rename = "blockId")]
+    block_id: String,
+    position: StickyIndex,
+}
+
+pub struct AwarenessOperations;
+
+impl AwarenessOperations {
+    /// Awareness of the given document, identified by the document's client id
+    pub fn create(doc: &Doc) -> Awareness {
+        Awareness::with_clock(doc.clone(), Self::now_millis)
+    }
+
+    /// Publish the local user and selection, returning the awareness update to broadcast
+    pub fn set_local_state(awareness: &Awareness, doc: &Doc, state: AwarenessStateDoc) -> Result<Vec<u8>, CustomRustError> {
+        let selection = match state.selection {
+            Some(selection) => Some(StoredSelection {
+                anchor: Self::store_position(doc, selection.anchor)?,
+                head: Self::store_position(doc, selection.head)?,
+            }),
+            None => None,
+        };
+
+        awareness.set_local_state(StoredState { user: state.user, selection })
+            .map_err(|e| DocError::EncodingError(format!("Failed to encode awareness state: {}", e)))?;
+        Self::encode_clients(awareness, vec![awareness.client_id()])
+    }
+
+    /// Mark the local client as gone, returning the awareness update to broadcast
+    pub fn clear_local_state(awareness: &Awareness) -> Result<Vec<u8>, CustomRustError> {
+        awareness.clean_local_state();
+        Self::encode_clients(awareness, vec![awareness.client_id()])
+    }
+
+    /// Update carrying the states of every connected client, sent to newly connected peers
+    pub fn encode_update(awareness: &Awareness) -> Result<Vec<u8>, CustomRustError> {
+        let update = awareness.update()
+            .map_err(|e| DocError::EncodingError(format!("Failed to encode awareness update: {}", e)))?;
+        Ok(update.encode_v1())
+    }
+
+    pub fn apply_update(awareness: &Awareness, update: &[u8]) -> Result<AwarenessChangesDoc, CustomRustError> {
+        let update = AwarenessUpdate::decode_v1(update).map_err(|e| {
+            log_error!("apply_update: Failed to decode awareness update: {}", e);
+            DocError::DecodingError(format!("Failed to decode awareness update: {}", e))
+        })?;
+
+        let summary = awareness.apply_update_summary(update)
+            .map_err(|e| DocError::StateError(format!("Failed to apply awareness update: {}", e)))?;
+        Ok(summary
+            .map(|summary| AwarenessChangesDoc {
+                added: summary.added,
+                updated: summary.updated,
+                removed: summary.removed,
+            })
+            .unwrap_or_default())
+    }
+
+    /// Every client with a state, ordered by client id
+    pub fn peers(awareness: &Awareness, doc: &Doc) -> Vec<AwarenessPeerDoc> {
+        let local_client_id = awareness.client_id();
+        let mut peers: Vec<AwarenessPeerDoc> = awareness
+            .iter()
+            .filter_map(|(client_id, state)| {
+                let json: serde_json::Value = serde_json::from_str(state.data.as_deref()?).ok()?;
+                let user = json.get("user").and_then(|user| serde_json::from_value(user.clone()).ok());
+                let selection = json.get("selection")
+                    .and_then(|selection| serde_json::from_value::<StoredSelection>(selection.clone()).ok())
+                    .and_then(|selection| Self::resolve_selection(doc, selection));
+
+                Some(AwarenessPeerDoc {
+                    client_id,
+                    clock: state.clock,
+                    last_updated: state.last_updated,
+                    is_local: client_id == local_client_id,
+                    user,
+                    selection,
+                })
+            })
+            .collect();
+        peers.sort_by_key(|peer| peer.client_id);
+        peers
+    }
+
+    /// Drop remote clients silent for `timeout_millis` and renew the local state once half
+    /// of the timeout has passed, mirroring the y-protocols awareness timer
+    pub fn check_timeouts(awareness: &Awareness, timeout_millis: u64) -> Result<AwarenessTimeoutDoc, CustomRustError> {
+        let now = Self::now_millis();
+        let local_client_id = awareness.client_id();
+
+        let mut result = AwarenessTimeoutDoc::default();
+        let states: Vec<_> = awareness.iter().filter(|(_, state)| state.data.is_some()).collect();
+        for (client_id, state) in states {
+            let elapsed = now.saturating_sub(state.last_updated);
+            if client_id == local_client_id {
+                if elapsed >= timeout_millis / 2 {
+                    if let Some(data) = state.data {
+                        awareness.set_local_state_raw(data);
+                        result.update = Some(Self::encode_clients(awareness, vec![local_client_id])?);
+                    }
+                }
+            } else if elapsed >= timeout_millis {
+                awareness.remove_state(client_id);
+                result.removed.push(client_id);
+            }
+        }
+
+        if !result.removed.is_empty() {
+            log_info!("check_timeouts: Removed {} outdated awareness states", result.removed.len());
+        }
+        Ok(result)
+    }
+
+    fn now_millis() -> u64 {
+        SystemTime::now()
+            .duration_since(UNIX_EPOCH)
+            .map(|duration| duration.as_millis() as u64)
+            .unwrap_or_default()
+    }
+
+    fn encode_clients(awareness: &Awareness, clients: Vec<u64>) -> Result<Vec<u8>, CustomRustError> {
+        let update = awareness.update_with_clients(clients)
+            .map_err(|e| DocError::EncodingError(format!("Failed to encode awareness update: {}", e)))?;
+        Ok(update.encode_v1())
+    }
+
+    fn store_position(doc: &Doc, position: TextPositionDoc) -> Result<StoredPosition, CustomRustError> {
+        let sticky = StickyOperations::sticky_index(doc, &position, StickyAssocDoc::After)?;
+        Ok(StoredPosition { block_id: position.block_id, position: sticky })
+    }
+
+    fn resolve_selection(doc: &Doc, selection: StoredSelection) -> Option<AwarenessSelectionDoc> {
+        Some(AwarenessSelectionDoc {
+            anchor: Self::resolve_position(doc, &selection.anchor)?,
+            head: Self::resolve_position(doc, &selection.head)?,
+        })
+    }
+
+    fn resolve_position(doc: &Doc, position: &StoredPosition) -> Option<TextPositionDoc> {
+        StickyOperations::resolve(doc, &position.block_id, &position.position)
+    }
+}
@@ -1,13 +1,14 @@
 use flutter_rust_bridge::DartFnFuture;
 use log::info;
-use std::collections::HashMap;
 use std::sync::Arc;
-use yrs::{ Array, ArrayRef, Map, MapPrelim, MapRef, ReadTxn, TextRef, TransactionMut };
+use yrs::{ Map, MapPrelim, MapRef, ReadTxn, TextRef, TransactionMut };
 
 use crate::doc::constants::{ ATTRIBUTES, DEFAULT_PARENT, ID, PARENT_ID, PREV_ID, TEXT, TYPE };
-use crate::doc::document_types::{ BlockActionDoc, CustomRustError };
+use crate::doc::conversions::conversion::Conversion;
+use crate::doc::document_types::{ AttributeModeDoc, BlockActionDoc, CustomRustError };
 use crate::doc::error::DocError;
 use crate::doc::operations::delta_ops::DeltaOperations;
+use crate::doc::utils::block_index::BlockIndex;
 use crate::doc::utils::util::MapExt;
 
 use crate::{ log_info, log_error };
@@ -18,7 +19,9 @@ impl BlockOperations {
     pub fn insert_node(
         txn: &mut TransactionMut,
         blocks_map: MapRef,
+        index: &mut BlockIndex,
         action: BlockActionDoc,
+        attribute_mode: AttributeModeDoc,
     ) -> Result<MapRef, CustomRustError> {
         let block_id = action.block.id.clone();
         log_info!("insert_node: Starting for block_id: {}", block_id);
@@ -32,12 +35,13 @@ impl BlockOperations {
 
         if parent_id != DEFAULT_PARENT {
             node_ref.insert(txn, Arc::from(PARENT_ID), parent_id.clone());
+            index.set_parent_id(&block_id, Some(&parent_id));
         }
 
         // Set attributes
         let mut attr_map = MapPrelim::default();
         for (k, v) in action.block.attributes {
-            attr_map.insert(k.into(), v.into());
+            attr_map.insert(k.into(), Conversion::attribute_to_yrs(&v, attribute_mode)?.into());
         }
         node_ref.insert(txn, Arc::from(ATTRIBUTES), attr_map);
 
@@ -51,6 +55,7 @@ impl BlockOperations {
         Self::handle_prev_id_chain(
             txn,
             blocks_map.clone(),
+            index,
             &block_id,
             action.block.prev_id.clone()
         )?;
@@ -58,15 +63,17 @@ impl BlockOperations {
         Self::handle_following_connection(
             txn,
             blocks_map.clone(),
+            index,
             &block_id,
             action.block.next_id.clone(),
             action.block.prev_id.clone()
-        );
+        )?;
 
         // Set the prev_id for this block
         if let Some(prev_id) = action.block.prev_id {
             log_info!("  Setting prev_id of block {} to {}", block_id, prev_id);
             let block = blocks_map.get_or_init_map(txn, block_id.to_string());
+            index.set_prev_id(&block_id, Some(&prev_id));
             block.insert(txn, Arc::from(PREV_ID), prev_id);
         }
 
@@ -78,6 +85,7 @@ impl BlockOperations {
         txn: &mut TransactionMut,
         blocks_map: MapRef,
         action: BlockActionDoc,
+        attribute_mode: AttributeModeDoc,
     ) -> Result<(), CustomRustError> {
         let block_id = action.block.id.clone();
         log_info!("update_node: Updating block_id: {}", block_id);
@@ -88,7 +96,7 @@ impl BlockOperations {
         if !action.block.attributes.is_empty() {
             let data = node.get_or_init_map(txn, ATTRIBUTES);
             for (k, v) in action.block.attributes {
-                data.insert(txn, k, v);
+                data.insert(txn, k, Conversion::attribute_to_yrs(&v, attribute_mode)?);
             }
         }
 
@@ -105,35 +113,35 @@ impl BlockOperations {
     pub fn delete_node(
         txn: &mut TransactionMut,
         blocks_map: MapRef,
+        index: &mut BlockIndex,
         block_id: &str,
         parent_id: &str
     ) -> Result<(), CustomRustError> {
         log_info!("delete_node: Starting for block_id: {}", block_id);
     
-        // Build the parent-child structure
-        let blocks_by_parent = Self::build_parent_child_structure(txn, blocks_map.clone());
-        
         // Get all descendants
-        let descendants = Self::find_descendants(block_id, &blocks_by_parent);
+        let descendants = index.descendants(block_id);
         log_info!("Block {} has {} descendants to delete", block_id, descendants.len());
         
         // Update the prev_id chain for the main block
-        Self::remove_block_from_prev_id_chain(txn, blocks_map.clone(), block_id)?;
+        Self::remove_block_from_prev_id_chain(txn, blocks_map.clone(), index, block_id)?;
         
         // Delete all descendants from bottom up (children first, then parents)
         for descendant_id in descendants {
             log_info!("Deleting descendant block: {}", descendant_id);
             
             // Update prev_id chain for each descendant
-            Self::remove_block_from_prev_id_chain(txn, blocks_map.clone(), &descendant_id)?;
+            Self::remove_block_from_prev_id_chain(txn, blocks_map.clone(), index, &descendant_id)?;
             
             // Remove the descendant block
             blocks_map.remove(txn, &descendant_id);
+            index.remove(&descendant_id);
         }
         
         // Finally remove the main block
         blocks_map.remove(txn, block_id)
             .ok_or_else(|| DocError::BlockNotFound(format!("Block {} not found in blocks map", block_id)))?;
+        index.remove(block_id);
         
         log_info!("delete_node: Successfully deleted block_id: {} and its descendants", block_id);
         Ok(())
@@ -142,34 +150,48 @@ impl BlockOperations {
     pub fn move_block(
         txn: &mut TransactionMut,
         blocks_map: MapRef,
-        old_path: &[u32],
-        new_path: &[u32],
-        parent_id: &str,
-        old_parent_id: &str,
-        block_id: &str,
-        prev_id: Option<String>,
-        next_id: Option<String>
+        index: &mut BlockIndex,
+        action: BlockActionDoc,
     ) -> Result<(), CustomRustError> {
+        let (Some(parent_id), Some(old_parent_id)) = (action.block.parent_id.as_deref(), action.block.old_parent_id.as_deref()) else {
+            return Err(DocError::InvalidOperation("Missing required fields for move operation".into()).into());
+        };
+        if action.old_path.is_none() {
+            return Err(DocError::InvalidOperation("Missing required fields for move operation".into()).into());
+        }
+        let block_id = action.block.id.as_str();
+        let prev_id = action.block.prev_id.clone();
+        let next_id = action.block.next_id.clone();
         log_info!(
             "move_block: Moving block_id: {} from parent: {} to parent: {}",
             block_id,
             old_parent_id,
             parent_id
         );
+
+        // The moved block would take its subtree out of the document
+        if parent_id == block_id || index.is_nested_under(parent_id, block_id) {
+            log_error!("move_block: Block {} cannot be moved under {}", block_id, parent_id);
+            return Err(DocError::InvalidOperation(
+                format!("Block {} cannot be moved into itself or its descendant {}", block_id, parent_id)
+            ).into());
+        }
     
         // Update the prev_id chain
-        Self::remove_block_from_prev_id_chain(txn, blocks_map.clone(), block_id)?;
+        Self::remove_block_from_prev_id_chain(txn, blocks_map.clone(), index, block_id)?;
     
         Self::handle_following_connection(
             txn,
             blocks_map.clone(),
-            &block_id,
+            index,
+            block_id,
             next_id,
             prev_id.clone()
-        );
+        )?;
     
         // Set the new prev_id or remove it
         let node = blocks_map.get_or_init_map(txn, block_id);
+        index.set_prev_id(block_id, prev_id.as_deref());
         if let Some(prev_id) = prev_id {
             // If another block has this prev_id, update its prev_id to point to this block
             node.insert(txn, Arc::from(PREV_ID), prev_id);
@@ -181,8 +203,10 @@ impl BlockOperations {
         if parent_id != old_parent_id {
             if parent_id != DEFAULT_PARENT {
                 node.insert(txn, Arc::from(PARENT_ID), parent_id.to_string());
+                index.set_parent_id(block_id, Some(parent_id));
             } else {
                 node.remove(txn, &Arc::from(PARENT_ID));
+                index.set_parent_id(block_id, None);
             }
         }
     
@@ -191,65 +215,10 @@ impl BlockOperations {
     }
 
     // Helper methods
-    fn find_block_index(txn: &TransactionMut, array: ArrayRef, block_id: &str) -> Option<u32> {
-        array
-            .iter(txn)
-            .position(|x| x.to_string(txn) == block_id)
-            .map(|pos| pos as u32)
-    }
-
-    fn find_block_referencing_prev_id(
-        txn: &mut TransactionMut,
-        blocks_map: MapRef,
-        prev_id_value: &str
-    ) -> Vec<String> {
-        log_info!("find_block_referencing_prev_id: Searching for blocks with prev_id = {}", prev_id_value);
-
-        // Collect all block IDs first
-        let block_ids: Vec<String> = blocks_map
-            .iter(txn)
-            .map(|(id, _)| id.to_string())
-            .collect();
-
-        log_info!("  Found {} total blocks to check", block_ids.len());
-
-        // Then check each block individually
-        let results: Vec<String> = block_ids
-            .iter()
-            .filter(|id| {
-                let block_map = blocks_map.get_or_init_map(txn, Arc::from(id.as_str()));
-                log_info!("  Checking block: {}", id);
-
-                if let Some(prev_id_out) = block_map.get(txn, PREV_ID) {
-                    if let yrs::Out::Any(yrs::Any::String(s)) = prev_id_out {
-                        let matches = s.to_string() == prev_id_value;
-                        log_info!("    Block has prev_id: {}, matches target: {}", s, matches);
-                        matches
-                    } else {
-                        log_info!("    Block has prev_id but not a string type");
-                        false
-                    }
-                } else {
-                    log_info!("    Block does not have prev_id");
-                    false
-                }
-            })
-            .cloned()
-            .collect();
-
-        log_info!(
-            "find_block_referencing_prev_id: Found {} blocks with prev_id = {}: {:?}",
-            results.len(),
-            prev_id_value,
-            results
-        );
-
-        results
-    }
-
     fn handle_prev_id_chain(
         txn: &mut TransactionMut,
         blocks_map: MapRef,
+        index: &mut BlockIndex,
         block_id: &str,
         prev_id: Option<String>
     ) -> Result<(), CustomRustError> {
@@ -258,11 +227,7 @@ impl BlockOperations {
         if let Some(prev_id) = prev_id {
             log_info!("  Finding blocks that reference prev_id: {}", prev_id);
             // Find all blocks that have this prev_id
-            let blocks_with_same_prev_id = Self::find_block_referencing_prev_id(
-                txn,
-                blocks_map.clone(),
-                &prev_id
-            );
+            let blocks_with_same_prev_id = index.next_ids(&prev_id);
 
             log_info!(
                 "  Found {} blocks with prev_id {}: {:?}",
@@ -279,6 +244,7 @@ impl BlockOperations {
                     block_id,
                     prev_id
                 );
+                index.set_prev_id(&other_block_id, Some(block_id));
                 let other_block = blocks_map.get_or_init_map(txn, other_block_id);
                 other_block.insert(txn, Arc::from(PREV_ID), block_id.to_string());
             }
@@ -293,6 +259,7 @@ impl BlockOperations {
     fn remove_block_from_prev_id_chain(
         txn: &mut TransactionMut,
         blocks_map: MapRef,
+        index: &mut BlockIndex,
         block_id: &str
     ) -> Result<(), CustomRustError> {
         log_info!("remove_block_from_prev_id_chain: Removing block {} from chain", block_id);
@@ -311,13 +278,14 @@ impl BlockOperations {
 
         // Find all blocks that reference this block as their prev_id
         log_info!("  Finding blocks that reference {} as their prev_id", block_id);
-        let next_blocks = Self::find_block_referencing_prev_id(txn, blocks_map.clone(), block_id);
+        let next_blocks = index.next_ids(block_id);
 
         log_info!("  Found {} next blocks: {:?}", next_blocks.len(), next_blocks);
 
         // Update each next block to point to this block's prev_id
         for next_id in next_blocks {
             let next_block = blocks_map.get_or_init_map(txn, next_id.clone());
+            index.set_prev_id(&next_id, prev_id.as_deref());
 
             // Update the next block to point to this block's prev_id
             if let Some(prev_id) = &prev_id {
@@ -357,6 +325,7 @@ impl BlockOperations {
     fn handle_following_connection(
         txn: &mut TransactionMut,
         blocks_map: MapRef,
+        index: &mut BlockIndex,
         block_id: &str,
         next_id: Option<String>,
         prev_id: Option<String>
@@ -372,7 +341,7 @@ impl BlockOperations {
         // that the previous node can be referenced by multiple nodes
         if let Some(prev_id) = &prev_id {
             log_info!("  Using prev_id strategy (prev_id={})", prev_id);
-            let next_nodes = Self::find_block_referencing_prev_id(txn, blocks_map.clone(), prev_id);
+            let next_nodes = index.next_ids(prev_id);
 
             log_info!(
                 "  Found {} nodes that reference prev_id {}: {:?}",
@@ -383,6 +352,7 @@ impl BlockOperations {
 
             for next_id in next_nodes {
                 log_info!("  Updating block {} to point to {}", next_id, block_id);
+                index.set_prev_id(&next_id, Some(block_id));
                 let next_block = blocks_map.get_or_init_map(txn, next_id);
                 next_block.insert(txn, Arc::from(PREV_ID), block_id.to_string());
             }
@@ -397,6 +367,7 @@ impl BlockOperations {
             let next_block: MapRef = blocks_map.get_or_init_map(txn, Arc::from(next_id.as_str()));
 
             log_info!("  Setting prev_id of next block {} to {}", next_id, block_id);
+            index.set_prev_id(next_id, Some(block_id));
             next_block.insert(txn, Arc::from(PREV_ID), block_id.to_string());
 
             log_info!(
@@ -445,66 +416,4 @@ impl BlockOperations {
         log_info!("handle_following_connection: Completed");
         Ok(())
     }
-
-    /// Build a mapping of parents to their children by analyzing all blocks in the map
-    /// Build a mapping of parents to their children by analyzing all blocks in the map
-    pub fn build_parent_child_structure(
-        txn: &mut TransactionMut, // Changed from 'mut txn: &TransactionMut'
-        blocks_map: MapRef
-    ) -> HashMap<String, Vec<String>> {
-        log_info!("Building parent-child structure");
-
-        let mut blocks_by_parent: HashMap<String, Vec<String>> = HashMap::new();
-
-        // Collect all block IDs
-        let block_ids: Vec<String> = blocks_map
-            .iter(txn)
-            .map(|(id, _)| id.to_string())
-            .collect();
-
-        log_info!("Found {} total blocks", block_ids.len());
-
-        // Assign each block to its parent
-        for block_id in &block_ids {
-            let block_data = blocks_map.get_or_init_map(txn, Arc::from(block_id.as_str()));
-
-            // Get parent ID if available, otherwise use "root"
-            let parent_id = if let Some(parent_out) = block_data.get(txn, PARENT_ID) {
-                if let yrs::Out::Any(yrs::Any::String(s)) = parent_out {
-                    s.to_string()
-                } else {
-                    "root".to_string()
-                }
-            } else {
-                "root".to_string()
-            };
-
-            blocks_by_parent.entry(parent_id.clone()).or_default().push(block_id.clone());
-            log_info!("Block {} assigned to parent {}", block_id, parent_id);
-        }
-
-        log_info!("Built parent-child structure with {} parent entries", blocks_by_parent.len());
-        blocks_by_parent
-    }
-
-    /// Find all descendants of a block (recursive)
-    pub fn find_descendants(
-        block_id: &str,
-        blocks_by_parent: &HashMap<String, Vec<String>>
-    ) -> Vec<String> {
-        let mut descendants = Vec::new();
-
-        // Get direct children
-        if let Some(children) = blocks_by_parent.get(block_id) {
-            for child_id in children {
-                descendants.push(child_id.clone());
-
-                // Recursively get children of children
-                let child_descendants = Self::find_descendants(child_id, blocks_by_parent);
-                descendants.extend(child_descendants);
-            }
-        }
-
-        descendants
-    }
 }
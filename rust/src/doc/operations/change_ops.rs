@@ -0,0 +1,487 @@
+use std::collections::{BTreeMap, BTreeSet, HashMap};
+use std::sync::{Arc, Mutex};
+
+use yrs::types::{EntryChange, Event, Events, PathSegment};
+use yrs::{DeepObservable, Doc, Origin, Subscription, Transact, TransactionMut};
+
+use crate::doc::constants::{
+    ATTRIBUTES, BLOCKS, CHAIN_REPAIR_ORIGIN, INTEGRITY_REPAIR_ORIGIN, LOCAL_ACTION_ORIGIN, NEXT_ID, ORDERING_MIGRATION_ORIGIN, PARENT_ID, PREV_ID,
+    ROOT_ID, SNAPSHOT_RESTORE_ORIGIN, TEXT, UNDO_MANAGER_ORIGIN,
+};
+use crate::doc::document_types::{
+    AttributeModeDoc, BlockActionDoc, BlockActionTypeDoc, BlockDoc, CustomRustError, DocumentChangeEvent, DocumentChangeKindDoc, DocumentState,
+};
+use crate::doc::error::DocError;
+use crate::doc::operations::delta_ops::DeltaOperations;
+use crate::doc::operations::update_ops::UpdateOperations;
+use crate::{log_info, log_error};
+
+/// Origin of the transactions of the current undo manager, which is replaced with the document
+pub type UndoOrigin = Arc<Mutex<Option<Origin>>>;
+
+/// Block level summary of deep events observed on the root map
+#[derive(Default, Debug)]
+pub struct BlockChanges {
+    /// Every block touched in any way
+    pub changed: BTreeSet<String>,
+    /// Blocks that did not exist before
+    pub inserted: BTreeSet<String>,
+    /// Blocks removed from the blocks map
+    pub deleted: BTreeSet<String>,
+    /// Blocks whose parentId or prevId changed
+    pub moved: BTreeSet<String>,
+    /// Blocks whose type, attributes or text changed
+    pub updated: BTreeSet<String>,
+    /// Text diffs in the order they were observed, as JSON delta strings
+    pub text_deltas: BTreeMap<String, Vec<String>>,
+    /// Blocks that received a brand new text, which has no diff to report
+    pub text_replaced: BTreeSet<String>,
+    pub root_id_changed: bool,
+    /// The blocks map itself was created, so nested events were not emitted
+    pub blocks_replaced: bool,
+}
+
+impl BlockChanges {
+    pub fn collect(&mut self, txn: &TransactionMut, events: &Events) {
+        for event in events.iter() {
+            let path = event.path();
+            let mut segments = path.iter().map(|segment| match segment {
+                PathSegment::Key(key) => Some(key.clone()),
+                PathSegment::Index(_) => None,
+            });
+
+            match (segments.next(), segments.next(), segments.next()) {
+                // Changes on the root map itself
+                (None, _, _) => {
+                    if let Event::Map(map_event) = event {
+                        for key in map_event.keys(txn).keys() {
+                            match key.as_ref() {
+                                BLOCKS => self.blocks_replaced = true,
+                                ROOT_ID => self.root_id_changed = true,
+                                _ => {}
+                            }
+                        }
+                    }
+                }
+                // Blocks added to or removed from the blocks map
+                (Some(Some(key)), None, _) if key.as_ref() == BLOCKS => {
+                    if let Event::Map(map_event) = event {
+                        for (block_id, change) in map_event.keys(txn) {
+                            self.collect_block_entry(block_id, change);
+                        }
+                    }
+                }
+                // Keys of a single block
+                (Some(Some(key)), Some(Some(block_id)), None) if key.as_ref() == BLOCKS => {
+                    let block_id = block_id.to_string();
+                    self.changed.insert(block_id.clone());
+                    if let Event::Map(map_event) = event {
+                        for (field, change) in map_event.keys(txn) {
+                            match field.as_ref() {
+                                PARENT_ID | PREV_ID => {
+                                    self.moved.insert(block_id.clone());
+                                }
+                                NEXT_ID => {}
+                                TEXT => {
+                                    if !matches!(change, EntryChange::Removed(_)) {
+                                        self.text_replaced.insert(block_id.clone());
+                                    }
+                                    self.updated.insert(block_id.clone());
+                                }
+                                _ => {
+                                    self.updated.insert(block_id.clone());
+                                }
+                            }
+                        }
+                    }
+                }
+                // Text or attributes nested inside a block
+                (Some(Some(key)), Some(Some(block_id)), Some(field)) if key.as_ref() == BLOCKS => {
+                    let block_id = block_id.to_string();
+                    self.changed.insert(block_id.clone());
+                    self.updated.insert(block_id.clone());
+
+                    match (event, field.as_deref()) {
+                        (Event::Text(text_event), Some(TEXT)) => {
+                            self.collect_text_delta(txn, &block_id, text_event.delta(txn));
+                        }
+                        (_, Some(ATTRIBUTES)) => {}
+                        _ => log_info!("BlockChanges: Unexpected nested change in block {}", block_id),
+                    }
+                }
+                _ => {}
+            }
+        }
+    }
+
+    fn collect_block_entry(&mut self, block_id: &str, change: &EntryChange) {
+        match change {
+            EntryChange::Removed(_) => {
+                self.changed.remove(block_id);
+                self.updated.remove(block_id);
+                self.moved.remove(block_id);
+                self.text_deltas.remove(block_id);
+                self.text_replaced.remove(block_id);
+                // A block created and removed within the same recording never existed
+                if !self.inserted.remove(block_id) {
+                    self.deleted.insert(block_id.to_string());
+                }
+            }
+            EntryChange::Inserted(_) => {
+                if !self.deleted.remove(block_id) {
+                    self.inserted.insert(block_id.to_string());
+                } else {
+                    self.updated.insert(block_id.to_string());
+                    self.moved.insert(block_id.to_string());
+                    self.text_replaced.insert(block_id.to_string());
+                }
+                self.changed.insert(block_id.to_string());
+            }
+            EntryChange::Updated(_, _) => {
+                self.changed.insert(block_id.to_string());
+                self.updated.insert(block_id.to_string());
+                self.moved.insert(block_id.to_string());
+                self.text_replaced.insert(block_id.to_string());
+            }
+        }
+    }
+
+    fn collect_text_delta(&mut self, txn: &TransactionMut, block_id: &str, deltas: &[yrs::types::Delta]) {
+        let json_deltas = deltas
+            .iter()
+            .map(|delta| DeltaOperations::delta_to_json(txn, delta.clone()))
+            .collect::<Result<Vec<_>, CustomRustError>>();
+
+        match json_deltas.map(|deltas| serde_json::to_string(&deltas)) {
+            Ok(Ok(delta)) => self.text_deltas.entry(block_id.to_string()).or_default().push(delta),
+            _ => {
+                log_error!("BlockChanges: Failed to serialize text delta of block {}", block_id);
+                self.text_replaced.insert(block_id.to_string());
+            }
+        }
+    }
+
+    /// Translate the recorded changes into editor actions.
+    ///
+    /// Actions are ordered so they can be replayed one after another: deletions bottom-up
+    /// with paths of the previous state, then moves, insertions top-down and updates, all
+    /// with paths of the new state.
+    pub fn into_actions(self, before: Option<&DocumentState>, after: &DocumentState) -> Vec<BlockActionDoc> {
+        let before_paths = before.map(ChangeOperations::block_paths).unwrap_or_default();
+        let after_paths = ChangeOperations::block_paths(after);
+        let existed = |id: &str| before.map(|state| state.blocks.contains_key(id)).unwrap_or(false);
+
+        let mut actions = Vec::new();
+
+        // Deletions, only the top-most deleted block of a subtree
+        if let Some(before) = before {
+            let mut deleted: Vec<(&Vec<u32>, &BlockDoc)> = self.deleted
+                .iter()
+                .filter(|id| !Self::has_deleted_ancestor(before, id, &self.deleted))
+                .filter_map(|id| Some((before_paths.get(id)?, before.blocks.get(id)?)))
+                .collect();
+            deleted.sort_by(|a, b| b.0.cmp(a.0));
+
+            for (path, block) in deleted {
+                actions.push(BlockActionDoc {
+                    action: BlockActionTypeDoc::Delete,
+                    block: block.clone(),
+                    path: path.clone(),
+                    old_path: None,
+                });
+            }
+        }
+
+        // Moves between or within parents
+        if let Some(before) = before {
+            let mut moved: Vec<BlockActionDoc> = Self::moved_blocks(before, after, &self)
+                .into_iter()
+                .filter_map(|id| {
+                    let mut block = after.blocks.get(&id)?.clone();
+                    block.old_parent_id = before.blocks.get(&id)?.parent_id.clone();
+                    Some(BlockActionDoc {
+                        action: BlockActionTypeDoc::Move,
+                        block,
+                        path: after_paths.get(&id)?.clone(),
+                        old_path: before_paths.get(&id).cloned(),
+                    })
+                })
+                .collect();
+            moved.sort_by(|a, b| a.path.cmp(&b.path));
+            actions.extend(moved);
+        }
+
+        // Insertions, parents before their children
+        let mut inserted: Vec<BlockActionDoc> = after.blocks
+            .iter()
+            .filter(|(id, _)| self.inserted.contains(*id) || (self.blocks_replaced && !existed(id)))
+            .filter_map(|(id, block)| Some(BlockActionDoc {
+                action: BlockActionTypeDoc::Insert,
+                block: block.clone(),
+                path: after_paths.get(id)?.clone(),
+                old_path: None,
+            }))
+            .collect();
+        inserted.sort_by(|a, b| a.path.cmp(&b.path));
+        actions.extend(inserted);
+
+        // Updates of attributes and text
+        for id in self.updated.iter().filter(|id| existed(id) && !self.inserted.contains(*id)) {
+            let (Some(block), Some(path)) = (after.blocks.get(id), after_paths.get(id)) else {
+                continue;
+            };
+
+            let deltas = match self.text_deltas.get(id) {
+                Some(deltas) if !self.text_replaced.contains(id) => deltas.iter().cloned().map(Some).collect(),
+                // Without a diff the whole text is sent, prefixed with the removal of the old one
+                _ => vec![Self::replacement_delta(before.and_then(|state| state.blocks.get(id)), block)],
+            };
+
+            for delta in deltas {
+                let mut block = block.clone();
+                block.delta = delta;
+                actions.push(BlockActionDoc {
+                    action: BlockActionTypeDoc::Update,
+                    block,
+                    path: path.clone(),
+                    old_path: None,
+                });
+            }
+        }
+
+        actions
+    }
+
+    /// Flatten the recorded changes into subscriber events: deletions, moves, insertions,
+    /// then updates, each updated block followed by its text deltas in observed order.
+    pub fn into_events(self, origin: Option<String>) -> Vec<DocumentChangeEvent> {
+        let event = |kind, block_id: &String, delta| DocumentChangeEvent {
+            kind,
+            block_id: block_id.clone(),
+            delta,
+            origin: origin.clone(),
+        };
+
+        let mut events: Vec<DocumentChangeEvent> = self.deleted
+            .iter()
+            .map(|id| event(DocumentChangeKindDoc::BlockDeleted, id, None))
+            .collect();
+        events.extend(self.moved
+            .iter()
+            .filter(|id| !self.inserted.contains(*id))
+            .map(|id| event(DocumentChangeKindDoc::BlockMoved, id, None)));
+        events.extend(self.inserted
+            .iter()
+            .map(|id| event(DocumentChangeKindDoc::BlockInserted, id, None)));
+
+        for id in self.updated.iter().filter(|id| !self.inserted.contains(*id)) {
+            events.push(event(DocumentChangeKindDoc::BlockUpdated, id, None));
+            if self.text_replaced.contains(id) {
+                continue;
+            }
+            for delta in self.text_deltas.get(id).into_iter().flatten() {
+                events.push(event(DocumentChangeKindDoc::TextDeltaChanged, id, Some(delta.clone())));
+            }
+        }
+
+        events
+    }
+
+    fn has_deleted_ancestor(state: &DocumentState, id: &str, deleted: &BTreeSet<String>) -> bool {
+        let mut visited = BTreeSet::new();
+        let mut current = state.blocks.get(id).and_then(|block| block.parent_id.as_deref());
+        while let Some(parent_id) = current {
+            if deleted.contains(parent_id) {
+                return true;
+            }
+            if !visited.insert(parent_id) {
+                return false;
+            }
+            current = state.blocks.get(parent_id).and_then(|block| block.parent_id.as_deref());
+        }
+        false
+    }
+
+    /// Blocks that changed parent, plus the smallest set of siblings whose reordering explains
+    /// the new order of every affected parent
+    fn moved_blocks(before: &DocumentState, after: &DocumentState, changes: &BlockChanges) -> BTreeSet<String> {
+        let survives = |id: &String| {
+            !changes.inserted.contains(id) &&
+                !changes.deleted.contains(id) &&
+                before.blocks.contains_key(id) &&
+                after.blocks.contains_key(id)
+        };
+
+        let mut moved = BTreeSet::new();
+        let mut parents = BTreeSet::new();
+        for id in changes.moved.iter().filter(|id| survives(id)) {
+            let old_parent = before.blocks[id].parent_id.clone().unwrap_or_else(|| "root".to_string());
+            let new_parent = after.blocks[id].parent_id.clone().unwrap_or_else(|| "root".to_string());
+            if old_parent != new_parent {
+                moved.insert(id.clone());
+            }
+            parents.insert(old_parent);
+            parents.insert(new_parent);
+        }
+
+        for parent_id in parents {
+            let old_order: Vec<&String> = before.children_map
+                .get(&parent_id)
+                .map(|children| children.iter().filter(|id| survives(id) && !moved.contains(*id)).collect())
+                .unwrap_or_default();
+            let new_positions: HashMap<&String, usize> = after.children_map
+                .get(&parent_id)
+                .map(|children| children.iter().enumerate().map(|(index, id)| (id, index)).collect())
+                .unwrap_or_default();
+
+            // Siblings outside the longest run that kept its relative order were moved
+            let positions: Vec<usize> = old_order.iter().filter_map(|id| new_positions.get(id).copied()).collect();
+            let kept = Self::longest_increasing_subsequence(&positions);
+            for (index, id) in old_order.iter().filter(|id| new_positions.contains_key(*id)).enumerate() {
+                if !kept.contains(&index) {
+                    moved.insert((*id).clone());
+                }
+            }
+        }
+        moved
+    }
+
+    /// Indexes of one longest strictly increasing subsequence, preferring the earliest elements
+    fn longest_increasing_subsequence(values: &[usize]) -> BTreeSet<usize> {
+        let mut tails: Vec<usize> = Vec::new();
+        let mut previous: Vec<Option<usize>> = vec![None; values.len()];
+        for (index, value) in values.iter().enumerate() {
+            let slot = tails.partition_point(|&tail| values[tail] < *value);
+            previous[index] = slot.checked_sub(1).map(|slot| tails[slot]);
+            if slot == tails.len() {
+                tails.push(index);
+            } else {
+                tails[slot] = index;
+            }
+        }
+
+        let mut result = BTreeSet::new();
+        let mut current = tails.last().copied();
+        while let Some(index) = current {
+            result.insert(index);
+            current = previous[index];
+        }
+        result
+    }
+
+    fn replacement_delta(old_block: Option<&BlockDoc>, new_block: &BlockDoc) -> Option<String> {
+        let old_len = old_block
+            .and_then(|block| block.delta.as_deref())
+            .map(Self::delta_text_len)
+            .unwrap_or(0);
+        if old_len == 0 {
+            return new_block.delta.clone();
+        }
+
+        let mut ops = vec![serde_json::json!({ "delete": old_len })];
+        if let Some(new_delta) = new_block.delta.as_deref() {
+            if let Ok(serde_json::Value::Array(new_ops)) = serde_json::from_str(new_delta) {
+                ops.extend(new_ops);
+            }
+        }
+        serde_json::to_string(&ops).ok()
+    }
+
+    fn delta_text_len(delta: &str) -> u32 {
+        match serde_json::from_str::<serde_json::Value>(delta) {
+            Ok(serde_json::Value::Array(ops)) => ops
+                .iter()
+                .filter_map(|op| op.get("insert").and_then(|insert| insert.as_str()))
+                .map(|insert| insert.encode_utf16().count() as u32)
+                .sum(),
+            _ => 0,
+        }
+    }
+}
+
+/// Receives every change event of a subscribed document
+pub type ChangeListener = Arc<dyn Fn(DocumentChangeEvent) + Send + Sync>;
+
+pub struct ChangeOperations;
+
+impl ChangeOperations {
+    /// Run `f` while recording every change it makes to the root map
+    pub fn record_changes<F, R>(doc: &Doc, f: F) -> Result<(R, BlockChanges), CustomRustError>
+    where
+        F: FnOnce() -> Result<R, CustomRustError>,
+    {
+        let root = doc.get_or_insert_map(ROOT_ID);
+        let changes = Arc::new(Mutex::new(BlockChanges::default()));
+        let subscription = {
+            let changes = changes.clone();
+            root.observe_deep(move |txn, events| {
+                if let Ok(mut changes) = changes.lock() {
+                    changes.collect(txn, events);
+                }
+            })
+        };
+
+        // Observers fire when transactions opened by `f` are committed
+        let result = f();
+        drop(subscription);
+
+        let changes = std::mem::take(&mut *changes.lock()
+            .map_err(|_| DocError::StateError("Change collector was poisoned".into()))?);
+        Ok((result?, changes))
+    }
+
+    /// Push the changes of every committed transaction to `listener` until the subscription is dropped
+    pub fn subscribe(doc: &Doc, listener: ChangeListener, undo_origin: UndoOrigin) -> Subscription {
+        let root = doc.get_or_insert_map(ROOT_ID);
+        root.observe_deep(move |txn, events| {
+            let mut changes = BlockChanges::default();
+            changes.collect(txn, events);
+            let undo_origin = undo_origin.lock().ok().and_then(|origin| origin.clone());
+            for event in changes.into_events(Self::origin_label(txn.origin(), undo_origin.as_ref())) {
+                listener(event);
+            }
+        })
+    }
+
+    /// Label of the origins this crate writes with. The undo manager tags its transactions with
+    /// a pointer of its own, origins of sync providers and other code are not reported.
+    fn origin_label(origin: Option<&Origin>, undo_origin: Option<&Origin>) -> Option<String> {
+        let origin = origin?;
+        if Some(origin) == undo_origin {
+            return Some(UNDO_MANAGER_ORIGIN.to_string());
+        }
+        [LOCAL_ACTION_ORIGIN, CHAIN_REPAIR_ORIGIN, INTEGRITY_REPAIR_ORIGIN, ORDERING_MIGRATION_ORIGIN, SNAPSHOT_RESTORE_ORIGIN]
+            .into_iter()
+            .find(|label| origin.as_ref() == label.as_bytes())
+            .map(str::to_string)
+    }
+
+    /// Current document state, or None while the document is not initialized yet
+    pub fn snapshot_state(doc: &Doc, doc_id: &str, attribute_mode: AttributeModeDoc) -> Option<DocumentState> {
+        let root = doc.get_or_insert_map(ROOT_ID);
+        let txn = doc.transact();
+        UpdateOperations::extract_document_state(&txn, root, doc_id, attribute_mode).ok()
+    }
+
+    /// Path of every block reachable from the root, as used by the editor
+    pub fn block_paths(state: &DocumentState) -> HashMap<String, Vec<u32>> {
+        let mut paths = HashMap::new();
+        paths.insert(state.root_id.clone(), Vec::new());
+
+        let mut stack = vec![(state.root_id.clone(), Vec::new())];
+        while let Some((parent_id, parent_path)) = stack.pop() {
+            if let Some(children) = state.children_map.get(&parent_id) {
+                for (index, child_id) in children.iter().enumerate() {
+                    if paths.contains_key(child_id) {
+                        continue;
+                    }
+                    let mut path: Vec<u32> = parent_path.clone();
+                    path.push(index as u32);
+                    paths.insert(child_id.clone(), path.clone());
+                    stack.push((child_id.clone(), path));
+                }
+            }
+        }
+        paths
+    }
+}
@@ -50,6 +50,19 @@ impl DeltaOperations {
         Self::apply_delta_diff_to_text(txn, text, &parsed_delta)
     }
 
+    /// Length of a text of `text_len` after applying a delta diff, failing where applying would
+    pub fn check_delta(delta: &str, text_len: u32) -> Result<u32, CustomRustError> {
+        let parsed_delta: Vec<HashMap<String, Value>> = serde_json::from_str(delta)
+            .map_err(|e| DocError::DecodingError(format!("Failed to parse delta diff: {}", e)))?;
+
+        let mut current_len = text_len;
+        let mut cursor_pos = 0;
+        for d in &parsed_delta {
+            Self::parse_delta_operation(d, &mut cursor_pos, &mut current_len)?;
+        }
+        Ok(current_len)
+    }
+
     pub fn apply_delta_diff_to_text(
         txn: &mut TransactionMut,
         text: TextRef,
@@ -206,6 +219,20 @@ impl DeltaOperations {
         }
     }
     
+    /// Text inserts of a JSON delta with their attributes. Embeds and malformed deltas are skipped.
+    pub fn text_inserts(delta: Option<&str>) -> Vec<(String, JsonMap<String, Value>)> {
+        let Some(ops) = delta.and_then(|delta| serde_json::from_str::<Vec<Value>>(delta).ok()) else {
+            return Vec::new();
+        };
+        ops.into_iter()
+            .filter_map(|op| {
+                let insert = op.get(INSERT)?.as_str()?.to_string();
+                let attributes = op.get(ATTRIBUTES).and_then(Value::as_object).cloned().unwrap_or_default();
+                Some((insert, attributes))
+            })
+            .collect()
+    }
+
     /// Convert a yjs Any value to JSON
     pub fn any_to_json(any: &yrs::Any) -> Value {
         match any {
@@ -0,0 +1,249 @@
+use serde_json::{Map as JsonMap, Value};
+
+use crate::doc::constants::{
+    BG_COLOR, BOLD, BULLETED_LIST_TYPE, CHECKED_ATTR, CODE, CODE_TYPE, COL_POSITION_ATTR, DIVIDER_TYPE, FONT_COLOR,
+    HEADING_TYPE, HREF, IMAGE_TYPE, ITALIC, LANGUAGE_ATTR, LEVEL_ATTR, NUMBERED_LIST_TYPE, NUMBER_ATTR, QUOTE_TYPE,
+    ROOT_TYPE, ROW_POSITION_ATTR, STRIKETHROUGH, TABLE_TYPE, TODO_LIST_TYPE, UNDERLINE, URL_ATTR,
+};
+use crate::doc::document_types::{BlockDoc, DocumentState};
+use crate::doc::operations::delta_ops::DeltaOperations;
+use crate::log_info;
+
+/// Schemes links may use, anything else (javascript:, data:, vbscript:, ...) is dropped
+const LINK_SCHEMES: [&str; 4] = ["http", "https", "mailto", "tel"];
+const IMAGE_SCHEMES: [&str; 2] = ["http", "https"];
+
+pub struct HtmlOperations;
+
+impl HtmlOperations {
+    /// Render the document as an HTML fragment. Text is escaped and only safe URLs and
+    /// colors make it into attributes, so the output can be embedded as is.
+    pub fn export_html(state: &DocumentState) -> String {
+        log_info!("export_html: Exporting {} blocks for doc_id: {}", state.blocks.len(), state.doc_id);
+
+        match state.blocks.get(&state.root_id) {
+            Some(root) if root.ty != ROOT_TYPE => Self::render_siblings(state, std::slice::from_ref(&state.root_id)),
+            _ => Self::render_children(state, &state.root_id),
+        }
+    }
+
+    fn render_children(state: &DocumentState, parent_id: &str) -> String {
+        match state.children_map.get(parent_id) {
+            Some(children) => Self::render_siblings(state, children),
+            None => String::new(),
+        }
+    }
+
+    /// Consecutive list items of the same type share one list element
+    fn render_siblings(state: &DocumentState, ids: &[String]) -> String {
+        let mut out = String::new();
+        let mut open_list: Option<&str> = None;
+
+        for block in ids.iter().filter_map(|id| state.blocks.get(id)) {
+            let list = match block.ty.as_str() {
+                BULLETED_LIST_TYPE | TODO_LIST_TYPE => Some("ul"),
+                NUMBERED_LIST_TYPE => Some("ol"),
+                _ => None,
+            };
+            // A numbered item with its own number starts a new list
+            let restarts = block.ty == NUMBERED_LIST_TYPE && block.attributes.contains_key(NUMBER_ATTR);
+            let same_type = open_list.is_some() && list == open_list && !restarts;
+
+            if let Some(tag) = open_list.filter(|_| !same_type) {
+                out.push_str(&format!("</{}>", tag));
+                open_list = None;
+            }
+            if let (Some(tag), None) = (list, open_list) {
+                out.push_str(&Self::open_list_tag(block, tag));
+                open_list = Some(tag);
+            }
+            out.push_str(&Self::render_block(state, block));
+        }
+
+        if let Some(tag) = open_list {
+            out.push_str(&format!("</{}>", tag));
+        }
+        out
+    }
+
+    fn open_list_tag(first: &BlockDoc, tag: &str) -> String {
+        if first.ty == TODO_LIST_TYPE {
+            return "<ul class=\"todo-list\">".to_string();
+        }
+        match first.attributes.get(NUMBER_ATTR).and_then(|n| n.parse::<u64>().ok()) {
+            Some(start) if tag == "ol" && start != 1 => format!("<ol start=\"{}\">", start),
+            _ => format!("<{}>", tag),
+        }
+    }
+
+    fn render_block(state: &DocumentState, block: &BlockDoc) -> String {
+        let text = || Self::render_delta(block.delta.as_deref());
+        let children = || Self::render_children(state, &block.id);
+
+        match block.ty.as_str() {
+            HEADING_TYPE => {
+                let level = block.attributes.get(LEVEL_ATTR).and_then(|l| l.parse().ok()).unwrap_or(1usize).clamp(1, 6);
+                format!("<h{level}>{}</h{level}>{}", text(), children())
+            }
+            BULLETED_LIST_TYPE | NUMBERED_LIST_TYPE => format!("<li>{}{}</li>", text(), children()),
+            TODO_LIST_TYPE => {
+                let checked = block.attributes.get(CHECKED_ATTR).map(|c| c == "true").unwrap_or(false);
+                let checkbox = if checked {
+                    "<input type=\"checkbox\" disabled checked>"
+                } else {
+                    "<input type=\"checkbox\" disabled>"
+                };
+                format!("<li>{} {}{}</li>", checkbox, text(), children())
+            }
+            QUOTE_TYPE => format!("<blockquote><p>{}</p>{}</blockquote>", text(), children()),
+            CODE_TYPE => {
+                let code: String = DeltaOperations::text_inserts(block.delta.as_deref())
+                    .into_iter()
+                    .map(|(insert, _)| insert)
+                    .collect();
+                let class = block.attributes.get(LANGUAGE_ATTR)
+                    .filter(|language| {
+                        !language.is_empty()
+                            && language.chars().all(|c| c.is_ascii_alphanumeric() || matches!(c, '-' | '_' | '+' | '#'))
+                    })
+                    .map(|language| format!(" class=\"language-{}\"", Self::escape(language)))
+                    .unwrap_or_default();
+                format!("<pre><code{}>{}</code></pre>{}", class, Self::escape(&code), children())
+            }
+            DIVIDER_TYPE => "<hr>".to_string(),
+            IMAGE_TYPE => match block.attributes.get(URL_ATTR).and_then(|url| Self::sanitize_url(url, &IMAGE_SCHEMES)) {
+                Some(url) => format!("<img src=\"{}\" alt=\"\">", Self::escape(&url)),
+                None => String::new(),
+            },
+            TABLE_TYPE => Self::render_table(state, block),
+            // Paragraphs and blocks we do not know are rendered as text, nested blocks follow
+            _ => format!("<p>{}</p>{}", text(), children()),
+        }
+    }
+
+    fn render_table(state: &DocumentState, table: &BlockDoc) -> String {
+        let mut rows: Vec<Vec<Option<&String>>> = Vec::new();
+        for cell_id in state.children_map.get(&table.id).into_iter().flatten() {
+            let Some(cell) = state.blocks.get(cell_id) else {
+                continue;
+            };
+            let position = |key: &str| cell.attributes.get(key).and_then(|p| p.parse::<usize>().ok());
+            let (Some(row), Some(col)) = (position(ROW_POSITION_ATTR), position(COL_POSITION_ATTR)) else {
+                continue;
+            };
+            if rows.len() <= row {
+                rows.resize(row + 1, Vec::new());
+            }
+            if rows[row].len() <= col {
+                rows[row].resize(col + 1, None);
+            }
+            rows[row][col] = Some(cell_id);
+        }
+
+        let cols = rows.iter().map(Vec::len).max().unwrap_or(0);
+        let mut out = "<table><tbody>".to_string();
+        for row in &rows {
+            out.push_str("<tr>");
+            for col in 0..cols {
+                let content = row.get(col).copied().flatten()
+                    .map(|cell_id| Self::render_children(state, cell_id))
+                    .unwrap_or_default();
+                out.push_str(&format!("<td>{}</td>", content));
+            }
+            out.push_str("</tr>");
+        }
+        out.push_str("</tbody></table>");
+        out
+    }
+
+    fn render_delta(delta: Option<&str>) -> String {
+        DeltaOperations::text_inserts(delta)
+            .into_iter()
+            .map(|(insert, attributes)| Self::render_insert(&insert, &attributes))
+            .collect()
+    }
+
+    /// Wrap a text insert in the elements of its attributes, innermost first
+    fn render_insert(insert: &str, attributes: &JsonMap<String, Value>) -> String {
+        let flag = |key: &str| attributes.get(key).and_then(Value::as_bool).unwrap_or(false);
+        let mut html = Self::escape(insert).replace('\n', "<br>");
+
+        for (key, tag) in [(CODE, "code"), (STRIKETHROUGH, "s"), (UNDERLINE, "u"), (ITALIC, "em"), (BOLD, "strong")] {
+            if flag(key) {
+                html = format!("<{tag}>{html}</{tag}>");
+            }
+        }
+
+        let styles: Vec<String> = [(FONT_COLOR, "color"), (BG_COLOR, "background-color")]
+            .into_iter()
+            .filter_map(|(key, property)| {
+                let color = Self::sanitize_color(attributes.get(key)?.as_str()?)?;
+                Some(format!("{}: {}", property, color))
+            })
+            .collect();
+        if !styles.is_empty() {
+            html = format!("<span style=\"{}\">{}</span>", Self::escape(&styles.join("; ")), html);
+        }
+
+        if let Some(href) = attributes.get(HREF).and_then(Value::as_str).and_then(|href| Self::sanitize_url(href, &LINK_SCHEMES)) {
+            html = format!("<a href=\"{}\" rel=\"noopener noreferrer\">{}</a>", Self::escape(&href), html);
+        }
+        html
+    }
+
+    /// The URL if it is relative or uses one of the allowed schemes. Browsers ignore
+    /// whitespace and control characters inside a scheme, so they are stripped before checking.
+    fn sanitize_url(url: &str, schemes: &[&str]) -> Option<String> {
+        let url: String = url.chars().filter(|c| !c.is_control() && !c.is_whitespace()).collect();
+        if url.is_empty() {
+            return None;
+        }
+
+        let scheme_end = url.find([':', '/', '?', '#']);
+        match scheme_end {
+            Some(end) if url[end..].starts_with(':') => {
+                let scheme = url[..end].to_ascii_lowercase();
+                schemes.contains(&scheme.as_str()).then_some(url)
+            }
+            _ => Some(url),
+        }
+    }
+
+    /// CSS color of an attribute value. AppFlowy stores colors as 0xAARRGGBB, hex, rgb()
+    /// and named colors are passed through, anything else is dropped.
+    fn sanitize_color(color: &str) -> Option<String> {
+        let color = color.trim();
+        if let Some(hex) = color.strip_prefix("0x").or_else(|| color.strip_prefix("0X")) {
+            let argb = u32::from_str_radix(hex, 16).ok().filter(|_| hex.len() == 8)?;
+            let [a, r, g, b] = argb.to_be_bytes();
+            return Some(format!("rgba({}, {}, {}, {:.3})", r, g, b, a as f64 / 255.0));
+        }
+        if let Some(hex) = color.strip_prefix('#') {
+            let valid = matches!(hex.len(), 3 | 4 | 6 | 8) && hex.chars().all(|c| c.is_ascii_hexdigit());
+            return valid.then(|| color.to_string());
+        }
+        if let Some(args) = color.strip_prefix("rgba(").or_else(|| color.strip_prefix("rgb(")) {
+            let valid = args.strip_suffix(')')
+                .map(|args| args.chars().all(|c| c.is_ascii_digit() || matches!(c, ',' | '.' | ' ' | '%')))
+                .unwrap_or(false);
+            return valid.then(|| color.to_string());
+        }
+        let named = !color.is_empty() && color.chars().all(|c| c.is_ascii_alphabetic());
+        named.then(|| color.to_string())
+    }
+
+    fn escape(text: &str) -> String {
+        let mut out = String::with_capacity(text.len());
+        for ch in text.chars() {
+            match ch {
+                '&' => out.push_str("&amp;"),
+                '<' => out.push_str("&lt;"),
+                '>' => out.push_str("&gt;"),
+                '"' => out.push_str("&quot;"),
+                '\'' => out.push_str("&#39;"),
+                _ => out.push(ch),
+            }
+        }
+        out
+    }
+}
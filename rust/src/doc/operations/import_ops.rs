@@ -0,0 +1,283 @@
+use std::collections::{HashMap, HashSet};
+use std::sync::Arc;
+
+use serde_json::{Map as JsonMap, Value};
+use yrs::{Doc, Map, MapPrelim, MapRef, ReadTxn, StateVector, Transact, TransactionMut};
+
+use crate::doc::constants::{ATTRIBUTES, BLOCKS, ID, PARENT_ID, PREV_ID, ROOT_ID, ROOT_TYPE, TEXT, TYPE};
+use crate::doc::conversions::conversion::Conversion;
+use crate::doc::document_types::{AttributeModeDoc, BlockDoc, CustomRustError, UpdateEncodingDoc};
+use crate::doc::error::DocError;
+use crate::doc::operations::delta_ops::DeltaOperations;
+use crate::doc::utils::util::MapExt;
+use crate::{log_info, log_error};
+
+pub struct ImportOperations;
+
+impl ImportOperations {
+    /// Import AppFlowy editor JSON into an empty document, either the nested `Document.toJson()`
+    /// shape or the flat `docId/blocks/childrenMap` shape of `Conversion::document_to_json`.
+    /// Parent and sibling links are rebuilt from the tree, stored `prevId`s are ignored.
+    pub fn import_json(
+        doc: &Doc,
+        doc_id: &str,
+        json: &str,
+        encoding: UpdateEncodingDoc,
+        attribute_mode: AttributeModeDoc
+    ) -> Result<Vec<u8>, CustomRustError> {
+        log_info!("import_json: Importing {} bytes of JSON for doc_id: {}", json.len(), doc_id);
+
+        let value: Value = serde_json::from_str(json).map_err(|e| {
+            log_error!("import_json: Failed to parse JSON for doc_id: {}: {}", doc_id, e);
+            DocError::DecodingError(format!("Failed to parse document JSON: {}", e))
+        })?;
+
+        let mut blocks = Vec::new();
+        let mut ids = BlockIds::new(doc.client_id());
+        match &value {
+            Value::Object(object) if object.contains_key("blocks") && object.contains_key("childrenMap") => {
+                Self::collect_flat(object, attribute_mode, &mut blocks)?;
+            }
+            Value::Object(object) => {
+                let root = object.get("document").unwrap_or(&value);
+                Self::reserve_ids(root, &mut ids)?;
+                Self::collect_nested(root, None, None, attribute_mode, &mut ids, &mut blocks)?;
+            }
+            _ => return Err(DocError::ValidationError("Document JSON must be an object".into()).into()),
+        }
+
+        Self::write_blocks(doc, doc_id, blocks, encoding, attribute_mode)
+    }
+
+    /// Write blocks into an empty document and return the update creating it. The first block
+    /// is the root, and every block must come after its parent and previous sibling.
+    pub fn write_blocks(
+        doc: &Doc,
+        doc_id: &str,
+        blocks: Vec<BlockDoc>,
+        encoding: UpdateEncodingDoc,
+        attribute_mode: AttributeModeDoc
+    ) -> Result<Vec<u8>, CustomRustError> {
+        let root_id = blocks.first()
+            .map(|block| block.id.clone())
+            .ok_or_else(|| DocError::ValidationError("Imported document has no blocks".into()))?;
+
+        let root = doc.get_or_insert_map(ROOT_ID);
+        let mut txn = doc.transact_mut();
+        let blocks_map = root.get_or_init_map(&mut txn, BLOCKS);
+        if blocks_map.len(&txn) > 0 {
+            log_error!("write_blocks: Document {} already has blocks", doc_id);
+            return Err(DocError::StateError("Only an empty document can be imported into".into()).into());
+        }
+
+        let count = blocks.len();
+        for block in blocks {
+            Self::write_block(&mut txn, &blocks_map, block, attribute_mode)?;
+        }
+        root.insert(&mut txn, ROOT_ID, root_id);
+
+        log_info!("write_blocks: Wrote {} blocks for doc_id: {}", count, doc_id);
+        Ok(match encoding {
+            UpdateEncodingDoc::V1 => txn.encode_state_as_update_v1(&StateVector::default()),
+            UpdateEncodingDoc::V2 => txn.encode_state_as_update_v2(&StateVector::default()),
+        })
+    }
+
+    fn write_block(
+        txn: &mut TransactionMut,
+        blocks_map: &MapRef,
+        block: BlockDoc,
+        attribute_mode: AttributeModeDoc
+    ) -> Result<(), CustomRustError> {
+        let node = blocks_map.get_or_init_map(txn, block.id.clone());
+        node.insert(txn, Arc::from(ID), block.id);
+        node.insert(txn, Arc::from(TYPE), block.ty);
+        if let Some(parent_id) = block.parent_id {
+            node.insert(txn, Arc::from(PARENT_ID), parent_id);
+        }
+        if let Some(prev_id) = block.prev_id {
+            node.insert(txn, Arc::from(PREV_ID), prev_id);
+        }
+
+        let mut attr_map = MapPrelim::default();
+        for (k, v) in block.attributes {
+            attr_map.insert(k.into(), Conversion::attribute_to_yrs(&v, attribute_mode)?.into());
+        }
+        node.insert(txn, Arc::from(ATTRIBUTES), attr_map);
+
+        if let Some(delta_json) = block.delta {
+            let text = node.get_or_init_text(txn, TEXT);
+            DeltaOperations::apply_delta_to_text(txn, text, delta_json)?;
+        }
+        Ok(())
+    }
+
+    /// Reserve the ids a node tree brings along, so ids generated for the other nodes skip them
+    fn reserve_ids(node: &Value, ids: &mut BlockIds) -> Result<(), CustomRustError> {
+        if let Some(id) = node.get("id").and_then(Value::as_str) {
+            ids.reserve(id)?;
+        }
+        for child in node.get("children").and_then(Value::as_array).into_iter().flatten() {
+            Self::reserve_ids(child, ids)?;
+        }
+        Ok(())
+    }
+
+    /// Walk a `{type, data, children}` node depth first, parents before children. Ids the
+    /// nodes bring along must have been reserved beforehand.
+    fn collect_nested(
+        node: &Value,
+        parent_id: Option<String>,
+        prev_id: Option<String>,
+        attribute_mode: AttributeModeDoc,
+        ids: &mut BlockIds,
+        blocks: &mut Vec<BlockDoc>
+    ) -> Result<String, CustomRustError> {
+        let ty = node.get("type").and_then(Value::as_str)
+            .ok_or_else(|| DocError::ValidationError("Every node needs a type".into()))?;
+        let id = match node.get("id").and_then(Value::as_str) {
+            Some(id) => id.to_string(),
+            None => ids.generate(),
+        };
+
+        let data = node.get("data").and_then(Value::as_object);
+        // Older documents keep the delta next to data instead of inside it
+        let delta = data.and_then(|data| data.get("delta")).or_else(|| node.get("delta"));
+        blocks.push(BlockDoc {
+            id: id.clone(),
+            ty: ty.to_string(),
+            attributes: data.map(|data| Self::attributes_from_json(data, attribute_mode)).unwrap_or_default(),
+            delta: Self::delta_from_json(delta)?,
+            parent_id,
+            prev_id,
+            next_id: None,
+            old_parent_id: None,
+        });
+
+        let mut prev_child_id = None;
+        for child in node.get("children").and_then(Value::as_array).into_iter().flatten() {
+            let child_id = Self::collect_nested(child, Some(id.clone()), prev_child_id.take(), attribute_mode, ids, blocks)?;
+            prev_child_id = Some(child_id);
+        }
+        Ok(id)
+    }
+
+    /// Order flat blocks from the root following `childrenMap`
+    fn collect_flat(
+        object: &JsonMap<String, Value>,
+        attribute_mode: AttributeModeDoc,
+        blocks: &mut Vec<BlockDoc>
+    ) -> Result<(), CustomRustError> {
+        let flat_blocks = object.get("blocks").and_then(Value::as_object)
+            .ok_or_else(|| DocError::ValidationError("blocks must be an object".into()))?;
+        let children_map: HashMap<&str, Vec<&str>> = object.get("childrenMap").and_then(Value::as_object)
+            .ok_or_else(|| DocError::ValidationError("childrenMap must be an object".into()))?
+            .iter()
+            .map(|(parent_id, children)| {
+                let children = children.as_array().into_iter().flatten().filter_map(Value::as_str).collect();
+                (parent_id.as_str(), children)
+            })
+            .collect();
+
+        // The root is the only block without a parent, a page when several claim to be one
+        let roots: Vec<(&String, &Value)> = flat_blocks.iter()
+            .filter(|(_, block)| block.get("parentId").and_then(Value::as_str).is_none())
+            .collect();
+        let root_id = roots.iter()
+            .find(|(_, block)| block.get("type").and_then(Value::as_str) == Some(ROOT_TYPE))
+            .or_else(|| roots.first())
+            .map(|(id, _)| id.as_str())
+            .ok_or_else(|| DocError::ValidationError("Document JSON has no root block".into()))?;
+
+        let mut visited = HashSet::new();
+        let mut stack = vec![(root_id, None::<&str>, None::<&str>)];
+        while let Some((id, parent_id, prev_id)) = stack.pop() {
+            if !visited.insert(id) {
+                return Err(DocError::ValidationError(format!("Block {} appears more than once in childrenMap", id)).into());
+            }
+            let block = flat_blocks.get(id)
+                .ok_or_else(|| DocError::BlockNotFound(format!("Block {} is listed in childrenMap but missing", id)))?;
+            let ty = block.get("type").and_then(Value::as_str)
+                .ok_or_else(|| DocError::ValidationError(format!("Block {} has no type", id)))?;
+
+            blocks.push(BlockDoc {
+                id: id.to_string(),
+                ty: ty.to_string(),
+                attributes: block.get("attributes").and_then(Value::as_object)
+                    .map(|attributes| Self::attributes_from_json(attributes, attribute_mode))
+                    .unwrap_or_default(),
+                delta: Self::delta_from_json(block.get("delta"))?,
+                parent_id: parent_id.map(str::to_string),
+                prev_id: prev_id.map(str::to_string),
+                next_id: None,
+                old_parent_id: None,
+            });
+
+            // Pushed in reverse so children are visited in order, right after their parent
+            let children = children_map.get(id).map(Vec::as_slice).unwrap_or_default();
+            for (i, child_id) in children.iter().enumerate().rev() {
+                let prev = if i == 0 { None } else { Some(children[i - 1]) };
+                stack.push((child_id, Some(id), prev));
+            }
+        }
+
+        let unreachable = flat_blocks.len() - visited.len();
+        if unreachable > 0 {
+            log_info!("collect_flat: Skipping {} blocks not reachable from root {}", unreachable, root_id);
+        }
+        Ok(())
+    }
+
+    /// Block attributes in the string form of the attribute mode, so typed values survive in Json mode
+    fn attributes_from_json(data: &JsonMap<String, Value>, attribute_mode: AttributeModeDoc) -> HashMap<String, String> {
+        data.iter()
+            .filter(|(key, _)| key.as_str() != "delta")
+            .map(|(key, value)| {
+                let value = match (value, attribute_mode) {
+                    (Value::String(s), AttributeModeDoc::Strings) => s.clone(),
+                    (other, _) => other.to_string(),
+                };
+                (key.clone(), value)
+            })
+            .collect()
+    }
+
+    fn delta_from_json(delta: Option<&Value>) -> Result<Option<String>, CustomRustError> {
+        match delta {
+            None | Some(Value::Null) => Ok(None),
+            Some(delta @ Value::Array(_)) => Ok(Some(delta.to_string())),
+            Some(_) => Err(DocError::ValidationError("delta must be an array of operations".into()).into()),
+        }
+    }
+}
+
+/// Ids of imported blocks, generated ones are prefixed with the client id so they do not
+/// collide with blocks created on other devices
+pub struct BlockIds {
+    prefix: String,
+    counter: usize,
+    used: HashSet<String>,
+}
+
+impl BlockIds {
+    pub fn new(client_id: u64) -> Self {
+        Self { prefix: format!("{:x}", client_id), counter: 0, used: HashSet::new() }
+    }
+
+    pub fn generate(&mut self) -> String {
+        loop {
+            self.counter += 1;
+            let id = format!("{}_{}", self.prefix, self.counter);
+            if self.used.insert(id.clone()) {
+                return id;
+            }
+        }
+    }
+
+    fn reserve(&mut self, id: &str) -> Result<(), CustomRustError> {
+        if !self.used.insert(id.to_string()) {
+            return Err(DocError::ValidationError(format!("Block id {} is used more than once", id)).into());
+        }
+        Ok(())
+    }
+}
@@ -0,0 +1,299 @@
+use std::collections::{BTreeMap, BTreeSet, HashMap};
+use std::sync::Arc;
+
+use yrs::{Any, Doc, Map, MapPrelim, MapRef, Out, ReadTxn, Transact, TransactionMut};
+
+use crate::doc::constants::{
+    ATTRIBUTES, BLOCKS, ID, INTEGRITY_REPAIR_ORIGIN, PARAGRAPH_TYPE, PARENT_ID, PREV_ID, ROOT_ID, ROOT_TYPE, TYPE,
+};
+use crate::doc::document_types::{
+    AttributeModeDoc, CustomRustError, DocumentState, IntegrityIssueDoc, IntegrityIssueKindDoc, IntegrityReport,
+    SiblingOrderingDoc, UpdateEncodingDoc,
+};
+use crate::doc::error::DocError;
+use crate::doc::operations::position_ops::PositionOperations;
+use crate::doc::operations::update_ops::UpdateOperations;
+use crate::doc::utils::fractional_index::FractionalIndex;
+use crate::doc::utils::util::MapExt;
+use crate::log_info;
+
+/// Links of a block as stored, before any defaults are filled in
+struct RawBlock {
+    has_id: bool,
+    has_type: bool,
+    parent_id: Option<String>,
+    prev_id: Option<String>,
+}
+
+pub struct IntegrityOperations;
+
+impl IntegrityOperations {
+    /// Check the blocks map for structural problems, and with `fix` write an update solving them:
+    /// missing ids and types are filled in, a missing root block is created, and blocks cut off
+    /// from the root are appended to it. Cycles are broken at their smallest block id and blocks
+    /// sharing a prevId are relinked in the order they are shown, so every replica fixes the
+    /// same way.
+    pub fn validate_document(
+        doc: &Doc,
+        doc_id: &str,
+        fix: bool,
+        encoding: UpdateEncodingDoc
+    ) -> Result<IntegrityReport, CustomRustError> {
+        log_info!("validate_document: Checking doc_id: {}", doc_id);
+
+        let root = doc.get_or_insert_map(ROOT_ID);
+        let txn = doc.transact();
+        let root_id = match root.get(&txn, ROOT_ID) {
+            Some(Out::Any(Any::String(id))) => Some(id.to_string()),
+            _ => None,
+        };
+        let blocks_map = match root.get(&txn, BLOCKS) {
+            Some(Out::YMap(map)) => map,
+            _ => return Err(DocError::StateError("Blocks map not found in document".into()).into()),
+        };
+        let blocks = Self::read_blocks(&txn, &blocks_map);
+        let issues = Self::find_issues(&blocks, root_id.as_deref());
+        log_info!("validate_document: Found {} issues in doc_id: {}", issues.len(), doc_id);
+
+        let mut report = IntegrityReport { issues, update: Vec::new() };
+        if !fix || report.issues.is_empty() {
+            return Ok(report);
+        }
+
+        // The shown order decides where reattached blocks go and how shared prevIds are relinked
+        let state = match root_id {
+            Some(_) => Some(UpdateOperations::extract_document_state(&txn, root.clone(), doc_id, AttributeModeDoc::Strings)?),
+            None => None,
+        };
+        let ordering = PositionOperations::ordering(&txn, &root);
+        drop(txn);
+
+        let mut txn = doc.transact_mut_with(INTEGRITY_REPAIR_ORIGIN);
+        Self::fix_issues(&mut txn, &blocks_map, &report.issues, state.as_ref(), ordering);
+        let before_state = txn.before_state();
+        report.update = UpdateOperations::encode_diff(&txn, before_state, encoding);
+
+        log_info!("validate_document: Fixed doc_id: {} with {} bytes", doc_id, report.update.len());
+        Ok(report)
+    }
+
+    fn read_blocks<T: ReadTxn>(txn: &T, blocks_map: &MapRef) -> BTreeMap<String, RawBlock> {
+        blocks_map.iter(txn)
+            .filter_map(|(id, out)| {
+                let Out::YMap(block) = out else {
+                    return None;
+                };
+                let link = |key: &str| match block.get(txn, key) {
+                    Some(Out::Any(Any::String(s))) => Some(s.to_string()),
+                    _ => None,
+                };
+                let raw = RawBlock {
+                    has_id: link(ID).is_some(),
+                    has_type: link(TYPE).is_some(),
+                    parent_id: link(PARENT_ID),
+                    prev_id: link(PREV_ID),
+                };
+                Some((id.to_string(), raw))
+            })
+            .collect()
+    }
+
+    /// Issues grouped by kind, blocks of an unreachable subtree are reported at its top only
+    fn find_issues(blocks: &BTreeMap<String, RawBlock>, root_id: Option<&str>) -> Vec<IntegrityIssueDoc> {
+        let mut issues = Vec::new();
+        let mut issue = |kind, block_ids: Vec<String>| issues.push(IntegrityIssueDoc { kind, block_ids });
+
+        match root_id {
+            None => issue(IntegrityIssueKindDoc::MissingRoot, Vec::new()),
+            Some(root_id) if !blocks.contains_key(root_id) => {
+                issue(IntegrityIssueKindDoc::MissingRoot, vec![root_id.to_string()]);
+            }
+            Some(_) => {}
+        }
+
+        let matching = |predicate: &dyn Fn(&str, &RawBlock) -> bool| -> Vec<String> {
+            blocks.iter().filter(|(id, block)| predicate(id, block)).map(|(id, _)| id.clone()).collect()
+        };
+        for (kind, block_ids) in [
+            (IntegrityIssueKindDoc::MissingId, matching(&|_, block| !block.has_id)),
+            (IntegrityIssueKindDoc::MissingType, matching(&|_, block| !block.has_type)),
+            (
+                IntegrityIssueKindDoc::MissingParent,
+                // Children of a missing root come back with it
+                matching(&|_, block| {
+                    block.parent_id.as_deref().is_some_and(|parent_id| !blocks.contains_key(parent_id) && Some(parent_id) != root_id)
+                }),
+            ),
+            (
+                IntegrityIssueKindDoc::Orphan,
+                matching(&|id, block| block.parent_id.is_none() && Some(id) != root_id),
+            ),
+        ] {
+            if !block_ids.is_empty() {
+                issue(kind, block_ids);
+            }
+        }
+
+        let parent_of = |id: &str| {
+            blocks.get(id)
+                .and_then(|block| block.parent_id.as_deref())
+                .filter(|parent_id| blocks.contains_key(*parent_id))
+        };
+        for cycle in Self::find_parent_cycles(blocks.keys().map(String::as_str), parent_of) {
+            issue(IntegrityIssueKindDoc::ParentCycle, cycle);
+        }
+
+        let mut blocks_by_prev: BTreeMap<&str, Vec<String>> = BTreeMap::new();
+        for (id, block) in blocks {
+            if let Some(prev_id) = &block.prev_id {
+                blocks_by_prev.entry(prev_id).or_default().push(id.clone());
+            }
+        }
+        for block_ids in blocks_by_prev.into_values().filter(|block_ids| block_ids.len() > 1) {
+            issue(IntegrityIssueKindDoc::SharedPrevId, block_ids);
+        }
+        issues
+    }
+
+    /// Each parent cycle once, as its sorted block ids, ordered by their smallest id.
+    /// `parent_of` returns None for blocks without parent and for missing parents.
+    pub fn find_parent_cycles<'a>(
+        block_ids: impl IntoIterator<Item = &'a str>,
+        parent_of: impl Fn(&str) -> Option<&'a str>
+    ) -> Vec<Vec<String>> {
+        let mut cycles = Vec::new();
+        // Walk number that first reached a block, so a walk meeting itself has found a cycle
+        let mut reached_by: HashMap<&str, usize> = HashMap::new();
+        for (walk, start) in block_ids.into_iter().enumerate() {
+            let mut path = Vec::new();
+            let mut current = Some(start);
+            while let Some(id) = current {
+                if let Some(&other_walk) = reached_by.get(id) {
+                    if other_walk == walk {
+                        let cycle_start = path.iter().position(|path_id| *path_id == id).unwrap_or_default();
+                        let mut cycle: Vec<String> = path[cycle_start..].iter().map(|id: &&str| id.to_string()).collect();
+                        cycle.sort();
+                        cycles.push(cycle);
+                    }
+                    break;
+                }
+                reached_by.insert(id, walk);
+                path.push(id);
+                current = parent_of(id);
+            }
+        }
+        cycles.sort();
+        cycles
+    }
+
+    /// The block of a parent cycle that gives up its parent, the same on every replica
+    pub fn cycle_break_point<'a>(cycle: &'a [String], root_id: &str) -> Option<&'a String> {
+        cycle.iter().find(|id| *id == root_id).or_else(|| cycle.first())
+    }
+
+    fn fix_issues(
+        txn: &mut TransactionMut,
+        blocks_map: &MapRef,
+        issues: &[IntegrityIssueDoc],
+        state: Option<&DocumentState>,
+        ordering: SiblingOrderingDoc
+    ) {
+        let mut reattached = BTreeSet::new();
+        for issue in issues {
+            match issue.kind {
+                IntegrityIssueKindDoc::MissingRoot => {
+                    // Without a root id there is nothing to create, blocks stay where they are
+                    for root_id in &issue.block_ids {
+                        let block = blocks_map.get_or_init_map(txn, root_id.clone());
+                        block.insert(txn, Arc::from(ID), root_id.clone());
+                        block.insert(txn, Arc::from(TYPE), ROOT_TYPE);
+                        block.insert(txn, Arc::from(ATTRIBUTES), MapPrelim::default());
+                    }
+                }
+                IntegrityIssueKindDoc::MissingId => {
+                    for block_id in &issue.block_ids {
+                        Self::set_link(txn, blocks_map, block_id, ID, Some(block_id));
+                    }
+                }
+                IntegrityIssueKindDoc::MissingType => {
+                    for block_id in &issue.block_ids {
+                        Self::set_link(txn, blocks_map, block_id, TYPE, Some(PARAGRAPH_TYPE));
+                    }
+                }
+                IntegrityIssueKindDoc::MissingParent | IntegrityIssueKindDoc::Orphan => {
+                    reattached.extend(issue.block_ids.iter().cloned());
+                }
+                IntegrityIssueKindDoc::ParentCycle => {
+                    let root_id = state.map(|state| state.root_id.as_str()).unwrap_or_default();
+                    reattached.extend(Self::cycle_break_point(&issue.block_ids, root_id).cloned());
+                }
+                IntegrityIssueKindDoc::SharedPrevId => {}
+            }
+        }
+
+        let Some(state) = state else {
+            return;
+        };
+        let shown_before = |block_id: &str, parent_id: &str| -> Option<&String> {
+            let siblings = state.children_map.get(parent_id)?;
+            let index = siblings.iter().position(|id| id == block_id)?;
+            index.checked_sub(1).map(|index| &siblings[index])
+        };
+
+        // Blocks sharing a prevId point at the block shown before them instead, keeping the order
+        for issue in issues.iter().filter(|issue| issue.kind == IntegrityIssueKindDoc::SharedPrevId) {
+            for block_id in issue.block_ids.iter().filter(|id| !reattached.contains(*id)) {
+                let Some(block) = state.blocks.get(block_id) else {
+                    continue;
+                };
+                let parent_id = block.parent_id.as_deref().unwrap_or("root");
+                let prev_id = shown_before(block_id, parent_id);
+                if prev_id != block.prev_id.as_ref() {
+                    Self::set_link(txn, blocks_map, block_id, PREV_ID, prev_id.map(String::as_str));
+                }
+            }
+        }
+
+        if reattached.remove(&state.root_id) {
+            // The root was part of a parent cycle, so it is the block giving up its parent
+            Self::set_link(txn, blocks_map, &state.root_id, PARENT_ID, None);
+            Self::set_link(txn, blocks_map, &state.root_id, PREV_ID, None);
+        }
+
+        // Cut off blocks follow the last child of the root, in id order. Blocks cut out of a parent
+        // cycle are already shown there, like this, so they do not count.
+        let mut prev_id = state.children_map.get(&state.root_id)
+            .and_then(|children| children.iter().rev().find(|id| !reattached.contains(*id)))
+            .cloned();
+        let last_key = prev_id.as_ref().and_then(|prev_id| {
+            PositionOperations::read_positions(txn, blocks_map).remove(prev_id).map(|position| position.key)
+        });
+        let keys = match ordering {
+            SiblingOrderingDoc::FractionalIndex => FractionalIndex::keys_after(last_key.as_deref(), reattached.len()),
+            SiblingOrderingDoc::PrevIdChain => Vec::new(),
+        };
+        for (i, block_id) in reattached.into_iter().enumerate() {
+            log_info!("fix_issues: Appending block {} to root {}", block_id, state.root_id);
+            Self::set_link(txn, blocks_map, &block_id, PARENT_ID, Some(&state.root_id));
+            Self::set_link(txn, blocks_map, &block_id, PREV_ID, prev_id.as_deref());
+            if let Some(key) = keys.get(i) {
+                PositionOperations::write_position(txn, blocks_map, &block_id, key);
+            }
+            prev_id = Some(block_id);
+        }
+    }
+
+    fn set_link(txn: &mut TransactionMut, blocks_map: &MapRef, block_id: &str, key: &str, value: Option<&str>) {
+        let Some(Out::YMap(block)) = blocks_map.get(txn, block_id) else {
+            return;
+        };
+        match value {
+            Some(value) => {
+                block.insert(txn, Arc::from(key), value.to_string());
+            }
+            None => {
+                block.remove(txn, key);
+            }
+        }
+    }
+}
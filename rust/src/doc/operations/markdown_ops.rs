@@ -0,0 +1,638 @@
+use std::collections::HashMap;
+
+use pulldown_cmark::{CodeBlockKind, Event, HeadingLevel, Options as ParserOptions, Parser, Tag, TagEnd};
+use serde_json::{Map as JsonMap, Value};
+use yrs::Doc;
+
+use crate::doc::constants::{
+    BOLD, BULLETED_LIST_TYPE, CHECKED_ATTR, CODE, CODE_TYPE, COLS_LEN_ATTR, COL_POSITION_ATTR, DIVIDER_TYPE,
+    HEADING_TYPE, HREF, IMAGE_TYPE, ITALIC, LANGUAGE_ATTR, LEVEL_ATTR, NUMBERED_LIST_TYPE, NUMBER_ATTR, PARAGRAPH_TYPE,
+    QUOTE_TYPE, ROOT_TYPE, ROWS_LEN_ATTR, ROW_POSITION_ATTR, STRIKETHROUGH, TABLE_CELL_TYPE, TABLE_TYPE, TODO_LIST_TYPE,
+    URL_ATTR,
+};
+use crate::doc::document_types::{AttributeModeDoc, BlockDoc, CustomRustError, DocumentState, UpdateEncodingDoc};
+use crate::doc::operations::delta_ops::{DeltaOperations, ATTRIBUTES as DELTA_ATTRIBUTES, INSERT};
+use crate::doc::operations::import_ops::{BlockIds, ImportOperations};
+use crate::log_info;
+
+pub struct MarkdownOperations;
+
+impl MarkdownOperations {
+    /// Write a CommonMark/GFM document into an empty document and return the update creating it.
+    /// Attribute values such as `level` and `checked` are written as numbers and booleans in Json mode.
+    pub fn import_markdown(
+        doc: &Doc,
+        doc_id: &str,
+        markdown: &str,
+        encoding: UpdateEncodingDoc,
+        attribute_mode: AttributeModeDoc
+    ) -> Result<Vec<u8>, CustomRustError> {
+        log_info!("import_markdown: Importing {} bytes of Markdown for doc_id: {}", markdown.len(), doc_id);
+
+        let blocks = MarkdownImporter::new(doc.client_id())
+            .parse(markdown)
+            .into_iter()
+            .map(|block| BlockDoc {
+                id: block.id,
+                ty: block.ty,
+                attributes: block.attributes,
+                delta: block.delta.map(Self::delta_to_json),
+                parent_id: block.parent_id,
+                prev_id: block.prev_id,
+                next_id: None,
+                old_parent_id: None,
+            })
+            .collect();
+        ImportOperations::write_blocks(doc, doc_id, blocks, encoding, attribute_mode)
+    }
+
+    /// Render the document as GFM Markdown, walking the blocks from the root in sibling order
+    pub fn export_markdown(state: &DocumentState) -> String {
+        log_info!("export_markdown: Exporting {} blocks for doc_id: {}", state.blocks.len(), state.doc_id);
+
+        let mut markdown = match state.blocks.get(&state.root_id) {
+            Some(root) if root.ty != ROOT_TYPE => Self::render_siblings(state, std::slice::from_ref(&state.root_id)),
+            _ => Self::render_children(state, &state.root_id),
+        };
+        if !markdown.is_empty() {
+            markdown.push('\n');
+        }
+        markdown
+    }
+
+    fn render_children(state: &DocumentState, parent_id: &str) -> String {
+        match state.children_map.get(parent_id) {
+            Some(children) => Self::render_siblings(state, children),
+            None => String::new(),
+        }
+    }
+
+    /// Consecutive list items are kept together, every other block is separated by a blank line
+    fn render_siblings(state: &DocumentState, ids: &[String]) -> String {
+        let mut out = String::new();
+        let mut previous_is_item = false;
+        let mut number = 0;
+
+        for id in ids {
+            let Some(block) = state.blocks.get(id) else {
+                continue;
+            };
+            if block.ty == NUMBERED_LIST_TYPE {
+                number = match block.attributes.get(NUMBER_ATTR).and_then(|n| n.parse().ok()) {
+                    Some(start) => start,
+                    None => number + 1,
+                };
+            } else {
+                number = 0;
+            }
+
+            let rendered = Self::render_block(state, block, number);
+            if rendered.is_empty() {
+                continue;
+            }
+            let is_item = Self::is_list_item(block);
+            if !out.is_empty() {
+                out.push_str(if is_item && previous_is_item { "\n" } else { "\n\n" });
+            }
+            out.push_str(&rendered);
+            previous_is_item = is_item;
+        }
+        out
+    }
+
+    fn render_block(state: &DocumentState, block: &BlockDoc, number: u64) -> String {
+        let text = || Self::render_delta(block.delta.as_deref(), true);
+        let children = || Self::render_children(state, &block.id);
+        // Nested lists follow their item directly so the list stays tight
+        let item_content = || {
+            let first_child = state.children_map.get(&block.id).and_then(|c| c.first()).and_then(|id| state.blocks.get(id));
+            match first_child {
+                Some(child) if Self::is_list_item(child) && !text().is_empty() => format!("{}\n{}", text(), children()),
+                _ => Self::join_blocks(text(), children()),
+            }
+        };
+
+        match block.ty.as_str() {
+            HEADING_TYPE => {
+                let level = block.attributes.get(LEVEL_ATTR).and_then(|l| l.parse().ok()).unwrap_or(1usize).clamp(1, 6);
+                Self::join_blocks(format!("{} {}", "#".repeat(level), text()), children())
+            }
+            BULLETED_LIST_TYPE => Self::prefix_lines("- ", "  ", &item_content()),
+            NUMBERED_LIST_TYPE => {
+                let marker = format!("{}. ", number);
+                let indent = " ".repeat(marker.len());
+                Self::prefix_lines(&marker, &indent, &item_content())
+            }
+            TODO_LIST_TYPE => {
+                let checked = block.attributes.get(CHECKED_ATTR).map(|c| c == "true").unwrap_or(false);
+                let marker = if checked { "- [x] " } else { "- [ ] " };
+                Self::prefix_lines(marker, "  ", &item_content())
+            }
+            QUOTE_TYPE => Self::prefix_lines("> ", "> ", &Self::join_blocks(text(), children())),
+            CODE_TYPE => {
+                let code = Self::plain_text(block.delta.as_deref());
+                let mut fence = "```".to_string();
+                while code.contains(&fence) {
+                    fence.push('`');
+                }
+                let language = block.attributes.get(LANGUAGE_ATTR).map(String::as_str).unwrap_or("");
+                Self::join_blocks(format!("{}{}\n{}\n{}", fence, language, code, fence), children())
+            }
+            DIVIDER_TYPE => "---".to_string(),
+            IMAGE_TYPE => match block.attributes.get(URL_ATTR) {
+                Some(url) => format!("![]({})", url),
+                None => String::new(),
+            },
+            TABLE_TYPE => Self::render_table(state, block),
+            // Paragraphs and blocks we do not know are rendered as text, nested blocks follow
+            // unindented since indentation would turn them into code
+            _ => Self::join_blocks(text(), children()),
+        }
+    }
+
+    fn render_table(state: &DocumentState, table: &BlockDoc) -> String {
+        let mut rows: Vec<Vec<String>> = Vec::new();
+        for cell_id in state.children_map.get(&table.id).into_iter().flatten() {
+            let Some(cell) = state.blocks.get(cell_id) else {
+                continue;
+            };
+            let position = |key: &str| cell.attributes.get(key).and_then(|p| p.parse::<usize>().ok());
+            let (Some(row), Some(col)) = (position(ROW_POSITION_ATTR), position(COL_POSITION_ATTR)) else {
+                continue;
+            };
+            // Cells hold paragraphs, a table row only has room for a single line
+            let text = state.children_map.get(cell_id).into_iter().flatten()
+                .filter_map(|id| state.blocks.get(id))
+                .map(|block| Self::render_delta(block.delta.as_deref(), false))
+                .collect::<Vec<_>>()
+                .join(" ")
+                .replace('|', "\\|")
+                .replace('\n', " ");
+
+            if rows.len() <= row {
+                rows.resize(row + 1, Vec::new());
+            }
+            if rows[row].len() <= col {
+                rows[row].resize(col + 1, String::new());
+            }
+            rows[row][col] = text;
+        }
+
+        let cols = rows.iter().map(Vec::len).max().unwrap_or(0);
+        if cols == 0 {
+            return String::new();
+        }
+        let line = |cells: &[String]| {
+            let cells: Vec<&str> = (0..cols).map(|col| cells.get(col).map(String::as_str).unwrap_or("")).collect();
+            format!("| {} |", cells.join(" | "))
+        };
+
+        let mut lines = vec![line(&rows[0]), format!("|{}", "---|".repeat(cols))];
+        lines.extend(rows[1..].iter().map(|row| line(row)));
+        lines.join("\n")
+    }
+
+    /// Inline Markdown of a JSON delta. Markers are kept inside the surrounding whitespace,
+    /// otherwise CommonMark would not treat them as emphasis.
+    fn render_delta(delta: Option<&str>, escape_line_start: bool) -> String {
+        let mut out = String::new();
+        for (insert, attributes) in DeltaOperations::text_inserts(delta) {
+            if attributes.get(CODE).and_then(Value::as_bool).unwrap_or(false) {
+                out.push_str(&Self::inline_code(&insert));
+                continue;
+            }
+
+            let trimmed = insert.trim();
+            if trimmed.is_empty() {
+                out.push_str(&Self::escape(&insert));
+                continue;
+            }
+            let mut text = Self::escape(trimmed);
+            let flag = |key: &str| attributes.get(key).and_then(Value::as_bool).unwrap_or(false);
+            if flag(STRIKETHROUGH) {
+                text = format!("~~{}~~", text);
+            }
+            if flag(ITALIC) {
+                text = format!("*{}*", text);
+            }
+            if flag(BOLD) {
+                text = format!("**{}**", text);
+            }
+            if let Some(href) = attributes.get(HREF).and_then(Value::as_str) {
+                text = format!("[{}]({})", text, href);
+            }
+
+            let start = insert.len() - insert.trim_start().len();
+            let end = insert.trim_end().len();
+            out.push_str(&insert[..start]);
+            out.push_str(&text);
+            out.push_str(&insert[end..]);
+        }
+
+        // Hard line breaks, a trailing backslash keeps them inside the block
+        let out = out.replace('\n', "\\\n");
+        if escape_line_start {
+            Self::escape_line_start(&out)
+        } else {
+            out
+        }
+    }
+
+    fn plain_text(delta: Option<&str>) -> String {
+        DeltaOperations::text_inserts(delta).into_iter().map(|(insert, _)| insert).collect()
+    }
+
+    /// Code span fenced by one backtick more than the longest run inside it. Code starting or
+    /// ending with a backtick is padded with spaces, which CommonMark strips again.
+    fn inline_code(code: &str) -> String {
+        let longest_run = code.split(|ch| ch != '`').map(str::len).max().unwrap_or(0);
+        let fence = "`".repeat(longest_run + 1);
+        if code.starts_with('`') || code.ends_with('`') {
+            format!("{} {} {}", fence, code, fence)
+        } else {
+            format!("{}{}{}", fence, code, fence)
+        }
+    }
+
+    fn escape(text: &str) -> String {
+        let mut out = String::with_capacity(text.len());
+        for ch in text.chars() {
+            if matches!(ch, '\\' | '*' | '_' | '`' | '[' | ']' | '~' | '<') {
+                out.push('\\');
+            }
+            out.push(ch);
+        }
+        out
+    }
+
+    /// Escape text that would otherwise start a heading, quote, list or divider
+    fn escape_line_start(text: &str) -> String {
+        let digits = text.chars().take_while(char::is_ascii_digit).count();
+        if text.starts_with(['#', '>', '-', '+']) {
+            format!("\\{}", text)
+        } else if digits > 0 && text[digits..].starts_with(['.', ')']) {
+            format!("{}\\{}", &text[..digits], &text[digits..])
+        } else {
+            text.to_string()
+        }
+    }
+
+    fn is_list_item(block: &BlockDoc) -> bool {
+        matches!(block.ty.as_str(), BULLETED_LIST_TYPE | NUMBERED_LIST_TYPE | TODO_LIST_TYPE)
+    }
+
+    fn join_blocks(text: String, children: String) -> String {
+        match (text.is_empty(), children.is_empty()) {
+            (_, true) => text,
+            (true, false) => children,
+            (false, false) => format!("{}\n\n{}", text, children),
+        }
+    }
+
+    /// Prefix the first line with a marker and indent the others, blank lines stay blank
+    fn prefix_lines(marker: &str, indent: &str, text: &str) -> String {
+        if text.is_empty() {
+            return marker.trim_end().to_string();
+        }
+        text.split('\n')
+            .enumerate()
+            .map(|(i, line)| {
+                let prefix = if i == 0 { marker } else { indent };
+                if line.is_empty() { prefix.trim_end().to_string() } else { format!("{}{}", prefix, line) }
+            })
+            .collect::<Vec<_>>()
+            .join("\n")
+    }
+
+    fn delta_to_json(delta: Vec<(String, JsonMap<String, Value>)>) -> String {
+        let ops: Vec<Value> = delta
+            .into_iter()
+            .map(|(insert, attributes)| {
+                let mut op = JsonMap::new();
+                op.insert(INSERT.to_string(), Value::String(insert));
+                if !attributes.is_empty() {
+                    op.insert(DELTA_ATTRIBUTES.to_string(), Value::Object(attributes));
+                }
+                Value::Object(op)
+            })
+            .collect();
+        Value::Array(ops).to_string()
+    }
+}
+
+/// A block produced by the importer, the page first and every parent before its children
+struct ImportedBlock {
+    id: String,
+    ty: String,
+    attributes: HashMap<String, String>,
+    parent_id: Option<String>,
+    prev_id: Option<String>,
+    // Text inserts with their inline attributes, None for blocks without text
+    delta: Option<Vec<(String, JsonMap<String, Value>)>>,
+}
+
+/// A block new blocks are nested under
+struct Container {
+    index: usize,
+    // List items and quotes take the text of their first paragraph, later ones become children
+    accepts_text: bool,
+}
+
+struct MarkdownImporter {
+    ids: BlockIds,
+    blocks: Vec<ImportedBlock>,
+    containers: Vec<Container>,
+    last_child: HashMap<String, String>,
+    // Start number of every open list, None for bulleted lists, and whether it has items yet
+    lists: Vec<(Option<u64>, bool)>,
+    // Block type and attributes created once the first inline content arrives
+    pending: Option<(&'static str, HashMap<String, String>)>,
+    // Block receiving inline content
+    current: Option<usize>,
+    styles: Vec<(&'static str, Value)>,
+    in_code: bool,
+    in_image: bool,
+    // Row and column of the next table cell
+    table_position: (usize, usize),
+}
+
+impl MarkdownImporter {
+    fn new(client_id: u64) -> Self {
+        Self {
+            ids: BlockIds::new(client_id),
+            blocks: Vec::new(),
+            containers: Vec::new(),
+            last_child: HashMap::new(),
+            lists: Vec::new(),
+            pending: None,
+            current: None,
+            styles: Vec::new(),
+            in_code: false,
+            in_image: false,
+            table_position: (0, 0),
+        }
+    }
+
+    fn parse(mut self, markdown: &str) -> Vec<ImportedBlock> {
+        let page = self.add_block(ROOT_TYPE, HashMap::new(), false);
+        self.containers.push(Container { index: page, accepts_text: false });
+
+        let options = ParserOptions::ENABLE_TABLES | ParserOptions::ENABLE_STRIKETHROUGH | ParserOptions::ENABLE_TASKLISTS;
+        for event in Parser::new_ext(markdown, options) {
+            self.handle(event);
+        }
+
+        // An empty page still gets the empty paragraph a blank editor starts with
+        if self.blocks.len() == 1 {
+            self.add_block(PARAGRAPH_TYPE, HashMap::new(), true);
+        }
+        self.blocks
+    }
+
+    fn handle(&mut self, event: Event) {
+        if self.in_image {
+            if let Event::End(TagEnd::Image) = event {
+                self.in_image = false;
+            }
+            return;
+        }
+
+        match event {
+            Event::Start(tag) => self.start(tag),
+            Event::End(tag) => self.end(tag),
+            Event::Text(text) => self.push_text(&text, None),
+            Event::Code(code) => self.push_text(&code, Some((CODE, Value::Bool(true)))),
+            Event::SoftBreak => self.push_text(" ", None),
+            Event::HardBreak => self.push_text("\n", None),
+            Event::Rule => {
+                self.current = None;
+                self.add_block(DIVIDER_TYPE, HashMap::new(), false);
+            }
+            Event::TaskListMarker(checked) => self.mark_todo(checked),
+            _ => {}
+        }
+    }
+
+    fn start(&mut self, tag: Tag) {
+        match tag {
+            Tag::Paragraph => {
+                match self.containers.last_mut() {
+                    Some(container) if container.accepts_text => {
+                        container.accepts_text = false;
+                        self.current = Some(container.index);
+                    }
+                    _ => self.pending = Some((PARAGRAPH_TYPE, HashMap::new())),
+                }
+            }
+            Tag::Heading { level, .. } => {
+                let level = match level {
+                    HeadingLevel::H1 => 1,
+                    HeadingLevel::H2 => 2,
+                    HeadingLevel::H3 => 3,
+                    HeadingLevel::H4 => 4,
+                    HeadingLevel::H5 => 5,
+                    HeadingLevel::H6 => 6,
+                };
+                self.pending = Some((HEADING_TYPE, HashMap::from([(LEVEL_ATTR.to_string(), level.to_string())])));
+            }
+            Tag::BlockQuote(_) => self.open_container(QUOTE_TYPE, HashMap::new(), true),
+            Tag::CodeBlock(kind) => {
+                let mut attributes = HashMap::new();
+                if let CodeBlockKind::Fenced(info) = kind {
+                    if let Some(language) = info.split_whitespace().next() {
+                        attributes.insert(LANGUAGE_ATTR.to_string(), language.to_string());
+                    }
+                }
+                self.current = Some(self.add_block(CODE_TYPE, attributes, true));
+                self.in_code = true;
+            }
+            Tag::List(start) => self.lists.push((start, false)),
+            Tag::Item => {
+                let (start, has_items) = self.lists.last().copied().unwrap_or((None, false));
+                let (ty, attributes) = match start {
+                    None => (BULLETED_LIST_TYPE, HashMap::new()),
+                    // Only the first item of a list not starting at 1 keeps its number
+                    Some(start) if start != 1 && !has_items => {
+                        (NUMBERED_LIST_TYPE, HashMap::from([(NUMBER_ATTR.to_string(), start.to_string())]))
+                    }
+                    Some(_) => (NUMBERED_LIST_TYPE, HashMap::new()),
+                };
+                if let Some(list) = self.lists.last_mut() {
+                    list.1 = true;
+                }
+                self.open_container(ty, attributes, true);
+            }
+            Tag::Table(alignments) => {
+                let attributes = HashMap::from([(COLS_LEN_ATTR.to_string(), alignments.len().to_string())]);
+                self.open_container(TABLE_TYPE, attributes, false);
+                self.table_position = (0, 0);
+            }
+            Tag::TableCell => {
+                let (row, col) = self.table_position;
+                let attributes = HashMap::from([
+                    (ROW_POSITION_ATTR.to_string(), row.to_string()),
+                    (COL_POSITION_ATTR.to_string(), col.to_string()),
+                ]);
+                self.open_container(TABLE_CELL_TYPE, attributes, false);
+                self.pending = Some((PARAGRAPH_TYPE, HashMap::new()));
+            }
+            Tag::Emphasis => self.styles.push((ITALIC, Value::Bool(true))),
+            Tag::Strong => self.styles.push((BOLD, Value::Bool(true))),
+            Tag::Strikethrough => self.styles.push((STRIKETHROUGH, Value::Bool(true))),
+            Tag::Link { dest_url, .. } => self.styles.push((HREF, Value::String(dest_url.to_string()))),
+            Tag::Image { dest_url, .. } => {
+                // Images are blocks of their own, text around them continues in a new block
+                self.current = None;
+                self.add_block(IMAGE_TYPE, HashMap::from([(URL_ATTR.to_string(), dest_url.to_string())]), false);
+                self.in_image = true;
+            }
+            _ => {}
+        }
+    }
+
+    fn end(&mut self, tag: TagEnd) {
+        match tag {
+            TagEnd::Paragraph => {
+                self.trim_trailing_newline();
+                self.pending = None;
+                self.current = None;
+            }
+            TagEnd::Heading(_) => {
+                // Empty headings are kept, empty paragraphs are not
+                if let Some((ty, attributes)) = self.pending.take() {
+                    self.add_block(ty, attributes, true);
+                }
+                self.current = None;
+            }
+            TagEnd::CodeBlock => {
+                self.trim_trailing_newline();
+                self.in_code = false;
+                self.current = None;
+            }
+            TagEnd::BlockQuote(_) | TagEnd::Item => {
+                self.current = None;
+                self.containers.pop();
+            }
+            TagEnd::List(_) => {
+                self.lists.pop();
+            }
+            TagEnd::TableCell => {
+                // Every cell holds a paragraph, even an empty one
+                if let Some((ty, attributes)) = self.pending.take() {
+                    self.add_block(ty, attributes, true);
+                }
+                self.current = None;
+                self.containers.pop();
+                self.table_position.1 += 1;
+            }
+            TagEnd::TableHead | TagEnd::TableRow => self.table_position = (self.table_position.0 + 1, 0),
+            TagEnd::Table => {
+                if let Some(container) = self.containers.pop() {
+                    let rows = self.table_position.0.to_string();
+                    self.blocks[container.index].attributes.insert(ROWS_LEN_ATTR.to_string(), rows);
+                }
+            }
+            TagEnd::Emphasis | TagEnd::Strong | TagEnd::Strikethrough | TagEnd::Link => {
+                self.styles.pop();
+            }
+            _ => {}
+        }
+    }
+
+    fn push_text(&mut self, text: &str, extra: Option<(&'static str, Value)>) {
+        if text.is_empty() {
+            return;
+        }
+
+        let mut attributes = JsonMap::new();
+        if !self.in_code {
+            for (key, value) in self.styles.iter().cloned().chain(extra) {
+                attributes.insert(key.to_string(), value);
+            }
+        }
+
+        let index = self.text_target();
+        let delta = self.blocks[index].delta.get_or_insert_with(Vec::new);
+        match delta.last_mut() {
+            Some((insert, last_attributes)) if *last_attributes == attributes => insert.push_str(text),
+            _ => delta.push((text.to_string(), attributes)),
+        }
+    }
+
+    /// Block the next inline content belongs to, created on demand
+    fn text_target(&mut self) -> usize {
+        if let Some(index) = self.current {
+            return index;
+        }
+
+        let index = match self.pending.take() {
+            Some((ty, attributes)) => self.add_block(ty, attributes, true),
+            None => match self.containers.last_mut() {
+                Some(container) if container.accepts_text => {
+                    container.accepts_text = false;
+                    container.index
+                }
+                _ => self.add_block(PARAGRAPH_TYPE, HashMap::new(), true),
+            },
+        };
+        self.current = Some(index);
+        index
+    }
+
+    fn trim_trailing_newline(&mut self) {
+        let Some(index) = self.current else {
+            return;
+        };
+        if let Some(delta) = self.blocks[index].delta.as_mut() {
+            if let Some((insert, _)) = delta.last_mut() {
+                if insert.ends_with('\n') {
+                    insert.pop();
+                }
+                if insert.is_empty() {
+                    delta.pop();
+                }
+            }
+        }
+    }
+
+    fn mark_todo(&mut self, checked: bool) {
+        let item = self.containers.iter().rev().find(|container| {
+            matches!(self.blocks[container.index].ty.as_str(), BULLETED_LIST_TYPE | NUMBERED_LIST_TYPE)
+        });
+        if let Some(container) = item {
+            let block = &mut self.blocks[container.index];
+            block.ty = TODO_LIST_TYPE.to_string();
+            block.attributes.remove(NUMBER_ATTR);
+            block.attributes.insert(CHECKED_ATTR.to_string(), checked.to_string());
+        }
+    }
+
+    fn open_container(&mut self, ty: &str, attributes: HashMap<String, String>, accepts_text: bool) {
+        self.current = None;
+        self.pending = None;
+        let index = self.add_block(ty, attributes, accepts_text);
+        self.containers.push(Container { index, accepts_text });
+    }
+
+    fn add_block(&mut self, ty: &str, attributes: HashMap<String, String>, has_text: bool) -> usize {
+        let id = self.ids.generate();
+        // A container with a child keeps the text it has, later paragraphs become children too
+        let parent_id = self.containers.last_mut().map(|container| {
+            container.accepts_text = false;
+            self.blocks[container.index].id.clone()
+        });
+
+        // Blocks are created in document order, so the previous sibling is the last child seen
+        let prev_id = parent_id.as_ref().and_then(|parent_id| self.last_child.insert(parent_id.clone(), id.clone()));
+
+        self.blocks.push(ImportedBlock {
+            id,
+            ty: ty.to_string(),
+            attributes,
+            parent_id,
+            prev_id,
+            delta: has_text.then(Vec::new),
+        });
+        self.blocks.len() - 1
+    }
+}
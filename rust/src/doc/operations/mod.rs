@@ -4,8 +4,47 @@ pub mod block_ops;
 pub mod delta_ops;
 /// flutter_rust_bridge:ignore
 pub mod update_ops;
+/// flutter_rust_bridge:ignore
+pub mod change_ops;
+/// flutter_rust_bridge:ignore
+pub mod undo_ops;
+/// flutter_rust_bridge:ignore
+pub mod snapshot_ops;
+/// flutter_rust_bridge:ignore
+pub mod awareness_ops;
+/// flutter_rust_bridge:ignore
+pub mod sticky_ops;
+/// flutter_rust_bridge:ignore
+pub mod protocol_ops;
+/// flutter_rust_bridge:ignore
+pub mod import_ops;
+/// flutter_rust_bridge:ignore
+pub mod markdown_ops;
+/// flutter_rust_bridge:ignore
+pub mod html_ops;
+/// flutter_rust_bridge:ignore
+pub mod integrity_ops;
+/// flutter_rust_bridge:ignore
+pub mod position_ops;
+/// flutter_rust_bridge:ignore
+pub mod repair_ops;
+/// flutter_rust_bridge:ignore
+pub mod validation_ops;
 
 // Re-export commonly used operations
 pub use block_ops::BlockOperations;
 pub use delta_ops::DeltaOperations;
-pub use update_ops::UpdateOperations;
\ No newline at end of file
+pub use update_ops::UpdateOperations;
+pub use change_ops::ChangeOperations;
+pub use undo_ops::UndoOperations;
+pub use snapshot_ops::SnapshotOperations;
+pub use awareness_ops::AwarenessOperations;
+pub use sticky_ops::StickyOperations;
+pub use protocol_ops::ProtocolOperations;
+pub use import_ops::ImportOperations;
+pub use markdown_ops::MarkdownOperations;
+pub use html_ops::HtmlOperations;
+pub use integrity_ops::IntegrityOperations;
+pub use position_ops::PositionOperations;
+pub use repair_ops::RepairOperations;
+pub use validation_ops::ValidationOperations;
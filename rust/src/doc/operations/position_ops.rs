@@ -0,0 +1,133 @@
+use std::collections::{HashMap, HashSet};
+use std::sync::Arc;
+
+use yrs::{Any, Doc, Map, MapRef, Out, ReadTxn, Transact, TransactionMut};
+
+use crate::doc::constants::{
+    BLOCKS, FRACTIONAL_ORDERING, ORDERING, ORDERING_MIGRATION_ORIGIN, POSITION, POSITION_CLIENT, ROOT_ID,
+};
+use crate::doc::document_types::{AttributeModeDoc, CustomRustError, SiblingOrderingDoc, UpdateEncodingDoc};
+use crate::doc::error::DocError;
+use crate::doc::operations::update_ops::UpdateOperations;
+use crate::doc::utils::block_index::BlockIndex;
+use crate::doc::utils::fractional_index::FractionalIndex;
+use crate::doc::utils::sorting::BlockPosition;
+use crate::doc::utils::util::MapExt;
+use crate::log_info;
+
+pub struct PositionOperations;
+
+impl PositionOperations {
+    /// How siblings of the document are ordered, prevId chains until it was migrated
+    pub fn ordering<T: ReadTxn>(txn: &T, root: &MapRef) -> SiblingOrderingDoc {
+        match root.get(txn, ORDERING) {
+            Some(Out::Any(Any::String(ordering))) if &*ordering == FRACTIONAL_ORDERING => {
+                SiblingOrderingDoc::FractionalIndex
+            }
+            _ => SiblingOrderingDoc::PrevIdChain,
+        }
+    }
+
+    /// Position keys of every block that has one
+    pub fn read_positions<T: ReadTxn>(txn: &T, blocks_map: &MapRef) -> HashMap<String, BlockPosition> {
+        blocks_map.iter(txn)
+            .filter_map(|(id, out)| {
+                let Out::YMap(block) = out else {
+                    return None;
+                };
+                Some((id.to_string(), Self::read_position(txn, &block)?))
+            })
+            .collect()
+    }
+
+    pub fn read_position<T: ReadTxn>(txn: &T, block: &MapRef) -> Option<BlockPosition> {
+        let Some(Out::Any(Any::String(key))) = block.get(txn, POSITION) else {
+            return None;
+        };
+        let client_id = match block.get(txn, POSITION_CLIENT) {
+            Some(Out::Any(Any::BigInt(client_id))) => client_id as u64,
+            Some(Out::Any(Any::Number(client_id))) => client_id as u64,
+            _ => 0,
+        };
+        Some(BlockPosition { key: key.to_string(), client_id })
+    }
+
+    /// Give a block inserted or moved right after `prev_id` a key between its new neighbours
+    pub fn place_block(
+        txn: &mut TransactionMut,
+        blocks_map: &MapRef,
+        index: &mut BlockIndex,
+        block_id: &str,
+        parent_id: Option<&str>,
+        prev_id: Option<&str>
+    ) -> Result<(), CustomRustError> {
+        let prev_key = prev_id.and_then(|prev_id| Self::keyed_predecessor(index, prev_id));
+        let next_key = parent_id
+            .into_iter()
+            .flat_map(|parent_id| index.children(parent_id))
+            .filter(|id| id.as_str() != block_id)
+            .filter_map(|id| index.position(id))
+            .filter(|key| prev_key.is_none_or(|prev_key| *key > prev_key))
+            .min();
+
+        // Keys written concurrently may leave no room, the block then goes right after its prev
+        let key = FractionalIndex::key_between(prev_key, next_key)
+            .or_else(|| FractionalIndex::key_between(prev_key, None))
+            .or_else(|| FractionalIndex::key_between(None, None))
+            .ok_or_else(|| DocError::StateError(format!("No position key for block {}", block_id)))?;
+        log_info!("place_block: Block {} gets position {} between {:?} and {:?}", block_id, key, prev_key, next_key);
+
+        Self::write_position(txn, blocks_map, block_id, &key);
+        index.set_position(block_id, BlockPosition { key, client_id: txn.doc().client_id() });
+        Ok(())
+    }
+
+    /// Key of `prev_id`, or of the nearest block before it in its prevId chain that has one.
+    /// Blocks written by clients that do not know about positions have no key.
+    fn keyed_predecessor<'a>(index: &'a BlockIndex, prev_id: &'a str) -> Option<&'a str> {
+        let mut visited = HashSet::new();
+        let mut current = Some(prev_id);
+        while let Some(block_id) = current.filter(|block_id| visited.insert(*block_id)) {
+            if let Some(key) = index.position(block_id) {
+                return Some(key);
+            }
+            current = index.prev_id(block_id);
+        }
+        None
+    }
+
+    /// Switch the document to fractional ordering, giving every block a key in its current
+    /// sibling order. Clients that know about positions order by them from then on.
+    pub fn enable_fractional_ordering(
+        doc: &Doc,
+        doc_id: &str,
+        encoding: UpdateEncodingDoc
+    ) -> Result<Vec<u8>, CustomRustError> {
+        log_info!("enable_fractional_ordering: Migrating doc_id: {}", doc_id);
+
+        let root = doc.get_or_insert_map(ROOT_ID);
+        let state = UpdateOperations::extract_document_state(&doc.transact(), root.clone(), doc_id, AttributeModeDoc::Strings)?;
+
+        let mut txn = doc.transact_mut_with(ORDERING_MIGRATION_ORIGIN);
+        let blocks_map = root.get_or_init_map(&mut txn, BLOCKS);
+        for child_ids in state.children_map.values() {
+            let keys = FractionalIndex::keys_after(None, child_ids.len());
+            for (block_id, key) in child_ids.iter().zip(&keys) {
+                Self::write_position(&mut txn, &blocks_map, block_id, key);
+            }
+        }
+        root.insert(&mut txn, ORDERING, FRACTIONAL_ORDERING);
+
+        log_info!("enable_fractional_ordering: Positioned {} blocks of doc_id: {}", state.blocks.len(), doc_id);
+        let before_state = txn.before_state();
+        Ok(UpdateOperations::encode_diff(&txn, before_state, encoding))
+    }
+
+    /// Store a position key of a block, stamped with the client writing it
+    pub fn write_position(txn: &mut TransactionMut, blocks_map: &MapRef, block_id: &str, key: &str) {
+        let client_id = txn.doc().client_id();
+        let block = blocks_map.get_or_init_map(txn, block_id);
+        block.insert(txn, Arc::from(POSITION), key.to_string());
+        block.insert(txn, Arc::from(POSITION_CLIENT), Any::BigInt(client_id as i64));
+    }
+}
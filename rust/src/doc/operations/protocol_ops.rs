@@ -0,0 +1,123 @@
+use yrs::encoding::read::Cursor;
+use yrs::sync::protocol::{DefaultProtocol, Message, MessageReader, Protocol, SyncMessage};
+use yrs::sync::{Awareness, AwarenessUpdate};
+use yrs::updates::decoder::{Decode, DecoderV1};
+use yrs::updates::encoder::{Encode, Encoder, EncoderV1};
+use yrs::{StateVector, Update};
+
+use crate::doc::document_types::{CustomRustError, SyncMessageDoc, SyncMessageKindDoc, SyncReplyDoc, UpdateEncodingDoc};
+use crate::doc::error::DocError;
+use crate::{log_info, log_error};
+
+pub struct ProtocolOperations;
+
+impl ProtocolOperations {
+    /// Messages opening a connection: our state vector followed by the known awareness states
+    pub fn start(awareness: &Awareness) -> Result<Vec<u8>, CustomRustError> {
+        let mut encoder = EncoderV1::new();
+        DefaultProtocol.start(awareness, &mut encoder).map_err(|e| {
+            log_error!("start: Failed to encode sync step 1: {}", e);
+            DocError::EncodingError(format!("Failed to encode sync step 1: {}", e))
+        })?;
+        Ok(encoder.to_vec())
+    }
+
+    /// Handle every message packed in `data` and collect the replies into a single payload.
+    /// Messages with custom tags are skipped, as y-websocket does.
+    pub fn handle(awareness: &Awareness, data: &[u8]) -> Result<SyncReplyDoc, CustomRustError> {
+        let mut decoder = DecoderV1::new(Cursor::new(data));
+        let mut result = SyncReplyDoc::default();
+        let mut replies = Vec::new();
+
+        for message in MessageReader::new(&mut decoder) {
+            let message = message
+                .map_err(|e| DocError::DecodingError(format!("Failed to decode sync message: {}", e)))?;
+            match &message {
+                Message::Sync(SyncMessage::SyncStep2(_)) | Message::Sync(SyncMessage::Update(_)) => result.document_changed = true,
+                Message::Awareness(_) => result.awareness_changed = true,
+                Message::Custom(tag, _) => {
+                    log_info!("handle: Skipping message with custom tag {}", tag);
+                    continue;
+                }
+                _ => {}
+            }
+
+            let reply = DefaultProtocol.handle_message(awareness, message).map_err(|e| {
+                log_error!("handle: Failed to handle sync message: {}", e);
+                DocError::StateError(format!("Failed to handle sync message: {}", e))
+            })?;
+            replies.extend(reply);
+        }
+
+        if !replies.is_empty() {
+            let mut encoder = EncoderV1::new();
+            for reply in replies {
+                reply.encode(&mut encoder);
+            }
+            result.reply = Some(encoder.to_vec());
+        }
+        Ok(result)
+    }
+
+    pub fn encode_message(message: SyncMessageDoc) -> Result<Vec<u8>, CustomRustError> {
+        let payload = message.payload;
+        let message = match message.kind {
+            SyncMessageKindDoc::SyncStep1 => {
+                let state_vector = StateVector::decode_v1(&payload)
+                    .map_err(|e| DocError::DecodingError(format!("Failed to decode state vector: {}", e)))?;
+                Message::Sync(SyncMessage::SyncStep1(state_vector))
+            }
+            SyncMessageKindDoc::SyncStep2 => Message::Sync(SyncMessage::SyncStep2(payload)),
+            SyncMessageKindDoc::Update => Message::Sync(SyncMessage::Update(payload)),
+            SyncMessageKindDoc::Awareness => {
+                let update = AwarenessUpdate::decode_v1(&payload)
+                    .map_err(|e| DocError::DecodingError(format!("Failed to decode awareness update: {}", e)))?;
+                Message::Awareness(update)
+            }
+            SyncMessageKindDoc::AwarenessQuery => Message::AwarenessQuery,
+            SyncMessageKindDoc::Auth if payload.is_empty() => Message::Auth(None),
+            SyncMessageKindDoc::Auth => {
+                let reason = String::from_utf8(payload)
+                    .map_err(|e| DocError::DecodingError(format!("Auth reason is not UTF-8: {}", e)))?;
+                Message::Auth(Some(reason))
+            }
+        };
+        Ok(message.encode_v1())
+    }
+
+    /// Split a payload into its messages. Messages with custom tags are skipped.
+    pub fn decode_messages(data: &[u8]) -> Result<Vec<SyncMessageDoc>, CustomRustError> {
+        let mut decoder = DecoderV1::new(Cursor::new(data));
+        let mut messages = Vec::new();
+
+        for message in MessageReader::new(&mut decoder) {
+            let message = message
+                .map_err(|e| DocError::DecodingError(format!("Failed to decode sync message: {}", e)))?;
+            let (kind, payload) = match message {
+                Message::Sync(SyncMessage::SyncStep1(state_vector)) => (SyncMessageKindDoc::SyncStep1, state_vector.encode_v1()),
+                Message::Sync(SyncMessage::SyncStep2(update)) => (SyncMessageKindDoc::SyncStep2, update),
+                Message::Sync(SyncMessage::Update(update)) => (SyncMessageKindDoc::Update, update),
+                Message::Awareness(update) => (SyncMessageKindDoc::Awareness, update.encode_v1()),
+                Message::AwarenessQuery => (SyncMessageKindDoc::AwarenessQuery, Vec::new()),
+                Message::Auth(reason) => (SyncMessageKindDoc::Auth, reason.map(String::into_bytes).unwrap_or_default()),
+                Message::Custom(tag, _) => {
+                    log_info!("decode_messages: Skipping message with custom tag {}", tag);
+                    continue;
+                }
+            };
+            messages.push(SyncMessageDoc { kind, payload });
+        }
+        Ok(messages)
+    }
+
+    /// Wrap a local update into an Update message, converting it to v1 as the protocol requires
+    pub fn encode_update_message(update: &[u8], encoding: UpdateEncodingDoc) -> Result<Vec<u8>, CustomRustError> {
+        let update = match encoding {
+            UpdateEncodingDoc::V1 => update.to_vec(),
+            UpdateEncodingDoc::V2 => Update::decode_v2(update)
+                .map_err(|e| DocError::UpdateDecodingFailed(format!("Failed to decode update: {}", e)))?
+                .encode_v1(),
+        };
+        Ok(Message::Sync(SyncMessage::Update(update)).encode_v1())
+    }
+}
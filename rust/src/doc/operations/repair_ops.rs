@@ -0,0 +1,80 @@
+use std::sync::Arc;
+
+use yrs::{Doc, Map, Out, Transact};
+
+use crate::doc::constants::{BLOCKS, CHAIN_REPAIR_ORIGIN, PREV_ID, ROOT_ID};
+use crate::doc::document_types::{AttributeModeDoc, ChainRepairResult, CustomRustError, UpdateEncodingDoc};
+use crate::doc::error::DocError;
+use crate::doc::operations::update_ops::UpdateOperations;
+use crate::doc::utils::sorting::{BrokenLinkKind, ChainSorting};
+use crate::log_info;
+
+pub struct RepairOperations;
+
+impl RepairOperations {
+    /// Relink every block whose prevId is dangling or closes a cycle to the block shown right
+    /// before it, or drop its prevId when it comes first. The shown order does not change, and
+    /// replicas repairing the same state write the same links.
+    pub fn repair_chains(
+        doc: &Doc,
+        doc_id: &str,
+        encoding: UpdateEncodingDoc
+    ) -> Result<ChainRepairResult, CustomRustError> {
+        log_info!("repair_chains: Checking prev_id chains of doc_id: {}", doc_id);
+
+        let root = doc.get_or_insert_map(ROOT_ID);
+        let state = UpdateOperations::extract_document_state(&doc.transact(), root.clone(), doc_id, AttributeModeDoc::Strings)?;
+        let broken_links = ChainSorting::find_broken_links(&state.blocks);
+
+        let mut result = ChainRepairResult {
+            cycle_block_ids: Vec::new(),
+            dangling_block_ids: Vec::new(),
+            update: Vec::new(),
+        };
+        if broken_links.is_empty() {
+            log_info!("repair_chains: Nothing to repair for doc_id: {}", doc_id);
+            return Ok(result);
+        }
+
+        let mut txn = doc.transact_mut_with(CHAIN_REPAIR_ORIGIN);
+        let blocks_map = match root.get(&txn, BLOCKS) {
+            Some(Out::YMap(map)) => map,
+            _ => return Err(DocError::StateError("Blocks map not found in document".into()).into()),
+        };
+
+        for link in broken_links {
+            let siblings = state.children_map.get(&link.parent_id).map(Vec::as_slice).unwrap_or_default();
+            let shown_before = siblings.iter()
+                .position(|id| *id == link.block_id)
+                .and_then(|index| index.checked_sub(1))
+                .map(|index| siblings[index].clone());
+            let Some(Out::YMap(block)) = blocks_map.get(&txn, &link.block_id) else {
+                continue;
+            };
+
+            log_info!("repair_chains: Relinking {:?} block {} to {:?}", link.kind, link.block_id, shown_before);
+            match shown_before {
+                Some(prev_id) => {
+                    block.insert(&mut txn, Arc::from(PREV_ID), prev_id);
+                }
+                None => {
+                    block.remove(&mut txn, PREV_ID);
+                }
+            }
+            match link.kind {
+                BrokenLinkKind::Cycle => result.cycle_block_ids.push(link.block_id),
+                BrokenLinkKind::Dangling => result.dangling_block_ids.push(link.block_id),
+            }
+        }
+
+        let before_state = txn.before_state();
+        result.update = UpdateOperations::encode_diff(&txn, before_state, encoding);
+        log_info!(
+            "repair_chains: Cut {} cycles and relinked {} dangling blocks of doc_id: {}",
+            result.cycle_block_ids.len(),
+            result.dangling_block_ids.len(),
+            doc_id
+        );
+        Ok(result)
+    }
+}
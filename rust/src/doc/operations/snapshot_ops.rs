@@ -0,0 +1,195 @@
+use std::collections::HashSet;
+use std::time::{SystemTime, UNIX_EPOCH};
+
+use yrs::updates::decoder::Decode;
+use yrs::updates::encoder::{Encode, Encoder, EncoderV1};
+use yrs::{Doc, Map, MapPrelim, MapRef, Out, ReadTxn, Snapshot, Text, Transact, TransactionMut, Update};
+
+use crate::doc::constants::{ATTRIBUTES, BLOCKS, ROOT_ID, SNAPSHOT_RESTORE_ORIGIN, TEXT};
+use crate::doc::document_types::{AttributeModeDoc, CreatedSnapshot, CustomRustError, DocumentState, SnapshotInfo};
+use crate::doc::error::DocError;
+use crate::doc::operations::delta_ops::DeltaOperations;
+use crate::doc::operations::update_ops::UpdateOperations;
+use crate::doc::utils::util::{MapExt, TextExt};
+use crate::{log_info, log_error};
+
+pub struct SnapshotOperations;
+
+impl SnapshotOperations {
+    /// Named snapshot of the current document state. The snapshot is not written to the
+    /// document, which peers sync, but returned for the host to persist.
+    pub fn create_snapshot(doc: &Doc, doc_id: &str, label: String) -> Result<CreatedSnapshot, CustomRustError> {
+        log_info!("create_snapshot: Creating snapshot '{}' for doc_id: {}", label, doc_id);
+        Self::ensure_gc_disabled(doc)?;
+
+        let created_at = SystemTime::now()
+            .duration_since(UNIX_EPOCH)
+            .map(|duration| duration.as_millis() as i64)
+            .unwrap_or_default();
+        let id = format!("{}-{}", created_at, doc.client_id());
+        let data = doc.transact().snapshot().encode_v1();
+
+        log_info!("create_snapshot: Created snapshot {} for doc_id: {}", id, doc_id);
+        Ok(CreatedSnapshot {
+            snapshot: SnapshotInfo { id, label, created_at },
+            data,
+        })
+    }
+
+    /// Render the document as it was when the snapshot was taken
+    pub fn document_state_at(
+        doc: &Doc,
+        doc_id: &str,
+        snapshot: &[u8],
+        attribute_mode: AttributeModeDoc
+    ) -> Result<DocumentState, CustomRustError> {
+        log_info!("document_state_at: Rendering snapshot for doc_id: {}", doc_id);
+
+        let past_doc = Self::doc_at_snapshot(doc, snapshot)?;
+        let root = past_doc.get_or_insert_map(ROOT_ID);
+        let txn = past_doc.transact();
+        UpdateOperations::extract_document_state(&txn, root, doc_id, attribute_mode)
+    }
+
+    /// Bring the document back to a snapshot by writing the difference as a new, forward update
+    pub fn restore_snapshot(doc: &Doc, doc_id: &str, snapshot: &[u8]) -> Result<Vec<u8>, CustomRustError> {
+        log_info!("restore_snapshot: Restoring snapshot for doc_id: {}", doc_id);
+
+        let past_doc = Self::doc_at_snapshot(doc, snapshot)?;
+        let past_root = past_doc.get_or_insert_map(ROOT_ID);
+        let past_txn = past_doc.transact();
+        let past_blocks = match past_root.get(&past_txn, BLOCKS) {
+            Some(Out::YMap(map)) => map,
+            _ => return Err(DocError::StateError("Blocks map not found in snapshot".into()).into()),
+        };
+
+        let root = doc.get_or_insert_map(ROOT_ID);
+        let mut txn = doc.transact_mut_with(SNAPSHOT_RESTORE_ORIGIN);
+        let blocks_map = root.get_or_init_map(&mut txn, BLOCKS);
+
+        // Remove blocks created after the snapshot
+        let past_ids: HashSet<String> = past_blocks.keys(&past_txn).map(|k| k.to_string()).collect();
+        let current_ids: Vec<String> = blocks_map.keys(&txn).map(|k| k.to_string()).collect();
+        for id in current_ids.iter().filter(|id| !past_ids.contains(*id)) {
+            blocks_map.remove(&mut txn, id);
+        }
+
+        // Rewrite every block of the snapshot that differs from the current one
+        for (id, value) in past_blocks.iter(&past_txn) {
+            if let Out::YMap(past_block) = value {
+                let block = blocks_map.get_or_init_map(&mut txn, id);
+                Self::restore_block(&mut txn, &block, &past_txn, &past_block)?;
+            }
+        }
+
+        if let Some(past_root_id) = past_root.get(&past_txn, ROOT_ID) {
+            if root.get(&txn, ROOT_ID).as_ref() != Some(&past_root_id) {
+                if let Out::Any(any) = past_root_id {
+                    root.insert(&mut txn, ROOT_ID, any);
+                }
+            }
+        }
+
+        let update = txn.encode_diff_v2(txn.before_state());
+        log_info!("restore_snapshot: Restored snapshot for doc_id: {}", doc_id);
+        Ok(update)
+    }
+
+    fn restore_block<T: ReadTxn>(
+        txn: &mut TransactionMut,
+        block: &MapRef,
+        past_txn: &T,
+        past_block: &MapRef,
+    ) -> Result<(), CustomRustError> {
+        let past_keys: HashSet<String> = past_block.keys(past_txn).map(|k| k.to_string()).collect();
+        let current_keys: Vec<String> = block.keys(txn).map(|k| k.to_string()).collect();
+        for key in current_keys.iter().filter(|key| !past_keys.contains(*key)) {
+            block.remove(txn, key);
+        }
+
+        for (key, value) in past_block.iter(past_txn) {
+            match (key, value) {
+                (ATTRIBUTES, Out::YMap(past_attributes)) => {
+                    let attributes = match block.get(txn, ATTRIBUTES) {
+                        Some(Out::YMap(attributes)) => attributes,
+                        _ => block.insert(txn, ATTRIBUTES, MapPrelim::default()),
+                    };
+                    Self::restore_attributes(txn, &attributes, past_txn, &past_attributes);
+                }
+                (TEXT, Out::YText(past_text)) => {
+                    let past_delta = DeltaOperations::deltas_to_json(past_txn, past_text.delta(past_txn))?;
+                    let text = block.get_or_init_text(txn, TEXT);
+                    let current_delta = DeltaOperations::deltas_to_json(txn, text.delta(txn))?;
+                    if past_delta != current_delta {
+                        let len = text.len(txn);
+                        text.remove_range(txn, 0, len);
+                        DeltaOperations::apply_delta_to_text(txn, text, past_delta.to_string())?;
+                    }
+                }
+                (key, Out::Any(past_value)) => {
+                    if block.get(txn, key) != Some(Out::Any(past_value.clone())) {
+                        block.insert(txn, key, past_value);
+                    }
+                }
+                (key, _) => {
+                    log_error!("restore_block: Skipping unsupported value under key {}", key);
+                }
+            }
+        }
+        Ok(())
+    }
+
+    fn restore_attributes<T: ReadTxn>(
+        txn: &mut TransactionMut,
+        attributes: &MapRef,
+        past_txn: &T,
+        past_attributes: &MapRef,
+    ) {
+        let past_keys: HashSet<String> = past_attributes.keys(past_txn).map(|k| k.to_string()).collect();
+        let current_keys: Vec<String> = attributes.keys(txn).map(|k| k.to_string()).collect();
+        for key in current_keys.iter().filter(|key| !past_keys.contains(*key)) {
+            attributes.remove(txn, key);
+        }
+
+        for (key, value) in past_attributes.iter(past_txn) {
+            if let Out::Any(past_value) = value {
+                if attributes.get(txn, key) != Some(Out::Any(past_value.clone())) {
+                    attributes.insert(txn, key, past_value);
+                }
+            }
+        }
+    }
+
+    /// Materialize a snapshot into a standalone document
+    fn doc_at_snapshot(doc: &Doc, snapshot: &[u8]) -> Result<Doc, CustomRustError> {
+        Self::ensure_gc_disabled(doc)?;
+
+        let snapshot = Snapshot::decode_v1(snapshot).map_err(|e| {
+            log_error!("doc_at_snapshot: Failed to decode snapshot: {}", e);
+            DocError::DecodingError(format!("Failed to decode snapshot: {}", e))
+        })?;
+        let txn = doc.transact();
+
+        let mut encoder = EncoderV1::new();
+        txn.encode_state_from_snapshot(&snapshot, &mut encoder)
+            .map_err(|e| DocError::StateEncodingFailed(format!("Failed to encode snapshot state: {}", e)))?;
+        let update = Update::decode_v1(&encoder.to_vec())
+            .map_err(|e| DocError::UpdateDecodingFailed(format!("Failed to decode snapshot state: {}", e)))?;
+
+        let past_doc = Doc::with_options(yrs::Options { skip_gc: true, ..yrs::Options::default() });
+        past_doc.transact_mut()
+            .apply_update(update)
+            .map_err(|e| DocError::MergeError(format!("Failed to apply snapshot state: {}", e)))?;
+        Ok(past_doc)
+    }
+
+    fn ensure_gc_disabled(doc: &Doc) -> Result<(), CustomRustError> {
+        if doc.skip_gc() {
+            Ok(())
+        } else {
+            Err(DocError::InvalidOperation(
+                "Snapshots require a document created with skip_gc enabled".into()
+            ).into())
+        }
+    }
+}
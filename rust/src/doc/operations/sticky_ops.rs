@@ -0,0 +1,164 @@
+use yrs::branch::{Branch, BranchPtr};
+use yrs::types::text::YChange;
+use yrs::updates::decoder::Decode;
+use yrs::updates::encoder::Encode;
+use yrs::{Any, Assoc, Doc, GetString, Map, MapRef, Out, ReadTxn, Snapshot, StickyIndex, Text, TextRef, Transact};
+
+use crate::doc::constants::{BLOCKS, ROOT_ID, TEXT};
+use crate::doc::document_types::{CustomRustError, StickyAssocDoc, TextPositionDoc};
+use crate::doc::error::DocError;
+use crate::{log_info, log_error};
+
+pub struct StickyOperations;
+
+impl StickyOperations {
+    /// Sticky index of a UTF-16 offset inside the text of a block.
+    ///
+    /// yrs cannot attach a position to the right of the last character, so a position at
+    /// the end of the text sticks to the character before it instead.
+    pub fn sticky_index(doc: &Doc, position: &TextPositionDoc, assoc: StickyAssocDoc) -> Result<StickyIndex, CustomRustError> {
+        let root = doc.get_or_insert_map(ROOT_ID);
+        let text = Self::block_text(&doc.transact(), &root, &position.block_id)
+            .ok_or_else(|| DocError::BlockNotFound(format!("Block {} has no text", position.block_id)))?;
+        let chunks = Self::chunk_lengths(doc, &text);
+
+        let txn = doc.transact();
+        if !Self::is_utf16_boundary(&text.get_string(&txn), position.offset) {
+            return Err(DocError::InvalidOperation(format!(
+                "Offset {} is not a valid position in the text of block {}", position.offset, position.block_id
+            )).into());
+        }
+
+        // A position sticking to the character before it is looked up one step to the left
+        let index = |assoc: Assoc| match assoc {
+            Assoc::Before if position.offset > 0 => Self::to_doc_offset(chunks.as_deref(), position.offset - 1) + 1,
+            _ => Self::to_doc_offset(chunks.as_deref(), position.offset),
+        };
+        let assoc = match assoc {
+            StickyAssocDoc::After => Assoc::After,
+            StickyAssocDoc::Before => Assoc::Before,
+        };
+        let branch = BranchPtr::from(AsRef::<Branch>::as_ref(&text));
+        StickyIndex::at(&txn, branch, index(assoc), assoc)
+            .or_else(|| StickyIndex::at(&txn, branch, index(Assoc::Before), Assoc::Before))
+            .ok_or_else(|| DocError::InvalidOperation(format!(
+                "Failed to create a sticky index at offset {} of block {}", position.offset, position.block_id
+            )).into())
+    }
+
+    /// Current UTF-16 offset of a sticky index in the text of a block, None once that text is
+    /// gone or when the index points into another text
+    pub fn resolve(doc: &Doc, block_id: &str, sticky: &StickyIndex) -> Option<TextPositionDoc> {
+        let root = doc.get_or_insert_map(ROOT_ID);
+        let text = Self::block_text(&doc.transact(), &root, block_id)?;
+        let chunks = Self::chunk_lengths(doc, &text);
+
+        let txn = doc.transact();
+        let offset = sticky.get_offset(&txn)?;
+        if offset.branch != BranchPtr::from(AsRef::<Branch>::as_ref(&text)) {
+            return None;
+        }
+
+        let offset = match offset.assoc {
+            Assoc::Before if offset.index > 0 => Self::to_utf16_offset(chunks.as_deref(), offset.index - 1) + 1,
+            _ => Self::to_utf16_offset(chunks.as_deref(), offset.index),
+        };
+        Some(TextPositionDoc { block_id: block_id.to_string(), offset })
+    }
+
+    /// Encode a position as a y.js compatible relative position
+    pub fn encode_position(doc: &Doc, position: &TextPositionDoc, assoc: StickyAssocDoc) -> Result<Vec<u8>, CustomRustError> {
+        log_info!("encode_position: Encoding offset {} of block {}", position.offset, position.block_id);
+        Ok(Self::sticky_index(doc, position, assoc)?.encode_v1())
+    }
+
+    pub fn decode_position(doc: &Doc, block_id: &str, encoded: &[u8]) -> Result<Option<TextPositionDoc>, CustomRustError> {
+        let sticky = StickyIndex::decode_v1(encoded).map_err(|e| {
+            log_error!("decode_position: Failed to decode sticky index: {}", e);
+            DocError::DecodingError(format!("Failed to decode sticky index: {}", e))
+        })?;
+        Ok(Self::resolve(doc, block_id, &sticky))
+    }
+
+    /// UTF-16 and document lengths of every item of a text, in order, or None when both
+    /// count the same, which the lengths the text keeps of itself tell without reading it.
+    ///
+    /// Otherwise (non-ASCII text in a document counting bytes) offsets are converted item by
+    /// item: yrs counts offsets inside an item in UTF-16 code units, like item clocks, and
+    /// only the items before it in the offset kind of the document. Diffed against an empty
+    /// snapshot, every item is reported as a chunk of its own. yrs only exposes items through
+    /// that diff, which takes a write transaction.
+    fn chunk_lengths(doc: &Doc, text: &TextRef) -> Option<Vec<(u32, u32)>> {
+        let branch: &Branch = text.as_ref();
+        if branch.len() == branch.content_len() {
+            return None;
+        }
+
+        let mut txn = doc.transact_mut();
+        let current = txn.snapshot();
+        let chunks = text.diff_range(&mut txn, Some(&current), Some(&Snapshot::default()), YChange::identity)
+            .into_iter()
+            .map(|chunk| match chunk.insert {
+                Out::Any(Any::String(chunk)) => (chunk.encode_utf16().count() as u32, chunk.len() as u32),
+                // Embeds take a single position in every offset kind
+                _ => (1, 1),
+            })
+            .collect();
+        Some(chunks)
+    }
+
+    fn to_doc_offset(chunks: Option<&[(u32, u32)]>, offset: u32) -> u32 {
+        let Some(chunks) = chunks else {
+            return offset;
+        };
+        let (mut utf16_start, mut doc_start) = (0, 0);
+        for (utf16_len, doc_len) in chunks {
+            if offset < utf16_start + utf16_len {
+                return doc_start + offset - utf16_start;
+            }
+            utf16_start += utf16_len;
+            doc_start += doc_len;
+        }
+        doc_start
+    }
+
+    fn to_utf16_offset(chunks: Option<&[(u32, u32)]>, offset: u32) -> u32 {
+        let Some(chunks) = chunks else {
+            return offset;
+        };
+        let (mut utf16_start, mut doc_start) = (0, 0);
+        for (utf16_len, doc_len) in chunks {
+            if offset < doc_start + doc_len {
+                return utf16_start + offset.saturating_sub(doc_start).min(*utf16_len);
+            }
+            utf16_start += utf16_len;
+            doc_start += doc_len;
+        }
+        utf16_start
+    }
+
+    fn block_text<T: ReadTxn>(txn: &T, root: &MapRef, block_id: &str) -> Option<TextRef> {
+        let Some(Out::YMap(blocks)) = root.get(txn, BLOCKS) else {
+            return None;
+        };
+        let Some(Out::YMap(block)) = blocks.get(txn, block_id) else {
+            return None;
+        };
+        match block.get(txn, TEXT) {
+            Some(Out::YText(text)) => Some(text),
+            _ => None,
+        }
+    }
+
+    /// False when the offset is past the end of the text or splits a surrogate pair
+    fn is_utf16_boundary(text: &str, offset: u32) -> bool {
+        let mut utf16 = 0;
+        for ch in text.chars() {
+            if utf16 >= offset {
+                break;
+            }
+            utf16 += ch.len_utf16() as u32;
+        }
+        utf16 == offset
+    }
+}
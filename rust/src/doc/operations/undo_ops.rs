@@ -0,0 +1,94 @@
+use std::collections::HashSet;
+
+use yrs::undo::{Options as UndoOptions, UndoManager};
+use yrs::{Doc, Map, Origin, ReadTxn, Transact};
+
+use crate::doc::constants::{BLOCKS, LOCAL_ACTION_ORIGIN, ROOT_ID, SNAPSHOT_RESTORE_ORIGIN};
+use crate::doc::document_types::{AttributeModeDoc, CustomRustError, UndoRedoResult};
+use crate::doc::error::DocError;
+use crate::doc::operations::change_ops::ChangeOperations;
+use crate::{log_info, log_error};
+
+pub struct UndoOperations;
+
+impl UndoOperations {
+    /// Create an undo manager over the blocks map tracking only local `apply_action` transactions
+    /// and snapshot restores.
+    /// Returns None while the blocks map does not exist yet.
+    pub fn create_manager(doc: &Doc, capture_timeout_millis: u64) -> Option<UndoManager<()>> {
+        let root = doc.get_or_insert_map(ROOT_ID);
+        let blocks_map = {
+            let txn = doc.transact();
+            match root.get(&txn, BLOCKS) {
+                Some(yrs::Out::YMap(map)) => map,
+                _ => return None,
+            }
+        };
+
+        let mut tracked_origins = HashSet::new();
+        tracked_origins.insert(Origin::from(LOCAL_ACTION_ORIGIN));
+        tracked_origins.insert(Origin::from(SNAPSHOT_RESTORE_ORIGIN));
+        let options = UndoOptions {
+            capture_timeout_millis,
+            tracked_origins,
+            ..UndoOptions::default()
+        };
+
+        log_info!("create_manager: Tracking blocks map with capture timeout {}ms", capture_timeout_millis);
+        Some(UndoManager::with_scope_and_options(doc, &blocks_map, options))
+    }
+
+    pub fn undo(
+        manager: &mut UndoManager<()>,
+        doc_id: &str,
+        attribute_mode: AttributeModeDoc
+    ) -> Result<UndoRedoResult, CustomRustError> {
+        log_info!("undo: Starting for doc_id: {}", doc_id);
+        Self::step(manager, doc_id, attribute_mode, |manager| manager.try_undo())
+    }
+
+    pub fn redo(
+        manager: &mut UndoManager<()>,
+        doc_id: &str,
+        attribute_mode: AttributeModeDoc
+    ) -> Result<UndoRedoResult, CustomRustError> {
+        log_info!("redo: Starting for doc_id: {}", doc_id);
+        Self::step(manager, doc_id, attribute_mode, |manager| manager.try_redo())
+    }
+
+    /// Run a single undo or redo step and describe what it changed
+    fn step<F>(
+        manager: &mut UndoManager<()>,
+        doc_id: &str,
+        attribute_mode: AttributeModeDoc,
+        f: F
+    ) -> Result<UndoRedoResult, CustomRustError>
+    where
+        F: FnOnce(&mut UndoManager<()>) -> Result<bool, yrs::TransactionAcqError>,
+    {
+        let doc = manager.doc().clone();
+        let before = ChangeOperations::snapshot_state(&doc, doc_id, attribute_mode);
+        let before_state = doc.transact().state_vector();
+
+        let (changed, changes) = ChangeOperations::record_changes(&doc, || {
+            f(manager).map_err(|e| {
+                log_error!("step: Failed to acquire transaction for doc_id: {}: {}", doc_id, e);
+                DocError::StateError(format!("Failed to acquire transaction: {}", e)).into()
+            })
+        })?;
+
+        if !changed {
+            log_info!("step: Nothing to apply for doc_id: {}", doc_id);
+            return Ok(UndoRedoResult { changed, update: Vec::new(), actions: Vec::new() });
+        }
+
+        let update = doc.transact().encode_diff_v2(&before_state);
+        let actions = match ChangeOperations::snapshot_state(&doc, doc_id, attribute_mode) {
+            Some(after) => changes.into_actions(before.as_ref(), &after),
+            None => Vec::new(),
+        };
+
+        log_info!("step: Produced {} actions for doc_id: {}", actions.len(), doc_id);
+        Ok(UndoRedoResult { changed, update, actions })
+    }
+}
@@ -1,10 +1,19 @@
-use yrs::updates::decoder::Decode;
-use yrs::{merge_updates_v2, Array, Doc, Map, ReadTxn, StateVector, Transact, Update};
-use std::collections::HashMap;
+use yrs::encoding::read::{Cursor, Read};
+use yrs::updates::decoder::{Decode, DecoderV1};
+use yrs::updates::encoder::Encode;
+use yrs::branch::{Branch, BranchID};
+use yrs::{merge_updates_v2, Any, Array, DeleteSet, Doc, Map, MapRef, Options, Out, ReadTxn, StateVector, Transact, Update};
+use std::collections::hash_map::Entry;
+use std::collections::{BTreeSet, HashMap, HashSet};
 
-use crate::doc::document_types::{BlockDoc, CustomRustError, DocumentState, FailedToDecodeUpdates};
+use crate::doc::conversions::conversion::Conversion;
+use crate::doc::document_types::{AppliedUpdateActions, AttributeModeDoc, AppliedUpdates, BlockDoc, CompactedUpdate, CustomRustError, DocumentState, FailedToDecodeUpdates, SiblingOrderingDoc, UpdateEncodingDoc, UpdateWithId};
 use crate::doc::error::DocError;
-use crate::doc::utils::sorting::ChainSorting;
+use crate::doc::operations::change_ops::{BlockChanges, ChangeOperations};
+use crate::doc::operations::integrity_ops::IntegrityOperations;
+use crate::doc::operations::position_ops::PositionOperations;
+use crate::doc::utils::block_index::BlockIndex;
+use crate::doc::utils::sorting::{BlockPosition, ChainSorting, PositionSorting};
 // In other files
 use crate::{log_info, log_error};
 use crate::doc::constants::{ATTRIBUTES, BLOCKS, ID, NEXT_ID, PARENT_ID, PREV_ID, ROOT_ID, TEXT, TYPE};
@@ -21,8 +30,11 @@ impl UpdateOperations {
     ) -> Result<(), CustomRustError> {
         log_info!("apply_updates: Starting with {} updates for doc_id: {}", updates.len(), doc_id);
 
-        // Extract just the binary updates
-        let updates_only = updates;
+        // Extract just the binary updates, merging requires them to be v2 encoded
+        let updates_only = updates
+            .into_iter()
+            .map(|update| Self::convert_update(update, None, UpdateEncodingDoc::V2))
+            .collect::<Result<Vec<_>, _>>()?;
         
         // Merge all the updates together
         let merged_update = merge_updates_v2(updates_only)
@@ -46,11 +58,86 @@ impl UpdateOperations {
         Ok(())
     }
 
+    /// Apply updates one by one, skipping the ones that fail to decode or integrate
+    pub fn apply_updates_tolerant(
+        doc: Doc,
+        doc_id: &str,
+        updates: Vec<UpdateWithId>
+    ) -> Result<FailedToDecodeUpdates, CustomRustError> {
+        log_info!("apply_updates_tolerant: Starting with {} updates for doc_id: {}", updates.len(), doc_id);
+
+        let mut failed_updates_ids = Vec::new();
+        let mut txn = doc.transact_mut();
+
+        for UpdateWithId { id, update } in updates {
+            let decoded_update = match Self::decode_update(&update) {
+                Ok(decoded_update) => decoded_update,
+                Err(e) => {
+                    log_error!("apply_updates_tolerant: Failed to decode update {} for doc_id: {}: {}", id, doc_id, e);
+                    failed_updates_ids.push(id);
+                    continue;
+                }
+            };
+
+            if let Err(e) = txn.apply_update(decoded_update) {
+                log_error!("apply_updates_tolerant: Failed to apply update {} for doc_id: {}: {}", id, doc_id, e);
+                failed_updates_ids.push(id);
+            }
+        }
+
+        log_info!(
+            "apply_updates_tolerant: Finished for doc_id: {} with {} failed updates",
+            doc_id,
+            failed_updates_ids.len()
+        );
+        Ok(FailedToDecodeUpdates { failed_updates_ids })
+    }
+
+    /// Apply only new updates on top of an existing document, keeping its client id,
+    /// and report which blocks were touched
+    pub fn apply_updates_incremental(
+        doc: &Doc,
+        doc_id: &str,
+        updates: Vec<Vec<u8>>
+    ) -> Result<AppliedUpdates, CustomRustError> {
+        log_info!("apply_updates_incremental: Starting with {} updates for doc_id: {}", updates.len(), doc_id);
+
+        let update = Update::merge_updates(Self::decode_updates(doc_id, updates)?);
+        let mut changes = Self::apply_update(doc, update)?;
+
+        let root = doc.get_or_insert_map(ROOT_ID);
+        let txn = doc.transact();
+        if changes.blocks_replaced {
+            // The blocks map itself arrived in this batch, so every block in it is new
+            if let Some(yrs::Out::YMap(blocks_map)) = root.get(&txn, BLOCKS) {
+                changes.changed.extend(blocks_map.keys(&txn).map(|k| k.to_string()));
+            }
+        }
+        let has_pending_updates = txn.store().pending_update().is_some() || txn.store().pending_ds().is_some();
+
+        let result = AppliedUpdates {
+            changed_block_ids: changes.changed.difference(&changes.deleted).cloned().collect(),
+            deleted_block_ids: changes.deleted.into_iter().collect(),
+            root_id_changed: changes.root_id_changed,
+            has_pending_updates,
+        };
+
+        log_info!(
+            "apply_updates_incremental: Finished for doc_id: {} ({} changed, {} deleted, pending: {})",
+            doc_id,
+            result.changed_block_ids.len(),
+            result.deleted_block_ids.len(),
+            result.has_pending_updates
+        );
+        Ok(result)
+    }
+
     /// Extract the current document state
     pub fn extract_document_state<T: ReadTxn>(
         txn: &T,
         root: yrs::MapRef,
-        doc_id: &str
+        doc_id: &str,
+        attribute_mode: AttributeModeDoc
     ) -> Result<DocumentState, CustomRustError> {
         log_info!("extract_document_state: Starting for doc_id: {}", doc_id);
     
@@ -66,22 +153,26 @@ impl UpdateOperations {
         log_info!("extract_document_state: Processing {} blocks", block_keys.len());
         for key in block_keys {
             let id = key.clone();
-            if let Some(block) = Self::extract_block(txn, &blocks_map, &id)? {
+            if let Some(block) = Self::extract_block(txn, &blocks_map, &id, attribute_mode)? {
                 blocks.insert(id, block);
             }
         }
     
-        // Sort blocks by chain to create children_map
-        let sorted_children = ChainSorting::sort_blocks_by_chain(&blocks);
-    
-        log_info!("extract_document_state: Extracted {} blocks and {} parent-child relationships", 
-                blocks.len(), sorted_children.len());
-    
         // Get root id 
         let root_id = match root.get(txn, ROOT_ID) {
             Some(yrs::Out::Any(yrs::Any::String(id))) => id.to_string(),
             _ => return Err(DocError::StateError("Root ID not found in document".into()).into()),
         };
+
+        // Sort blocks by chain or by position to create children_map
+        let positions = match PositionOperations::ordering(txn, &root) {
+            SiblingOrderingDoc::FractionalIndex => Some(PositionOperations::read_positions(txn, &blocks_map)),
+            SiblingOrderingDoc::PrevIdChain => None,
+        };
+        let sorted_children = Self::sort_children(&mut blocks, positions.as_ref(), &root_id);
+    
+        log_info!("extract_document_state: Extracted {} blocks and {} parent-child relationships", 
+                blocks.len(), sorted_children.len());
         
         // Build the complete document state
         Ok(DocumentState {
@@ -92,11 +183,58 @@ impl UpdateOperations {
         })
     }
 
+    /// Children of every parent in sibling order, by position when `positions` are given and
+    /// by prevId chain otherwise
+    fn sort_children(
+        blocks: &mut HashMap<String, BlockDoc>,
+        positions: Option<&HashMap<String, BlockPosition>>,
+        root_id: &str
+    ) -> HashMap<String, Vec<String>> {
+        let cut_ids = Self::break_parent_cycles(blocks, root_id);
+        let mut sorted_children = match positions {
+            Some(positions) => PositionSorting::sort_blocks_by_position(blocks, positions),
+            None => ChainSorting::sort_blocks_by_chain(blocks),
+        };
+
+        // Blocks cut out of a cycle go last under the root, where the integrity fix puts them
+        if !cut_ids.is_empty() {
+            let root_children = sorted_children.entry(root_id.to_string()).or_default();
+            root_children.retain(|id| !cut_ids.contains(id));
+            root_children.extend(cut_ids.into_iter().filter(|id| id != root_id));
+        }
+        sorted_children
+    }
+
+    /// Concurrent moves can leave blocks whose parents lead back to themselves, unreachable from
+    /// the root. Each cycle is cut at the same block on every replica, which is then shown under
+    /// the root. Returns the cut blocks in id order.
+    fn break_parent_cycles(blocks: &mut HashMap<String, BlockDoc>, root_id: &str) -> Vec<String> {
+        let parent_of = |id: &str| {
+            blocks.get(id)
+                .and_then(|block| block.parent_id.as_deref())
+                .filter(|parent_id| blocks.contains_key(*parent_id))
+        };
+        let mut cut_ids: Vec<String> = IntegrityOperations::find_parent_cycles(blocks.keys().map(String::as_str), parent_of)
+            .iter()
+            .filter_map(|cycle| IntegrityOperations::cycle_break_point(cycle, root_id).cloned())
+            .collect();
+
+        for cut_id in &cut_ids {
+            log_info!("break_parent_cycles: Showing block {} of a parent cycle under root {}", cut_id, root_id);
+            if let Some(block) = blocks.get_mut(cut_id) {
+                block.parent_id = (cut_id != root_id).then(|| root_id.to_string());
+            }
+        }
+        cut_ids.sort();
+        cut_ids
+    }
+
     /// Extract a single block from the document
     fn extract_block<T: ReadTxn>(
         txn: &T, 
         blocks_map: &yrs::MapRef, 
-        id: &str
+        id: &str,
+        attribute_mode: AttributeModeDoc
     ) -> Result<Option<BlockDoc>, CustomRustError> {
         if let Some(yrs::Out::YMap(block_map)) = blocks_map.get(txn, id) {
             // Extract text content if present
@@ -127,7 +265,7 @@ impl UpdateOperations {
                 let mut result = HashMap::new();
                 for key in attrs.keys(txn) {
                     if let Some(out) = attrs.get(txn, &key) {
-                        result.insert(key.to_string(), out.to_string(txn));
+                        result.insert(key.to_string(), Conversion::attribute_from_yrs(txn, &out, attribute_mode));
                     }
                 }
                 result
@@ -161,11 +299,391 @@ impl UpdateOperations {
         }
     }
     
+    /// Apply new updates and translate them into the editor actions that reproduce them.
+    ///
+    /// Only the changed blocks and the siblings their paths depend on are read, linked as
+    /// `index` had them before the apply and as a freshly built index has them after it.
+    /// Blocks the updates remove or give a new text are read up front, while they are still there.
+    pub fn apply_updates_as_actions(
+        doc: &Doc,
+        doc_id: &str,
+        index: &mut BlockIndex,
+        updates: Vec<Vec<u8>>,
+        attribute_mode: AttributeModeDoc
+    ) -> Result<AppliedUpdateActions, CustomRustError> {
+        log_info!("apply_updates_as_actions: Starting with {} updates for doc_id: {}", updates.len(), doc_id);
+
+        let update = Update::merge_updates(Self::decode_updates(doc_id, updates)?);
+        let root = doc.get_or_insert_map(ROOT_ID);
+        let before = {
+            let txn = doc.transact();
+            Self::root_and_blocks(&txn, &root).map(|(root_id, blocks_map)| {
+                index.refresh(&txn, &blocks_map);
+                (root_id, Self::extract_removed_blocks(&txn, &blocks_map, update.delete_set(), attribute_mode))
+            })
+        };
+        let changes = Self::apply_update(doc, update)?;
+
+        let txn = doc.transact();
+        let result = match (before, Self::root_and_blocks(&txn, &root)) {
+            (Some((before_root_id, removed)), Some((root_id, blocks_map))) if !changes.root_id_changed && before_root_id == root_id => {
+                let mut after_index = BlockIndex::default();
+                after_index.refresh(&txn, &blocks_map);
+                let ordering = PositionOperations::ordering(&txn, &root);
+                let no_removed = HashMap::new();
+                let before = PartialState { txn: &txn, blocks_map: &blocks_map, index, removed: &removed, ordering, attribute_mode };
+                let after = PartialState { txn: &txn, blocks_map: &blocks_map, index: &after_index, removed: &no_removed, ordering, attribute_mode };
+
+                // Paths of changed blocks depend on the siblings under their old and new parents
+                let parent_ids: BTreeSet<String> = changes.changed
+                    .iter()
+                    .flat_map(|id| [before.index.parent_id(id), after.index.parent_id(id)])
+                    .flatten()
+                    .map(str::to_string)
+                    .chain([root_id.clone()])
+                    .collect();
+                let before = before.extract(doc_id, &root_id, changes.changed.difference(&changes.inserted), &parent_ids)?;
+                let after = after.extract(doc_id, &root_id, changes.changed.difference(&changes.deleted), &parent_ids)?;
+                AppliedUpdateActions {
+                    actions: changes.into_actions(Some(&before), &after),
+                    requires_full_reload: false,
+                }
+            }
+            _ => AppliedUpdateActions { actions: Vec::new(), requires_full_reload: true },
+        };
+
+        log_info!(
+            "apply_updates_as_actions: Finished for doc_id: {} with {} actions (full reload: {})",
+            doc_id,
+            result.actions.len(),
+            result.requires_full_reload
+        );
+        Ok(result)
+    }
+
+    /// Decode every update up front so a bad blob leaves the document untouched
+    fn decode_updates(doc_id: &str, updates: Vec<Vec<u8>>) -> Result<Vec<Update>, CustomRustError> {
+        updates
+            .iter()
+            .map(|update| Self::decode_update(update).inspect_err(|e| {
+                log_error!("decode_updates: Failed to decode update for doc_id: {}: {}", doc_id, e);
+            }))
+            .collect()
+    }
+
+    /// Root id and blocks map, None while the document is not initialized yet
+    fn root_and_blocks<T: ReadTxn>(txn: &T, root: &MapRef) -> Option<(String, MapRef)> {
+        let Some(Out::Any(Any::String(root_id))) = root.get(txn, ROOT_ID) else {
+            return None;
+        };
+        let Some(Out::YMap(blocks_map)) = root.get(txn, BLOCKS) else {
+            return None;
+        };
+        Some((root_id.to_string(), blocks_map))
+    }
+
+    /// Blocks whose map or text `delete_set` deletes, which cannot be read once it is applied
+    fn extract_removed_blocks<T: ReadTxn>(
+        txn: &T,
+        blocks_map: &MapRef,
+        delete_set: &DeleteSet,
+        attribute_mode: AttributeModeDoc
+    ) -> HashMap<String, BlockDoc> {
+        let is_deleted = |branch: &Branch| matches!(branch.id(), BranchID::Nested(id) if delete_set.is_deleted(&id));
+        blocks_map.iter(txn)
+            .filter(|(_, out)| match out {
+                Out::YMap(block) => is_deleted(block.as_ref()) || matches!(
+                    block.get(txn, TEXT),
+                    Some(Out::YText(text)) if is_deleted(text.as_ref())
+                ),
+                _ => false,
+            })
+            .filter_map(|(id, _)| {
+                let block = Self::extract_block(txn, blocks_map, id, attribute_mode).ok()??;
+                Some((id.to_string(), block))
+            })
+            .collect()
+    }
+
+    /// Apply a merged batch of updates at once while recording what it changed. A batch failing
+    /// to integrate is rejected as a whole instead of leaving the updates before it applied.
+    fn apply_update(doc: &Doc, update: Update) -> Result<BlockChanges, CustomRustError> {
+        let ((), changes) = ChangeOperations::record_changes(doc, || {
+            let mut txn = doc.transact_mut();
+            txn.apply_update(update)
+                .map_err(|e| DocError::MergeError(format!("Failed to apply update: {}", e)))?;
+            Ok(())
+        })?;
+        Ok(changes)
+    }
+
+    /// Squash an update log into a single update covering the same state. With `gc` the
+    /// content of deleted items is dropped, which makes the result smaller but loses what
+    /// snapshots taken before the deletions need to be rendered or restored.
+    pub fn compact_updates(
+        doc_id: &str,
+        updates: Vec<Vec<u8>>,
+        gc: bool,
+        encoding: UpdateEncodingDoc
+    ) -> Result<CompactedUpdate, CustomRustError> {
+        log_info!("compact_updates: Compacting {} updates for doc_id: {} (gc: {})", updates.len(), doc_id, gc);
+
+        let decoded_updates = Self::decode_updates(doc_id, updates)?;
+        let doc = Doc::with_options(Options { skip_gc: !gc, ..Options::default() });
+        {
+            let mut txn = doc.transact_mut();
+            for update in decoded_updates {
+                txn.apply_update(update)
+                    .map_err(|e| DocError::MergeError(format!("Failed to apply update: {}", e)))?;
+            }
+        }
+
+        Ok(Self::encode_checkpoint(&doc, encoding))
+    }
+
+    /// Compact the live document. Documents keeping deleted content are replayed into a
+    /// garbage collected one when `gc` is requested.
+    pub fn compact_doc(doc: &Doc, doc_id: &str, gc: bool, encoding: UpdateEncodingDoc) -> Result<CompactedUpdate, CustomRustError> {
+        if gc && doc.skip_gc() {
+            let update = doc.transact().encode_state_as_update_v2(&StateVector::default());
+            return Self::compact_updates(doc_id, vec![update], gc, encoding);
+        }
+
+        log_info!("compact_doc: Compacting document for doc_id: {}", doc_id);
+        Ok(Self::encode_checkpoint(doc, encoding))
+    }
+
+    fn encode_checkpoint(doc: &Doc, encoding: UpdateEncodingDoc) -> CompactedUpdate {
+        let txn = doc.transact();
+        let update = match encoding {
+            UpdateEncodingDoc::V1 => txn.encode_state_as_update_v1(&StateVector::default()),
+            UpdateEncodingDoc::V2 => txn.encode_state_as_update_v2(&StateVector::default()),
+        };
+        CompactedUpdate {
+            update,
+            state_vector: Self::encode_state_vector(&txn, encoding),
+            has_pending_updates: txn.store().pending_update().is_some() || txn.store().pending_ds().is_some(),
+        }
+    }
+
+    /// Guess the encoding of an update. v2 updates always start with a zero feature flag,
+    /// v1 updates only when they carry no structs, so v2 is tried first for those.
+    pub fn detect_encoding(update: &[u8]) -> Option<UpdateEncodingDoc> {
+        if update.first() == Some(&0) && Update::decode_v2(update).is_ok() {
+            return Some(UpdateEncodingDoc::V2);
+        }
+        Self::decode_v1_exact(update).map(|_| UpdateEncodingDoc::V1)
+    }
+
+    /// Decode an update in either encoding
+    pub fn decode_update(update: &[u8]) -> Result<Update, CustomRustError> {
+        if update.first() == Some(&0) {
+            if let Ok(decoded) = Update::decode_v2(update) {
+                return Ok(decoded);
+            }
+        }
+        Self::decode_v1_exact(update)
+            .ok_or_else(|| DocError::UpdateDecodingFailed("Update is neither v1 nor v2 encoded".into()).into())
+    }
+
+    /// Decode a v1 update that must span the whole buffer. A corrupt or truncated v2 update
+    /// often reads as a short v1 one, the leftover bytes tell them apart.
+    fn decode_v1_exact(update: &[u8]) -> Option<Update> {
+        let mut decoder = DecoderV1::new(Cursor::new(update));
+        let decoded = Update::decode(&mut decoder).ok()?;
+        decoder.read_u8().is_err().then_some(decoded)
+    }
+
+    /// Re-encode an update, detecting its current encoding when `from` is None.
+    /// Updates already in the target encoding are returned untouched.
+    pub fn convert_update(
+        update: Vec<u8>,
+        from: Option<UpdateEncodingDoc>,
+        to: UpdateEncodingDoc
+    ) -> Result<Vec<u8>, CustomRustError> {
+        let from = from.or_else(|| Self::detect_encoding(&update))
+            .ok_or_else(|| DocError::UpdateDecodingFailed("Update is neither v1 nor v2 encoded".into()))?;
+        if from == to {
+            return Ok(update);
+        }
+
+        let decoded = match from {
+            UpdateEncodingDoc::V1 => Self::decode_v1_exact(&update)
+                .ok_or_else(|| DocError::UpdateDecodingFailed("Failed to decode v1 update".into()))?,
+            UpdateEncodingDoc::V2 => Update::decode_v2(&update)
+                .map_err(|e| DocError::UpdateDecodingFailed(format!("Failed to decode update: {}", e)))?,
+        };
+        Ok(match to {
+            UpdateEncodingDoc::V1 => decoded.encode_v1(),
+            UpdateEncodingDoc::V2 => decoded.encode_v2(),
+        })
+    }
+
+    /// Encode what changed since `state_vector`
+    pub fn encode_diff<T: ReadTxn>(txn: &T, state_vector: &StateVector, encoding: UpdateEncodingDoc) -> Vec<u8> {
+        match encoding {
+            UpdateEncodingDoc::V1 => txn.encode_diff_v1(state_vector),
+            UpdateEncodingDoc::V2 => txn.encode_diff_v2(state_vector),
+        }
+    }
+
+    /// Copy of `doc` under the same client, to write to without touching the original
+    pub fn copy_doc(doc: &Doc, options: &Options) -> Result<Doc, CustomRustError> {
+        let state = doc.transact().encode_state_as_update_v2(&StateVector::default());
+        let update = Update::decode_v2(&state)
+            .map_err(|e| DocError::UpdateDecodingFailed(format!("Failed to decode document state: {}", e)))?;
+        let copy = Doc::with_options(options.clone());
+        copy.transact_mut().apply_update(update)
+            .map_err(|e| DocError::MergeError(format!("Failed to copy document: {}", e)))?;
+        Ok(copy)
+    }
+
+    /// Encode the state vector of the document
+    pub fn encode_state_vector<T: ReadTxn>(txn: &T, encoding: UpdateEncodingDoc) -> Vec<u8> {
+        let state_vector = txn.state_vector();
+        match encoding {
+            UpdateEncodingDoc::V1 => state_vector.encode_v1(),
+            UpdateEncodingDoc::V2 => state_vector.encode_v2(),
+        }
+    }
+
+    /// Decode a state vector sent by a remote peer
+    pub fn decode_state_vector(state_vector: &[u8], encoding: UpdateEncodingDoc) -> Result<StateVector, CustomRustError> {
+        let decoded = match encoding {
+            UpdateEncodingDoc::V1 => StateVector::decode_v1(state_vector),
+            UpdateEncodingDoc::V2 => StateVector::decode_v2(state_vector),
+        };
+        decoded.map_err(|e| DocError::DecodingError(format!("Failed to decode state vector: {}", e)).into())
+    }
+
+    /// Encode everything the remote peer is missing according to its state vector
+    pub fn encode_diff_since<T: ReadTxn>(
+        txn: &T,
+        remote_state_vector: &[u8],
+        encoding: UpdateEncodingDoc
+    ) -> Result<Vec<u8>, CustomRustError> {
+        let state_vector = Self::decode_state_vector(remote_state_vector, encoding)?;
+        Ok(Self::encode_diff(txn, &state_vector, encoding))
+    }
+
     /// Merge multiple document updates into one
     pub fn merge_updates(updates: Vec<Vec<u8>>) -> Result<Vec<u8>, CustomRustError> {
         log_info!("merge_updates: Merging {} updates", updates.len());
-        
+
+        let updates = updates
+            .into_iter()
+            .map(|update| Self::convert_update(update, None, UpdateEncodingDoc::V2))
+            .collect::<Result<Vec<_>, _>>()?;
         merge_updates_v2(updates)
             .map_err(|e| DocError::MergeError(format!("Failed to merge updates: {}", e)).into())
     }
 }
+
+/// Reads the blocks of a document state covering only some of them, linked as `index` links them
+struct PartialState<'a, T: ReadTxn> {
+    txn: &'a T,
+    blocks_map: &'a MapRef,
+    index: &'a BlockIndex,
+    // Blocks no longer in the document, read before they were removed
+    removed: &'a HashMap<String, BlockDoc>,
+    ordering: SiblingOrderingDoc,
+    attribute_mode: AttributeModeDoc,
+}
+
+impl<T: ReadTxn> PartialState<'_, T> {
+    /// State with `block_ids` read in full, and of the other blocks only what ordering the
+    /// children of `parent_ids` and of their ancestors takes, which is all their paths depend on
+    fn extract<'b>(
+        &self,
+        doc_id: &str,
+        root_id: &str,
+        block_ids: impl Iterator<Item = &'b String>,
+        parent_ids: &BTreeSet<String>
+    ) -> Result<DocumentState, CustomRustError> {
+        let mut blocks = HashMap::new();
+        for block_id in block_ids {
+            if let Some(block) = self.block(block_id, true)? {
+                blocks.insert(block_id.clone(), block);
+            }
+        }
+
+        let mut visited = HashSet::new();
+        let mut pending: Vec<String> = parent_ids.iter().cloned().collect();
+        while let Some(parent_id) = pending.pop() {
+            if !visited.insert(parent_id.clone()) {
+                continue;
+            }
+            let sibling_ids: Vec<String> = self.index.children(&parent_id).cloned().chain([parent_id.clone()]).collect();
+            for block_id in sibling_ids {
+                if let Entry::Vacant(entry) = blocks.entry(block_id) {
+                    if let Some(block) = self.block(entry.key(), false)? {
+                        entry.insert(block);
+                    }
+                }
+            }
+            if let Some(grandparent_id) = self.index.parent_id(&parent_id) {
+                pending.push(grandparent_id.to_string());
+            }
+        }
+
+        let positions = match self.ordering {
+            SiblingOrderingDoc::FractionalIndex => Some(blocks.keys()
+                .filter_map(|block_id| Some((block_id.clone(), self.index.block_position(block_id)?.clone())))
+                .collect()),
+            SiblingOrderingDoc::PrevIdChain => None,
+        };
+        let children_map = UpdateOperations::sort_children(&mut blocks, positions.as_ref(), root_id);
+        log_info!("PartialState::extract: Read {} blocks under {} parents", blocks.len(), visited.len());
+
+        Ok(DocumentState {
+            blocks,
+            children_map,
+            doc_id: doc_id.to_string(),
+            root_id: root_id.to_string(),
+        })
+    }
+
+    /// A block in full, or with only the attributes sibling sorting looks at
+    fn block(&self, block_id: &str, full: bool) -> Result<Option<BlockDoc>, CustomRustError> {
+        if let Some(block) = self.removed.get(block_id) {
+            return Ok(Some(block.clone()));
+        }
+
+        let block = if full {
+            UpdateOperations::extract_block(self.txn, self.blocks_map, block_id, self.attribute_mode)?
+        } else {
+            self.sort_keys(block_id)
+        };
+        Ok(block.map(|mut block| {
+            block.parent_id = self.index.parent_id(block_id).map(str::to_string);
+            block.prev_id = self.index.prev_id(block_id).map(str::to_string);
+            block
+        }))
+    }
+
+    fn sort_keys(&self, block_id: &str) -> Option<BlockDoc> {
+        let Some(Out::YMap(block)) = self.blocks_map.get(self.txn, block_id) else {
+            return None;
+        };
+        let attributes = match block.get(self.txn, ATTRIBUTES) {
+            Some(Out::YMap(attributes)) => ["device", "timestamp"]
+                .into_iter()
+                .filter_map(|key| {
+                    let value = attributes.get(self.txn, key)?;
+                    Some((key.to_string(), Conversion::attribute_from_yrs(self.txn, &value, self.attribute_mode)))
+                })
+                .collect(),
+            _ => HashMap::new(),
+        };
+        Some(BlockDoc {
+            id: block_id.to_string(),
+            ty: String::new(),
+            attributes,
+            delta: None,
+            parent_id: None,
+            prev_id: None,
+            next_id: None,
+            old_parent_id: None,
+        })
+    }
+}
@@ -0,0 +1,157 @@
+use std::collections::{HashMap, HashSet};
+
+use yrs::{GetString, Map, MapRef, Out, ReadTxn};
+
+use crate::doc::constants::{DEFAULT_PARENT, TEXT};
+use crate::doc::document_types::{BlockActionDoc, BlockActionTypeDoc, CustomRustError};
+use crate::doc::error::DocError;
+use crate::doc::operations::delta_ops::DeltaOperations;
+use crate::doc::utils::block_index::BlockIndex;
+use crate::log_info;
+
+pub struct ValidationOperations;
+
+impl ValidationOperations {
+    /// Check a batch of actions against the document as each earlier action of the batch would
+    /// leave it, without writing anything, so a batch the document cannot take is turned down
+    /// before it is written. The error names the index of the first rejected action.
+    pub fn validate_actions<T: ReadTxn>(
+        txn: &T,
+        blocks_map: Option<&MapRef>,
+        index: &BlockIndex,
+        actions: &[BlockActionDoc]
+    ) -> Result<(), CustomRustError> {
+        let mut batch = BatchState { txn, blocks_map, index, present: HashMap::new(), parents: HashMap::new(), text_lens: HashMap::new() };
+        for (i, action) in actions.iter().enumerate() {
+            batch.apply(action).map_err(|e| {
+                log_info!("validate_actions: Rejecting action {} on block {}: {}", i, action.block.id, e);
+                e.at_action(i)
+            })?;
+        }
+        Ok(())
+    }
+}
+
+/// The parts of the document a batch depends on, as changed by the actions checked so far
+struct BatchState<'a, T: ReadTxn> {
+    txn: &'a T,
+    blocks_map: Option<&'a MapRef>,
+    index: &'a BlockIndex,
+    // Blocks inserted or deleted earlier in the batch
+    present: HashMap<String, bool>,
+    // Parents set earlier in the batch, None for blocks moved to the top level
+    parents: HashMap<String, Option<String>>,
+    // Text lengths after the deltas applied earlier in the batch
+    text_lens: HashMap<String, u32>,
+}
+
+impl<T: ReadTxn> BatchState<'_, T> {
+    fn apply(&mut self, action: &BlockActionDoc) -> Result<(), CustomRustError> {
+        let block = &action.block;
+        let parent_id = block.parent_id.as_deref().filter(|parent_id| *parent_id != DEFAULT_PARENT);
+        match action.action {
+            BlockActionTypeDoc::Insert => {
+                // Inserting over an existing block keeps its text, a deleted one starts empty
+                let text_len = if self.exists(&block.id) { self.text_len(&block.id) } else { 0 };
+                self.check_delta(&block.id, block.delta.as_deref(), text_len)?;
+                self.present.insert(block.id.clone(), true);
+                self.parents.insert(block.id.clone(), parent_id.map(str::to_string));
+            }
+            BlockActionTypeDoc::Update => {
+                self.require_block(&block.id)?;
+                let text_len = self.text_len(&block.id);
+                self.check_delta(&block.id, block.delta.as_deref(), text_len)?;
+            }
+            BlockActionTypeDoc::Delete => {
+                self.require_block(&block.id)?;
+                for descendant_id in self.descendants(&block.id) {
+                    self.present.insert(descendant_id, false);
+                }
+                self.present.insert(block.id.clone(), false);
+            }
+            BlockActionTypeDoc::Move => {
+                if action.old_path.is_none() || block.parent_id.is_none() || block.old_parent_id.is_none() {
+                    return Err(DocError::InvalidOperation("Missing required fields for move operation".into()).into());
+                }
+                self.require_block(&block.id)?;
+                if let Some(parent_id) = parent_id.filter(|parent_id| *parent_id == block.id || self.is_nested_under(parent_id, &block.id)) {
+                    return Err(DocError::InvalidOperation(
+                        format!("Block {} cannot be moved into itself or its descendant {}", block.id, parent_id)
+                    ).into());
+                }
+                self.parents.insert(block.id.clone(), parent_id.map(str::to_string));
+            }
+        }
+        Ok(())
+    }
+
+    fn exists(&self, block_id: &str) -> bool {
+        match self.present.get(block_id) {
+            Some(present) => *present,
+            None => self.blocks_map.is_some_and(|blocks_map| blocks_map.contains_key(self.txn, block_id)),
+        }
+    }
+
+    fn require_block(&self, block_id: &str) -> Result<(), CustomRustError> {
+        if !self.exists(block_id) {
+            return Err(DocError::BlockNotFound(format!("Block {} not found in blocks map", block_id)).into());
+        }
+        Ok(())
+    }
+
+    fn parent_id(&self, block_id: &str) -> Option<&str> {
+        match self.parents.get(block_id) {
+            Some(parent_id) => parent_id.as_deref(),
+            None => self.index.parent_id(block_id),
+        }
+    }
+
+    /// Blocks nested under `block_id` once the earlier actions are applied
+    fn descendants(&self, block_id: &str) -> Vec<String> {
+        // Blocks moved or inserted earlier may have joined or left the subtree
+        let candidates: HashSet<String> = self.index.descendants(block_id).into_iter()
+            .chain(self.parents.keys().cloned())
+            .collect();
+        candidates.into_iter()
+            .filter(|id| self.exists(id) && self.is_nested_under(id, block_id))
+            .collect()
+    }
+
+    fn is_nested_under(&self, id: &str, ancestor_id: &str) -> bool {
+        let mut visited = HashSet::new();
+        let mut current = self.parent_id(id);
+        while let Some(parent_id) = current {
+            if parent_id == ancestor_id {
+                return true;
+            }
+            if !visited.insert(parent_id) {
+                return false;
+            }
+            current = self.parent_id(parent_id);
+        }
+        false
+    }
+
+    fn text_len(&self, block_id: &str) -> u32 {
+        if let Some(text_len) = self.text_lens.get(block_id) {
+            return *text_len;
+        }
+        let Some(Out::YMap(block)) = self.blocks_map.and_then(|blocks_map| blocks_map.get(self.txn, block_id)) else {
+            return 0;
+        };
+        match block.get(self.txn, TEXT) {
+            // Deltas count UTF-16 units whatever offset kind the document uses
+            Some(Out::YText(text)) => text.get_string(self.txn).encode_utf16().count() as u32,
+            _ => 0,
+        }
+    }
+
+    fn check_delta(&mut self, block_id: &str, delta: Option<&str>, text_len: u32) -> Result<(), CustomRustError> {
+        let text_len = match delta {
+            Some(delta) => DeltaOperations::check_delta(delta, text_len)?,
+            None => text_len,
+        };
+        self.text_lens.insert(block_id.to_string(), text_len);
+        Ok(())
+    }
+}
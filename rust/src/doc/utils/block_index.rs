@@ -0,0 +1,236 @@
+use std::collections::{BTreeSet, HashMap, HashSet};
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::sync::Arc;
+
+use yrs::{Any, Doc, Map, MapRef, Out, ReadTxn, Subscription};
+
+use crate::doc::constants::{PARENT_ID, PREV_ID};
+use crate::doc::operations::position_ops::PositionOperations;
+use crate::doc::utils::sorting::BlockPosition;
+use crate::{log_error, log_info};
+
+/// Sibling and parent links of every block, so local edits can fix the prev_id chain
+/// without scanning the whole blocks map. Local actions keep it up to date while they
+/// write, any other change to the document marks it stale and it is rebuilt on next use.
+pub struct BlockIndex {
+    // Blocks whose prev_id points at the key
+    next_by_prev: HashMap<String, BTreeSet<String>>,
+    prev_of: HashMap<String, String>,
+    children_by_parent: HashMap<String, BTreeSet<String>>,
+    parent_of: HashMap<String, String>,
+    // Fractional positions, only present in documents ordered by them
+    position_of: HashMap<String, BlockPosition>,
+    // Set by the document observer on every committed change
+    stale: Arc<AtomicBool>,
+    subscription: Option<Subscription>,
+}
+
+impl Default for BlockIndex {
+    fn default() -> Self {
+        Self {
+            next_by_prev: HashMap::new(),
+            prev_of: HashMap::new(),
+            children_by_parent: HashMap::new(),
+            parent_of: HashMap::new(),
+            position_of: HashMap::new(),
+            stale: Arc::new(AtomicBool::new(true)),
+            subscription: None,
+        }
+    }
+}
+
+impl BlockIndex {
+    /// Follow changes of `doc` instead of the previously attached document
+    pub fn attach(&mut self, doc: &Doc) {
+        self.stale.store(true, Ordering::SeqCst);
+        let stale = self.stale.clone();
+        self.subscription = match doc.observe_update_v1(move |_, _| stale.store(true, Ordering::SeqCst)) {
+            Ok(subscription) => Some(subscription),
+            Err(e) => {
+                // Without an observer the index cannot tell remote changes apart, so it is rebuilt every time
+                log_error!("BlockIndex::attach: Failed to observe document updates: {:?}", e);
+                None
+            }
+        };
+    }
+
+    /// Rebuild the index from the blocks map if the document changed behind its back
+    pub fn refresh<T: ReadTxn>(&mut self, txn: &T, blocks_map: &MapRef) {
+        if !self.stale.load(Ordering::SeqCst) {
+            return;
+        }
+
+        self.next_by_prev.clear();
+        self.prev_of.clear();
+        self.children_by_parent.clear();
+        self.parent_of.clear();
+        self.position_of.clear();
+        for (id, out) in blocks_map.iter(txn) {
+            let Out::YMap(block) = out else {
+                continue;
+            };
+            let link = |key: &str| match block.get(txn, key) {
+                Some(Out::Any(Any::String(s))) => Some(s.to_string()),
+                _ => None,
+            };
+            if let Some(prev_id) = link(PREV_ID) {
+                self.link_prev(id, prev_id);
+            }
+            if let Some(parent_id) = link(PARENT_ID) {
+                self.link_parent(id, parent_id);
+            }
+            if let Some(position) = PositionOperations::read_position(txn, &block) {
+                self.position_of.insert(id.to_string(), position);
+            }
+        }
+
+        log_info!("BlockIndex::refresh: Indexed {} blocks", blocks_map.len(txn));
+        self.stale.store(false, Ordering::SeqCst);
+    }
+
+    /// Copy of the links, following no document, for the writes of a batch to be mirrored into
+    pub fn detached(&self) -> Self {
+        Self {
+            next_by_prev: self.next_by_prev.clone(),
+            prev_of: self.prev_of.clone(),
+            children_by_parent: self.children_by_parent.clone(),
+            parent_of: self.parent_of.clone(),
+            position_of: self.position_of.clone(),
+            stale: Arc::new(AtomicBool::new(self.stale.load(Ordering::SeqCst))),
+            subscription: None,
+        }
+    }
+
+    /// Take over the links of a detached copy once the writes mirrored into it reached the
+    /// followed document
+    pub fn adopt(&mut self, copy: BlockIndex) {
+        let stale = copy.stale.load(Ordering::SeqCst);
+        self.next_by_prev = copy.next_by_prev;
+        self.prev_of = copy.prev_of;
+        self.children_by_parent = copy.children_by_parent;
+        self.parent_of = copy.parent_of;
+        self.position_of = copy.position_of;
+        if stale {
+            self.stale.store(true, Ordering::SeqCst);
+        } else {
+            self.mark_fresh();
+        }
+    }
+
+    /// Called once the transaction whose writes were mirrored into the index is committed
+    pub fn mark_fresh(&self) {
+        if self.subscription.is_some() {
+            self.stale.store(false, Ordering::SeqCst);
+        }
+    }
+
+    /// Blocks whose prev_id is `prev_id`
+    pub fn next_ids(&self, prev_id: &str) -> Vec<String> {
+        self.next_by_prev.get(prev_id).map(|ids| ids.iter().cloned().collect()).unwrap_or_default()
+    }
+
+    /// Direct children of `parent_id`, in no particular order
+    pub fn children(&self, parent_id: &str) -> impl Iterator<Item = &String> {
+        self.children_by_parent.get(parent_id).into_iter().flatten()
+    }
+
+    pub fn parent_id(&self, block_id: &str) -> Option<&str> {
+        self.parent_of.get(block_id).map(String::as_str)
+    }
+
+    pub fn prev_id(&self, block_id: &str) -> Option<&str> {
+        self.prev_of.get(block_id).map(String::as_str)
+    }
+
+    pub fn position(&self, block_id: &str) -> Option<&str> {
+        self.position_of.get(block_id).map(|position| position.key.as_str())
+    }
+
+    pub fn block_position(&self, block_id: &str) -> Option<&BlockPosition> {
+        self.position_of.get(block_id)
+    }
+
+    /// Every block nested under `block_id`, parents before their children
+    pub fn descendants(&self, block_id: &str) -> Vec<String> {
+        let mut descendants = Vec::new();
+        // A parent cycle would otherwise never end
+        let mut visited = HashSet::from([block_id.to_string()]);
+        let mut stack = vec![block_id.to_string()];
+        while let Some(id) = stack.pop() {
+            for child_id in self.children_by_parent.get(&id).into_iter().flatten() {
+                if visited.insert(child_id.clone()) {
+                    descendants.push(child_id.clone());
+                    stack.push(child_id.clone());
+                }
+            }
+        }
+        descendants
+    }
+
+    /// Whether `ancestor_id` is found walking up the parents of `block_id`
+    pub fn is_nested_under(&self, block_id: &str, ancestor_id: &str) -> bool {
+        let mut visited = HashSet::new();
+        let mut current = self.parent_id(block_id);
+        while let Some(parent_id) = current {
+            if parent_id == ancestor_id {
+                return true;
+            }
+            if !visited.insert(parent_id) {
+                return false;
+            }
+            current = self.parent_id(parent_id);
+        }
+        false
+    }
+
+    pub fn set_prev_id(&mut self, block_id: &str, prev_id: Option<&str>) {
+        self.unlink_prev(block_id);
+        if let Some(prev_id) = prev_id {
+            self.link_prev(block_id, prev_id.to_string());
+        }
+    }
+
+    pub fn set_parent_id(&mut self, block_id: &str, parent_id: Option<&str>) {
+        self.unlink_parent(block_id);
+        if let Some(parent_id) = parent_id {
+            self.link_parent(block_id, parent_id.to_string());
+        }
+    }
+
+    pub fn set_position(&mut self, block_id: &str, position: BlockPosition) {
+        self.position_of.insert(block_id.to_string(), position);
+    }
+
+    /// Forget the links of a block removed from the blocks map
+    pub fn remove(&mut self, block_id: &str) {
+        self.unlink_prev(block_id);
+        self.unlink_parent(block_id);
+        self.position_of.remove(block_id);
+    }
+
+    fn link_prev(&mut self, block_id: &str, prev_id: String) {
+        self.next_by_prev.entry(prev_id.clone()).or_default().insert(block_id.to_string());
+        self.prev_of.insert(block_id.to_string(), prev_id);
+    }
+
+    fn unlink_prev(&mut self, block_id: &str) {
+        if let Some(prev_id) = self.prev_of.remove(block_id) {
+            if let Some(ids) = self.next_by_prev.get_mut(&prev_id) {
+                ids.remove(block_id);
+            }
+        }
+    }
+
+    fn link_parent(&mut self, block_id: &str, parent_id: String) {
+        self.children_by_parent.entry(parent_id.clone()).or_default().insert(block_id.to_string());
+        self.parent_of.insert(block_id.to_string(), parent_id);
+    }
+
+    fn unlink_parent(&mut self, block_id: &str) {
+        if let Some(parent_id) = self.parent_of.remove(block_id) {
+            if let Some(ids) = self.children_by_parent.get_mut(&parent_id) {
+                ids.remove(block_id);
+            }
+        }
+    }
+}
@@ -0,0 +1,244 @@
+/// Base 62 digits in ASCII order, so keys compare as plain strings
+const DIGITS: &[u8; 62] = b"0123456789ABCDEFGHIJKLMNOPQRSTUVWXYZabcdefghijklmnopqrstuvwxyz";
+const BASE: usize = DIGITS.len();
+const INTEGER_ZERO: &str = "a0";
+const SMALLEST_INTEGER: &str = "A00000000000000000000000000";
+
+/// Order keys that always have room for another key between two of them.
+///
+/// A key is an integer part, whose head letter encodes its length, followed by an optional
+/// fraction without trailing zeros. Appending or prepending grows keys logarithmically,
+/// inserting between two keys grows them by about one digit per halving.
+pub struct FractionalIndex;
+
+impl FractionalIndex {
+    /// A key sorting after `a` and before `b`. None when a key is malformed or `a` is not below `b`.
+    pub fn key_between(a: Option<&str>, b: Option<&str>) -> Option<String> {
+        if let Some(a) = a {
+            Self::validate(a)?;
+        }
+        if let Some(b) = b {
+            Self::validate(b)?;
+        }
+
+        match (a, b) {
+            (None, None) => Some(INTEGER_ZERO.to_string()),
+            (None, Some(b)) => {
+                let integer_b = Self::integer_part(b)?;
+                let fraction_b = &b[integer_b.len()..];
+                if integer_b == SMALLEST_INTEGER {
+                    return Some(format!("{}{}", integer_b, Self::midpoint("", Some(fraction_b))?));
+                }
+                if integer_b.len() < b.len() {
+                    return Some(integer_b.to_string());
+                }
+                Self::decrement_integer(integer_b)
+            }
+            (Some(a), None) => {
+                let integer_a = Self::integer_part(a)?;
+                let fraction_a = &a[integer_a.len()..];
+                match Self::increment_integer(integer_a) {
+                    Some(integer) => Some(integer),
+                    None => Some(format!("{}{}", integer_a, Self::midpoint(fraction_a, None)?)),
+                }
+            }
+            (Some(a), Some(b)) => {
+                if a >= b {
+                    return None;
+                }
+                let integer_a = Self::integer_part(a)?;
+                let fraction_a = &a[integer_a.len()..];
+                let integer_b = Self::integer_part(b)?;
+                let fraction_b = &b[integer_b.len()..];
+                if integer_a == integer_b {
+                    return Some(format!("{}{}", integer_a, Self::midpoint(fraction_a, Some(fraction_b))?));
+                }
+                let integer = Self::increment_integer(integer_a)?;
+                if integer.as_str() < b {
+                    return Some(integer);
+                }
+                Some(format!("{}{}", integer_a, Self::midpoint(fraction_a, None)?))
+            }
+        }
+    }
+
+    /// `count` increasing keys after `a`, for laying out siblings that had no keys yet
+    pub fn keys_after(a: Option<&str>, count: usize) -> Vec<String> {
+        let mut keys: Vec<String> = Vec::with_capacity(count);
+        for _ in 0..count {
+            let prev = keys.last().map(String::as_str).or(a);
+            match Self::key_between(prev, None) {
+                Some(key) => keys.push(key),
+                None => break,
+            }
+        }
+        keys
+    }
+
+    fn validate(key: &str) -> Option<()> {
+        if key == SMALLEST_INTEGER || !key.bytes().all(|c| Self::digit(c).is_some()) {
+            return None;
+        }
+        let integer = Self::integer_part(key)?;
+        (!key[integer.len()..].ends_with('0')).then_some(())
+    }
+
+    fn integer_length(head: u8) -> Option<usize> {
+        match head {
+            b'a'..=b'z' => Some((head - b'a') as usize + 2),
+            b'A'..=b'Z' => Some((b'Z' - head) as usize + 2),
+            _ => None,
+        }
+    }
+
+    fn integer_part(key: &str) -> Option<&str> {
+        let length = Self::integer_length(*key.as_bytes().first()?)?;
+        key.get(..length)
+    }
+
+    fn digit(c: u8) -> Option<usize> {
+        DIGITS.iter().position(|&d| d == c)
+    }
+
+    /// Digits strictly between two fractions, `b` None meaning one
+    fn midpoint(a: &str, b: Option<&str>) -> Option<String> {
+        let (a, b) = (a.as_bytes(), b.map(str::as_bytes));
+        if let Some(b) = b {
+            // Keep the common prefix, treating a missing digit of `a` as zero
+            let common = b.iter()
+                .enumerate()
+                .take_while(|(i, &digit)| a.get(*i).copied().unwrap_or(b'0') == digit)
+                .count();
+            if common == b.len() {
+                return None;
+            }
+            if common > 0 {
+                let rest_a = std::str::from_utf8(a.get(common..).unwrap_or_default()).ok()?;
+                let rest_b = std::str::from_utf8(&b[common..]).ok()?;
+                let prefix = std::str::from_utf8(&b[..common]).ok()?;
+                return Some(format!("{}{}", prefix, Self::midpoint(rest_a, Some(rest_b))?));
+            }
+        }
+
+        let digit_a = match a.first() {
+            Some(&c) => Self::digit(c)?,
+            None => 0,
+        };
+        let digit_b = match b {
+            Some(b) => Self::digit(b[0])?,
+            None => BASE,
+        };
+        if digit_b > digit_a + 1 {
+            let middle = (digit_a + digit_b).div_ceil(2);
+            return Some((DIGITS[middle] as char).to_string());
+        }
+        match b {
+            Some(b) if b.len() > 1 => Some((b[0] as char).to_string()),
+            _ => {
+                let rest_a = std::str::from_utf8(a.get(1..).unwrap_or_default()).ok()?;
+                Some(format!("{}{}", DIGITS[digit_a] as char, Self::midpoint(rest_a, None)?))
+            }
+        }
+    }
+
+    fn increment_integer(integer: &str) -> Option<String> {
+        let head = integer.as_bytes()[0];
+        let mut digits = integer.as_bytes()[1..].to_vec();
+        let mut carry = true;
+        for digit in digits.iter_mut().rev() {
+            let next = Self::digit(*digit)? + 1;
+            if next == BASE {
+                *digit = b'0';
+            } else {
+                *digit = DIGITS[next];
+                carry = false;
+                break;
+            }
+        }
+
+        if !carry {
+            return Some(format!("{}{}", head as char, String::from_utf8(digits).ok()?));
+        }
+
+        // Every digit overflowed, continue with the next integer length
+        let head = match head {
+            b'Z' => return Some(INTEGER_ZERO.to_string()),
+            b'z' => return None,
+            _ => head + 1,
+        };
+        if head > b'a' {
+            digits.push(b'0');
+        } else {
+            digits.pop();
+        }
+        Some(format!("{}{}", head as char, String::from_utf8(digits).ok()?))
+    }
+
+    fn decrement_integer(integer: &str) -> Option<String> {
+        let head = integer.as_bytes()[0];
+        let mut digits = integer.as_bytes()[1..].to_vec();
+        let mut borrow = true;
+        for digit in digits.iter_mut().rev() {
+            match Self::digit(*digit)? {
+                0 => *digit = DIGITS[BASE - 1],
+                value => {
+                    *digit = DIGITS[value - 1];
+                    borrow = false;
+                    break;
+                }
+            }
+        }
+
+        if !borrow {
+            return Some(format!("{}{}", head as char, String::from_utf8(digits).ok()?));
+        }
+
+        // Every digit underflowed, continue with the previous integer length
+        let head = match head {
+            b'a' => return Some(format!("Z{}", DIGITS[BASE - 1] as char)),
+            b'A' => return None,
+            _ => head - 1,
+        };
+        if head < b'Z' {
+            digits.push(DIGITS[BASE - 1]);
+        } else {
+            digits.pop();
+        }
+        Some(format!("{}{}", head as char, String::from_utf8(digits).ok()?))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_keys_stay_ordered_and_short() {
+        assert_eq!(FractionalIndex::key_between(None, None).unwrap(), "a0");
+        assert_eq!(FractionalIndex::key_between(Some("a0"), None).unwrap(), "a1");
+        assert_eq!(FractionalIndex::key_between(None, Some("a0")).unwrap(), "Zz");
+        assert_eq!(FractionalIndex::key_between(Some("a0"), Some("a1")).unwrap(), "a0V");
+        assert_eq!(FractionalIndex::key_between(Some("az"), None).unwrap(), "b00");
+        assert!(FractionalIndex::key_between(Some("a1"), Some("a0")).is_none());
+        assert!(FractionalIndex::key_between(Some("a10"), None).is_none());
+
+        // Appending grows keys logarithmically
+        let keys = FractionalIndex::keys_after(None, 5000);
+        assert!(keys.windows(2).all(|pair| pair[0] < pair[1]));
+        assert!(keys.iter().all(|key| key.len() <= 4));
+
+        // Repeatedly inserting at the same spot keeps every key between its neighbours
+        let (low, mut high) = ("a0".to_string(), "a1".to_string());
+        for _ in 0..200 {
+            let key = FractionalIndex::key_between(Some(&low), Some(&high)).unwrap();
+            assert!(low < key && key < high);
+            high = key;
+        }
+        let mut low = "a0".to_string();
+        for _ in 0..200 {
+            let key = FractionalIndex::key_between(Some(&low), Some("a1")).unwrap();
+            assert!(low < key && key.as_str() < "a1");
+            low = key;
+        }
+    }
+}
@@ -18,4 +18,10 @@ pub mod sorting;
 
 
 /// flutter_rust_bridge:ignore
-pub mod logging;
\ No newline at end of file
+pub mod logging;
+
+/// flutter_rust_bridge:ignore
+pub mod block_index;
+
+/// flutter_rust_bridge:ignore
+pub mod fractional_index;
@@ -1,10 +1,37 @@
-use std::collections::HashMap;
+use std::cmp::Ordering;
+use std::collections::{BTreeMap, HashMap, HashSet};
 
 use crate::{doc::document_types::BlockDoc, log_info};
 
+/// Why the prev_id of a block was ignored while ordering its siblings
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum BrokenLinkKind {
+    // The prev_id names a block that is missing or has another parent
+    Dangling,
+    // The block is where a prev_id cycle was cut open
+    Cycle,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct BrokenLink {
+    pub block_id: String,
+    pub parent_id: String,
+    pub kind: BrokenLinkKind,
+}
+
+/// Fractional position of a block, the client that wrote it breaks ties between equal keys
+#[derive(Debug, Clone)]
+pub struct BlockPosition {
+    pub key: String,
+    pub client_id: u64,
+}
+
 pub struct ChainSorting;
 
 impl ChainSorting {
+    /// Order the children of every parent by following prevId chains. Chains of one device stay
+    /// together and devices come in id order. Ties are broken by timestamp, then block id, so the
+    /// same blocks give the same order on every device whatever order the map iterates in.
     pub fn sort_blocks_by_chain(
         blocks: &HashMap<String, BlockDoc>
     ) -> HashMap<String, Vec<String>> {
@@ -19,224 +46,212 @@ impl ChainSorting {
             blocks_by_parent.entry(parent_id.clone()).or_default().push(block_id.clone());
             log_info!("Block {} assigned to parent {}", block_id, parent_id);
         }
-
-        //Print blocks by parent map
-        for (parent_id, child_ids) in &blocks_by_parent {
-            log_info!("Parent {} has {} children: {:?}", parent_id, child_ids.len(), child_ids);
-        }
         
-        log_info!("Grouped {} blocks by parent", blocks.len());
+        log_info!("Grouped {} blocks by {} parents", blocks.len(), blocks_by_parent.len());
+        
+        let sorted_children: HashMap<String, Vec<String>> = blocks_by_parent
+            .into_iter()
+            .map(|(parent_id, child_ids)| {
+                let (sorted_ids, _) = Self::sort_siblings(blocks, &parent_id, child_ids);
+                (parent_id, sorted_ids)
+            })
+            .collect();
+        
+        log_info!("====== COMPLETED BLOCK SORTING ======");
+        log_info!("Final sorted children map has {} parents", sorted_children.len());
         
-        let mut sorted_children = HashMap::new();
+        sorted_children
+    }
+
+    /// Blocks whose prev_id the sorter has to ignore, all dangling ones and one per cycle,
+    /// sorted by block id. They are the ones a repair has to relink.
+    pub fn find_broken_links(blocks: &HashMap<String, BlockDoc>) -> Vec<BrokenLink> {
+        let mut blocks_by_parent: HashMap<String, Vec<String>> = HashMap::new();
+        for (block_id, block) in blocks {
+            let parent_id = block.parent_id.clone().unwrap_or_else(|| "root".to_string());
+            blocks_by_parent.entry(parent_id).or_default().push(block_id.clone());
+        }
 
+        let mut broken_links = Vec::new();
         for (parent_id, child_ids) in blocks_by_parent {
-            log_info!("\n----- Sorting children of parent: {} -----", parent_id);
-            log_info!("Parent has {} children to sort", child_ids.len());
-            
-            // Create a map of blocks by ID for quick access
-            let blocks_by_id: HashMap<String, &BlockDoc> = child_ids
-                .iter()
-                .filter_map(|id| {
-                    let block = blocks.get(id);
-                    if block.is_none() {
-                        log_info!("WARNING: Block with ID {} not found in blocks map", id);
-                    }
-                    block.map(|b| (id.clone(), b))
-                })
-                .collect();
-                
-            log_info!("Mapped {} valid blocks by ID", blocks_by_id.len());
-                
-            // Group blocks by device - this will help us maintain device grouping
-            let mut blocks_by_device: HashMap<String, Vec<String>> = HashMap::new();
-            
-            for (id, block) in &blocks_by_id {
-                let device_id = block.attributes
-                    .get("device")
-                    .unwrap_or(&"unknown".to_string())
-                    .clone();
-                
-                blocks_by_device.entry(device_id.clone()).or_default().push(id.clone());
-                log_info!("Block {} assigned to device {}", id, device_id);
+            let siblings: HashSet<String> = child_ids.iter().cloned().collect();
+            let (_, chain_starts) = Self::sort_siblings(blocks, &parent_id, child_ids);
+            for block_id in chain_starts {
+                let prev_id = blocks[&block_id].prev_id.as_deref().unwrap_or_default();
+                let kind = if siblings.contains(prev_id) {
+                    BrokenLinkKind::Cycle
+                } else {
+                    BrokenLinkKind::Dangling
+                };
+                broken_links.push(BrokenLink { block_id, parent_id: parent_id.clone(), kind });
             }
-            
-            log_info!("Grouped blocks into {} devices", blocks_by_device.len());
-            for (device, blocks) in &blocks_by_device {
-                log_info!("  - Device {} has {} blocks", device, blocks.len());
+        }
+
+        broken_links.sort_by(|a, b| a.block_id.cmp(&b.block_id));
+        log_info!("find_broken_links: Found {} broken prev_id links", broken_links.len());
+        broken_links
+    }
+
+    /// Sorted siblings, and the blocks that had to start a chain although they have a prev_id
+    fn sort_siblings(
+        blocks: &HashMap<String, BlockDoc>,
+        parent_id: &str,
+        mut child_ids: Vec<String>
+    ) -> (Vec<String>, Vec<String>) {
+        log_info!("----- Sorting {} children of parent: {} -----", child_ids.len(), parent_id);
+
+        // Siblings start out in a canonical order, so nothing below depends on map iteration
+        child_ids.sort_by(|a, b| Self::compare_blocks(blocks, a, b));
+
+        // Group blocks by device - this will help us maintain device grouping
+        let mut blocks_by_device: BTreeMap<&str, Vec<&str>> = BTreeMap::new();
+        // Build a multi-map from prevId to blocks, each list in canonical order
+        let mut next_blocks: HashMap<&str, Vec<&str>> = HashMap::new();
+        let mut has_prev = HashSet::new();
+
+        for id in &child_ids {
+            let block = &blocks[id];
+            let device_id = block.attributes.get("device").map(|device| Self::unquoted(device)).unwrap_or("unknown");
+            blocks_by_device.entry(device_id).or_default().push(id);
+
+            if let Some(prev) = &block.prev_id {
+                next_blocks.entry(prev).or_default().push(id);
+                has_prev.insert(id.as_str());
             }
-                
-            // Build a multi-map from prevId to blocks
-            let mut next_blocks: HashMap<String, Vec<String>> = HashMap::new();
-            let mut has_prev = std::collections::HashSet::new();
-            
-            for (id, block) in &blocks_by_id {
-                if let Some(prev) = &block.prev_id {
-                    next_blocks.entry(prev.clone()).or_default().push(id.clone());
-                    has_prev.insert(id.clone());
-                    log_info!("Block {} has prev_id {}", id, prev);
-                } else {
-                    log_info!("Block {} has no prev_id", id);
-                }
+        }
+        log_info!("Grouped blocks into {} devices, {} blocks have a prev_id", blocks_by_device.len(), has_prev.len());
+
+        // Blocks not reached from any block without prev_id have a dangling prev_id or sit in a cycle
+        let mut reached = HashSet::new();
+        for start in child_ids.iter().filter(|id| !has_prev.contains(id.as_str())) {
+            Self::visit_chain(start, &next_blocks, &mut reached, &mut Vec::new());
+        }
+
+        let mut sorted_ids = Vec::with_capacity(child_ids.len());
+        let mut visited = HashSet::new();
+        let mut chain_starts = Vec::new();
+        let mut deferred = Vec::new();
+
+        // Device by device, the chains starting at blocks without prev_id come first, followed by
+        // the broken chains of the device, each cut open at its earliest block. Devices without a
+        // chain of their own leave their broken chains to the end.
+        for (device, device_blocks) in &blocks_by_device {
+            let broken = device_blocks.iter().copied().filter(|id| !reached.contains(id));
+            if device_blocks.iter().all(|id| has_prev.contains(id)) {
+                deferred.extend(broken);
+                continue;
             }
-            
-            log_info!("Built prev->next map with {} entries", next_blocks.len());
-            for (prev, nexts) in &next_blocks {
-                log_info!("  - {} has {} next blocks: {:?}", prev, nexts.len(), nexts);
+
+            for start in device_blocks.iter().filter(|id| !has_prev.contains(*id)) {
+                log_info!("Starting chain of device {} from block: {}", device, start);
+                Self::visit_chain(start, &next_blocks, &mut visited, &mut sorted_ids);
             }
-            
-            // Find all root blocks (those without prev_id)
-            let mut roots_by_device: HashMap<String, Vec<String>> = HashMap::new();
-            
-            for (device, device_blocks) in &blocks_by_device {
-                let device_roots: Vec<String> = device_blocks
-                    .iter()
-                    .filter(|id| !has_prev.contains(*id))
-                    .cloned()
-                    .collect();
-                    
-                if !device_roots.is_empty() {
-                    roots_by_device.insert(device.clone(), device_roots.clone());
-                    log_info!("Device {} has {} root blocks: {:?}", device, device_roots.len(), device_roots);
-                } else {
-                    log_info!("Device {} has no root blocks", device);
+            for start in broken {
+                if !visited.contains(start) {
+                    log_info!("Starting broken chain of device {} from block: {}", device, start);
+                    chain_starts.push(start.to_string());
+                    Self::visit_chain(start, &next_blocks, &mut visited, &mut sorted_ids);
                 }
             }
-            
-            // Process each device's chain separately to maintain grouping
-            let mut sorted_ids = Vec::new();
-            let mut visited = std::collections::HashSet::new();
-            
-            // Process devices in order (for consistent results)
-            let mut devices: Vec<String> = blocks_by_device.keys().cloned().collect();
-            devices.sort();
-            log_info!("Processing devices in order: {:?}", devices);
-            
-            for device in &devices {
-                log_info!("\nProcessing device: {}", device);
-                if let Some(roots) = roots_by_device.get(device) {
-                    let mut device_roots = roots.clone();
-                    
-                    // Sort roots within each device by timestamp
-                    device_roots.sort_by(|a, b| {
-                        let block_a = blocks_by_id.get(a).unwrap();
-                        let block_b = blocks_by_id.get(b).unwrap();
-                        
-                        let binding = "".to_string();
-                        let time_a = block_a.attributes.get("timestamp").unwrap_or(&binding);
-                        let time_b = block_b.attributes.get("timestamp").unwrap_or(&binding);
-                        log_info!("Comparing root blocks: {} (time: {}) vs {} (time: {})", a, time_a, b, time_b);
-                        time_a.cmp(time_b)
-                    });
-                    
-                    log_info!("Sorted roots for device {}: {:?}", device, device_roots);
-                    
-                    // Process each root from this device and its chain
-                    for root in &device_roots {
-                        if visited.contains(root) {
-                            log_info!("Root {} already visited, skipping", root);
-                            continue;
-                        }
-                        
-                        log_info!("Starting chain from root: {}", root);
-                        let mut stack = vec![root.clone()];
-                        
-                        // Process this chain completely
-                        while let Some(block_id) = stack.pop() {
-                            if visited.contains(&block_id) {
-                                log_info!("  Block {} already visited, skipping", block_id);
-                                continue;
-                            }
-                            
-                            log_info!("  Adding block {} to sorted list", block_id);
-                            sorted_ids.push(block_id.clone());
-                            visited.insert(block_id.clone());
-                            
-                            if let Some(next_block_ids) = next_blocks.get(&block_id) {
-                                // Sort next blocks by timestamp if there are multiple
-                                let mut next_blocks_sorted = next_block_ids.clone();
-                                
-                                if next_blocks_sorted.len() > 1 {
-                                    log_info!("  Multiple next blocks for {}: {:?}, sorting by timestamp", block_id, next_blocks_sorted);
-                                    next_blocks_sorted.sort_by(|a, b| {
-                                        let block_a = blocks_by_id.get(a).unwrap();
-                                        let block_b = blocks_by_id.get(b).unwrap();
-                                        
-                                        let binding = "".to_string();
-                                        let time_a = block_a.attributes.get("timestamp").unwrap_or(&binding);
-                                        let time_b = block_b.attributes.get("timestamp").unwrap_or(&binding);
-                                        log_info!("    Comparing: {} (time: {}) vs {} (time: {})", a, time_a, b, time_b);
-                                        time_a.cmp(time_b)
-                                    });
-                                    log_info!("    Sorted next blocks: {:?}", next_blocks_sorted);
-                                }
-                                
-                                // Add to stack in reverse order for depth-first traversal
-                                for next_id in next_blocks_sorted.into_iter().rev() {
-                                    if !visited.contains(&next_id) {
-                                        log_info!("    Pushing {} onto stack", next_id);
-                                        stack.push(next_id);
-                                    } else {
-                                        log_info!("    Next block {} already visited, skipping", next_id);
-                                    }
-                                }
-                            } else {
-                                log_info!("  No next blocks for {}", block_id);
-                            }
-                        }
-                    }
-                    
-                    // Add any remaining blocks from this device that weren't in chains
-                    if let Some(device_blocks) = blocks_by_device.get(device) {
-                        let remaining = device_blocks.iter()
-                            .filter(|id| !visited.contains(*id))
-                            .collect::<Vec<_>>();
-                            
-                        if !remaining.is_empty() {
-                            log_info!("Adding {} remaining blocks for device {}: {:?}", 
-                                remaining.len(), device, remaining);
-                                
-                            for block_id in device_blocks {
-                                if !visited.contains(block_id) {
-                                    log_info!("  Adding remaining block {} to sorted list", block_id);
-                                    sorted_ids.push(block_id.clone());
-                                    visited.insert(block_id.clone());
-                                }
-                            }
-                        } else {
-                            log_info!("No remaining blocks for device {}", device);
-                        }
-                    }
-                } else {
-                    log_info!("No roots for device {}, skipping", device);
-                }
+        }
+        for start in deferred {
+            if !visited.contains(start) {
+                log_info!("Starting broken chain from block: {}", start);
+                chain_starts.push(start.to_string());
+                Self::visit_chain(start, &next_blocks, &mut visited, &mut sorted_ids);
             }
-            
-            // Add any remaining blocks that weren't processed
-            let remaining = child_ids.iter()
-                .filter(|id| !visited.contains(*id))
-                .collect::<Vec<_>>();
-                
-            if !remaining.is_empty() {
-                log_info!("Adding {} globally remaining blocks: {:?}", remaining.len(), remaining);
-                
-                for block_id in child_ids {
-                    if !visited.contains(&block_id) {
-                        log_info!("  Adding remaining block {} to sorted list", block_id);
-                        sorted_ids.push(block_id.clone());
-                        visited.insert(block_id.clone());
-                    }
-                }
-            } else {
-                log_info!("No globally remaining blocks");
+        }
+
+        log_info!("Final sorted order for parent {}: {:?}", parent_id, sorted_ids);
+        (sorted_ids, chain_starts)
+    }
+
+    /// Depth first walk along the prev_id links starting at `start`
+    fn visit_chain<'a>(
+        start: &'a str,
+        next_blocks: &HashMap<&str, Vec<&'a str>>,
+        visited: &mut HashSet<&'a str>,
+        sorted_ids: &mut Vec<String>
+    ) {
+        let mut stack = vec![start];
+        while let Some(block_id) = stack.pop() {
+            if !visited.insert(block_id) {
+                continue;
+            }
+            sorted_ids.push(block_id.to_string());
+
+            // Pushed in reverse so the earliest next block is walked first
+            if let Some(next_ids) = next_blocks.get(block_id) {
+                stack.extend(next_ids.iter().rev().filter(|id| !visited.contains(*id)));
             }
-            
-            log_info!("Final sorted order for parent {}: {:?}", parent_id, sorted_ids);
-            sorted_children.insert(parent_id.clone(), sorted_ids);
         }
-        
-        log_info!("====== COMPLETED BLOCK SORTING ======");
-        log_info!("Final sorted children map has {} parents", sorted_children.len());
-        
+    }
+
+    /// Total order on blocks by timestamp, then id
+    fn compare_blocks(blocks: &HashMap<String, BlockDoc>, a: &str, b: &str) -> Ordering {
+        let timestamp = |id: &str| blocks.get(id).and_then(|block| block.attributes.get("timestamp"));
+        Self::compare_timestamps(timestamp(a), timestamp(b)).then_with(|| a.cmp(b))
+    }
+
+    /// Missing timestamps come first, numbers compare as numbers and come before anything else,
+    /// which compares as text
+    fn compare_timestamps(a: Option<&String>, b: Option<&String>) -> Ordering {
+        let (rank_a, number_a, text_a) = Self::timestamp_key(a);
+        let (rank_b, number_b, text_b) = Self::timestamp_key(b);
+        rank_a.cmp(&rank_b)
+            .then_with(|| number_a.total_cmp(&number_b))
+            .then_with(|| text_a.cmp(text_b))
+    }
+
+    fn timestamp_key(timestamp: Option<&String>) -> (u8, f64, &str) {
+        match timestamp.map(|t| Self::unquoted(t)) {
+            None => (0, 0.0, ""),
+            Some(t) => match t.parse::<f64>() {
+                Ok(number) if number.is_finite() => (1, number, t),
+                _ => (2, 0.0, t),
+            },
+        }
+    }
+
+    /// Attribute value without the quotes it is read with in the JSON attribute mode, so both
+    /// modes group and order blocks alike
+    fn unquoted(value: &str) -> &str {
+        value.trim().trim_matches('"')
+    }
+}
+
+pub struct PositionSorting;
+
+impl PositionSorting {
+    /// Order siblings by position key, then client id and block id. Blocks written by clients
+    /// that do not know about positions follow the positioned ones in their prevId chain order.
+    pub fn sort_blocks_by_position(
+        blocks: &HashMap<String, BlockDoc>,
+        positions: &HashMap<String, BlockPosition>
+    ) -> HashMap<String, Vec<String>> {
+        let mut sorted_children = if blocks.keys().all(|id| positions.contains_key(id)) {
+            let mut blocks_by_parent: HashMap<String, Vec<String>> = HashMap::new();
+            for (block_id, block) in blocks {
+                let parent_id = block.parent_id.clone().unwrap_or_else(|| "root".to_string());
+                blocks_by_parent.entry(parent_id).or_default().push(block_id.clone());
+            }
+            blocks_by_parent
+        } else {
+            ChainSorting::sort_blocks_by_chain(blocks)
+        };
+
+        // The sort is stable, so blocks without a position keep their chain order
+        for child_ids in sorted_children.values_mut() {
+            child_ids.sort_by(|a, b| match (positions.get(a), positions.get(b)) {
+                (Some(position_a), Some(position_b)) => (&position_a.key, position_a.client_id, a)
+                    .cmp(&(&position_b.key, position_b.client_id, b)),
+                (Some(_), None) => Ordering::Less,
+                (None, Some(_)) => Ordering::Greater,
+                (None, None) => Ordering::Equal,
+            });
+        }
+
+        log_info!("sort_blocks_by_position: Sorted {} blocks under {} parents", blocks.len(), sorted_children.len());
         sorted_children
     }
-}
\ No newline at end of file
+}
@@ -831,4 +831,130 @@ mod tests {
         assert_eq!(sorted["parent2"], vec!["p2a"]);
     }
 
+    #[test]
+    fn test_broken_chain_stays_with_its_device() {
+        let blocks = vec![
+            create_test_block("a1", "para", "device_a", "1", None, Some("root")),
+            create_test_block("a2", "para", "device_a", "2", Some("gone"), Some("root")),
+            create_test_block("b1", "para", "device_b", "1", None, Some("root")),
+            // Reached from the chain of device_b, so not broken although device_a comes first
+            create_test_block("a3", "para", "device_a", "3", Some("b1"), Some("root")),
+            create_test_block("c1", "para", "device_c", "1", Some("gone"), Some("root")),
+        ];
+        let sorted = assert_converges(&blocks);
+        // Devices without a chain of their own come last
+        assert_eq!(sorted["root"], vec!["a1", "a2", "b1", "a3", "c1"]);
+    }
+
+    #[test]
+    fn test_quoted_device_groups_with_plain_device() {
+        // The JSON attribute mode reads the device with quotes around it
+        let mut blocks = HashMap::new();
+        blocks.insert("a1".to_string(), create_test_block("a1", "para", "device_a", "1", None, Some("root")));
+        blocks.insert("b1".to_string(), create_test_block("b1", "para", "\"device_b\"", "1", None, Some("root")));
+        blocks.insert("b2".to_string(), create_test_block("b2", "para", "device_b", "2", None, Some("root")));
+
+        let sorted = ChainSorting::sort_blocks_by_chain(&blocks);
+        assert_eq!(sorted["root"], vec!["a1", "b1", "b2"]);
+    }
+
+    /// Orders to insert the blocks of one scenario in: as listed, reversed, every rotation,
+    /// and even positions before odd ones and the other way round
+    fn permutations(blocks: &[BlockDoc]) -> Vec<Vec<&BlockDoc>> {
+        let listed: Vec<&BlockDoc> = blocks.iter().collect();
+        let mut permutations = vec![listed.iter().rev().copied().collect()];
+        for shift in 1..listed.len() {
+            let mut rotated = listed.clone();
+            rotated.rotate_left(shift);
+            permutations.push(rotated);
+        }
+        let (even, odd): (Vec<_>, Vec<_>) = listed.iter().copied().enumerate().partition(|(i, _)| i % 2 == 0);
+        permutations.push(even.iter().chain(&odd).map(|(_, block)| *block).collect());
+        permutations.push(odd.iter().chain(&even).map(|(_, block)| *block).collect());
+        permutations.insert(0, listed);
+        permutations
+    }
+
+    fn assert_converges(blocks: &[BlockDoc]) -> HashMap<String, Vec<String>> {
+        let sort = |order: &[&BlockDoc]| {
+            let blocks: HashMap<String, BlockDoc> = order.iter().map(|block| (block.id.clone(), (*block).clone())).collect();
+            ChainSorting::sort_blocks_by_chain(&blocks)
+        };
+        let permutations = permutations(blocks);
+        let expected = sort(&permutations[0]);
+        for (i, order) in permutations.iter().enumerate().skip(1) {
+            assert_eq!(sort(order), expected, "permutation {}", i);
+        }
+        expected
+    }
+
+    #[test]
+    fn test_convergence_concurrent_inserts_after_same_prev() {
+        let blocks = vec![
+            create_test_block("a", "para", "device_a", "1", None, Some("root")),
+            create_test_block("z", "para", "device_b", "5", Some("a"), Some("root")),
+            create_test_block("y", "para", "device_c", "5", Some("a"), Some("root")),
+            create_test_block("x", "para", "device_a", "5", Some("a"), Some("root")),
+            create_test_block("w", "para", "device_a", "6", Some("a"), Some("root")),
+        ];
+        let sorted = assert_converges(&blocks);
+        // Equal timestamps fall back to the block id
+        assert_eq!(sorted["root"], vec!["a", "x", "y", "z", "w"]);
+    }
+
+    #[test]
+    fn test_convergence_cycles_and_dangling_prev_ids() {
+        let blocks = vec![
+            create_test_block("root_block", "para", "device_a", "1", None, Some("root")),
+            create_test_block("c1", "para", "device_a", "2", Some("c3"), Some("root")),
+            create_test_block("c2", "para", "device_a", "3", Some("c1"), Some("root")),
+            create_test_block("c3", "para", "device_a", "4", Some("c2"), Some("root")),
+            create_test_block("d1", "para", "device_b", "2", Some("gone"), Some("root")),
+            create_test_block("d2", "para", "device_b", "2", Some("also_gone"), Some("root")),
+            create_test_block("d3", "para", "device_b", "3", Some("d1"), Some("root")),
+            create_test_block("self", "para", "device_c", "1", Some("self"), Some("root")),
+        ];
+        let sorted = assert_converges(&blocks);
+        assert_eq!(
+            sorted["root"],
+            vec!["root_block", "c1", "c2", "c3", "d1", "d3", "d2", "self"]
+        );
+    }
+
+    #[test]
+    fn test_convergence_mixed_timestamps() {
+        let mut blocks = vec![
+            create_test_block("ten", "para", "device_a", "10", None, Some("root")),
+            create_test_block("nine", "para", "device_a", "9", None, Some("root")),
+            create_test_block("text", "para", "device_a", "yesterday", None, Some("root")),
+            create_test_block("quoted", "para", "device_a", "\"8\"", None, Some("root")),
+            create_test_block("missing", "para", "device_a", "", None, Some("root")),
+        ];
+        blocks[4].attributes.remove("timestamp");
+        let sorted = assert_converges(&blocks);
+        // Numbers compare as numbers, not as strings
+        assert_eq!(sorted["root"], vec!["missing", "quoted", "nine", "ten", "text"]);
+    }
+
+    #[test]
+    fn test_convergence_nested_parents_and_devices() {
+        let mut blocks = Vec::new();
+        for parent in ["p1", "p2", "p3"] {
+            for (i, device) in ["device_b", "device_a", "device_c"].iter().enumerate() {
+                let id = format!("{}_{}", parent, i);
+                let prev = (i > 0).then(|| format!("{}_{}", parent, i - 1));
+                blocks.push(create_test_block(&id, "para", device, "7", prev.as_deref(), Some(parent)));
+                // A concurrent insert after the same prev from another device
+                let twin = format!("{}_{}_twin", parent, i);
+                blocks.push(create_test_block(&twin, "para", "device_d", "7", prev.as_deref(), Some(parent)));
+            }
+        }
+        blocks.push(create_test_block("no_parent", "page", "device_a", "1", None, None));
+
+        let sorted = assert_converges(&blocks);
+        assert_eq!(sorted["root"], vec!["no_parent"]);
+        for parent in ["p1", "p2", "p3"] {
+            assert_eq!(sorted[parent].len(), 6);
+        }
+    }
 }